@@ -55,5 +55,16 @@ table_bench!(
 
 table_bench!(big_table, { [0; 16] });
 
-criterion_group!(benches, small_table, big_table, small_table_stylish);
+table_bench!(big_table_blank, { [0; 16] }, Style::blank());
+
+table_bench!(big_table_psql, { [0; 16] }, Style::psql());
+
+criterion_group!(
+    benches,
+    small_table,
+    big_table,
+    big_table_blank,
+    big_table_psql,
+    small_table_stylish
+);
 criterion_main!(benches);
@@ -0,0 +1,89 @@
+//! Measures building of large tables,
+//! where measuring widths of cells takes most of the time.
+
+use std::iter::FromIterator;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use tabled::{builder::Builder, format::Format, object::Segment, Modify, Width};
+
+pub fn wide_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_table");
+    group.sample_size(10);
+
+    for (count_rows, count_columns) in [(1_000, 100), (10_000, 100), (10_000, 300)] {
+        let id = format!("{}x{}", count_rows, count_columns);
+        let data = build_data(count_rows, count_columns);
+
+        group.bench_with_input(BenchmarkId::new("build", &id), &data, |b, data| {
+            b.iter(|| black_box(Builder::from(data.clone()).build()));
+        });
+
+        group.bench_with_input(BenchmarkId::new("from_iter", &id), &data, |b, data| {
+            b.iter(|| {
+                black_box(
+                    Builder::from_iter(data.iter().map(|row| row.iter().map(String::as_str)))
+                        .build(),
+                )
+            });
+        });
+    }
+
+    group.finish();
+}
+
+pub fn wide_table_to_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_table_to_string");
+    group.sample_size(10);
+
+    for (count_rows, count_columns) in [(1_000, 100), (10_000, 100)] {
+        let id = format!("{}x{}", count_rows, count_columns);
+        let data = build_data(count_rows, count_columns);
+
+        group.bench_with_input(BenchmarkId::from_parameter(id), &data, |b, data| {
+            b.iter(|| black_box(Builder::from(data.clone()).build().to_string()));
+        });
+    }
+
+    group.finish();
+}
+
+pub fn wide_table_wrap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_table_wrap");
+    group.sample_size(10);
+
+    for (count_rows, count_columns) in [(1_000, 100), (10_000, 100)] {
+        let id = format!("{}x{}", count_rows, count_columns);
+        let data = build_data(count_rows, count_columns);
+
+        group.bench_with_input(BenchmarkId::from_parameter(id), &data, |b, data| {
+            b.iter(|| {
+                let mut table = Builder::from(data.clone()).build();
+                table
+                    .with(Modify::new(Segment::all()).with(Format::new(|s| s.to_uppercase())))
+                    .with(Width::wrap(count_columns * 15));
+                black_box(table.to_string())
+            });
+        });
+    }
+
+    group.finish();
+}
+
+// Cells are multiline, have wide characters and ANSI sequences,
+// so measuring them is not trivial.
+fn build_data(count_rows: usize, count_columns: usize) -> Vec<Vec<String>> {
+    (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|col| match (row + col) % 3 {
+                    0 => format!("cell {}-{}", row, col),
+                    1 => format!("\u{1b}[31m{}\u{1b}[39m\n値 {}", row, col),
+                    _ => format!("line 1\nline 2 {}\nline 3 {}", row, col),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+criterion_group!(benches, wide_table, wide_table_to_string, wide_table_wrap);
+criterion_main!(benches);
@@ -19,6 +19,7 @@ impl JsonTable<'_> {
             value,
             cfg: Config {
                 plain: true,
+                flatten: false,
                 style: None,
                 cfg: None,
                 array_orientation: Orientation::Vertical,
@@ -43,6 +44,44 @@ impl<'val, ModeVisitor> JsonTable<'val, ModeVisitor> {
         self
     }
 
+    /// Flatten nested objects and arrays into columns named by a dot separated path.
+    ///
+    /// An array on the top level is rendered as a list of rows,
+    /// any other value as a single row.
+    ///
+    /// It takes precedence over [`JsonTable::collapse`] and table modes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    ///
+    /// let value = json!([
+    ///     { "name": "Aleix Melon", "role": ["Dev", "DBA"], "address": { "city": "Innsbruck" } },
+    ///     { "name": "Bob Smith", "role": ["Dev"] },
+    /// ]);
+    ///
+    /// let table = json_to_table(&value).flatten().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------------+-------------+--------+--------+\n",
+    ///         "| address.city | name        | role.0 | role.1 |\n",
+    ///         "+--------------+-------------+--------+--------+\n",
+    ///         "| Innsbruck    | Aleix Melon | Dev    | DBA    |\n",
+    ///         "+--------------+-------------+--------+--------+\n",
+    ///         "|              | Bob Smith   | Dev    |        |\n",
+    ///         "+--------------+-------------+--------+--------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn flatten(&mut self) -> &mut Self {
+        self.cfg.flatten = true;
+        self
+    }
+
     /// Set a table mode for a [`serde_json::Value::Object`].
     ///
     /// BE AWARE: The setting works only in not collapsed mode.
@@ -157,6 +196,7 @@ where
 #[derive(Debug, Clone)]
 struct Config {
     plain: bool,
+    flatten: bool,
     style: Option<RawStyle>,
     cfg: Option<GridConfig>,
     object_orientation: Orientation,
@@ -176,7 +216,7 @@ pub enum Orientation {
 mod json_to_table {
     #![allow(clippy::too_many_arguments)]
 
-    use std::cmp;
+    use std::{cmp, collections::HashMap};
 
     use tabled::{
         builder::Builder,
@@ -195,13 +235,85 @@ mod json_to_table {
     where
         F: FnMut(&Value) -> Orientation,
     {
-        if cfg.plain {
+        if cfg.flatten {
+            json_to_table_flat(value, cfg)
+        } else if cfg.plain {
             json_to_table_f(value, cfg, &mut mode_visitor, true)
         } else {
             json_to_table_r(value, cfg, 0, 0, true, true, false, false, &[], None)
         }
     }
 
+    fn json_to_table_flat(value: &Value, config: &Config) -> Table {
+        let rows = match value {
+            Value::Array(arr) => arr.iter().collect(),
+            value => vec![value],
+        };
+
+        let mut columns: Vec<String> = Vec::new();
+        let mut column_index: HashMap<String, usize> = HashMap::new();
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut fields = Vec::new();
+            flatten_value(row, String::new(), &mut fields);
+
+            let mut record = Vec::new();
+            for (path, text) in fields {
+                let col = *column_index.entry(path).or_insert_with_key(|path| {
+                    columns.push(path.clone());
+                    columns.len() - 1
+                });
+
+                if record.len() <= col {
+                    record.resize(col + 1, String::new());
+                }
+
+                record[col] = text;
+            }
+
+            records.push(record);
+        }
+
+        let mut builder = Builder::new();
+        builder.hint_column_size(columns.len());
+        builder.set_columns(columns);
+        for record in records {
+            builder.add_record(record);
+        }
+
+        let mut table = builder.build();
+        set_table_style(&mut table, config);
+
+        table
+    }
+
+    fn flatten_value(value: &Value, path: String, fields: &mut Vec<(String, String)>) {
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                for (key, value) in map {
+                    flatten_value(value, join_path(&path, key), fields);
+                }
+            }
+            Value::Array(arr) if !arr.is_empty() => {
+                for (i, value) in arr.iter().enumerate() {
+                    flatten_value(value, join_path(&path, &i.to_string()), fields);
+                }
+            }
+            Value::String(text) => fields.push((path, text.clone())),
+            Value::Bool(val) => fields.push((path, val.to_string())),
+            Value::Number(num) => fields.push((path, num.to_string())),
+            Value::Null | Value::Object(_) | Value::Array(_) => fields.push((path, String::new())),
+        }
+    }
+
+    fn join_path(path: &str, key: &str) -> String {
+        if path.is_empty() {
+            key.to_owned()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    }
+
     fn json_to_table_f<F>(
         v: &Value,
         config: &Config,
@@ -0,0 +1,107 @@
+use json_to_table::json_to_table;
+use serde_json::json;
+use tabled::{Style, Table};
+
+#[test]
+fn flatten_object_test() {
+    let value = json!(
+        {
+            "name": "John Doe",
+            "age": 43,
+            "address": {
+                "street": "10 Downing Street",
+                "city": "London"
+            },
+            "phones": ["+44 1234567", "+44 2345678"],
+            "spouse": null,
+            "tags": [],
+        }
+    );
+
+    let table = json_to_table(&value)
+        .set_style(Style::psql())
+        .flatten()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " address.city | address.street    | age | name     | phones.0    | phones.1    | spouse | tags \n",
+            "--------------+-------------------+-----+----------+-------------+-------------+--------+------\n",
+            " London       | 10 Downing Street | 43  | John Doe | +44 1234567 | +44 2345678 |        |      ",
+        )
+    );
+}
+
+#[test]
+fn flatten_array_of_objects_test() {
+    let value = json!(
+        [
+            { "id": 1, "meta": { "ok": true } },
+            { "id": 2, "meta": { "ok": false, "reason": "timeout" } },
+            { "extra": [[1, 2]] },
+        ]
+    );
+
+    let table = json_to_table(&value)
+        .set_style(Style::psql())
+        .flatten()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " id | meta.ok | meta.reason | extra.0.0 | extra.0.1 \n",
+            "----+---------+-------------+-----------+-----------\n",
+            " 1  | true    |             |           |           \n",
+            " 2  | false   | timeout     |           |           \n",
+            "    |         |             | 1         | 2         ",
+        )
+    );
+}
+
+#[test]
+fn flatten_array_of_scalars_test() {
+    let value = json!([1, "two", null]);
+
+    let table = json_to_table(&value)
+        .set_style(Style::psql())
+        .flatten()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!("     \n", "-----\n", " 1   \n", " two \n", "     ",)
+    );
+}
+
+#[test]
+fn flatten_empty_array_test() {
+    let value = json!([]);
+
+    let table = json_to_table(&value).flatten().to_string();
+
+    assert_eq!(table, "");
+}
+
+#[test]
+fn flatten_into_table_test() {
+    let value = json!({ "a": { "b": 1 } });
+
+    let mut json = json_to_table(&value);
+    json.flatten();
+
+    let table = Table::from(json);
+
+    assert_eq!(table.shape(), (2, 1));
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+-----+\n",
+            "| a.b |\n",
+            "+-----+\n",
+            "| 1   |\n",
+            "+-----+",
+        )
+    );
+}
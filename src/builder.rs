@@ -109,19 +109,25 @@
 //! )
 //! ```
 
-use std::{borrow::Cow, iter::FromIterator};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    iter::FromIterator,
+    panic::RefUnwindSafe,
+    sync::Arc,
+};
 
 use papergrid::{
     records::{
         cell_info::CellInfo,
         vec_records::{CellMut, VecRecords},
-        Records,
+        Records, RecordsMut,
     },
     width::{CfgWidthFunction, WidthFunc},
     AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding,
 };
 
-use crate::{Style, Table};
+use crate::{records::InternedRecords, Style, Table};
 
 /// Builder creates a [`Table`] from dynamic data set.
 ///
@@ -161,11 +167,26 @@ pub struct Builder<'a> {
     records: Vec<Vec<CellInfo<'a>>>,
     /// A columns row.
     columns: Option<Vec<CellInfo<'a>>>,
+    /// A list of header rows above a columns row.
+    header_rows: Vec<Vec<CellInfo<'a>>>,
     /// A number of columns.
     size: usize,
     different_column_sizes_used: bool,
     /// A content of cells which are created in case rows has different length.
     empty_cell_text: Option<String>,
+    /// A list of typed rows which are not formatted yet, with an index of a row they belong to.
+    /// Cells which are [`None`] are not typed.
+    typed_records: Vec<(usize, Vec<Option<TypedCell<'a>>>)>,
+    /// A list of number formats for each column.
+    number_formats: Vec<Option<NumberFormat>>,
+    /// A function which validates records before they are added.
+    validator: Option<RecordValidator>,
+    /// A number of records which were pushed, including the rejected ones.
+    count_pushed: usize,
+    /// A list of errors of rejected records.
+    errors: Vec<RecordError>,
+    /// A limit of a content of each cell.
+    cell_limit: Option<CellLimit>,
 }
 
 impl<'a> Builder<'a> {
@@ -197,7 +218,7 @@ impl<'a> Builder<'a> {
         T: Into<Cow<'a, str>>,
     {
         let ctrl = CfgWidthFunction::new(4);
-        let list = create_row(columns, self.size, &ctrl);
+        let list = create_row(columns, self.size, self.cell_limit.as_ref(), &ctrl);
 
         self.update_size(list.len());
         self.columns = Some(list);
@@ -205,6 +226,106 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Sets a [`Table`] header which consists of a few rows, given from the top one.
+    ///
+    /// The last row is used as columns (see [`Self::set_columns`]),
+    /// and the rows above it are groups of columns.
+    /// Adjacent cells of a group row with the same text are merged into a single spanned cell,
+    /// as long as they are in the same group of a row above.
+    /// An empty cell of a group row is merged with the first non empty cell below it.
+    ///
+    /// Group rows are not used by [`Self::index`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_header_rows(vec![
+    ///     vec!["", "price", "price"],
+    ///     vec!["item", "net", "gross"],
+    /// ]);
+    /// builder.add_record(["apple", "10", "12"]);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+-------+-----+-------+\n\
+    ///      | item  | price       |\n\
+    ///      +       +-----+-------+\n\
+    ///      |       | net | gross |\n\
+    ///      +-------+-----+-------+\n\
+    ///      | apple | 10  | 12    |\n\
+    ///      +-------+-----+-------+"
+    /// );
+    /// ```
+    pub fn set_header_rows<H, R, T>(&mut self, rows: H) -> &mut Self
+    where
+        H: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        let ctrl = CfgWidthFunction::new(4);
+        let mut rows = rows
+            .into_iter()
+            .map(|row| create_row(row, self.size, self.cell_limit.as_ref(), &ctrl))
+            .collect::<Vec<_>>();
+
+        let columns = match rows.pop() {
+            Some(columns) => columns,
+            None => return self.remove_columns(),
+        };
+
+        for row in rows.iter().chain(Some(&columns)) {
+            self.update_size(row.len());
+        }
+
+        // rows of a header may be shorter than others, which is not tracked by `update_size`.
+        if rows.iter().any(|row| row.len() != self.size) || columns.len() != self.size {
+            self.different_column_sizes_used = true;
+        }
+
+        self.header_rows = rows;
+        self.columns = Some(columns);
+
+        self
+    }
+
+    /// Changes a case of columns to a given [`CasingStyle`].
+    ///
+    /// It's useful when columns are aggregated from different sources.
+    /// It affects only columns which are already set.
+    ///
+    /// ```rust
+    /// use tabled::builder::{Builder, CasingStyle};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_columns(["First Name", "lastName", "AGE"])
+    ///     .normalize_headers(CasingStyle::Snake)
+    ///     .add_record(["John", "Doe", "43"]);
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------------+-----------+-----+\n\
+    ///      | first_name | last_name | age |\n\
+    ///      +------------+-----------+-----+\n\
+    ///      | John       | Doe       | 43  |\n\
+    ///      +------------+-----------+-----+"
+    /// );
+    /// ```
+    pub fn normalize_headers(&mut self, casing: CasingStyle) -> &mut Self {
+        if let Some(columns) = self.columns.as_mut() {
+            let ctrl = CfgWidthFunction::new(4);
+            for column in columns.iter_mut() {
+                let text = casing.cast(column.as_ref());
+                CellMut::set(column, text, &ctrl);
+            }
+        }
+
+        self
+    }
+
     /// Sets off a [`Table`] header.
     ///
     /// If not set its a nop.
@@ -244,6 +365,7 @@ impl<'a> Builder<'a> {
     /// ```
     pub fn remove_columns(&mut self) -> &mut Self {
         self.columns = None;
+        self.header_rows.clear();
         let size = self.get_size();
         self.size = size;
 
@@ -266,15 +388,447 @@ impl<'a> Builder<'a> {
         R: IntoIterator<Item = T>,
         T: Into<Cow<'a, str>>,
     {
+        if let Some(list) = self.create_record(row) {
+            self.update_size(list.len());
+            self.records.push(list);
+        }
+
+        self
+    }
+
+    /// Creates cells of a record, validating it by a function set via [`Self::on_push`].
+    ///
+    /// It returns [`None`] if a record was rejected.
+    fn create_record<R, T>(&mut self, row: R) -> Option<Vec<CellInfo<'a>>>
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        let index = self.count_pushed;
+        self.count_pushed += 1;
+
         let ctrl = CfgWidthFunction::new(4);
-        let list = create_row(row, self.size, &ctrl);
+        let list = match &self.validator {
+            Some(validator) => {
+                let mut cells = row
+                    .into_iter()
+                    .map(|text| text.into().into_owned())
+                    .collect::<Vec<_>>();
+
+                if let Err(message) = (validator.0)(&mut cells) {
+                    self.errors.push(RecordError::new(index, message));
+                    return None;
+                }
+
+                create_row(cells, self.size, self.cell_limit.as_ref(), &ctrl)
+            }
+            None => create_row(row, self.size, self.cell_limit.as_ref(), &ctrl),
+        };
+
+        Some(list)
+    }
+
+    /// Inserts a row to a [`Table`] at a given position, shifting all rows after it.
+    ///
+    /// The index doesn't count columns set via [`Self::set_columns`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is bigger than a number of records.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_columns(["name", "age"])
+    ///     .add_record(["Tom", "30"])
+    ///     .add_record(["Bob", "20"])
+    ///     .insert_record(1, ["Ann", "4"]);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+-----+\n\
+    ///      | name | age |\n\
+    ///      +------+-----+\n\
+    ///      | Tom  | 30  |\n\
+    ///      +------+-----+\n\
+    ///      | Ann  | 4   |\n\
+    ///      +------+-----+\n\
+    ///      | Bob  | 20  |\n\
+    ///      +------+-----+"
+    /// );
+    /// ```
+    pub fn insert_record<R, T>(&mut self, index: usize, row: R) -> &mut Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        let list = match self.create_record(row) {
+            Some(list) => list,
+            None => return self,
+        };
 
         self.update_size(list.len());
-        self.records.push(list);
+        self.records.insert(index, list);
+
+        for (row, _) in &mut self.typed_records {
+            if *row >= index {
+                *row += 1;
+            }
+        }
+
+        self
+    }
+
+    /// Removes a row of a [`Table`] at a given position, shifting all rows after it.
+    ///
+    /// The index doesn't count columns set via [`Self::set_columns`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_columns(["name", "age"])
+    ///     .add_record(["Tom", "30"])
+    ///     .add_record(["Bob", "20"])
+    ///     .remove_record(0);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+-----+\n\
+    ///      | name | age |\n\
+    ///      +------+-----+\n\
+    ///      | Bob  | 20  |\n\
+    ///      +------+-----+"
+    /// );
+    /// ```
+    pub fn remove_record(&mut self, index: usize) -> &mut Self {
+        self.records.remove(index);
+
+        self.typed_records.retain(|(row, _)| *row != index);
+        for (row, _) in &mut self.typed_records {
+            if *row > index {
+                *row -= 1;
+            }
+        }
 
         self
     }
 
+    /// Inserts a column to a [`Table`] at a given position, shifting all columns after it.
+    ///
+    /// A `header` is put into columns set via [`Self::set_columns`], and it's ignored if they are not set.
+    /// Records which have no value get a default text (see [`Self::set_default_text`]),
+    /// and values left after the last record are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is bigger than a number of columns.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_columns(["name", "age"])
+    ///     .add_record(["Tom", "30"])
+    ///     .add_record(["Ann", "4"])
+    ///     .insert_column(1, "surname", ["Smith"]);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+---------+-----+\n\
+    ///      | name | surname | age |\n\
+    ///      +------+---------+-----+\n\
+    ///      | Tom  | Smith   | 30  |\n\
+    ///      +------+---------+-----+\n\
+    ///      | Ann  |         | 4   |\n\
+    ///      +------+---------+-----+"
+    /// );
+    /// ```
+    pub fn insert_column<H, V, T>(&mut self, index: usize, header: H, values: V) -> &mut Self
+    where
+        H: Into<Cow<'a, str>>,
+        V: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        assert!(
+            index <= self.size,
+            "column index (is {}) should be <= number of columns (is {})",
+            index,
+            self.size
+        );
+
+        self.fix_rows();
+
+        let ctrl = CfgWidthFunction::new(4);
+        let text = self.empty_cell_text.clone().unwrap_or_default();
+        let empty_cell_text = CellInfo::new(text, &ctrl);
+
+        let limit = self.cell_limit.as_ref();
+
+        if let Some(columns) = self.columns.as_mut() {
+            columns.insert(index, CellInfo::new(limit_text(header, limit), &ctrl));
+        }
+
+        for row in &mut self.header_rows {
+            row.insert(index, empty_cell_text.clone());
+        }
+
+        let mut values = values.into_iter();
+        for row in &mut self.records {
+            let cell = match values.next() {
+                Some(text) => CellInfo::new(limit_text(text, limit), &ctrl),
+                None => empty_cell_text.clone(),
+            };
+
+            row.insert(index, cell);
+        }
+
+        for (_, cells) in &mut self.typed_records {
+            if cells.len() >= index {
+                cells.insert(index, None);
+            }
+        }
+
+        if self.number_formats.len() > index {
+            self.number_formats.insert(index, None);
+        }
+
+        self.size += 1;
+
+        self
+    }
+
+    /// Removes a column of a [`Table`] at a given position, shifting all columns after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_columns(["name", "surname", "age"])
+    ///     .add_record(["Tom", "Smith", "30"])
+    ///     .remove_column(1);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+-----+\n\
+    ///      | name | age |\n\
+    ///      +------+-----+\n\
+    ///      | Tom  | 30  |\n\
+    ///      +------+-----+"
+    /// );
+    /// ```
+    pub fn remove_column(&mut self, index: usize) -> &mut Self {
+        assert!(
+            index < self.size,
+            "column index (is {}) should be < number of columns (is {})",
+            index,
+            self.size
+        );
+
+        self.fix_rows();
+
+        if let Some(columns) = self.columns.as_mut() {
+            columns.remove(index);
+        }
+
+        for row in self.header_rows.iter_mut().chain(&mut self.records) {
+            row.remove(index);
+        }
+
+        for (_, cells) in &mut self.typed_records {
+            if cells.len() > index {
+                cells.remove(index);
+            }
+        }
+
+        if self.number_formats.len() > index {
+            self.number_formats.remove(index);
+        }
+
+        self.size -= 1;
+
+        self
+    }
+
+    /// Sets a function which validates each record added by [`Self::add_record`],
+    /// [`Self::insert_record`] and [`Self::push_record_typed`].
+    ///
+    /// The function may change the cells of a record to fix it.
+    /// If it returns an error the record is not added,
+    /// and the error is kept so it can be checked via [`Self::errors`] or [`Self::try_build`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name", "age"]);
+    /// builder.on_push(|cells| {
+    ///     cells.resize(2, String::new());
+    ///     match cells[1].parse::<u8>() {
+    ///         Ok(_) => Ok(()),
+    ///         Err(_) => Err(format!("age {:?} is not a number", cells[1])),
+    ///     }
+    /// });
+    /// builder.add_record(["Tom", "30"]);
+    /// builder.add_record(["Ann", "???"]);
+    ///
+    /// let errors = builder.try_build().unwrap_err();
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].to_string(), "record 1: age \"???\" is not a number");
+    /// ```
+    pub fn on_push<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&mut Vec<String>) -> Result<(), String> + Send + Sync + RefUnwindSafe + 'static,
+    {
+        self.validator = Some(RecordValidator(Arc::new(f)));
+        self
+    }
+
+    /// Returns errors of records rejected by a function set via [`Self::on_push`].
+    pub fn errors(&self) -> &[RecordError] {
+        &self.errors
+    }
+
+    /// Adds a row of typed values to a [`Table`].
+    ///
+    /// Unlike [`Self::add_record`] the values are not converted to strings right away.
+    /// They are kept as they are and formatted by [`Display`] when a table is built,
+    /// so a [`NumberFormat`] of a column is applied regardless of when it was set.
+    /// Values may be borrowed for as long as the [`Builder`] lives.
+    ///
+    /// A function set via [`Self::on_push`] gets the values as strings.
+    /// If it changes a value, the value is kept as a string and it's not formatted.
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
+    /// ```rust
+    /// use tabled::builder::{Builder, NumberFormat};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["id", "name", "price"]);
+    /// builder.push_record_typed((1u32, "apple", 3.5));
+    /// builder.push_record_typed((2u32, "melon", 1234.125));
+    /// builder.set_number_format(2, NumberFormat::new().precision(2).thousands_separator(','));
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+----+-------+----------+\n\
+    ///      | id | name  | price    |\n\
+    ///      +----+-------+----------+\n\
+    ///      | 1  | apple | 3.50     |\n\
+    ///      +----+-------+----------+\n\
+    ///      | 2  | melon | 1,234.12 |\n\
+    ///      +----+-------+----------+"
+    /// );
+    /// ```
+    pub fn push_record_typed<R>(&mut self, row: R) -> &mut Self
+    where
+        R: TypedRecord<'a>,
+    {
+        let mut row = row.into_typed_cells();
+
+        let index = self.count_pushed;
+        self.count_pushed += 1;
+
+        if let Some(validator) = &self.validator {
+            let mut texts = row.iter().map(|cell| cell.value.to_string()).collect();
+            if let Err(message) = (validator.0)(&mut texts) {
+                self.errors.push(RecordError::new(index, message));
+                return self;
+            }
+
+            let mut cells = row.into_iter();
+            row = texts
+                .into_iter()
+                .map(|text| match cells.next() {
+                    Some(cell) if cell.value.to_string() == text => cell,
+                    _ => TypedCell::new(text),
+                })
+                .collect();
+        }
+
+        self.update_size(row.len());
+        self.records.push(vec![CellInfo::default(); row.len()]);
+        self.typed_records
+            .push((self.records.len() - 1, row.into_iter().map(Some).collect()));
+
+        self
+    }
+
+    /// Sets a [`NumberFormat`] of a column.
+    ///
+    /// It's applied to numeric values added by [`Self::push_record_typed`].
+    pub fn set_number_format(&mut self, column: usize, format: NumberFormat) -> &mut Self {
+        if self.number_formats.len() <= column {
+            self.number_formats.resize(column + 1, None);
+        }
+
+        self.number_formats[column] = Some(format);
+        self
+    }
+
+    /// Sets a [`CellLimit`] which caps a content of each cell.
+    ///
+    /// Cells are capped when they are added,
+    /// before they are measured,
+    /// so a single giant cell (like a dump or a stack trace) doesn't slow down
+    /// a measurement and a wrapping of a whole table.
+    /// Cells which are already added are capped as well.
+    ///
+    /// ```rust
+    /// use tabled::builder::{Builder, CellLimit};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_cell_limit(CellLimit::new().bytes(8));
+    /// builder.set_columns(["id", "message"]);
+    /// builder.add_record(["1", "Hello World!"]);
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+----+--------------------------+\n\
+    ///      | id | message                  |\n\
+    ///      +----+--------------------------+\n\
+    ///      | 1  | Hello Wo [truncated 4 B] |\n\
+    ///      +----+--------------------------+"
+    /// );
+    /// ```
+    pub fn set_cell_limit(&mut self, limit: CellLimit) -> &mut Self {
+        let ctrl = CfgWidthFunction::new(4);
+        let rows = self
+            .header_rows
+            .iter_mut()
+            .chain(self.columns.iter_mut())
+            .chain(self.records.iter_mut());
+        for cell in rows.flatten() {
+            let text = match limit.apply(cell.as_ref()) {
+                Cow::Owned(text) => text,
+                Cow::Borrowed(_) => continue,
+            };
+
+            CellMut::set(cell, text, &ctrl);
+        }
+
+        self.cell_limit = Some(limit);
+        self
+    }
+
     /// Sets a content of cells which are created in case rows has different length.
     ///
     ///
@@ -304,13 +858,68 @@ impl<'a> Builder<'a> {
     /// builder.add_record(["0", "value1", "value2"]);
     /// ```
     pub fn build(mut self) -> Table<VecRecords<CellInfo<'a>>> {
+        self.format_typed_records();
+
+        if self.different_column_sizes_used {
+            self.fix_rows();
+        }
+
+        let count_header_rows = self.count_header_rows();
+        let records = build_grid(self.header_rows, self.records, self.columns, self.size);
+
+        let mut table = build_table(records, count_header_rows);
+        span_header_rows(&mut table, count_header_rows);
+
+        table
+    }
+
+    /// Build creates a [`Table`] instance
+    /// unless some records were rejected by a function set via [`Self::on_push`],
+    /// in which case all the errors are returned.
+    pub fn try_build(self) -> Result<Table<VecRecords<CellInfo<'a>>>, Vec<RecordError>> {
+        if self.errors.is_empty() {
+            Ok(self.build())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Build creates a [`Table`] instance backed by [`InternedRecords`].
+    ///
+    /// Identical cells share a single allocation,
+    /// which is useful for large tables with many repeated values.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name", "status"]);
+    /// builder.add_record(["a", "ok"]);
+    /// builder.add_record(["b", "ok"]);
+    ///
+    /// let table = builder.build_interned();
+    /// assert_eq!(table.get_records().count_unique(), 5);
+    /// ```
+    pub fn build_interned(mut self) -> Table<InternedRecords> {
+        self.format_typed_records();
+
         if self.different_column_sizes_used {
             self.fix_rows();
         }
 
-        let has_columns = self.columns.is_some();
-        let records = build_grid(self.records, self.columns, self.size);
-        build_table(records, has_columns)
+        let count_header_rows = self.count_header_rows();
+        let records = self
+            .header_rows
+            .iter()
+            .chain(&self.columns)
+            .chain(&self.records)
+            .map(|row| row.iter().map(AsRef::as_ref))
+            .collect::<InternedRecords>();
+
+        let mut table = build_table(records, count_header_rows);
+        span_header_rows(&mut table, count_header_rows);
+
+        table
     }
 
     /// Add an index to the [`Table`].
@@ -337,7 +946,8 @@ impl<'a> Builder<'a> {
     ///      +---+-------+"
     /// )
     /// ```
-    pub fn index(self) -> IndexBuilder<'a> {
+    pub fn index(mut self) -> IndexBuilder<'a> {
+        self.format_typed_records();
         IndexBuilder::new(self)
     }
 
@@ -365,11 +975,45 @@ impl<'a> Builder<'a> {
     /// )
     /// ```
     pub fn clean(&mut self) -> &mut Self {
+        self.format_typed_records();
         self.clean_columns();
         self.clean_rows();
         self
     }
 
+    /// Creates a [`Builder`] from a comma separated data, using the first row as columns.
+    ///
+    /// Rows with a different number of fields are padded.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let builder = Builder::from_csv("a,b,c\n1,2\n3".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+---+---+---+\n\
+    ///      | a | b | c |\n\
+    ///      +---+---+---+\n\
+    ///      | 1 | 2 |   |\n\
+    ///      +---+---+---+\n\
+    ///      | 3 |   |   |\n\
+    ///      +---+---+---+"
+    /// );
+    /// ```
+    ///
+    /// See [`CsvReader`] for more options.
+    ///
+    /// [`CsvReader`]: crate::csv::CsvReader
+    #[cfg(feature = "csv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn from_csv<R>(reader: R) -> std::io::Result<Builder<'static>>
+    where
+        R: std::io::Read,
+    {
+        crate::csv::CsvReader::new().read(reader)
+    }
+
     /// Creates a Builder from a built [`Records`]
     ///
     /// [`Records`]: papergrid::records::Records
@@ -377,6 +1021,22 @@ impl<'a> Builder<'a> {
         CustomRecords::new(records)
     }
 
+    fn format_typed_records(&mut self) {
+        let ctrl = CfgWidthFunction::new(4);
+        for (row, cells) in std::mem::take(&mut self.typed_records) {
+            for (col, cell) in cells.into_iter().enumerate() {
+                let cell = match cell {
+                    Some(cell) => cell,
+                    None => continue,
+                };
+
+                let format = self.number_formats.get(col).and_then(Option::as_ref);
+                let text = limit_text(cell.format(format), self.cell_limit.as_ref());
+                self.records[row][col] = CellInfo::new(text, &ctrl);
+            }
+        }
+    }
+
     fn clean_columns(&mut self) {
         let mut i = 0;
         for col in 0..self.size {
@@ -395,7 +1055,8 @@ impl<'a> Builder<'a> {
                     self.records[row].remove(col);
                 }
 
-                if let Some(columns) = self.columns.as_mut() {
+                let header = self.header_rows.iter_mut().chain(self.columns.as_mut());
+                for columns in header {
                     if columns.len() > col {
                         columns.remove(col);
                     }
@@ -428,6 +1089,14 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Returns an amount of header rows, including groups set by [`Self::set_header_rows`].
+    fn count_header_rows(&self) -> usize {
+        match self.columns {
+            Some(_) => self.header_rows.len() + 1,
+            None => 0,
+        }
+    }
+
     fn update_size(&mut self, size: usize) {
         match size.cmp(&self.size) {
             std::cmp::Ordering::Less => {
@@ -448,6 +1117,11 @@ impl<'a> Builder<'a> {
 
     fn get_size(&mut self) -> usize {
         let mut max = self.columns.as_ref().map_or(0, Vec::len);
+        max = self
+            .header_rows
+            .iter()
+            .map(Vec::len)
+            .fold(max, std::cmp::max);
 
         let max_records = self.records.iter().map(Vec::len).max().unwrap_or(0);
 
@@ -461,7 +1135,7 @@ impl<'a> Builder<'a> {
         let text = self.empty_cell_text.clone().unwrap_or_default();
         let empty_cell_text = CellInfo::new(text, &ctrl);
 
-        if let Some(header) = self.columns.as_mut() {
+        for header in self.header_rows.iter_mut().chain(self.columns.as_mut()) {
             if self.size > header.len() {
                 append_vec(header, self.size - header.len(), &empty_cell_text);
             }
@@ -589,6 +1263,7 @@ impl<'a> IndexBuilder<'a> {
     /// ```
     fn new(mut b: Builder<'a>) -> Self {
         let index = build_range_index(b.records.len());
+        b.header_rows.clear();
 
         if b.columns.is_none() {
             b.columns = Some(build_range_index(b.size));
@@ -796,10 +1471,528 @@ where
     ///
     /// [`Records`]: papergrid::records::Records
     pub fn build(self) -> Table<R> {
-        build_table(self.records, self.has_header)
+        build_table(self.records, self.has_header as usize)
+    }
+}
+
+/// TableDef is a schema of a [`Table`] which is defined once and used to render any number of row sets.
+///
+/// It keeps names, alignment and formatting of columns,
+/// so tables of the same shape don't repeat their layout next to the data.
+///
+/// Cells of a row are matched to columns by their order,
+/// extra cells are dropped and missing ones are left empty.
+///
+/// ```rust
+/// use tabled::{alignment::AlignmentHorizontal, builder::TableDef, Style};
+///
+/// fn fmt_bytes(size: &str) -> String {
+///     format!("{} B", size)
+/// }
+///
+/// let def = TableDef::new()
+///     .column("name", AlignmentHorizontal::Left)
+///     .column_with("size", AlignmentHorizontal::Right, fmt_bytes);
+///
+/// let mut table = def.build([["Cargo.toml", "1024"], ["LICENSE", "64"]]);
+/// table.with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " name       |   size ",
+///         "\n------------+--------",
+///         "\n Cargo.toml | 1024 B ",
+///         "\n LICENSE    |   64 B ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TableDef {
+    columns: Vec<ColumnDef>,
+}
+
+#[derive(Debug, Clone)]
+struct ColumnDef {
+    name: String,
+    alignment: AlignmentHorizontal,
+    format: Option<CellFormatter>,
+}
+
+type FormatFn = dyn Fn(&str) -> String + Send + Sync;
+
+#[derive(Clone)]
+struct CellFormatter(Arc<FormatFn>);
+
+impl fmt::Debug for CellFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CellFormatter").finish()
+    }
+}
+
+impl TableDef {
+    /// Creates a [`TableDef`] without columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a column with a given name and alignment.
+    pub fn column<N>(mut self, name: N, alignment: AlignmentHorizontal) -> Self
+    where
+        N: Into<String>,
+    {
+        self.columns.push(ColumnDef {
+            name: name.into(),
+            alignment,
+            format: None,
+        });
+
+        self
+    }
+
+    /// Adds a column with a given name and alignment,
+    /// which content is changed by a given function.
+    pub fn column_with<N, F>(mut self, name: N, alignment: AlignmentHorizontal, f: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.columns.push(ColumnDef {
+            name: name.into(),
+            alignment,
+            format: Some(CellFormatter(Arc::new(f))),
+        });
+
+        self
+    }
+
+    /// Returns a number of columns.
+    pub fn count_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Builds a [`Table`] out of rows according to the definition.
+    pub fn build<I, R, T>(&self, rows: I) -> Table
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut builder = Builder::default();
+        builder.set_columns(self.columns.iter().map(|column| column.name.clone()));
+
+        for row in rows {
+            let mut row = row
+                .into_iter()
+                .zip(&self.columns)
+                .map(|(text, column)| match &column.format {
+                    Some(format) => (format.0)(text.as_ref()),
+                    None => text.as_ref().to_owned(),
+                })
+                .collect::<Vec<_>>();
+            row.resize(self.columns.len(), String::new());
+
+            builder.add_record(row);
+        }
+
+        let mut table = builder.build();
+        for (col, column) in self.columns.iter().enumerate() {
+            table
+                .get_config_mut()
+                .set_alignment_horizontal(Entity::Column(col), column.alignment);
+        }
+
+        table
+    }
+}
+
+/// A value of a cell added by [`Builder::push_record_typed`].
+///
+/// It keeps the value as is until a [`Table`] is built,
+/// then it's formatted by [`Display`] and a [`NumberFormat`] of its column.
+///
+/// A value is formatted as a number if it's printed as one.
+/// It's considered to be a float, like [`f64`], if it takes a precision into account,
+/// otherwise it's considered to be an integer.
+#[derive(Clone)]
+pub struct TypedCell<'a> {
+    value: Arc<dyn Display + Send + Sync + RefUnwindSafe + 'a>,
+}
+
+impl<'a> TypedCell<'a> {
+    /// Creates a new [`TypedCell`] from a value.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Display + Send + Sync + RefUnwindSafe + 'a,
+    {
+        Self {
+            value: Arc::new(value),
+        }
+    }
+
+    fn format(&self, format: Option<&NumberFormat>) -> String {
+        let text = self.value.to_string();
+        match format {
+            Some(format) => format.format_value(&*self.value, text),
+            None => text,
+        }
+    }
+}
+
+impl fmt::Debug for TypedCell<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedCell")
+            .field("value", &self.value.to_string())
+            .finish()
+    }
+}
+
+/// A row which can be added by [`Builder::push_record_typed`].
+///
+/// It's implemented for tuples of up to 12 elements.
+pub trait TypedRecord<'a> {
+    /// Converts a row into a list of cells.
+    fn into_typed_cells(self) -> Vec<TypedCell<'a>>;
+}
+
+impl<'a> TypedRecord<'a> for Vec<TypedCell<'a>> {
+    fn into_typed_cells(self) -> Vec<TypedCell<'a>> {
+        self
+    }
+}
+
+macro_rules! impl_typed_record_for_tuple {
+    ( $($name:ident)+ ) => {
+        impl<'a, $($name),+> TypedRecord<'a> for ($($name,)+)
+        where
+            $($name: Display + Send + Sync + RefUnwindSafe + 'a),+
+        {
+            #[allow(non_snake_case)]
+            fn into_typed_cells(self) -> Vec<TypedCell<'a>> {
+                let ($($name,)+) = self;
+                vec![$(TypedCell::new($name)),+]
+            }
+        }
+    };
+}
+
+impl_typed_record_for_tuple! { A }
+impl_typed_record_for_tuple! { A B }
+impl_typed_record_for_tuple! { A B C }
+impl_typed_record_for_tuple! { A B C D }
+impl_typed_record_for_tuple! { A B C D E }
+impl_typed_record_for_tuple! { A B C D E F }
+impl_typed_record_for_tuple! { A B C D E F G }
+impl_typed_record_for_tuple! { A B C D E F G H }
+impl_typed_record_for_tuple! { A B C D E F G H I }
+impl_typed_record_for_tuple! { A B C D E F G H I J }
+impl_typed_record_for_tuple! { A B C D E F G H I J K }
+impl_typed_record_for_tuple! { A B C D E F G H I J K L }
+
+/// CasingStyle defines a case of a text.
+///
+/// It's used by [`Builder::normalize_headers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasingStyle {
+    /// Indicate word boundaries with uppercase letter, excluding the first word.
+    Camel,
+    /// Keep all letters lowercase and indicate word boundaries with hyphens.
+    Kebab,
+    /// Indicate word boundaries with uppercase letter, including the first word.
+    Pascal,
+    /// Keep all letters uppercase and indicate word boundaries with underscores.
+    ScreamingSnake,
+    /// Keep all letters lowercase and indicate word boundaries with underscores.
+    Snake,
+    /// Keep all letters lowercase and remove word boundaries.
+    Lower,
+    /// Keep all letters uppercase and remove word boundaries.
+    Upper,
+}
+
+impl CasingStyle {
+    /// Converts a text to the case.
+    pub fn cast(self, text: &str) -> String {
+        use heck::{
+            ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase,
+        };
+
+        match self {
+            CasingStyle::Camel => text.to_lower_camel_case(),
+            CasingStyle::Kebab => text.to_kebab_case(),
+            CasingStyle::Pascal => text.to_upper_camel_case(),
+            CasingStyle::ScreamingSnake => text.to_shouty_snake_case(),
+            CasingStyle::Snake => text.to_snake_case(),
+            CasingStyle::Lower => text.to_snake_case().replace('_', ""),
+            CasingStyle::Upper => text.to_shouty_snake_case().replace('_', ""),
+        }
+    }
+}
+
+type ValidateFn = dyn Fn(&mut Vec<String>) -> Result<(), String> + Send + Sync + RefUnwindSafe;
+
+#[derive(Clone)]
+struct RecordValidator(Arc<ValidateFn>);
+
+impl fmt::Debug for RecordValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordValidator").finish()
     }
 }
 
+/// RecordError is an error of a record rejected by a function set via [`Builder::on_push`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordError {
+    row: usize,
+    message: String,
+}
+
+impl RecordError {
+    fn new(row: usize, message: String) -> Self {
+        Self { row, message }
+    }
+
+    /// Returns an index of a record among all pushed records, including the rejected ones.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns a message returned by a validation function.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "record {}: {}", self.row, self.message)
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+/// NumberFormat defines how numeric values of a column are formatted.
+///
+/// It's used by [`Builder::set_number_format`].
+///
+/// ```rust
+/// use tabled::builder::NumberFormat;
+///
+/// let format = NumberFormat::new().precision(1).thousands_separator('_');
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    precision: Option<usize>,
+    thousands_separator: Option<char>,
+}
+
+impl NumberFormat {
+    /// Creates a [`NumberFormat`] which doesn't change a value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a number of digits after a decimal point.
+    ///
+    /// It's applied only to floating point numbers.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets a separator of thousands groups in an integer part of a number.
+    pub fn thousands_separator(mut self, c: char) -> Self {
+        self.thousands_separator = Some(c);
+        self
+    }
+
+    /// Formats a value printed as a given text, if it's a number.
+    fn format_value(&self, value: &dyn Display, text: String) -> String {
+        // integers ignore a precision, floats add a fraction, and strings are cut by it.
+        let probe = format!("{:.1}", value);
+        let is_integer = probe == text && text.parse::<i128>().is_ok();
+        let is_float = probe.contains('.') && probe.parse::<f64>().is_ok();
+
+        if is_integer {
+            return self.separate_thousands(text);
+        }
+
+        if !is_float {
+            return text;
+        }
+
+        let text = match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => text,
+        };
+
+        self.separate_thousands(text)
+    }
+
+    /// Formats a number written in a text,
+    /// or returns [`None`] if the text is not a number.
+    pub(crate) fn format_text(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        if !text.bytes().any(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        if text.parse::<i128>().is_ok() {
+            return Some(self.separate_thousands(text.to_owned()));
+        }
+
+        let value = text.parse::<f64>().ok().filter(|value| value.is_finite())?;
+        let text = match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => text.to_owned(),
+        };
+
+        Some(self.separate_thousands(text))
+    }
+
+    fn separate_thousands(&self, text: String) -> String {
+        let sep = match self.thousands_separator {
+            Some(sep) => sep,
+            None => return text,
+        };
+
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.as_str()),
+        };
+
+        let int_end = unsigned.find('.').unwrap_or(unsigned.len());
+        let (int, fraction) = unsigned.split_at(int_end);
+        if !int.bytes().all(|b| b.is_ascii_digit()) {
+            return text;
+        }
+
+        let mut buf = String::with_capacity(text.len() + int.len() / 3);
+        buf.push_str(sign);
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                buf.push(sep);
+            }
+
+            buf.push(c);
+        }
+        buf.push_str(fraction);
+
+        buf
+    }
+}
+
+/// CellLimit caps a content of a cell by a number of bytes and lines.
+///
+/// A cut off part is replaced by a marker with its size, like `[truncated 1.2 MB]`.
+/// A text is cut on a character boundary,
+/// though an ANSI escape sequence may be cut in the middle.
+///
+/// It's used by [`Builder::set_cell_limit`].
+///
+/// ```rust
+/// use tabled::builder::CellLimit;
+///
+/// let limit = CellLimit::new().bytes(64 * 1024).lines(100);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CellLimit {
+    bytes: Option<usize>,
+    lines: Option<usize>,
+}
+
+impl CellLimit {
+    /// Creates a [`CellLimit`] which doesn't limit anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a maximum number of bytes of a cell, not counting a marker.
+    pub fn bytes(mut self, bytes: usize) -> Self {
+        self.bytes = Some(bytes);
+        self
+    }
+
+    /// Sets a maximum number of lines of a cell, not counting a marker.
+    pub fn lines(mut self, lines: usize) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    /// Caps a given text, adding a marker if anything was cut off.
+    ///
+    /// ```rust
+    /// use tabled::builder::CellLimit;
+    ///
+    /// let limit = CellLimit::new().lines(2);
+    ///
+    /// assert_eq!(limit.apply("1\n2\n3\n4"), "1\n2\n[truncated 4 B]");
+    /// assert_eq!(limit.apply("1\n2"), "1\n2");
+    /// ```
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut end = text.len();
+        let mut is_line_cut = false;
+
+        match self.lines {
+            Some(0) => end = 0,
+            Some(lines) => {
+                if let Some((i, _)) = text.match_indices('\n').nth(lines - 1) {
+                    end = i;
+                    is_line_cut = true;
+                }
+            }
+            None => {}
+        }
+
+        if let Some(bytes) = self.bytes {
+            if bytes < end {
+                end = floor_char_boundary(text, bytes);
+                is_line_cut = false;
+            }
+        }
+
+        if end == text.len() {
+            return Cow::Borrowed(text);
+        }
+
+        let kept = &text[..end];
+        let cut = text.len() - end;
+        let sep = match (kept.is_empty(), is_line_cut) {
+            (true, _) => "",
+            (false, true) => "\n",
+            (false, false) => " ",
+        };
+
+        Cow::Owned(format!("{}{}[truncated {}]", kept, sep, format_size(cut)))
+    }
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    index
+}
+
+/// Formats a number of bytes using decimal units, like `1.2 MB`.
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1000 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1000.0;
+    let mut unit = 0;
+    while size >= 999.95 && unit + 1 < UNITS.len() {
+        size /= 1000.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
 fn make_rows_columns(v: &mut Vec<Vec<CellInfo<'_>>>, count_columns: usize) {
     let mut columns = Vec::with_capacity(count_columns);
     for _ in 0..count_columns {
@@ -876,7 +2069,12 @@ fn build_range_index(n: usize) -> Vec<CellInfo<'static>> {
         .collect()
 }
 
-fn create_row<'a, R, T, W>(row: R, size: usize, ctrl: &W) -> Vec<CellInfo<'a>>
+fn create_row<'a, R, T, W>(
+    row: R,
+    size: usize,
+    limit: Option<&CellLimit>,
+    ctrl: &W,
+) -> Vec<CellInfo<'a>>
 where
     R: IntoIterator<Item = T>,
     T: Into<Cow<'a, str>>,
@@ -884,12 +2082,26 @@ where
 {
     let mut list = Vec::with_capacity(size);
     for text in row {
-        list.push(CellInfo::new(text, ctrl))
+        list.push(CellInfo::new(limit_text(text, limit), ctrl))
     }
 
     list
 }
 
+fn limit_text<'a, T>(text: T, limit: Option<&CellLimit>) -> Cow<'a, str>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let text = text.into();
+    if let Some(limit) = limit {
+        if let Cow::Owned(limited) = limit.apply(&text) {
+            return Cow::Owned(limited);
+        }
+    }
+
+    text
+}
+
 fn create_row_exact<'a, R, T, W>(list: &mut [CellInfo<'a>], row: R, ctrl: &W)
 where
     R: IntoIterator<Item = T>,
@@ -902,7 +2114,7 @@ where
 }
 
 /// Building [`Table`] from ordinary data.
-fn build_table<R>(records: R, with_header: bool) -> Table<R>
+fn build_table<R>(records: R, count_header_rows: usize) -> Table<R>
 where
     R: Records,
 {
@@ -910,24 +2122,110 @@ where
     table.with(Style::ascii());
 
     configure_grid(table.get_config_mut());
-    table.set_header_flag(with_header);
+    table.set_header_rows(count_header_rows);
 
     table
 }
 
 /// Building [`Grid`] from ordinary data.
 fn build_grid<'a>(
-    mut records: Vec<Vec<CellInfo<'a>>>,
+    header_rows: Vec<Vec<CellInfo<'a>>>,
+    records: Vec<Vec<CellInfo<'a>>>,
     columns: Option<Vec<CellInfo<'a>>>,
     count_columns: usize,
 ) -> VecRecords<CellInfo<'a>> {
-    if let Some(columns) = columns {
-        records.insert(0, columns);
-    }
+    let records = if header_rows.is_empty() && columns.is_none() {
+        records
+    } else {
+        header_rows
+            .into_iter()
+            .chain(columns)
+            .chain(records)
+            .collect()
+    };
 
     VecRecords::with_hint(records, count_columns)
 }
 
+/// Merges cells of a header which consists of a few rows.
+///
+/// Adjacent cells of a group row with the same text, within the same group of a row above,
+/// are spanned over columns.
+/// An empty cell of a group row is spanned over rows down to the first non empty cell.
+pub(crate) fn span_header_rows<R>(table: &mut Table<R>, count_header_rows: usize)
+where
+    R: Records + RecordsMut<String>,
+{
+    let (count_rows, count_columns) = table.shape();
+    if count_header_rows < 2 || count_rows < count_header_rows {
+        return;
+    }
+
+    let names = (0..count_header_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|col| table.get_records().get_text((row, col)).to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+    let last_row = count_header_rows - 1;
+
+    let mut spanned = vec![vec![false; count_columns]; count_header_rows];
+    for row in 0..last_row {
+        let mut col = 0;
+        while col < count_columns {
+            let text = &names[row][col];
+            if text.is_empty() {
+                col += 1;
+                continue;
+            }
+
+            let is_same_group = |c: usize| (0..=row).all(|r| names[r][c] == names[r][col]);
+            let span = (col..count_columns)
+                .take_while(|&c| is_same_group(c))
+                .count();
+            if span > 1 {
+                table.get_config_mut().set_column_span((row, col), span);
+                spanned[row][col..col + span].fill(true);
+                for c in col + 1..col + span {
+                    table.get_records_mut().set((row, c), String::new(), &ctrl);
+                }
+            }
+
+            col += span;
+        }
+    }
+
+    for col in 0..count_columns {
+        let mut row = 0;
+        while row < last_row {
+            if !names[row][col].is_empty() {
+                row += 1;
+                continue;
+            }
+
+            let below = (row + 1..=last_row).find(|&r| !names[r][col].is_empty());
+            match below {
+                Some(below) if !spanned[below][col] => {
+                    let text = names[below][col].clone();
+                    table.get_records_mut().set((row, col), text, &ctrl);
+                    table
+                        .get_records_mut()
+                        .set((below, col), String::new(), &ctrl);
+                    table
+                        .get_config_mut()
+                        .set_row_span((row, col), below - row + 1);
+                    row = below + 1;
+                }
+                Some(below) => row = below,
+                None => break,
+            }
+        }
+    }
+}
+
 fn configure_grid(cfg: &mut GridConfig) {
     cfg.set_tab_width(4);
     cfg.set_padding(
@@ -0,0 +1,94 @@
+//! This module contains a logic of writing a [`Table`] as a screen-reader friendly list of records.
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt;
+
+use papergrid::{records::Records, GridConfig};
+
+use crate::{
+    features::{accessible::Accessible, caption::Caption},
+    table::header_cells,
+};
+
+/// Writes records as lines of `header: value` separated by a record separator.
+///
+/// The first `count_header_rows` rows are considered to be a header,
+/// a value of a column is labeled by all non empty header cells above it.
+pub(crate) fn write_accessible<R>(
+    f: &mut impl fmt::Write,
+    records: R,
+    cfg: &GridConfig,
+    count_header_rows: usize,
+    captions: &[Caption],
+    settings: &Accessible,
+) -> fmt::Result
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
+    let shape = (count_rows, count_cols);
+
+    let mut blocks = Vec::new();
+
+    // the last added top caption is the farthest from a table.
+    let top = captions.iter().filter(|c| c.is_top()).rev();
+    blocks.extend(top.map(|caption| join_lines(caption.get_text())));
+
+    let headers = (0..count_cols)
+        .map(|col| header_label(&records, cfg, shape, count_header_rows, col))
+        .collect::<Vec<_>>();
+
+    for row in count_header_rows..count_rows {
+        let mut lines = Vec::with_capacity(count_cols);
+        for (col, header) in headers.iter().enumerate() {
+            if !cfg.is_cell_visible((row, col), shape) {
+                continue;
+            }
+
+            let value = join_lines(records.get_text((row, col)));
+            let line = if count_header_rows > 0 {
+                format!("{}{}{}", header, settings.get_separator(), value)
+            } else {
+                value
+            };
+
+            lines.push(line.trim_end().to_owned());
+        }
+
+        blocks.push(lines.join("\n"));
+    }
+
+    let bottom = captions.iter().filter(|c| !c.is_top());
+    blocks.extend(bottom.map(|caption| join_lines(caption.get_text())));
+
+    let separator = format!("\n{}\n", settings.get_record_separator());
+    f.write_str(&blocks.join(&separator))
+}
+
+/// Joins texts of header cells which cover a column, from the top one.
+pub(crate) fn header_label<R>(
+    records: &R,
+    cfg: &GridConfig,
+    shape: (usize, usize),
+    count_header_rows: usize,
+    col: usize,
+) -> String
+where
+    R: Records,
+{
+    header_cells(cfg, shape, count_header_rows, col)
+        .map(|pos| join_lines(records.get_text(pos)))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Joins lines of a text by a space, dropping the empty ones.
+fn join_lines(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
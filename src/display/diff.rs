@@ -0,0 +1,493 @@
+//! This module contains a [`TableDiff`] structure which renders a difference of 2 tables as a single table.
+//!
+//! ```
+//! use tabled::{display::TableDiff, Style, Table};
+//!
+//! let before = Table::new([("timeout", "30"), ("retries", "3"), ("debug", "false")]);
+//! let after = Table::new([("timeout", "60"), ("retries", "3"), ("region", "eu")]);
+//!
+//! let table = TableDiff::new(&before, &after)
+//!     .key([0])
+//!     .build()
+//!     .with(Style::psql())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "   | &str    | &str     \n",
+//!         "---+---------+----------\n",
+//!         " ~ | timeout | 30 -> 60 \n",
+//!         "   | retries | 3        \n",
+//!         " - | debug   | false    \n",
+//!         " + | region  | eu       ",
+//!     )
+//! );
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+use papergrid::records::Records;
+
+use crate::{builder::Builder, Table, Tabled};
+
+#[cfg(feature = "color")]
+use papergrid::AnsiColor;
+
+#[cfg(feature = "color")]
+use crate::{color::Color, features::colorization::colorize_lines};
+
+/// `TableDiff` compares records of 2 tables with the same columns
+/// and builds a single table which marks added, removed and changed records.
+///
+/// By default a record is compared as a whole,
+/// so a record which was changed is shown as a removed one followed by an added one.
+/// Records are matched the same way as lines are matched by a `diff` tool,
+/// so records which are present in both tables keep their order.
+///
+/// A [`TableDiff::key`] sets columns which identify a record,
+/// then records with the same key are compared cell by cell
+/// and a changed cell is shown as `old -> new`.
+/// Records are matched by their keys whatever order they go in,
+/// and records are shown in order of a new table.
+/// Big tables without a key are matched the same way by whole records,
+/// as matching them the way a `diff` tool does takes too much memory.
+///
+/// A first column of a built table contains a mark of a record:
+/// `+` for an added one, `-` for a removed one, `~` for a changed one,
+/// and nothing for a record which is the same.
+///
+/// A header is taken from a new table, or from an old one if a new one has no header.
+#[derive(Debug, Clone)]
+pub struct TableDiff {
+    header: Option<Vec<String>>,
+    old: Vec<Vec<String>>,
+    new: Vec<Vec<String>>,
+    key: Vec<usize>,
+    marks: bool,
+    only_changes: bool,
+    #[cfg(feature = "color")]
+    colors: Option<DiffColors>,
+}
+
+#[cfg(feature = "color")]
+#[derive(Debug, Clone)]
+struct DiffColors {
+    added: AnsiColor<'static>,
+    removed: AnsiColor<'static>,
+    changed: AnsiColor<'static>,
+}
+
+impl TableDiff {
+    /// Creates a difference of 2 tables.
+    pub fn new<L, R>(old: &Table<L>, new: &Table<R>) -> Self
+    where
+        L: Records,
+        R: Records,
+    {
+        let header = collect_header(new).or_else(|| collect_header(old));
+        let old = collect_records(old);
+        let new = collect_records(new);
+
+        Self::from_records(header, old, new)
+    }
+
+    /// Creates a difference of 2 lists of [`Tabled`] values.
+    #[cfg_attr(feature = "derive", doc = "```")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// use tabled::{display::TableDiff, Style, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Setting {
+    ///     name: &'static str,
+    ///     value: usize,
+    /// }
+    ///
+    /// let before = [Setting { name: "workers", value: 4 }];
+    /// let after = [Setting { name: "workers", value: 8 }];
+    ///
+    /// let table = TableDiff::from_tabled(before, after)
+    ///     .key([0])
+    ///     .no_marks()
+    ///     .build()
+    ///     .with(Style::markdown())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "| name    | value  |\n",
+    ///         "|---------|--------|\n",
+    ///         "| workers | 4 -> 8 |",
+    ///     )
+    /// );
+    /// ```
+    pub fn from_tabled<I, J, T>(old: I, new: J) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        J: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        let header = T::headers().into_iter().map(|h| h.into_owned()).collect();
+        let old = old.into_iter().map(collect_fields).collect();
+        let new = new.into_iter().map(collect_fields).collect();
+
+        Self::from_records(Some(header), old, new)
+    }
+
+    fn from_records(
+        header: Option<Vec<String>>,
+        old: Vec<Vec<String>>,
+        new: Vec<Vec<String>>,
+    ) -> Self {
+        Self {
+            header,
+            old,
+            new,
+            key: Vec::new(),
+            marks: true,
+            only_changes: false,
+            #[cfg(feature = "color")]
+            colors: None,
+        }
+    }
+
+    /// Sets indexes of columns which identify a record.
+    ///
+    /// Records with the same key are considered to be the same record,
+    /// so they are compared cell by cell.
+    pub fn key<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.key = columns.into_iter().collect();
+        self
+    }
+
+    /// Removes a column with marks of records.
+    pub fn no_marks(mut self) -> Self {
+        self.marks = false;
+        self
+    }
+
+    /// Leaves only records which were added, removed or changed.
+    pub fn only_changes(mut self) -> Self {
+        self.only_changes = true;
+        self
+    }
+
+    /// Sets colors of added and removed records, and of changed cells.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn colors(mut self, added: Color, removed: Color, changed: Color) -> Self {
+        self.colors = Some(DiffColors {
+            added: added.into(),
+            removed: removed.into(),
+            changed: changed.into(),
+        });
+        self
+    }
+
+    /// Builds a table of the difference.
+    pub fn build(&self) -> Table {
+        let mut builder = Builder::default();
+
+        if let Some(header) = &self.header {
+            let mut columns = header.clone();
+            if self.marks {
+                columns.insert(0, String::new());
+            }
+
+            builder.set_columns(columns);
+        }
+
+        for change in self.diff() {
+            if self.only_changes && matches!(change, Change::Same(_)) {
+                continue;
+            }
+
+            let (mark, mut record) = match change {
+                Change::Same(record) => ("", record.clone()),
+                Change::Added(record) => ("+", self.color_added(record)),
+                Change::Removed(record) => ("-", self.color_removed(record)),
+                Change::Changed(old, new) => ("~", self.changed_cells(old, new)),
+            };
+
+            if self.marks {
+                record.insert(0, self.color_mark(&change, mark));
+            }
+
+            builder.add_record(record);
+        }
+
+        builder.build()
+    }
+
+    fn diff(&self) -> Vec<Change<'_>> {
+        let old_keys = self
+            .old
+            .iter()
+            .map(|r| self.record_key(r))
+            .collect::<Vec<_>>();
+        let new_keys = self
+            .new
+            .iter()
+            .map(|r| self.record_key(r))
+            .collect::<Vec<_>>();
+
+        // a table of a common subsequence takes a product of the lengths,
+        // so it's built only for small tables.
+        let is_small = old_keys.len().saturating_mul(new_keys.len()) <= LCS_MAX_SIZE;
+        let steps = if self.key.is_empty() && is_small {
+            diff_sequences(&old_keys, &new_keys)
+        } else {
+            match_sequences(&old_keys, &new_keys)
+        };
+
+        let mut changes = Vec::with_capacity(self.old.len() + self.new.len());
+        for step in steps {
+            let change = match step {
+                Step::Same(i, j) if self.old[i] == self.new[j] => Change::Same(&self.new[j]),
+                Step::Same(i, j) => Change::Changed(&self.old[i], &self.new[j]),
+                Step::Removed(i) => Change::Removed(&self.old[i]),
+                Step::Added(j) => Change::Added(&self.new[j]),
+            };
+
+            changes.push(change);
+        }
+
+        changes
+    }
+
+    fn record_key<'a>(&self, record: &'a [String]) -> Vec<&'a str> {
+        if self.key.is_empty() {
+            return record.iter().map(String::as_str).collect();
+        }
+
+        self.key
+            .iter()
+            .map(|&col| record.get(col).map_or("", String::as_str))
+            .collect()
+    }
+
+    fn changed_cells(&self, old: &[String], new: &[String]) -> Vec<String> {
+        let count_cells = std::cmp::max(old.len(), new.len());
+        (0..count_cells)
+            .map(|i| {
+                let old = old.get(i).map_or("", String::as_str);
+                let new = new.get(i).map_or("", String::as_str);
+                if old == new {
+                    return new.to_owned();
+                }
+
+                let text = format!("{} -> {}", old, new);
+
+                #[cfg(feature = "color")]
+                if let Some(colors) = &self.colors {
+                    return colorize_lines(&text, &colors.changed);
+                }
+
+                text
+            })
+            .collect()
+    }
+
+    #[allow(unused_variables)]
+    fn color_mark(&self, change: &Change<'_>, mark: &str) -> String {
+        #[cfg(feature = "color")]
+        if let Some(colors) = &self.colors {
+            let color = match change {
+                Change::Same(_) => return mark.to_owned(),
+                Change::Added(_) => &colors.added,
+                Change::Removed(_) => &colors.removed,
+                Change::Changed(_, _) => &colors.changed,
+            };
+
+            return colorize_lines(mark, color);
+        }
+
+        mark.to_owned()
+    }
+
+    fn color_added(&self, record: &[String]) -> Vec<String> {
+        #[cfg(feature = "color")]
+        if let Some(colors) = &self.colors {
+            return colorize_record(record, &colors.added);
+        }
+
+        record.to_vec()
+    }
+
+    fn color_removed(&self, record: &[String]) -> Vec<String> {
+        #[cfg(feature = "color")]
+        if let Some(colors) = &self.colors {
+            return colorize_record(record, &colors.removed);
+        }
+
+        record.to_vec()
+    }
+}
+
+impl Display for TableDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build().fmt(f)
+    }
+}
+
+enum Change<'a> {
+    Same(&'a Vec<String>),
+    Added(&'a Vec<String>),
+    Removed(&'a Vec<String>),
+    Changed(&'a Vec<String>, &'a Vec<String>),
+}
+
+enum Step {
+    Same(usize, usize),
+    Removed(usize),
+    Added(usize),
+}
+
+/// A maximum product of lengths of sequences which are matched by [`diff_sequences`].
+const LCS_MAX_SIZE: usize = 1 << 16;
+
+/// Matches equal items of 2 sequences in order of their occurrences by a hash map.
+///
+/// Unlike [`diff_sequences`] matched items may go in a different order, then the new order is kept.
+/// Removed items go before added ones between matched items.
+fn match_sequences<T>(old: &[T], new: &[T]) -> Vec<Step>
+where
+    T: Hash + Eq,
+{
+    let mut positions: HashMap<&T, VecDeque<usize>> = HashMap::with_capacity(old.len());
+    for (i, item) in old.iter().enumerate() {
+        positions.entry(item).or_default().push_back(i);
+    }
+
+    let matches = new
+        .iter()
+        .map(|item| positions.get_mut(item).and_then(VecDeque::pop_front))
+        .collect::<Vec<_>>();
+
+    let mut is_matched = vec![false; old.len()];
+    for &i in matches.iter().flatten() {
+        is_matched[i] = true;
+    }
+
+    let mut steps = Vec::with_capacity(old.len() + new.len());
+    let mut added = Vec::new();
+    let mut next_old = 0;
+    for (j, i) in matches.into_iter().enumerate() {
+        let i = match i {
+            Some(i) => i,
+            None => {
+                added.push(j);
+                continue;
+            }
+        };
+
+        let removed = (next_old..i).filter(|&k| !is_matched[k]);
+        steps.extend(removed.map(Step::Removed));
+
+        next_old = std::cmp::max(next_old, i + 1);
+        steps.extend(added.drain(..).map(Step::Added));
+        steps.push(Step::Same(i, j));
+    }
+
+    steps.extend(
+        (next_old..old.len())
+            .filter(|&i| !is_matched[i])
+            .map(Step::Removed),
+    );
+    steps.extend(added.into_iter().map(Step::Added));
+
+    steps
+}
+
+/// Matches 2 sequences by their longest common subsequence.
+///
+/// Removed items go before added ones between matched items.
+fn diff_sequences<T>(old: &[T], new: &[T]) -> Vec<Step>
+where
+    T: PartialEq,
+{
+    // lengths[i][j] is a length of a common subsequence of old[i..] and new[j..].
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                std::cmp::max(lengths[i + 1][j], lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut steps = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            steps.push(Step::Same(i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            steps.push(Step::Removed(i));
+            i += 1;
+        } else {
+            steps.push(Step::Added(j));
+            j += 1;
+        }
+    }
+
+    steps.extend((i..old.len()).map(Step::Removed));
+    steps.extend((j..new.len()).map(Step::Added));
+
+    steps
+}
+
+fn collect_header<R>(table: &Table<R>) -> Option<Vec<String>>
+where
+    R: Records,
+{
+    if table.is_empty() {
+        return None;
+    }
+
+    let header = table.header()?;
+
+    Some(header.into_iter().map(str::to_owned).collect())
+}
+
+fn collect_records<R>(table: &Table<R>) -> Vec<Vec<String>>
+where
+    R: Records,
+{
+    let records = table.get_records();
+    let first_row = table.count_header_rows();
+
+    (first_row..records.count_rows())
+        .map(|row| {
+            (0..records.count_columns())
+                .map(|col| records.get_text((row, col)).to_owned())
+                .collect()
+        })
+        .collect()
+}
+
+fn collect_fields<T>(value: T) -> Vec<String>
+where
+    T: Tabled,
+{
+    value.fields().into_iter().map(|f| f.into_owned()).collect()
+}
+
+#[cfg(feature = "color")]
+fn colorize_record(record: &[String], color: &AnsiColor<'_>) -> Vec<String> {
+    record
+        .iter()
+        .map(|text| colorize_lines(text, color))
+        .collect()
+}
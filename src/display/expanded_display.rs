@@ -48,9 +48,12 @@
 //! assert_eq!(table, expected);
 //! ```
 
-use papergrid::util::{cut_str_basic, string_width};
+use papergrid::{
+    records::Records,
+    util::{cut_str_basic, string_width},
+};
 
-use crate::Tabled;
+use crate::{display::header_label, Table, Tabled};
 
 /// `ExpandedDisplay` display data in a 'expanded display mode' from postgresql.
 /// It may be useful for a large data sets with a lot of fields.
@@ -108,6 +111,47 @@ impl ExpandedDisplay {
         }
     }
 
+    /// Creates an `ExpandedDisplay` from records of a [`Table`].
+    ///
+    /// Header rows of a table are used as names of fields,
+    /// and indexes of columns are used if it has no header.
+    pub(crate) fn from_table<R>(table: &Table<R>) -> Self
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+        let count_header_rows = table.count_header_rows();
+        let records = table.get_records();
+        let cfg = table.get_config();
+
+        let fields = (0..count_cols)
+            .map(|col| match count_header_rows {
+                0 => col.to_string(),
+                _ => header_label(
+                    records,
+                    cfg,
+                    (count_rows, count_cols),
+                    count_header_rows,
+                    col,
+                )
+                .escape_debug()
+                .to_string(),
+            })
+            .collect();
+        let data = (count_header_rows..count_rows)
+            .map(|row| {
+                (0..count_cols)
+                    .map(|col| records.get_text((row, col)).escape_debug().to_string())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            records: data,
+            fields,
+        }
+    }
+
     /// Truncates table to a set width value for a table.
     /// It returns a success inticator, where `false` means it's not possible to set the table width,
     /// because of the given arguments.
@@ -0,0 +1,597 @@
+//! This module contains a specialized rendering of a [`Table`] whose cells share the same padding.
+//!
+//! Such tables (like the ones made by [`Style::blank`] or [`Style::psql`]) are just a set of lines,
+//! where each line is a concatenation of padded cells and vertical separators,
+//! and split lines are made of whole horizontal borders.
+//! So a configuration of a cell can be resolved once per row rather than on each line,
+//! and borders of a row are resolved once too, which saves a lot of lookups.
+//!
+//! The output is exactly the same as the one produced by [`Grid`].
+//!
+//! [`Table`]: crate::Table
+//! [`Style::blank`]: crate::Style::blank
+//! [`Style::psql`]: crate::Style::psql
+//! [`Grid`]: papergrid::Grid
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+use papergrid::{
+    records::Records,
+    util::string_trim,
+    width::{CfgWidthFunction, WidthFunc},
+    AlignmentHorizontal, AlignmentVertical, Entity, Formatting, GridConfig, Padding, Position,
+};
+
+#[cfg(feature = "color")]
+use papergrid::{AnsiColor, PaddingColor};
+
+const DEFAULT_SPACE_CHAR: char = ' ';
+
+/// Checks whether a table can be rendered by [`print_grid`].
+///
+/// It's the case when all cells have the same padding
+/// and there's no margin, spans or a text on split lines.
+pub(crate) fn is_fast_render_applicable<R>(cfg: &GridConfig, records: &R) -> bool
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
+
+    let margin = cfg.get_margin();
+    let has_margin = margin.top.size > 0
+        || margin.bottom.size > 0
+        || margin.left.size > 0
+        || margin.right.size > 0;
+    let has_spans = cfg.has_column_spans() || cfg.has_row_spans();
+    let has_split_text = (0..=count_rows).any(|row| cfg.get_split_line_text(row).is_some());
+    if has_margin || has_spans || has_split_text {
+        return false;
+    }
+
+    let padding = cfg.get_padding(Entity::Global);
+    (0..count_rows)
+        .flat_map(|row| (0..count_cols).map(move |col| (row, col)))
+        .all(|pos| cfg.get_padding(pos.into()) == padding)
+}
+
+/// Prints a table which satisfies [`is_fast_render_applicable`].
+pub(crate) fn print_grid<R>(
+    f: &mut fmt::Formatter<'_>,
+    cfg: &GridConfig,
+    records: &R,
+    widths: &[usize],
+    heights: &[usize],
+) -> fmt::Result
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
+    if count_rows == 0 || count_cols == 0 {
+        return Ok(());
+    }
+
+    let width_ctrl = CfgWidthFunction::from_cfg(cfg);
+    let padding = cfg.get_padding(Entity::Global);
+
+    let mut buf = String::new();
+    let mut cells = Vec::with_capacity(count_cols);
+    let mut borders = Vec::with_capacity(count_cols + 1);
+    let mut prev_empty_horizontal = false;
+    for (row, &height) in heights.iter().enumerate().take(count_rows) {
+        if cfg.has_horizontal(row, count_rows) {
+            buf.clear();
+
+            if prev_empty_horizontal {
+                buf.push('\n');
+            }
+
+            print_split_line(&mut buf, cfg, widths, row, (count_rows, count_cols))?;
+
+            prev_empty_horizontal = height == 0;
+            if !prev_empty_horizontal {
+                buf.push('\n');
+            }
+
+            f.write_str(&buf)?;
+        } else if height > 0 && prev_empty_horizontal {
+            f.write_char('\n')?;
+            prev_empty_horizontal = false;
+        }
+
+        cells.clear();
+        borders.clear();
+
+        for col in 0..count_cols {
+            let pos = (row, col);
+            cells.push(CellLayout::new(cfg, records, pos, padding, height));
+            borders.push(VerticalChar::new(cfg, pos, count_cols));
+        }
+
+        borders.push(VerticalChar::new(cfg, (row, count_cols), count_cols));
+
+        for line in 0..height {
+            // a line is built in a buffer as writing to a formatter piece by piece is costly
+            buf.clear();
+
+            for (col, cell) in cells.iter().enumerate() {
+                borders[col].print(&mut buf, cfg, line, height)?;
+                cell.print_line(&mut buf, cfg, records, widths[col], line, &width_ctrl)?;
+            }
+
+            borders[count_cols].print(&mut buf, cfg, line, height)?;
+
+            let is_last_line = line + 1 == height;
+            let is_last_row = row + 1 == count_rows;
+            if !(is_last_line && is_last_row) {
+                buf.push('\n');
+            }
+
+            f.write_str(&buf)?;
+        }
+    }
+
+    if cfg.has_horizontal(count_rows, count_rows) {
+        buf.clear();
+        buf.push('\n');
+        print_split_line(&mut buf, cfg, widths, count_rows, (count_rows, count_cols))?;
+        f.write_str(&buf)?;
+    }
+
+    Ok(())
+}
+
+/// Prints a horizontal line above a given row.
+fn print_split_line(
+    f: &mut impl Write,
+    cfg: &GridConfig,
+    widths: &[usize],
+    row: usize,
+    shape: (usize, usize),
+) -> fmt::Result {
+    #[cfg(feature = "color")]
+    let mut used_color = None;
+
+    for (col, &width) in widths.iter().enumerate().take(shape.1) {
+        if col == 0 {
+            if let Some(&c) = cfg.get_intersection((row, col), shape) {
+                #[cfg(feature = "color")]
+                prepare_coloring(
+                    f,
+                    cfg.get_intersection_color((row, col), shape),
+                    &mut used_color,
+                )?;
+                f.write_char(c)?;
+            }
+        }
+
+        if width > 0 {
+            match cfg.get_horizontal((row, col), shape.0) {
+                Some(&c) => {
+                    #[cfg(feature = "color")]
+                    prepare_coloring(
+                        f,
+                        cfg.get_horizontal_color((row, col), shape.0),
+                        &mut used_color,
+                    )?;
+                    print_horizontal_border(f, cfg, (row, col), width, c)?;
+                }
+                None => repeat_char(f, DEFAULT_SPACE_CHAR, width)?,
+            }
+        }
+
+        if let Some(&c) = cfg.get_intersection((row, col + 1), shape) {
+            #[cfg(feature = "color")]
+            prepare_coloring(
+                f,
+                cfg.get_intersection_color((row, col + 1), shape),
+                &mut used_color,
+            )?;
+            f.write_char(c)?;
+        }
+    }
+
+    #[cfg(feature = "color")]
+    if let Some(clr) = used_color {
+        f.write_str(clr.get_suffix())?;
+    }
+
+    Ok(())
+}
+
+fn print_horizontal_border(
+    f: &mut impl Write,
+    cfg: &GridConfig,
+    pos: Position,
+    width: usize,
+    c: char,
+) -> fmt::Result {
+    if !cfg.is_overidden_horizontal(pos) {
+        return repeat_char(f, c, width);
+    }
+
+    for i in 0..width {
+        let c = cfg.lookup_overidden_horizontal(pos, i, width).unwrap_or(c);
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+/// Switches a color of a split line, so a color sequence is not repeated for each character.
+#[cfg(feature = "color")]
+fn prepare_coloring<'a>(
+    f: &mut impl Write,
+    clr: Option<&'a AnsiColor<'a>>,
+    used_color: &mut Option<&'a AnsiColor<'a>>,
+) -> fmt::Result {
+    match (clr, used_color.as_ref()) {
+        (Some(clr), Some(used)) if *used == clr => {}
+        (Some(clr), used) => {
+            if let Some(used) = used {
+                f.write_str(used.get_suffix())?;
+            }
+
+            f.write_str(clr.get_prefix())?;
+            *used_color = Some(clr);
+        }
+        (None, Some(used)) => {
+            f.write_str(used.get_suffix())?;
+            *used_color = None;
+        }
+        (None, None) => {}
+    }
+
+    Ok(())
+}
+
+/// A pre resolved configuration of a cell.
+struct CellLayout<'a> {
+    pos: Position,
+    padding: &'a Padding,
+    #[cfg(feature = "color")]
+    padding_color: &'a PaddingColor<'a>,
+    alignment: AlignmentHorizontal,
+    formatting: Formatting,
+    /// An amount of lines before a content starts.
+    indent: usize,
+    /// An amount of content lines.
+    height: usize,
+    /// An amount of empty lines skipped at the beginning when vertical trim is used.
+    skip: usize,
+}
+
+impl<'a> CellLayout<'a> {
+    fn new<R>(
+        cfg: &'a GridConfig,
+        records: &R,
+        pos: Position,
+        padding: &'a Padding,
+        height: usize,
+    ) -> Self
+    where
+        R: Records,
+    {
+        let formatting = *cfg.get_formatting(pos.into());
+
+        let mut skip = 0;
+        let mut cell_height = records.count_lines(pos);
+        if formatting.vertical_trim {
+            skip = count_empty_lines_at_start(records, pos);
+            cell_height -= skip + count_empty_lines_at_end(records, pos);
+        }
+
+        // it may happen if the height estimation decide so
+        cell_height = std::cmp::min(cell_height, height);
+
+        let alignment = *cfg.get_alignment_vertical(pos.into());
+        let available = height - padding.top.size;
+        let indent = padding.top.size + indent_from_top(alignment, available, cell_height);
+
+        Self {
+            pos,
+            padding,
+            #[cfg(feature = "color")]
+            padding_color: cfg.get_padding_color(pos.into()),
+            alignment: *cfg.get_alignment_horizontal(pos.into()),
+            formatting,
+            indent,
+            height: cell_height,
+            skip,
+        }
+    }
+
+    fn print_line<R, W>(
+        &self,
+        f: &mut impl Write,
+        cfg: &GridConfig,
+        records: &R,
+        width: usize,
+        line: usize,
+        width_ctrl: &W,
+    ) -> fmt::Result
+    where
+        R: Records,
+        W: WidthFunc,
+    {
+        if self.indent > line {
+            return self.print_indent(f, self.padding.top.fill, width, Side::Top);
+        }
+
+        let index = line - self.indent;
+        if index >= self.height {
+            return self.print_indent(f, self.padding.bottom.fill, width, Side::Bottom);
+        }
+
+        let index = index + self.skip;
+        if self.formatting.vertical_trim && index > records.count_lines(self.pos) {
+            return self.print_indent(f, self.padding.top.fill, width, Side::Top);
+        }
+
+        let padding = self.padding;
+        self.print_indent(f, padding.left.fill, padding.left.size, Side::Left)?;
+
+        let available = width - padding.left.size - padding.right.size;
+        self.print_text_line(f, cfg, records, index, available, width_ctrl)?;
+
+        self.print_indent(f, padding.right.fill, padding.right.size, Side::Right)
+    }
+
+    fn print_text_line<R, W>(
+        &self,
+        f: &mut impl Write,
+        cfg: &GridConfig,
+        records: &R,
+        index: usize,
+        available: usize,
+        width_ctrl: &W,
+    ) -> fmt::Result
+    where
+        R: Records,
+        W: WidthFunc,
+    {
+        let pos = self.pos;
+        let tab_width = cfg.get_tab_width();
+        let formatting = self.formatting;
+
+        let line = records.get_line(pos, index);
+        let (line, line_width) = if formatting.horizontal_trim && !line.is_empty() {
+            let line = string_trim(line);
+            let width = width_ctrl.width(&line);
+            (line, width)
+        } else {
+            let width = records.get_line_width(pos, index, width_ctrl);
+            (Cow::Borrowed(line), width)
+        };
+
+        if formatting.allow_lines_alignement {
+            let (left, right) = calculate_indent(self.alignment, line_width, available);
+            return print_text_formated(f, records, pos, &line, tab_width, left, right);
+        }
+
+        let cell_width = if formatting.horizontal_trim {
+            (0..records.count_lines(pos))
+                .map(|i| width_ctrl.width(records.get_line(pos, i).trim()))
+                .max()
+                .unwrap_or(0)
+        } else {
+            records.get_width(pos, width_ctrl)
+        };
+
+        let (left, right) = calculate_indent(self.alignment, cell_width, available);
+        print_text_formated(f, records, pos, &line, tab_width, left, right)?;
+
+        repeat_char(f, DEFAULT_SPACE_CHAR, cell_width - line_width)
+    }
+
+    #[allow(unused_variables)]
+    fn print_indent(&self, f: &mut impl Write, c: char, n: usize, side: Side) -> fmt::Result {
+        #[cfg(feature = "color")]
+        let color = match side {
+            Side::Top => &self.padding_color.top,
+            Side::Bottom => &self.padding_color.bottom,
+            Side::Left => &self.padding_color.left,
+            Side::Right => &self.padding_color.right,
+        };
+
+        #[cfg(feature = "color")]
+        f.write_str(color.get_prefix())?;
+        repeat_char(f, c, n)?;
+        #[cfg(feature = "color")]
+        f.write_str(color.get_suffix())?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// A pre resolved vertical border.
+struct VerticalChar<'a> {
+    pos: Position,
+    c: Option<char>,
+    is_overridden: bool,
+    #[cfg(feature = "color")]
+    color: Option<&'a AnsiColor<'a>>,
+    #[cfg(not(feature = "color"))]
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> VerticalChar<'a> {
+    fn new(cfg: &'a GridConfig, pos: Position, count_columns: usize) -> Self {
+        Self {
+            pos,
+            c: cfg.get_vertical(pos, count_columns).copied(),
+            is_overridden: cfg.is_overidden_vertical(pos),
+            #[cfg(feature = "color")]
+            color: cfg.get_vertical_color(pos, count_columns),
+            #[cfg(not(feature = "color"))]
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    fn print(
+        &self,
+        f: &mut impl Write,
+        cfg: &GridConfig,
+        line: usize,
+        count_lines: usize,
+    ) -> fmt::Result {
+        let c = match self.c {
+            Some(c) if self.is_overridden => cfg
+                .lookup_overidden_vertical(self.pos, line, count_lines)
+                .unwrap_or(c),
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        #[cfg(feature = "color")]
+        if let Some(clr) = self.color {
+            f.write_str(clr.get_prefix())?;
+            f.write_char(c)?;
+            return f.write_str(clr.get_suffix());
+        }
+
+        f.write_char(c)
+    }
+}
+
+#[allow(unused_variables)]
+fn print_text_formated<R>(
+    f: &mut impl Write,
+    records: &R,
+    pos: Position,
+    text: &str,
+    tab_width: usize,
+    left: usize,
+    right: usize,
+) -> fmt::Result
+where
+    R: Records,
+{
+    repeat_char(f, DEFAULT_SPACE_CHAR, left)?;
+
+    #[cfg(feature = "color")]
+    write!(f, "{}", TextPrefix(records, pos))?;
+
+    print_text(f, text, tab_width)?;
+
+    #[cfg(feature = "color")]
+    write!(f, "{}", TextSuffix(records, pos))?;
+
+    repeat_char(f, DEFAULT_SPACE_CHAR, right)
+}
+
+#[cfg(feature = "color")]
+struct TextPrefix<'a, R>(&'a R, Position);
+
+#[cfg(feature = "color")]
+impl<R> fmt::Display for TextPrefix<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_text_prefix(f, self.1)
+    }
+}
+
+#[cfg(feature = "color")]
+struct TextSuffix<'a, R>(&'a R, Position);
+
+#[cfg(feature = "color")]
+impl<R> fmt::Display for TextSuffix<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_text_suffix(f, self.1)
+    }
+}
+
+fn indent_from_top(alignment: AlignmentVertical, available: usize, real: usize) -> usize {
+    match alignment {
+        AlignmentVertical::Top => 0,
+        AlignmentVertical::Bottom => available - real,
+        AlignmentVertical::Center => (available - real) / 2,
+    }
+}
+
+fn calculate_indent(
+    alignment: AlignmentHorizontal,
+    text_width: usize,
+    available: usize,
+) -> (usize, usize) {
+    let diff = available - text_width;
+    match alignment {
+        AlignmentHorizontal::Left => (0, diff),
+        AlignmentHorizontal::Right => (diff, 0),
+        AlignmentHorizontal::Center => {
+            let left = diff / 2;
+            (left, diff - left)
+        }
+    }
+}
+
+fn count_empty_lines_at_end<R>(records: &R, pos: Position) -> usize
+where
+    R: Records,
+{
+    (0..records.count_lines(pos))
+        .map(|i| records.get_line(pos, i))
+        .rev()
+        .take_while(|l| l.trim().is_empty())
+        .count()
+}
+
+fn count_empty_lines_at_start<R>(records: &R, pos: Position) -> usize
+where
+    R: Records,
+{
+    (0..records.count_lines(pos))
+        .map(|i| records.get_line(pos, i))
+        .take_while(|s| s.trim().is_empty())
+        .count()
+}
+
+fn print_text(f: &mut impl Write, text: &str, tab_width: usize) -> fmt::Result {
+    // most of the lines has nothing to be replaced so we write them at once
+    let mut rest = text;
+    while let Some(i) = rest.find(['\t', '\r']) {
+        f.write_str(&rest[..i])?;
+        if rest.as_bytes()[i] == b'\t' {
+            repeat_char(f, ' ', tab_width)?;
+        }
+
+        rest = &rest[i + 1..];
+    }
+
+    f.write_str(rest)
+}
+
+fn repeat_char(f: &mut impl Write, c: char, mut n: usize) -> fmt::Result {
+    const SPACES: &str = "                                                                ";
+
+    if c == ' ' {
+        while n > 0 {
+            let size = std::cmp::min(n, SPACES.len());
+            f.write_str(&SPACES[..size])?;
+            n -= size;
+        }
+
+        return Ok(());
+    }
+
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+//! This module contains a logic of describing a resolved layout of a [`Table`] in JSON.
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt;
+
+use papergrid::{
+    records::Records, AlignmentHorizontal, AlignmentVertical, Border, Entity, GridConfig, Indent,
+    Sides,
+};
+
+/// A version of a layout format.
+///
+/// It's increased on any change which may break an existing reader.
+const LAYOUT_VERSION: usize = 1;
+
+/// Writes a layout of a table as a compact JSON object.
+///
+/// `widths` and `heights` are sizes of columns and rows a table is printed with,
+/// including padding but not borders.
+pub(crate) fn write_layout_json<R>(
+    f: &mut impl fmt::Write,
+    records: R,
+    cfg: &GridConfig,
+    widths: &[usize],
+    heights: &[usize],
+    has_header: bool,
+) -> fmt::Result
+where
+    R: Records,
+{
+    let shape = (records.count_rows(), records.count_columns());
+
+    write!(
+        f,
+        "{{\"version\":{},\"rows\":{},\"columns\":{},\"header\":{},",
+        LAYOUT_VERSION,
+        shape.0,
+        shape.1,
+        has_header && shape.0 > 0
+    )?;
+
+    f.write_str("\"widths\":")?;
+    write_numbers(f, widths.iter().copied())?;
+    f.write_str(",\"heights\":")?;
+    write_numbers(f, heights.iter().copied())?;
+
+    // an empty table is not printed at all, so it has no lines.
+    let is_empty = shape.0 == 0 || shape.1 == 0;
+
+    f.write_str(",\"horizontal_lines\":")?;
+    write_numbers(
+        f,
+        (0..=shape.0).filter(|&row| !is_empty && cfg.has_horizontal(row, shape.0)),
+    )?;
+    f.write_str(",\"vertical_lines\":")?;
+    write_numbers(
+        f,
+        (0..=shape.1).filter(|&col| !is_empty && cfg.has_vertical(col, shape.1)),
+    )?;
+
+    f.write_str(",\"margin\":")?;
+    write_sides(f, cfg.get_margin())?;
+
+    f.write_str(",\"cells\":[")?;
+    let mut is_first = true;
+    for row in 0..shape.0 {
+        for col in 0..shape.1 {
+            let pos = (row, col);
+            if !cfg.is_cell_visible(pos, shape) {
+                continue;
+            }
+
+            if !is_first {
+                f.write_char(',')?;
+            }
+            is_first = false;
+
+            let row_span = cfg.get_row_span(pos, shape).unwrap_or(1);
+            let column_span = cfg.get_column_span(pos, shape).unwrap_or(1);
+
+            write!(
+                f,
+                "{{\"row\":{},\"column\":{},\"row_span\":{},\"column_span\":{},\"text\":",
+                row, col, row_span, column_span
+            )?;
+            write_string(f, records.get_text(pos))?;
+
+            let entity = Entity::Cell(row, col);
+            write!(
+                f,
+                ",\"alignment\":{{\"horizontal\":\"{}\",\"vertical\":\"{}\"}}",
+                horizontal_name(cfg.get_alignment_horizontal(entity)),
+                vertical_name(cfg.get_alignment_vertical(entity)),
+            )?;
+
+            f.write_str(",\"padding\":")?;
+            write_sides(f, cfg.get_padding(entity))?;
+
+            f.write_str(",\"border\":")?;
+            write_border(f, &cfg.get_border(pos, shape))?;
+
+            f.write_char('}')?;
+        }
+    }
+    f.write_char(']')?;
+
+    f.write_char('}')
+}
+
+fn write_numbers(f: &mut impl fmt::Write, numbers: impl Iterator<Item = usize>) -> fmt::Result {
+    f.write_char('[')?;
+    for (i, n) in numbers.enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+
+        write!(f, "{}", n)?;
+    }
+    f.write_char(']')
+}
+
+fn write_sides(f: &mut impl fmt::Write, sides: &Sides<Indent>) -> fmt::Result {
+    write!(
+        f,
+        "{{\"top\":{},\"bottom\":{},\"left\":{},\"right\":{}}}",
+        sides.top.size, sides.bottom.size, sides.left.size, sides.right.size
+    )
+}
+
+fn write_border(f: &mut impl fmt::Write, border: &Border<char>) -> fmt::Result {
+    let sides = [
+        ("top", border.top),
+        ("bottom", border.bottom),
+        ("left", border.left),
+        ("right", border.right),
+        ("left_top_corner", border.left_top_corner),
+        ("left_bottom_corner", border.left_bottom_corner),
+        ("right_top_corner", border.right_top_corner),
+        ("right_bottom_corner", border.right_bottom_corner),
+    ];
+
+    f.write_char('{')?;
+    for (i, (name, c)) in sides.iter().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+
+        write!(f, "\"{}\":", name)?;
+        match c {
+            Some(c) => write_string(f, c.encode_utf8(&mut [0; 4]))?,
+            None => f.write_str("null")?,
+        }
+    }
+    f.write_char('}')
+}
+
+/// Writes a JSON string literal.
+fn write_string(f: &mut impl fmt::Write, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+fn horizontal_name(alignment: &AlignmentHorizontal) -> &'static str {
+    match alignment {
+        AlignmentHorizontal::Left => "left",
+        AlignmentHorizontal::Center => "center",
+        AlignmentHorizontal::Right => "right",
+    }
+}
+
+fn vertical_name(alignment: &AlignmentVertical) -> &'static str {
+    match alignment {
+        AlignmentVertical::Top => "top",
+        AlignmentVertical::Center => "center",
+        AlignmentVertical::Bottom => "bottom",
+    }
+}
@@ -0,0 +1,153 @@
+//! This module contains a [`Live`] structure which periodically re-renders a table in place.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use tabled::{display::Live, Table};
+//!
+//! let mut tick = 0;
+//! Live::new(|| {
+//!     tick += 1;
+//!     Table::new([("tick", tick)])
+//! })
+//! .interval(Duration::from_millis(500))
+//! .run()
+//! .unwrap();
+//! ```
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// `Live` re-renders a table built by a given function with an interval,
+/// and redraws it in place of the previously printed one,
+/// like the `watch` command does.
+///
+/// Previously printed lines are cleared by ANSI cursor movement escapes,
+/// so a terminal must support them.
+/// Keep in mind that lines which are wider than a terminal are wrapped by it,
+/// which breaks the clearing,
+/// so you might want to limit a width of a table (see [`Width`]).
+///
+/// [`Width`]: crate::Width
+#[derive(Debug, Clone)]
+pub struct Live<F> {
+    factory: F,
+    interval: Duration,
+    limit: Option<usize>,
+    printed_lines: usize,
+}
+
+impl<F, T> Live<F>
+where
+    F: FnMut() -> T,
+    T: Display,
+{
+    /// Creates a new [`Live`] view,
+    /// which calls a given function to build a table on each render.
+    ///
+    /// The default interval is 1 second.
+    pub fn new(factory: F) -> Self {
+        Self {
+            factory,
+            interval: Duration::from_secs(1),
+            limit: None,
+            printed_lines: 0,
+        }
+    }
+
+    /// Sets an interval between renders.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets a number of renders after which [`Live::run`] returns.
+    ///
+    /// By default it runs until an error occurs.
+    pub fn limit(mut self, count: usize) -> Self {
+        self.limit = Some(count);
+        self
+    }
+
+    /// Re-renders a table into the stdout with an interval.
+    ///
+    /// An output depends on a terminal the stdout is attached to,
+    /// so it panics if a `strict` feature is turned on.
+    /// Use [`Live::run_to`] to render into a given writer instead.
+    pub fn run(self) -> io::Result<()> {
+        crate::strict::env_dependent("Live::run");
+
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+        self.run_to(&mut stdout)
+    }
+
+    /// Re-renders a table into a given writer with an interval.
+    pub fn run_to<W>(mut self, mut out: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut count = 0;
+        loop {
+            self.render(&mut out)?;
+
+            count += 1;
+            if matches!(self.limit, Some(limit) if count >= limit) {
+                return Ok(());
+            }
+
+            thread::sleep(self.interval);
+        }
+    }
+
+    /// Renders a table once,
+    /// clearing the lines printed by a previous render.
+    ///
+    /// ```
+    /// use tabled::{display::Live, Style, Table};
+    ///
+    /// let mut live = Live::new(|| {
+    ///     let mut table = Table::new(["Hello"]);
+    ///     table.with(Style::psql());
+    ///     table
+    /// });
+    ///
+    /// let mut out = Vec::new();
+    /// live.render(&mut out).unwrap();
+    /// live.render(&mut out).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     concat!(
+    ///         " &str  \n",
+    ///         "-------\n",
+    ///         " Hello \n",
+    ///         "\u{1b}[3A\u{1b}[J",
+    ///         " &str  \n",
+    ///         "-------\n",
+    ///         " Hello \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn render<W>(&mut self, mut out: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let text = (self.factory)().to_string();
+
+        if self.printed_lines > 0 {
+            // move the cursor to the first printed line and clear everything below it.
+            write!(out, "\u{1b}[{}A\u{1b}[J", self.printed_lines)?;
+        }
+
+        writeln!(out, "{}", text)?;
+        out.flush()?;
+
+        self.printed_lines = text.matches('\n').count() + 1;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! This module contains a logic of building a GitHub flavored markdown table out of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt;
+
+use papergrid::{records::Records, util::string_width, AlignmentHorizontal, Entity, GridConfig};
+
+/// A minimum amount of `-` chars required by a delimiter row.
+const MIN_DELIMITER_WIDTH: usize = 3;
+
+/// Writes records as a GitHub flavored markdown table.
+///
+/// The first row is considered to be a header.
+/// Alignment of a column is taken from a first data cell (or a header if there's no data).
+pub(crate) fn write_markdown<R>(
+    f: &mut impl fmt::Write,
+    records: R,
+    cfg: &GridConfig,
+) -> fmt::Result
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
+    if count_rows == 0 || count_cols == 0 {
+        return Ok(());
+    }
+
+    let shape = (count_rows, count_cols);
+
+    let mut cells = vec![vec![String::new(); count_cols]; count_rows];
+    for (row, cells) in cells.iter_mut().enumerate() {
+        for (col, cell) in cells.iter_mut().enumerate() {
+            if cfg.is_cell_visible((row, col), shape) {
+                *cell = escape_text(records.get_text((row, col)));
+            }
+        }
+    }
+
+    let mut widths = vec![MIN_DELIMITER_WIDTH; count_cols];
+    for cells in &cells {
+        for (cell, width) in cells.iter().zip(widths.iter_mut()) {
+            *width = std::cmp::max(*width, string_width(cell));
+        }
+    }
+
+    let alignments = (0..count_cols)
+        .map(|col| {
+            let row = if count_rows > 1 { 1 } else { 0 };
+            *cfg.get_alignment_horizontal(Entity::Cell(row, col))
+        })
+        .collect::<Vec<_>>();
+
+    write_row(f, &cells[0], &widths, &alignments)?;
+    f.write_char('\n')?;
+    write_delimiter(f, &widths, &alignments)?;
+
+    for cells in &cells[1..] {
+        f.write_char('\n')?;
+        write_row(f, cells, &widths, &alignments)?;
+    }
+
+    Ok(())
+}
+
+fn write_row(
+    f: &mut impl fmt::Write,
+    cells: &[String],
+    widths: &[usize],
+    alignments: &[AlignmentHorizontal],
+) -> fmt::Result {
+    f.write_char('|')?;
+    for ((text, &width), alignment) in cells.iter().zip(widths).zip(alignments) {
+        let rest = width - string_width(text);
+        let (left, right) = match alignment {
+            AlignmentHorizontal::Left => (0, rest),
+            AlignmentHorizontal::Right => (rest, 0),
+            AlignmentHorizontal::Center => (rest / 2, rest - rest / 2),
+        };
+
+        f.write_char(' ')?;
+        repeat_char(f, ' ', left)?;
+        f.write_str(text)?;
+        repeat_char(f, ' ', right)?;
+        f.write_str(" |")?;
+    }
+
+    Ok(())
+}
+
+fn write_delimiter(
+    f: &mut impl fmt::Write,
+    widths: &[usize],
+    alignments: &[AlignmentHorizontal],
+) -> fmt::Result {
+    f.write_char('|')?;
+    for (&width, alignment) in widths.iter().zip(alignments) {
+        // we count padding spaces as a part of the delimiter
+        let width = width + 2;
+        match alignment {
+            AlignmentHorizontal::Left => repeat_char(f, '-', width)?,
+            AlignmentHorizontal::Right => {
+                repeat_char(f, '-', width - 1)?;
+                f.write_char(':')?;
+            }
+            AlignmentHorizontal::Center => {
+                f.write_char(':')?;
+                repeat_char(f, '-', width - 2)?;
+                f.write_char(':')?;
+            }
+        }
+
+        f.write_char('|')?;
+    }
+
+    Ok(())
+}
+
+fn repeat_char(f: &mut impl fmt::Write, c: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+/// Escapes a text so it can be safely used inside a markdown cell.
+///
+/// Pipes are escaped, and new lines are replaced by `<br>`.
+/// When a `color` feature is on ANSI sequences are removed.
+fn escape_text(text: &str) -> String {
+    #[cfg(feature = "color")]
+    let text = ansi_str::AnsiStr::ansi_strip(text);
+
+    let mut buf = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            buf.push_str("<br>");
+        }
+
+        for c in line.chars() {
+            match c {
+                '|' => buf.push_str("\\|"),
+                '\r' => {}
+                c => buf.push(c),
+            }
+        }
+    }
+
+    buf
+}
@@ -2,6 +2,21 @@
 //!
 //! [`Table`]: crate::Table
 
+mod accessible;
+mod diff;
 mod expanded_display;
+mod fast_render;
+mod layout_json;
+mod live;
+mod markdown;
+mod side_by_side;
 
+pub use diff::TableDiff;
 pub use expanded_display::*;
+pub use live::Live;
+pub use side_by_side::SideBySide;
+
+pub(crate) use accessible::{header_label, write_accessible};
+pub(crate) use fast_render::{is_fast_render_applicable, print_grid};
+pub(crate) use layout_json::write_layout_json;
+pub(crate) use markdown::write_markdown;
@@ -0,0 +1,116 @@
+//! This module contains a [`SideBySide`] structure which renders 2 tables next to each other.
+//!
+//! ```
+//! use tabled::{display::SideBySide, Table};
+//!
+//! let before = Table::new(["Hello", "World"]);
+//! let after = Table::new(["Hello\nthere", "World"]);
+//!
+//! let table = SideBySide::new(&before, &after).gap(2).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+-------+  +-------+\n\
+//!      | &str  |  | &str  |\n\
+//!      +-------+  +-------+\n\
+//!      | Hello |  | Hello |\n\
+//!      |       |  | there |\n\
+//!      +-------+  +-------+\n\
+//!      | World |  | World |\n\
+//!      +-------+  +-------+"
+//! );
+//! ```
+
+use std::fmt::{self, Display};
+
+use papergrid::{records::Records, util::string_width};
+
+use crate::{height::get_table_total_height2, Height, Table};
+
+/// `SideBySide` renders 2 tables next to each other,
+/// so they can be compared line by line.
+///
+/// Heights of rows with the same index are balanced,
+/// so rows of both tables start on the same line.
+/// A shorter table is padded by empty lines at the bottom.
+///
+/// Keep in mind that rows are aligned only when both tables
+/// use the same horizontal lines and margins.
+#[derive(Debug, Clone)]
+pub struct SideBySide<'a, L, R> {
+    left: &'a Table<L>,
+    right: &'a Table<R>,
+    gap: usize,
+}
+
+impl<'a, L, R> SideBySide<'a, L, R> {
+    /// Creates a new [`SideBySide`] view of 2 tables with a gap of 1 space.
+    pub fn new(left: &'a Table<L>, right: &'a Table<R>) -> Self {
+        Self {
+            left,
+            right,
+            gap: 1,
+        }
+    }
+
+    /// Sets a number of spaces between tables.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl<L, R> Display for SideBySide<'_, L, R>
+where
+    L: Records + Clone,
+    R: Records + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+
+        let (_, mut left_heights) = get_table_total_height2(left.get_records(), left.get_config());
+        let (_, mut right_heights) =
+            get_table_total_height2(right.get_records(), right.get_config());
+
+        for (lhs, rhs) in left_heights.iter_mut().zip(right_heights.iter_mut()) {
+            let height = std::cmp::max(*lhs, *rhs);
+            *lhs = height;
+            *rhs = height;
+        }
+
+        left.with(Height::list(left_heights));
+        right.with(Height::list(right_heights));
+
+        let left = left.to_string();
+        let right = right.to_string();
+
+        let left_width = left.lines().map(string_width).max().unwrap_or(0);
+        let gap = " ".repeat(self.gap);
+
+        let mut left_lines = left.lines();
+        let mut right_lines = right.lines();
+        let mut is_first = true;
+        loop {
+            let (lhs, rhs) = match (left_lines.next(), right_lines.next()) {
+                (None, None) => break,
+                (lhs, rhs) => (lhs.unwrap_or(""), rhs),
+            };
+
+            if !is_first {
+                f.write_str("\n")?;
+            }
+
+            is_first = false;
+
+            f.write_str(lhs)?;
+
+            if let Some(rhs) = rhs {
+                let padding = left_width - string_width(lhs);
+                write!(f, "{}{}{}", " ".repeat(padding), gap, rhs)?;
+            }
+        }
+
+        Ok(())
+    }
+}
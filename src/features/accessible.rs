@@ -0,0 +1,146 @@
+//! This module contains an [`Accessible`] option which renders a [`Table`] as a plain list of records.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Accessible, Table};
+//!
+//! let data = [("Tom", 30), ("Ann", 4)];
+//!
+//! let table = Table::new(data)
+//!     .with(Accessible::new())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "&str: Tom\n",
+//!         "i32: 30\n",
+//!         "\n",
+//!         "&str: Ann\n",
+//!         "i32: 4",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{Table, TableOption};
+
+/// Accessible switches a [`Table`] to a screen-reader friendly rendering.
+///
+/// A table is written without any borders, padding or alignment,
+/// as a list of records where each value is put on its own line as `header: value`.
+/// If a table has no header (see [`Table::has_header`]) only values are written.
+/// Records are separated by an empty line, which can be changed by [`Accessible::record_separator`].
+///
+/// Lines of a multiline text are joined by a space, so each value takes exactly 1 line.
+/// Captions are written before and after the records and are separated from them the same way.
+///
+/// As it's only a way a table is printed, it can be turned on and off at runtime,
+/// for example by a command line flag, via [`Accessible::enabled`]
+/// while the rest of settings of a table stay the same.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Accessible, Style, Table};
+///
+/// let accessible = true;
+///
+/// let table = Table::new([("Tom", 30), ("Ann", 4)])
+///     .with(Style::modern())
+///     .with(
+///         Accessible::new()
+///             .enabled(accessible)
+///             .separator(" = ")
+///             .record_separator("--"),
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "&str = Tom\n",
+///         "i32 = 30\n",
+///         "--\n",
+///         "&str = Ann\n",
+///         "i32 = 4",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::has_header`]: crate::Table::has_header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accessible {
+    enabled: bool,
+    separator: String,
+    record_separator: String,
+}
+
+impl Accessible {
+    /// Creates an enabled accessible rendering,
+    /// with `: ` between a header and a value and an empty line between records.
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            separator: String::from(": "),
+            record_separator: String::new(),
+        }
+    }
+
+    /// Sets whether the accessible rendering is used.
+    ///
+    /// A disabled option brings back a usual rendering of a table.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets a text which is put between a header and a value.
+    pub fn separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets a line which is put between records.
+    ///
+    /// It's empty by default, which makes records separated by a blank line.
+    pub fn record_separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.record_separator = separator.into();
+        self
+    }
+
+    pub(crate) fn get_separator(&self) -> &str {
+        &self.separator
+    }
+
+    pub(crate) fn get_record_separator(&self) -> &str {
+        &self.record_separator
+    }
+}
+
+impl Default for Accessible {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> TableOption<R> for Accessible {
+    fn change(&mut self, table: &mut Table<R>) {
+        let accessible = if self.enabled {
+            Some(self.clone())
+        } else {
+            None
+        };
+
+        table.set_accessible(accessible);
+    }
+}
@@ -48,9 +48,15 @@
 //! [`Table`]: crate::Table
 //! [`AlignmentStrategy`]: crate::formatting::AlignmentStrategy
 
-use papergrid::Entity;
+use std::iter::FromIterator;
 
-use crate::{CellOption, Table, TableOption};
+use papergrid::{
+    records::Records,
+    width::{CfgWidthFunction, WidthFunc},
+    Entity, GridConfig, Position,
+};
+
+use crate::{builder::Builder, CellOption, Table, TableOption};
 
 pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 
@@ -65,11 +71,16 @@ pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 ///
 /// [`Table`]: crate::Table
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alignment {
     /// A horizontal alignment.
-    Horizontal(AlignmentHorizontal),
+    Horizontal(
+        #[cfg_attr(feature = "serde", serde(with = "AlignmentHorizontalDef"))] AlignmentHorizontal,
+    ),
     /// A vertical alignment.
-    Vertical(AlignmentVertical),
+    Vertical(
+        #[cfg_attr(feature = "serde", serde(with = "AlignmentVerticalDef"))] AlignmentVertical,
+    ),
 }
 
 impl Alignment {
@@ -119,6 +130,31 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Justify constructs a [`Justification`] which fills a space between parts of a line with a given character.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Alignment, Modify, Style, Table};
+    ///
+    /// let contents = [("Introduction\t1",), ("Getting started\t12",)];
+    ///
+    /// let table = Table::new(contents)
+    ///     .with(Style::blank())
+    ///     .with(Modify::new(Columns::first()).with(Alignment::justify('.')))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &str                   \n",
+    ///         " Introduction.........1 \n",
+    ///         " Getting started.....12 ",
+    ///     )
+    /// );
+    /// ```
+    pub fn justify(fill: char) -> Justification {
+        Justification::new(fill)
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::Horizontal(alignment)
@@ -133,18 +169,223 @@ impl Alignment {
 impl<R> CellOption<R> for Alignment {
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         match *self {
-            Self::Horizontal(a) => table.get_config_mut().set_alignment_horizontal(entity, a),
-            Self::Vertical(a) => table.get_config_mut().set_alignment_vertical(entity, a),
+            Self::Horizontal(a) => {
+                table.set_justification(entity, None);
+                table
+                    .get_config_mut_raw()
+                    .set_alignment_horizontal(entity, a)
+            }
+            Self::Vertical(a) => table.get_config_mut_raw().set_alignment_vertical(entity, a),
         };
     }
 }
 
 impl<R> TableOption<R> for Alignment {
     fn change(&mut self, table: &mut Table<R>) {
-        let cfg = table.get_config_mut();
         match self {
-            Alignment::Horizontal(a) => cfg.set_alignment_horizontal(Entity::Global, *a),
-            Alignment::Vertical(a) => cfg.set_alignment_vertical(Entity::Global, *a),
+            Alignment::Horizontal(a) => {
+                table.set_justification(Entity::Global, None);
+                table
+                    .get_config_mut_raw()
+                    .set_alignment_horizontal(Entity::Global, *a)
+            }
+            Alignment::Vertical(a) => table
+                .get_config_mut_raw()
+                .set_alignment_vertical(Entity::Global, *a),
+        }
+    }
+}
+
+/// Justification splits each line of a cell by a separator
+/// and fills a space between the left and the right parts with a given character,
+/// so the right part is pressed to the right edge of a cell.
+///
+/// It's handy for table of contents like rows (`Introduction.....1`).
+///
+/// The default separator is a tab (`'\t'`).
+/// Lines which don't contain a separator are left untouched,
+/// and are aligned by a horizontal [`Alignment`] as usual.
+///
+/// A line is filled when a table is rendered,
+/// so it takes the whole width of a column even after the column was changed,
+/// for example by [`Width::wrap`].
+/// Keep in mind that [`Width`] settings replace tabs by spaces,
+/// so set a different separator if a width of a column is going to be changed.
+/// A separator itself is replaced by a fill,
+/// so it makes sense to use a wider separator to get a longer minimal fill.
+/// Colored text is measured without its ANSI sequences.
+///
+/// Setting a horizontal [`Alignment`] on a cell drops its justification.
+///
+/// [`Width`]: crate::Width
+/// [`Width::wrap`]: crate::Width::wrap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Justification {
+    fill: char,
+    separator: String,
+}
+
+impl Justification {
+    /// Creates a [`Justification`] with a given fill character.
+    pub fn new(fill: char) -> Self {
+        Self {
+            fill,
+            separator: String::from("\t"),
+        }
+    }
+
+    /// Sets a separator of left and right parts of a line.
+    ///
+    /// An empty separator is ignored.
+    pub fn separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let separator = separator.into();
+        if !separator.is_empty() {
+            self.separator = separator;
         }
+
+        self
+    }
+
+    fn justify_line<W>(&self, line: &str, width: usize, width_fn: &W) -> Option<String>
+    where
+        W: WidthFunc,
+    {
+        let (left, right) = line.split_at(line.find(&self.separator)?);
+        // wrapping with kept words leaves trailing spaces which must not take a place of the fill.
+        let right = right[self.separator.len()..].trim_end_matches(' ');
+
+        let text_width = width_fn.width(left) + width_fn.width(right);
+        let fill_width = width.saturating_sub(text_width);
+
+        let fill_char_width = std::cmp::max(width_fn.width(self.fill.encode_utf8(&mut [0; 4])), 1);
+        let count_fills = fill_width / fill_char_width;
+        let rest = fill_width - count_fills * fill_char_width;
+
+        let mut buf = String::with_capacity(line.len() + fill_width);
+        buf.push_str(left);
+        for _ in 0..count_fills {
+            buf.push(self.fill);
+        }
+        buf.push_str(&" ".repeat(rest));
+        buf.push_str(right);
+
+        Some(buf)
     }
 }
+
+impl<R> CellOption<R> for Justification {
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        table.set_justification(entity, Some(self.clone()));
+    }
+}
+
+impl<R> TableOption<R> for Justification {
+    fn change(&mut self, table: &mut Table<R>) {
+        table.set_justification(Entity::Global, Some(self.clone()));
+    }
+}
+
+/// Builds a table with justified lines,
+/// which is printed instead of an original one.
+///
+/// The last justification set for a cell is used.
+pub(crate) fn build_justified_table<R>(
+    records: &R,
+    cfg: &GridConfig,
+    widths: &[usize],
+    heights: &[usize],
+    justifications: &[(Entity, Option<Justification>)],
+) -> Table
+where
+    R: Records,
+{
+    let shape = (records.count_rows(), records.count_columns());
+    let width_fn = CfgWidthFunction::from_cfg(cfg);
+
+    let rows = (0..shape.0).map(|row| {
+        (0..shape.1)
+            .map(|col| {
+                let pos = (row, col);
+                let text = records.get_text(pos);
+                let justification = justifications
+                    .iter()
+                    .rev()
+                    .find(|(entity, _)| is_entity_cell(*entity, pos))
+                    .and_then(|(_, justification)| justification.as_ref());
+
+                let justification = match justification {
+                    Some(justification) if cfg.is_cell_visible(pos, shape) => justification,
+                    _ => return text.to_owned(),
+                };
+
+                let width = cell_width(cfg, widths, pos, shape);
+                let lines = (0..records.count_lines(pos)).map(|i| {
+                    let line = records.get_line(pos, i);
+                    justification
+                        .justify_line(line, width, &width_fn)
+                        .unwrap_or_else(|| line.to_owned())
+                });
+
+                lines.collect::<Vec<_>>().join("\n")
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut table = Builder::from_iter(rows).build();
+    *table.get_config_mut() = cfg.clone();
+    table.cache_width(widths.to_vec());
+    table.cache_height(heights.to_vec());
+
+    table
+}
+
+fn is_entity_cell(entity: Entity, (row, col): Position) -> bool {
+    match entity {
+        Entity::Global => true,
+        Entity::Column(c) => c == col,
+        Entity::Row(r) => r == row,
+        Entity::Cell(r, c) => r == row && c == col,
+    }
+}
+
+/// Returns a width available for a content of a cell.
+pub(crate) fn cell_width(
+    cfg: &GridConfig,
+    widths: &[usize],
+    pos: Position,
+    shape: (usize, usize),
+) -> usize {
+    let (_, col) = pos;
+    let count_cols = shape.1;
+    let span = cfg.get_column_span(pos, shape).unwrap_or(1);
+
+    let width = widths[col..col + span].iter().sum::<usize>();
+    let verticals = (col + 1..col + span)
+        .filter(|&c| cfg.has_vertical(c, count_cols))
+        .count();
+
+    let padding = cfg.get_padding(pos.into());
+
+    (width + verticals).saturating_sub(padding.left.size + padding.right.size)
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "AlignmentHorizontal")]
+pub(crate) enum AlignmentHorizontalDef {
+    Center,
+    Left,
+    Right,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "AlignmentVertical")]
+pub(crate) enum AlignmentVerticalDef {
+    Center,
+    Top,
+    Bottom,
+}
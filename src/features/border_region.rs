@@ -0,0 +1,141 @@
+//! This module contains a [`BorderRegion`] primitive, which restyles or erases
+//! all borders around cells of a [`Table`] segment.
+//!
+//! [`Table`]: crate::Table
+
+use std::collections::BTreeSet;
+
+use papergrid::{records::Records, Position};
+
+use crate::{object::Object, Border, Table, TableOption};
+
+/// BorderRegion changes all existing borders around cells of a target [`Table`] segment:
+/// lines between cells of the segment, its outline and their intersections.
+///
+/// Unlike [`Highlight`] which draws only an outline of a segment,
+/// it touches each line of the segment.
+/// And unlike setting a [`Border`] via [`Modify`] it never adds a new line,
+/// it only changes the lines which the table already has.
+/// So it can be used to erase lines in order to visually group cells.
+///
+/// Only the sides set in a [`Border`] are changed.
+/// Lines on an outline of a segment are shared with neighbor cells,
+/// so they are changed as well.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{object::Rows, BorderRegion, Style, Table};
+///
+/// let data = [["a", "b"], ["c", "d"], ["e", "f"]];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(BorderRegion::erase(Rows::new(2..)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "┌───┬───┐\n\
+///      │ 0 │ 1 │\n\
+///      ├───┼───┤\n\
+///      │ a │ b │\n\
+///      \u{20}        \n\
+///      \u{20} c   d  \n\
+///      \u{20}        \n\
+///      \u{20} e   f  \n\
+///      \u{20}        "
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Highlight`]: crate::Highlight
+/// [`Modify`]: crate::Modify
+#[derive(Debug)]
+pub struct BorderRegion<O> {
+    target: O,
+    border: Border,
+}
+
+impl<O> BorderRegion<O>
+where
+    O: Object,
+{
+    /// Creates a [`BorderRegion`] which changes borders of a target to a given [`Border`].
+    pub fn new(target: O, border: Border) -> Self {
+        Self { target, border }
+    }
+
+    /// Creates a [`BorderRegion`] which erases borders of a target,
+    /// replacing them with spaces.
+    pub fn erase(target: O) -> Self {
+        Self::new(target, Border::filled(' '))
+    }
+}
+
+impl<O, R> TableOption<R> for BorderRegion<O>
+where
+    O: Object,
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let border: Option<papergrid::Border> = self.border.clone().into();
+        let border = match border {
+            Some(border) => border,
+            None => return,
+        };
+
+        let (count_rows, count_cols) = table.shape();
+        let cells = self
+            .target
+            .cells(table)
+            .flat_map(|entity| entity.iter(count_rows, count_cols))
+            .collect::<BTreeSet<Position>>();
+
+        let cfg = table.get_config_mut();
+        let lines = cells
+            .iter()
+            .map(|&(row, col)| {
+                let lines = CellLines {
+                    top: cfg.has_horizontal(row, count_rows),
+                    bottom: cfg.has_horizontal(row + 1, count_rows),
+                    left: cfg.has_vertical(col, count_cols),
+                    right: cfg.has_vertical(col + 1, count_cols),
+                };
+
+                ((row, col), lines)
+            })
+            .collect::<Vec<_>>();
+
+        for (pos, lines) in lines {
+            cfg.set_border(pos, fit_border(&border, lines));
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CellLines {
+    top: bool,
+    bottom: bool,
+    left: bool,
+    right: bool,
+}
+
+/// Leaves only the sides of a border which a cell has lines for.
+fn fit_border(border: &papergrid::Border, lines: CellLines) -> papergrid::Border {
+    let side = |c: Option<char>, has: bool| c.filter(|_| has);
+
+    papergrid::Border {
+        top: side(border.top, lines.top),
+        bottom: side(border.bottom, lines.bottom),
+        left: side(border.left, lines.left),
+        right: side(border.right, lines.right),
+        left_top_corner: side(border.left_top_corner, lines.top && lines.left),
+        right_top_corner: side(border.right_top_corner, lines.top && lines.right),
+        left_bottom_corner: side(border.left_bottom_corner, lines.bottom && lines.left),
+        right_bottom_corner: side(border.right_bottom_corner, lines.bottom && lines.right),
+    }
+}
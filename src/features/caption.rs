@@ -0,0 +1,292 @@
+//! This module contains a [`Caption`] structure which adds a title or a note to a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Caption, Table, Width};
+//!
+//! let data = [[1, 2, 3], [4, 5, 6]];
+//!
+//! let table = Table::new(data)
+//!     .with(Caption::top("A caption which is longer than the table"))
+//!     .with(Width::truncate(11))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+---------+\n\
+//!      | A capti |\n\
+//!      | on whic |\n\
+//!      | h is lo |\n\
+//!      | nger th |\n\
+//!      | an the  |\n\
+//!      | table   |\n\
+//!      +--+--+---+\n\
+//!      |  |  | 2 |\n\
+//!      +--+--+---+\n\
+//!      |  |  | 3 |\n\
+//!      +--+--+---+\n\
+//!      |  |  | 6 |\n\
+//!      +--+--+---+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::iter::FromIterator;
+
+use papergrid::{
+    records::Records, util::string_width_multiline, AlignmentHorizontal, Borders, Entity,
+    GridConfig, Indent,
+};
+
+#[cfg(feature = "color")]
+use crate::color::to_static_color;
+
+use crate::{builder::Builder, width::wrap_text, Table, TableOption};
+
+/// Caption adds a row above or below a [`Table`] which spans all its columns.
+///
+/// Unlike [`Panel`] a caption doesn't take part in a width calculation of columns.
+/// It's rendered when a table is printed and wrapped to the final width of the table,
+/// so a long caption doesn't widen narrow columns beneath it,
+/// and it follows any changes made by [`Width`].
+///
+/// A caption uses borders, padding and margin of a table,
+/// but its alignment is set separately.
+///
+/// Several captions on the same side are stacked,
+/// the last added one being the farthest from the table.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{alignment::AlignmentHorizontal, Caption, Style, Table};
+///
+/// let data = [[1, 2, 3], [4, 5, 6]];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(Caption::bottom("Total: 2").alignment(AlignmentHorizontal::Right))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "┌───┬───┬───┐\n\
+///      │ 0 │ 1 │ 2 │\n\
+///      ├───┼───┼───┤\n\
+///      │ 1 │ 2 │ 3 │\n\
+///      ├───┼───┼───┤\n\
+///      │ 4 │ 5 │ 6 │\n\
+///      └───┴───┴───┘\n\
+///      │  Total: 2 │\n\
+///      └───────────┘"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Panel`]: crate::Panel
+/// [`Width`]: crate::Width
+#[derive(Debug, Clone)]
+pub struct Caption {
+    text: String,
+    is_top: bool,
+    alignment: AlignmentHorizontal,
+    keep_words: bool,
+}
+
+impl Caption {
+    /// Creates a caption above a table.
+    pub fn top<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(text.into(), true)
+    }
+
+    /// Creates a caption below a table.
+    pub fn bottom<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(text.into(), false)
+    }
+
+    /// Sets a horizontal alignment of a caption.
+    ///
+    /// By default it's [`AlignmentHorizontal::Left`].
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Makes a caption to be wrapped by words if it doesn't fit a table.
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+
+    fn new(text: String, is_top: bool) -> Self {
+        Self {
+            text,
+            is_top,
+            alignment: AlignmentHorizontal::Left,
+            keep_words: false,
+        }
+    }
+
+    pub(crate) fn is_top(&self) -> bool {
+        self.is_top
+    }
+
+    pub(crate) fn get_text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl<R> TableOption<R> for Caption {
+    fn change(&mut self, table: &mut Table<R>) {
+        table.add_caption(self.clone());
+    }
+}
+
+/// Builds a table of captions of one side of a table.
+///
+/// A `width` is a width of a table without a margin.
+/// If it's `None` captions keep their own width.
+pub(crate) fn build_captions_table<'a, C>(
+    captions: C,
+    cfg: &GridConfig,
+    width: Option<usize>,
+    is_top: bool,
+) -> Table
+where
+    C: Iterator<Item = &'a Caption>,
+{
+    let mut captions = captions.collect::<Vec<_>>();
+    if is_top {
+        captions.reverse();
+    }
+
+    let borders = captions_borders(cfg.get_borders(), is_top);
+    let padding = *cfg.get_padding(Entity::Global);
+    let count_verticals = borders.has_left() as usize + borders.has_right() as usize;
+    let cell_width = width.map(|width| width.saturating_sub(count_verticals));
+    let text_width = cell_width.map(|width| {
+        let width = width.saturating_sub(padding.left.size + padding.right.size);
+        std::cmp::max(width, 1)
+    });
+
+    let texts = captions.iter().map(|caption| match text_width {
+        Some(width) if string_width_multiline(&caption.text) > width => {
+            [wrap_text(&caption.text, width, caption.keep_words)]
+        }
+        _ => [caption.text.clone()],
+    });
+
+    let mut table = Builder::from_iter(texts).build();
+
+    let mut margin = *cfg.get_margin();
+    if is_top {
+        margin.bottom = Indent::default();
+    } else {
+        margin.top = Indent::default();
+    }
+
+    let table_cfg = table.get_config_mut();
+    table_cfg.set_borders(borders);
+    table_cfg.set_padding(Entity::Global, padding);
+    table_cfg.set_margin(margin);
+    for (row, caption) in captions.iter().enumerate() {
+        table_cfg.set_alignment_horizontal(Entity::Row(row), caption.alignment);
+    }
+
+    #[cfg(feature = "color")]
+    {
+        if let Some(color) = cfg.get_border_color_global() {
+            let color = to_static_color(color);
+            table.get_config_mut().set_border_color_global(color);
+        }
+
+        let color = cfg.get_margin_color();
+        let color = papergrid::MarginColor {
+            top: to_static_color(&color.top),
+            bottom: to_static_color(&color.bottom),
+            left: to_static_color(&color.left),
+            right: to_static_color(&color.right),
+        };
+        table.get_config_mut().set_margin_color(color);
+    }
+
+    if let Some(width) = cell_width {
+        let margin = margin.left.size + margin.right.size;
+        let natural_width = table.total_width() - margin - count_verticals;
+        if natural_width <= width {
+            table.cache_width(vec![width]);
+        }
+    }
+
+    table
+}
+
+/// Removes a margin of a table on a side where captions are.
+pub(crate) fn strip_margin(cfg: &mut GridConfig, has_top: bool, has_bottom: bool) {
+    let mut margin = *cfg.get_margin();
+    if has_top {
+        margin.top = Indent::default();
+    }
+
+    if has_bottom {
+        margin.bottom = Indent::default();
+    }
+
+    cfg.set_margin(margin);
+}
+
+/// Returns a width of a table without a margin.
+pub(crate) fn table_width<R>(table: &Table<R>) -> Option<usize>
+where
+    R: Records,
+{
+    if table.is_empty() {
+        return None;
+    }
+
+    let margin = table.get_config().get_margin();
+    Some(table.total_width() - margin.left.size - margin.right.size)
+}
+
+fn captions_borders(borders: &Borders<char>, is_top: bool) -> Borders<char> {
+    let mut b = Borders {
+        vertical_left: borders.vertical_left,
+        vertical_right: borders.vertical_right,
+        horizontal: borders.horizontal,
+        horizontal_left: borders.horizontal_left,
+        horizontal_right: borders.horizontal_right,
+        ..Default::default()
+    };
+
+    if is_top {
+        b.top = borders.top;
+        b.top_left = borders.top_left;
+        b.top_right = borders.top_right;
+
+        if !borders.has_top() {
+            b.bottom = borders.horizontal;
+            b.bottom_left = borders.horizontal_left;
+            b.bottom_right = borders.horizontal_right;
+        }
+    } else {
+        b.bottom = borders.bottom;
+        b.bottom_left = borders.bottom_left;
+        b.bottom_right = borders.bottom_right;
+
+        if !borders.has_bottom() {
+            b.top = borders.horizontal;
+            b.top_left = borders.horizontal_left;
+            b.top_right = borders.horizontal_right;
+        }
+    }
+
+    b
+}
@@ -4,7 +4,7 @@
 
 use std::{borrow::Cow, convert::TryFrom};
 
-use papergrid::{records::Records, AnsiColor, Entity};
+use papergrid::{records::Records, AnsiColor, Entity, PaddingColor};
 
 use crate::{CellOption, Table, TableOption};
 
@@ -205,10 +205,31 @@ impl TryFrom<String> for Color {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (self.0.get_prefix(), self.0.get_suffix()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (prefix, suffix) = <(String, String)>::deserialize(deserializer)?;
+        Ok(Self::new(prefix, suffix))
+    }
+}
+
 impl<R> TableOption<R> for Color {
     fn change(&mut self, table: &mut Table<R>) {
         let color = self.0.clone();
-        table.get_config_mut().set_border_color_global(color);
+        table.get_config_mut_raw().set_border_color_global(color);
     }
 }
 
@@ -221,7 +242,9 @@ where
 
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
-            table.get_config_mut().set_border_color(pos, border.clone());
+            table
+                .get_config_mut_raw()
+                .set_border_color(pos, border.clone());
         }
     }
 }
@@ -235,7 +258,9 @@ where
 
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
-            table.get_config_mut().set_border_color(pos, border.clone());
+            table
+                .get_config_mut_raw()
+                .set_border_color(pos, border.clone());
         }
     }
 }
@@ -252,3 +277,101 @@ fn border_color(color: &Color) -> papergrid::Border<AnsiColor<'static>> {
         color.0.clone(),
     )
 }
+
+/// Copies a color which borrows its sequences, like the one returned by a [`GridConfig`].
+///
+/// [`GridConfig`]: papergrid::GridConfig
+pub(crate) fn to_static_color(color: &AnsiColor<'_>) -> AnsiColor<'static> {
+    AnsiColor::new(
+        color.get_prefix().to_owned().into(),
+        color.get_suffix().to_owned().into(),
+    )
+}
+
+/// Copies a padding color which borrows its sequences, like the one returned by a [`GridConfig`].
+///
+/// [`GridConfig`]: papergrid::GridConfig
+pub(crate) fn to_static_padding_color(color: &PaddingColor<'_>) -> PaddingColor<'static> {
+    PaddingColor {
+        top: to_static_color(&color.top),
+        bottom: to_static_color(&color.bottom),
+        left: to_static_color(&color.left),
+        right: to_static_color(&color.right),
+    }
+}
+
+/// Returns a background color which is set at the first visible character of a text.
+pub(crate) fn get_first_background(text: &str) -> Option<AnsiColor<'static>> {
+    find_background(text, true)
+}
+
+/// Returns a background color which is set at the last visible character of a text.
+pub(crate) fn get_last_background(text: &str) -> Option<AnsiColor<'static>> {
+    find_background(text, false)
+}
+
+fn find_background(text: &str, first: bool) -> Option<AnsiColor<'static>> {
+    let mut current = None;
+    let mut found = None;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(seq) = rest.strip_prefix("\u{1b}[") {
+            let end = seq.find(|c: char| !c.is_ascii_digit() && c != ';')?;
+            let (params, tail) = seq.split_at(end);
+            let last = tail.chars().next()?;
+            if last == 'm' {
+                update_background(&mut current, params);
+            }
+
+            rest = &tail[last.len_utf8()..];
+            continue;
+        }
+
+        rest = &rest[c.len_utf8()..];
+        if c == '\n' {
+            continue;
+        }
+
+        found = current.clone();
+        if first {
+            break;
+        }
+    }
+
+    found.map(|bg| {
+        AnsiColor::new(
+            Cow::Owned(format!("\u{1b}[{}m", bg)),
+            Cow::Borrowed("\u{1b}[49m"),
+        )
+    })
+}
+
+fn update_background(current: &mut Option<String>, params: &str) {
+    let mut codes = params.split(';');
+    while let Some(code) = codes.next() {
+        match code {
+            "" | "0" | "49" => *current = None,
+            "38" | "48" | "58" => {
+                let args = match codes.next() {
+                    Some("5") => codes.next().map(|n| format!("5;{}", n)),
+                    Some("2") => {
+                        let rgb = codes.by_ref().take(3).collect::<Vec<_>>();
+                        Some(format!("2;{}", rgb.join(";")))
+                    }
+                    _ => None,
+                };
+
+                if let (Some(args), "48") = (args, code) {
+                    *current = Some(format!("48;{}", args));
+                }
+            }
+            code => {
+                if let Ok(n) = code.parse::<u8>() {
+                    if (40..=47).contains(&n) || (100..=107).contains(&n) {
+                        *current = Some(code.to_owned());
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! This module contains a [`Colorization`] structure which colors cells based on their content.
+//!
+//! The module is available only when a `color` feature is on.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{color::Color, Colorization, Table};
+//!
+//! let data = [("Gold", "1.5"), ("Oil", "-0.3")];
+//!
+//! let table = Table::new(data)
+//!     .with(Colorization::by(|_, text| match text.parse::<f64>() {
+//!         Ok(n) if n < 0.0 => Some(Color::FG_RED),
+//!         _ => None,
+//!     }))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+------+------+\n\
+//!      | &str | &str |\n\
+//!      +------+------+\n\
+//!      | Gold | 1.5  |\n\
+//!      +------+------+\n\
+//!      | Oil  | \u{1b}[31m-0.3\u{1b}[39m |\n\
+//!      +------+------+"
+//! );
+//! ```
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    AnsiColor, Entity, Position,
+};
+
+use crate::{color::Color, CellOption, Table, TableOption};
+
+/// Colorization sets a color of a text of cells chosen by a function
+/// which gets a position of a cell and its text.
+///
+/// If the function returns `None` a cell is left untouched.
+///
+/// Each line of a cell is colored separately, so borders are never colored.
+///
+/// # Ordering
+///
+/// Colors are written into records as ANSI sequences when the option is applied,
+/// so options applied afterwards get a colored text.
+/// Width calculations ignore ANSI sequences,
+/// so a colored content can still be wrapped or truncated by [`Width`] and keeps its color.
+/// But the function gets a text which a cell has at the moment the option is applied,
+/// so if [`Width`] goes first the function gets a wrapped or truncated text.
+///
+/// ```
+/// use tabled::{color::Color, Colorization, Table, Width};
+///
+/// let is_long = |_, text: &str| match text.len() > 3 {
+///     true => Some(Color::FG_RED),
+///     false => None,
+/// };
+///
+/// let colored = Table::new(["long"])
+///     .with(Colorization::by(is_long))
+///     .with(Width::truncate(6))
+///     .to_string();
+///
+/// let truncated = Table::new(["long"])
+///     .with(Width::truncate(6))
+///     .with(Colorization::by(is_long))
+///     .to_string();
+///
+/// assert!(colored.contains("\u{1b}[31m"));
+/// assert!(!truncated.contains("\u{1b}[31m"));
+/// ```
+///
+/// It can be used either as a [`CellOption`] (via [`Modify`]) or as a [`TableOption`],
+/// in which case it's applied to all cells.
+///
+/// [`Width`]: crate::Width
+/// [`Modify`]: crate::Modify
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug, Clone)]
+pub struct Colorization<F> {
+    f: F,
+}
+
+impl<F> Colorization<F> {
+    /// Creates a [`Colorization`] from a function.
+    pub fn by(f: F) -> Self
+    where
+        F: FnMut(Position, &str) -> Option<Color>,
+    {
+        Self { f }
+    }
+}
+
+impl<F, R> CellOption<R> for Colorization<F>
+where
+    F: FnMut(Position, &str) -> Option<Color>,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            let color = match (self.f)(pos, text) {
+                Some(color) => AnsiColor::from(color),
+                None => continue,
+            };
+
+            let text = colorize_lines(text, &color);
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache();
+    }
+}
+
+impl<F, R> TableOption<R> for Colorization<F>
+where
+    F: FnMut(Position, &str) -> Option<Color>,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+pub(crate) fn colorize_lines(text: &str, color: &AnsiColor<'_>) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        buf.push_str(color.get_prefix());
+        buf.push_str(line);
+        buf.push_str(color.get_suffix());
+    }
+
+    buf
+}
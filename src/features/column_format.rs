@@ -0,0 +1,395 @@
+//! This module contains a [`ColumnFormat`] option which formats values of a column by their type.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Columns, ColumnFormat, Modify, Style, Table};
+//!
+//! let data = [("apple", 3.5), ("melon", 1234.125)];
+//!
+//! let table = Table::new(data)
+//!     .with(Style::psql())
+//!     .with(Modify::new(Columns::single(1)).with(ColumnFormat::number().precision(2).thousands_separator(',')))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str  | f64      \n",
+//!         "-------+----------\n",
+//!         " apple | 3.50     \n",
+//!         " melon | 1,234.12 ",
+//!     )
+//! );
+//! ```
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{builder::NumberFormat, CellOption, Table};
+
+/// ColumnFormat formats values of cells which it's applied to according to their type,
+/// like numbers or dates.
+///
+/// Header rows are left untouched if a table has a header (see [`Table::count_header_rows`]),
+/// so it's meant to be applied to whole columns.
+/// Cells which can't be parsed as a value of the type are left untouched as well.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{object::Columns, ColumnFormat, Modify, Style, Table};
+///
+/// let data = [("release", "2022-10-05T12:30:00Z"), ("epoch", "0")];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(ColumnFormat::datetime("%d %b %Y %H:%M")))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str    | &str              \n",
+///         "---------+-------------------\n",
+///         " release | 05 Oct 2022 12:30 \n",
+///         " epoch   | 01 Jan 1970 00:00 ",
+///     )
+/// );
+/// ```
+///
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+#[derive(Debug, Clone)]
+pub struct ColumnFormat {
+    kind: FormatKind,
+}
+
+#[derive(Debug, Clone)]
+enum FormatKind {
+    Number(NumberFormat),
+    DateTime(String),
+}
+
+impl ColumnFormat {
+    /// Creates a format of numbers.
+    ///
+    /// Integers and floating point numbers are recognized,
+    /// and their look is set by [`ColumnFormat::precision`] and [`ColumnFormat::thousands_separator`].
+    pub fn number() -> Self {
+        Self {
+            kind: FormatKind::Number(NumberFormat::new()),
+        }
+    }
+
+    /// Creates a format of dates and times, which are printed by a given pattern.
+    ///
+    /// Dates like `2022-10-05`, date times like `2022-10-05 12:30:00` or `2022-10-05T12:30:00+02:00`
+    /// and unix timestamps in seconds are recognized.
+    /// A time zone offset is dropped, so a time is printed as it's written.
+    ///
+    /// A pattern supports the following specifiers:
+    ///
+    /// - `%Y` a year, `%y` a year without a century,
+    /// - `%m` a month, `%b` and `%B` a short and a full name of a month,
+    /// - `%d` a day, `%e` a day padded by a space, `%j` a day of a year,
+    /// - `%a` and `%A` a short and a full name of a weekday,
+    /// - `%H` an hour, `%I` an hour of a 12-hour clock, `%p` `AM` or `PM`,
+    /// - `%M` a minute, `%S` a second,
+    /// - `%F` a date as `%Y-%m-%d`, `%T` a time as `%H:%M:%S`,
+    /// - `%%` a `%` sign.
+    ///
+    /// Other specifiers are printed as they are.
+    pub fn datetime<S>(pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            kind: FormatKind::DateTime(pattern.into()),
+        }
+    }
+
+    /// Sets a number of digits after a decimal point of floating point numbers.
+    ///
+    /// It has no effect on a format of dates.
+    pub fn precision(mut self, precision: usize) -> Self {
+        if let FormatKind::Number(format) = self.kind {
+            self.kind = FormatKind::Number(format.precision(precision));
+        }
+
+        self
+    }
+
+    /// Sets a separator of thousands groups in an integer part of numbers.
+    ///
+    /// It has no effect on a format of dates.
+    pub fn thousands_separator(mut self, c: char) -> Self {
+        if let FormatKind::Number(format) = self.kind {
+            self.kind = FormatKind::Number(format.thousands_separator(c));
+        }
+
+        self
+    }
+
+    fn format(&self, text: &str) -> Option<String> {
+        match &self.kind {
+            FormatKind::Number(format) => format.format_text(text),
+            FormatKind::DateTime(pattern) => {
+                DateTime::parse(text.trim()).map(|datetime| datetime.format(pattern))
+            }
+        }
+    }
+}
+
+impl<R> CellOption<R> for ColumnFormat
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let count_header_rows = table.count_header_rows();
+        let (count_rows, count_cols) = table.shape();
+
+        let mut changed = false;
+        for pos in entity.iter(count_rows, count_cols) {
+            if pos.0 < count_header_rows {
+                continue;
+            }
+
+            let text = table.get_records().get_text(pos);
+            if let Some(text) = self.format(text) {
+                table.get_records_mut_raw().set(pos, text, &width_fn);
+                changed = true;
+            }
+        }
+
+        if changed {
+            table.destroy_width_cache_of(entity);
+            table.destroy_height_cache();
+        }
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl DateTime {
+    fn parse(text: &str) -> Option<Self> {
+        if let Ok(timestamp) = text.parse::<i64>() {
+            return Some(Self::from_timestamp(timestamp));
+        }
+
+        let (date, time) = match text.find(['T', ' ']) {
+            Some(i) => (&text[..i], Some(&text[i + 1..])),
+            None => (text, None),
+        };
+
+        let mut parts = date.splitn(3, '-');
+        let year = parse_number(parts.next()?, 4)?;
+        let month = parse_number(parts.next()?, 2)?;
+        let day = parse_number(parts.next()?, 2)?;
+
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year as i64, month) {
+            return None;
+        }
+
+        let (hour, minute, second) = match time {
+            Some(time) => parse_time(time)?,
+            None => (0, 0, 0),
+        };
+
+        Some(Self {
+            year: year as i64,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    fn from_timestamp(timestamp: i64) -> Self {
+        let days = timestamp.div_euclid(86400);
+        let seconds = timestamp.rem_euclid(86400) as u32;
+
+        let (year, month, day) = civil_from_days(days);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: seconds / 3600,
+            minute: seconds % 3600 / 60,
+            second: seconds % 60,
+        }
+    }
+
+    fn format(&self, pattern: &str) -> String {
+        let mut buf = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                buf.push(c);
+                continue;
+            }
+
+            let spec = match chars.next() {
+                Some(spec) => spec,
+                None => {
+                    buf.push('%');
+                    break;
+                }
+            };
+
+            let month = MONTHS[self.month as usize - 1];
+            let weekday = WEEKDAYS[self.weekday()];
+
+            match spec {
+                'Y' => buf.push_str(&self.year.to_string()),
+                'y' => buf.push_str(&format!("{:02}", self.year.rem_euclid(100))),
+                'm' => buf.push_str(&format!("{:02}", self.month)),
+                'b' => buf.push_str(&month[..3]),
+                'B' => buf.push_str(month),
+                'd' => buf.push_str(&format!("{:02}", self.day)),
+                'e' => buf.push_str(&format!("{:>2}", self.day)),
+                'j' => buf.push_str(&format!("{:03}", self.day_of_year())),
+                'a' => buf.push_str(&weekday[..3]),
+                'A' => buf.push_str(weekday),
+                'H' => buf.push_str(&format!("{:02}", self.hour)),
+                'I' => buf.push_str(&format!("{:02}", (self.hour + 11) % 12 + 1)),
+                'p' => buf.push_str(if self.hour < 12 { "AM" } else { "PM" }),
+                'M' => buf.push_str(&format!("{:02}", self.minute)),
+                'S' => buf.push_str(&format!("{:02}", self.second)),
+                'F' => buf.push_str(&self.format("%Y-%m-%d")),
+                'T' => buf.push_str(&self.format("%H:%M:%S")),
+                '%' => buf.push('%'),
+                spec => {
+                    buf.push('%');
+                    buf.push(spec);
+                }
+            }
+        }
+
+        buf
+    }
+
+    /// Returns a day of a week, where 0 is Sunday.
+    fn weekday(&self) -> usize {
+        // 1970-01-01 was Thursday.
+        (days_from_civil(self.year, self.month, self.day) + 4).rem_euclid(7) as usize
+    }
+
+    fn day_of_year(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) - days_from_civil(self.year, 1, 1) + 1
+    }
+}
+
+fn parse_time(text: &str) -> Option<(u32, u32, u32)> {
+    // a time zone offset is dropped.
+    let end = text.find(['Z', '+', '-']).unwrap_or(text.len());
+    let text = text[..end].trim_end();
+
+    let mut parts = text.splitn(3, ':');
+    let hour = parse_number(parts.next()?, 2)?;
+    let minute = parse_number(parts.next()?, 2)?;
+    let second = match parts.next() {
+        Some(second) => {
+            // a fraction of a second is dropped.
+            let second = second.split('.').next()?;
+            parse_number(second, 2)?
+        }
+        None => 0,
+    };
+
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    Some((hour, minute, second))
+}
+
+fn parse_number(text: &str, len: usize) -> Option<u32> {
+    if text.len() != len || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    text.parse().ok()
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns a number of days since 1970-01-01.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+/// Returns a date by a number of days since 1970-01-01.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    (year, month, day)
+}
@@ -50,15 +50,20 @@
 use papergrid::{
     records::{Records, RecordsMut, Resizable},
     width::CfgWidthFunction,
+    GridConfig,
 };
 
-use crate::{Table, TableOption};
+use crate::{table::copy_cell_config, Table, TableOption};
 
 /// [`Concat`] concatenate tables along a particular axis [Horizontal | Vertical].
 /// It doesn't do any key or column comparisons like SQL's join does.
 ///
 /// When the tables has different sizes, empty cells will be created by default.
 ///
+/// Settings of cells of a second table (alignment, padding, formatting, colors and spans)
+/// are carried along with its records, while a style and other settings of a whole table
+/// are taken from a first one.
+///
 /// [`Concat`] in horizontal mode has similar behaiviour to tuples `(a, b)`.
 /// But it behaives on tables rather than on an actual data.
 ///
@@ -163,5 +168,37 @@ where
                 }
             }
         }
+
+        let offset = match self.mode {
+            ConcatMode::Horizontal => (0, count_cols),
+            ConcatMode::Vertical => (count_rows, 0),
+        };
+
+        merge_config(lhs.get_config_mut(), rhs.get_config(), rhs.shape(), offset);
+
+        lhs.destroy_width_cache();
+        lhs.destroy_height_cache();
+    }
+}
+
+/// Copies settings of cells and spans of a `rhs` config to a `lhs` one shifted by an `offset`.
+fn merge_config(
+    lhs: &mut GridConfig,
+    rhs: &GridConfig,
+    shape: (usize, usize),
+    (row_offset, col_offset): (usize, usize),
+) {
+    for row in 0..shape.0 {
+        for col in 0..shape.1 {
+            copy_cell_config(lhs, (row + row_offset, col + col_offset), rhs, (row, col));
+        }
+    }
+
+    for ((row, col), span) in rhs.iter_column_spans(shape) {
+        lhs.set_column_span((row + row_offset, col + col_offset), span);
+    }
+
+    for ((row, col), span) in rhs.iter_row_spans(shape) {
+        lhs.set_row_span((row + row_offset, col + col_offset), span);
     }
 }
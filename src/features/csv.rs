@@ -0,0 +1,175 @@
+//! This module contains a [`CsvReader`] structure which reads a delimited data (CSV, TSV) into a [`Builder`].
+//!
+//! The module is available only when a `csv` feature is on.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{csv::CsvReader, Style};
+//!
+//! let data = "name,version\ntabled,0.10.0\npapergrid,0.7.1\n";
+//!
+//! let table = CsvReader::new()
+//!     .read(data.as_bytes())
+//!     .unwrap()
+//!     .build()
+//!     .with(Style::markdown())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "| name      | version |\n\
+//!      |-----------|---------|\n\
+//!      | tabled    | 0.10.0  |\n\
+//!      | papergrid | 0.7.1   |"
+//! );
+//! ```
+//!
+//! [`Builder`]: crate::builder::Builder
+
+use std::io::{self, Read};
+
+use crate::builder::Builder;
+
+/// [`CsvReader`] reads a delimited data into a [`Builder`].
+///
+/// Fields may be quoted by `"`, a quote inside a quoted field is escaped by doubling it (`""`).
+/// A quoted field can contain delimiters and line breaks.
+///
+/// Empty lines are skipped.
+/// Rows with a different number of fields are padded by the [`Builder`].
+///
+/// [`Builder`]: crate::builder::Builder
+#[derive(Debug, Clone)]
+pub struct CsvReader {
+    delimiter: char,
+    has_headers: bool,
+}
+
+impl CsvReader {
+    /// Creates a [`CsvReader`] for a comma separated data,
+    /// where the first row is used as a header.
+    pub fn new() -> Self {
+        Self {
+            delimiter: ',',
+            has_headers: true,
+        }
+    }
+
+    /// Creates a [`CsvReader`] for a tab separated data,
+    /// where the first row is used as a header.
+    pub fn tsv() -> Self {
+        Self::new().delimiter('\t')
+    }
+
+    /// Sets a delimiter of fields.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first row must be used as a header.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Reads the data into a [`Builder`].
+    ///
+    /// It fails if a reader fails, the data is not valid UTF-8
+    /// or a quoted field is not closed.
+    ///
+    /// [`Builder`]: crate::builder::Builder
+    pub fn read<R>(&self, mut reader: R) -> io::Result<Builder<'static>>
+    where
+        R: Read,
+    {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        let mut records = parse(&text, self.delimiter)?.into_iter();
+
+        let mut builder = Builder::default();
+        if self.has_headers {
+            if let Some(columns) = records.next() {
+                builder.set_columns(columns);
+            }
+        }
+
+        for record in records {
+            builder.add_record(record);
+        }
+
+        Ok(builder)
+    }
+}
+
+impl Default for CsvReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse(text: &str, delimiter: char) -> io::Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut is_record_empty = true;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    is_quoted = false;
+                }
+            } else {
+                field.push(c);
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => {
+                is_quoted = true;
+                is_record_empty = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                if !is_record_empty {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+
+                is_record_empty = true;
+            }
+            c if c == delimiter => {
+                record.push(std::mem::take(&mut field));
+                is_record_empty = false;
+            }
+            c => {
+                field.push(c);
+                is_record_empty = false;
+            }
+        }
+    }
+
+    if is_quoted {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "a quoted field is not closed",
+        ));
+    }
+
+    if !is_record_empty {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,129 @@
+//! This module contains helpers to build one-off custom options from closures.
+//!
+//! They take care of a cache invalidation of a [`Table`],
+//! so a closure needs to know nothing about internals.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity, Position,
+};
+
+use crate::{CellOption, Table, TableOption};
+
+/// CellMap changes a content of cells by a function which gets a position of a cell and its text.
+///
+/// It can be used either as a [`CellOption`] (via [`Modify`]) or as a [`TableOption`],
+/// in which case it's applied to all cells.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{custom::CellMap, object::Rows, Modify, Table};
+///
+/// let data = vec![("Tom", 30), ("Ann", 4)];
+///
+/// let table = Table::new(data)
+///     .with(Modify::new(Rows::new(1..)).with(CellMap::new(|(row, _), text| format!("{}. {}", row, text))))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+--------+-------+\n\
+///      | &str   | i32   |\n\
+///      +--------+-------+\n\
+///      | 1. Tom | 1. 30 |\n\
+///      +--------+-------+\n\
+///      | 2. Ann | 2. 4  |\n\
+///      +--------+-------+"
+/// );
+/// ```
+///
+/// [`Modify`]: crate::Modify
+#[derive(Debug, Clone)]
+pub struct CellMap<F> {
+    f: F,
+}
+
+impl<F> CellMap<F> {
+    /// Creates a [`CellMap`] from a function.
+    pub fn new(f: F) -> Self
+    where
+        F: FnMut(Position, &str) -> String,
+    {
+        Self { f }
+    }
+}
+
+impl<F, R> CellOption<R> for CellMap<F>
+where
+    F: FnMut(Position, &str) -> String,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            let text = (self.f)(pos, text);
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+impl<F, R> TableOption<R> for CellMap<F>
+where
+    F: FnMut(Position, &str) -> String,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+/// TableInspect calls a function with a [`Table`] without changing it.
+///
+/// It may be used to look at an intermediate state of a table in a chain of options.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{custom::TableInspect, Table, Width};
+///
+/// let mut widths = Vec::new();
+///
+/// let table = Table::new(["Hello World!"])
+///     .with(TableInspect::new(|table: &Table| widths.push(table.total_width())))
+///     .with(Width::truncate(10))
+///     .with(TableInspect::new(|table: &Table| widths.push(table.total_width())))
+///     .to_string();
+///
+/// assert_eq!(widths, [16, 10]);
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct TableInspect<F> {
+    f: F,
+}
+
+impl<F> TableInspect<F> {
+    /// Creates a [`TableInspect`] from a function.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F, R> TableOption<R> for TableInspect<F>
+where
+    F: FnMut(&Table<R>),
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        (self.f)(table);
+    }
+}
@@ -0,0 +1,219 @@
+//! This module contains a [`DerivedConfig`] structure which carries a look of one [`Table`] to another.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Alignment, Modify, object::Segment, Style, Table};
+//!
+//! let mut parent = Table::new([("Tom", 30), ("Ann", 4)]);
+//! parent
+//!     .with(Style::psql())
+//!     .with(Modify::new(Segment::all()).with(Alignment::right()));
+//!
+//! let table = Table::new([("Bob", 100)])
+//!     .with(parent.derive_config())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str | i32 \n",
+//!         "------+-----\n",
+//!         "  Bob | 100 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{records::Records, Entity, GridConfig};
+
+use crate::{HeaderPosition, Table, TableOption};
+
+#[cfg(feature = "color")]
+use crate::features::stripe::Stripe;
+
+/// DerivedConfig is a look of a [`Table`] which can be applied to other tables,
+/// so extracted regions, nested tables or split chunks look the same as the original one.
+///
+/// It's created by [`Table::derive_config`].
+///
+/// It carries borders and lines, margin, global padding, alignment and formatting,
+/// settings of header rows, a [`HeaderPosition`], and when a `color` feature is on colors and stripes.
+/// Settings of particular cells, spans and texts on borders are not carried,
+/// as they are bound to a content of the original table.
+///
+/// Horizontal and vertical lines which are inside a table are kept by their index,
+/// while the outer ones stay outer regardless of a shape of a table.
+///
+/// Applying it overrides the according settings made before,
+/// so it's better to apply it first and tweak the result after.
+///
+/// [`Table`]: crate::Table
+/// [`Table::derive_config`]: crate::Table::derive_config
+#[derive(Debug, Clone)]
+pub struct DerivedConfig {
+    cfg: GridConfig,
+    shape: (usize, usize),
+    count_header_rows: usize,
+    header_position: HeaderPosition,
+    #[cfg(feature = "color")]
+    stripe: Option<Stripe>,
+}
+
+impl DerivedConfig {
+    pub(crate) fn new(
+        cfg: GridConfig,
+        shape: (usize, usize),
+        count_header_rows: usize,
+        header_position: HeaderPosition,
+    ) -> Self {
+        Self {
+            cfg,
+            shape,
+            count_header_rows,
+            header_position,
+            #[cfg(feature = "color")]
+            stripe: None,
+        }
+    }
+
+    #[cfg(feature = "color")]
+    pub(crate) fn set_stripe(&mut self, stripe: Option<Stripe>) {
+        self.stripe = stripe;
+    }
+}
+
+impl<R> TableOption<R> for DerivedConfig
+where
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let shape = table.shape();
+        let count_header_rows = std::cmp::min(self.count_header_rows, table.count_header_rows());
+
+        let mut cfg = self.cfg.clone();
+        reset_cell_settings(&mut cfg, &self.cfg, count_header_rows);
+        reset_spans(&mut cfg, &self.cfg, self.shape);
+        reset_lines(&mut cfg, &self.cfg, self.shape, shape);
+
+        #[cfg(feature = "color")]
+        for row in 0..self.shape.0 {
+            for col in 0..self.shape.1 {
+                cfg.remove_border_color((row, col), self.shape);
+            }
+        }
+
+        *table.get_config_mut() = cfg;
+        table.set_header_position(self.header_position);
+
+        #[cfg(feature = "color")]
+        if let Some(stripe) = &self.stripe {
+            table.set_stripe(stripe.clone());
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Leaves only global settings of cells and the ones of header rows.
+fn reset_cell_settings(new_cfg: &mut GridConfig, cfg: &GridConfig, count_header_rows: usize) {
+    // setting a global value drops all the values of particular cells, rows and columns.
+    new_cfg.set_padding(Entity::Global, *cfg.get_padding(Entity::Global));
+    new_cfg.set_alignment_horizontal(
+        Entity::Global,
+        *cfg.get_alignment_horizontal(Entity::Global),
+    );
+    new_cfg.set_alignment_vertical(Entity::Global, *cfg.get_alignment_vertical(Entity::Global));
+    new_cfg.set_formatting(Entity::Global, *cfg.get_formatting(Entity::Global));
+
+    for header in (0..count_header_rows).map(Entity::Row) {
+        if cfg.get_padding(header) != cfg.get_padding(Entity::Global) {
+            new_cfg.set_padding(header, *cfg.get_padding(header));
+        }
+
+        if cfg.get_alignment_horizontal(header) != cfg.get_alignment_horizontal(Entity::Global) {
+            new_cfg.set_alignment_horizontal(header, *cfg.get_alignment_horizontal(header));
+        }
+
+        if cfg.get_alignment_vertical(header) != cfg.get_alignment_vertical(Entity::Global) {
+            new_cfg.set_alignment_vertical(header, *cfg.get_alignment_vertical(header));
+        }
+
+        if cfg.get_formatting(header) != cfg.get_formatting(Entity::Global) {
+            new_cfg.set_formatting(header, *cfg.get_formatting(header));
+        }
+    }
+
+    #[cfg(feature = "color")]
+    {
+        use crate::color::to_static_padding_color;
+
+        let color = cfg.get_padding_color(Entity::Global);
+        new_cfg.set_padding_color(Entity::Global, to_static_padding_color(color));
+
+        for header in (0..count_header_rows).map(Entity::Row) {
+            if cfg.get_padding_color(header) != color {
+                let color = to_static_padding_color(cfg.get_padding_color(header));
+                new_cfg.set_padding_color(header, color);
+            }
+        }
+    }
+}
+
+fn reset_spans(new_cfg: &mut GridConfig, cfg: &GridConfig, shape: (usize, usize)) {
+    for (pos, _) in cfg.iter_column_spans(shape) {
+        new_cfg.set_column_span(pos, 1);
+    }
+
+    for (pos, _) in cfg.iter_row_spans(shape) {
+        new_cfg.set_row_span(pos, 1);
+    }
+}
+
+/// Sets borders and lines of a `cfg` of a `shape` to a `new_cfg` of a `new_shape`,
+/// dropping the ones which were set to particular cells.
+fn reset_lines(
+    new_cfg: &mut GridConfig,
+    cfg: &GridConfig,
+    shape: (usize, usize),
+    new_shape: (usize, usize),
+) {
+    new_cfg.clear_theme();
+    new_cfg.set_borders(cfg.get_borders().clone());
+
+    if let Some(&c) = cfg.get_global_border() {
+        new_cfg.set_global_border(c);
+    }
+
+    for line in 0..=shape.0 {
+        if let Some(&hline) = cfg.get_horizontal_line(line) {
+            if let Some(line) = map_line(line, shape.0, new_shape.0) {
+                new_cfg.set_horizontal_line(line, hline);
+            }
+        }
+    }
+
+    for line in 0..=shape.1 {
+        if let Some(&vline) = cfg.get_vertical_line(line) {
+            if let Some(line) = map_line(line, shape.1, new_shape.1) {
+                new_cfg.set_vertical_line(line, vline);
+            }
+        }
+    }
+}
+
+/// Returns an index of a line in a table with `new_count` rows (or columns),
+/// an outer line stays outer and an inner line is kept only if there's such in a new table.
+fn map_line(line: usize, count: usize, new_count: usize) -> Option<usize> {
+    if line == 0 {
+        Some(0)
+    } else if line == count {
+        Some(new_count)
+    } else if line < new_count {
+        Some(line)
+    } else {
+        None
+    }
+}
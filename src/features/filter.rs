@@ -0,0 +1,87 @@
+//! This module contains a [`Filter`] structure which removes rows of a [`Table`] by a predicate.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Filter, Table};
+//!
+//! let data = vec![("Tom", 30), ("Ann", 4), ("Bob", 100)];
+//!
+//! let table = Table::new(data)
+//!     .with(Filter::rows(|record| record[0] != "Ann"))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+------+-----+\n\
+//!      | &str | i32 |\n\
+//!      +------+-----+\n\
+//!      | Tom  | 30  |\n\
+//!      +------+-----+\n\
+//!      | Bob  | 100 |\n\
+//!      +------+-----+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{Table, TableOption};
+
+/// Filter keeps only the rows of a [`Table`] which satisfy a predicate.
+///
+/// A predicate gets a list of cells of a row.
+/// Header rows are left in place if a table has a header (see [`Table::count_header_rows`]).
+///
+/// [`Table`]: crate::Table
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+#[derive(Debug, Clone)]
+pub struct Filter<F> {
+    predicate: F,
+}
+
+impl<F> Filter<F> {
+    /// Creates a [`Filter`] which removes rows for which a predicate returns `false`.
+    pub fn rows(predicate: F) -> Self
+    where
+        F: FnMut(&[&str]) -> bool,
+    {
+        Self { predicate }
+    }
+}
+
+impl<F, R> TableOption<R> for Filter<F>
+where
+    F: FnMut(&[&str]) -> bool,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        let start = table.count_header_rows();
+
+        let records = table.get_records();
+        let mut removed = Vec::new();
+        let mut record = Vec::with_capacity(count_columns);
+        for row in start..count_rows {
+            record.clear();
+            record.extend((0..count_columns).map(|col| records.get_text((row, col))));
+
+            if !(self.predicate)(&record) {
+                removed.push(row);
+            }
+        }
+
+        if removed.is_empty() {
+            return;
+        }
+
+        let records = table.get_records_mut();
+        for row in removed.into_iter().rev() {
+            records.remove_row(row);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! [`Table`]: crate::Table
 
+use std::borrow::Cow;
+
 use papergrid::{
     records::{Records, RecordsMut},
     width::CfgWidthFunction,
@@ -124,6 +126,129 @@ impl Format<()> {
 
         Format::new(closure)
     }
+
+    /// This function creates a new [`FormatContent`] which, unlike [`Format::new`],
+    /// may leave a cell as it is.
+    ///
+    /// A function returns a [`Cow`], and a cell is updated only if a new string is returned,
+    /// so a pass over a big table doesn't allocate for cells which are not changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use tabled::{format::Format, object::Segment, Modify, Style, Table};
+    ///
+    /// let data = vec![("Grodno", -1), ("Minsk", 3)];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Segment::all()).with(Format::content(|s| match s.strip_prefix('-') {
+    ///         Some(n) => Cow::Owned(format!("({})", n)),
+    ///         None => Cow::Borrowed(s),
+    ///     })))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &str   | i32 \n",
+    ///         "--------+-----\n",
+    ///         " Grodno | (1) \n",
+    ///         " Minsk  | 3   ",
+    ///     )
+    /// );
+    /// ```
+    pub fn content<F>(f: F) -> FormatContent<F>
+    where
+        F: for<'a> FnMut(&'a str) -> Cow<'a, str>,
+    {
+        FormatContent { f }
+    }
+
+    /// This function creates a new [`FormatBuffered`], where a function writes a new content of a cell into a buffer.
+    ///
+    /// The same buffer is reused for all cells, so a pass over a big table
+    /// doesn't allocate a string for each call of a function.
+    /// A buffer is cleared before each cell,
+    /// and a cell is updated only if a buffer ends up different from its content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    ///
+    /// use tabled::{format::Format, object::Columns, Modify, Style, Table};
+    ///
+    /// let data = vec![("Grodno", 1), ("Minsk", 3)];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Columns::single(1)).with(Format::buffered(|s, buf| {
+    ///         match s.parse::<usize>() {
+    ///             Ok(n) => write!(buf, "{} km", n * 10).unwrap(),
+    ///             Err(_) => buf.push_str(s),
+    ///         }
+    ///     })))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &str   | i32   \n",
+    ///         "--------+-------\n",
+    ///         " Grodno | 10 km \n",
+    ///         " Minsk  | 30 km ",
+    ///     )
+    /// );
+    /// ```
+    pub fn buffered<F>(f: F) -> FormatBuffered<F>
+    where
+        F: FnMut(&str, &mut String),
+    {
+        FormatBuffered {
+            f,
+            buf: String::new(),
+        }
+    }
+
+    /// This function creates a new [`FormatPositioned`], which is like [`Format::content`]
+    /// but also provides a row and column index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use tabled::{format::Format, object::Segment, Modify, Style, Table};
+    ///
+    /// let data = vec![("Grodno", 1), ("Minsk", 3)];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Segment::all()).with(Format::positioned(|s, (row, _)| match row {
+    ///         0 => Cow::Owned(s.to_uppercase()),
+    ///         _ => Cow::Borrowed(s),
+    ///     })))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &STR   | I32 \n",
+    ///         "--------+-----\n",
+    ///         " Grodno | 1   \n",
+    ///         " Minsk  | 3   ",
+    ///     )
+    /// );
+    /// ```
+    pub fn positioned<F>(f: F) -> FormatPositioned<F>
+    where
+        F: for<'a> FnMut(&'a str, (usize, usize)) -> Cow<'a, str>,
+    {
+        FormatPositioned { f }
+    }
 }
 
 impl<F, R> CellOption<R> for Format<F>
@@ -138,17 +263,17 @@ where
             let records = table.get_records();
             let content = records.get_text(pos);
             let content = (self.f)(content);
-            table.get_records_mut().set(pos, content, &width_fn);
+            table.get_records_mut_raw().set(pos, content, &width_fn);
         }
 
-        table.destroy_width_cache();
+        table.destroy_width_cache_of(entity);
         table.destroy_height_cache();
     }
 }
 
 /// [`FormatWithIndex`] is like a [`Format`] an abstraction over a function you can use against a cell.
 ///
-/// It differerent from [`Format`] that it provides a row and column index.
+/// It differs from [`Format`] in that it provides a row and column index.
 #[derive(Debug)]
 pub struct FormatWithIndex<F> {
     f: F,
@@ -175,14 +300,128 @@ where
             let records = table.get_records();
             let content = records.get_text(pos);
             let content = (self.f)(content, pos);
-            table.get_records_mut().set(pos, content, &width_fn);
+            table.get_records_mut_raw().set(pos, content, &width_fn);
+        }
+
+        table.destroy_width_cache_of(entity);
+        table.destroy_height_cache();
+    }
+}
+
+/// [`FormatContent`] is like a [`Format`] an abstraction over a function you can use against a cell.
+///
+/// It differs from [`Format`] in that a function may leave a cell unchanged.
+/// See [`Format::content`].
+#[derive(Debug)]
+pub struct FormatContent<F> {
+    f: F,
+}
+
+impl<F, R> CellOption<R> for FormatContent<F>
+where
+    F: for<'a> FnMut(&'a str) -> Cow<'a, str>,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let f = &mut self.f;
+        format_changed_cells(table, entity, |text, _| f(text));
+    }
+}
+
+/// [`FormatPositioned`] is like a [`FormatContent`] but it also provides a row and column index.
+///
+/// See [`Format::positioned`].
+#[derive(Debug)]
+pub struct FormatPositioned<F> {
+    f: F,
+}
+
+impl<F, R> CellOption<R> for FormatPositioned<F>
+where
+    F: for<'a> FnMut(&'a str, (usize, usize)) -> Cow<'a, str>,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        format_changed_cells(table, entity, &mut self.f);
+    }
+}
+
+/// [`FormatBuffered`] is like a [`Format`] but a function writes a content into a reused buffer.
+///
+/// See [`Format::buffered`].
+#[derive(Debug)]
+pub struct FormatBuffered<F> {
+    f: F,
+    buf: String,
+}
+
+impl<F, R> CellOption<R> for FormatBuffered<F>
+where
+    F: FnMut(&str, &mut String),
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+
+        let mut changed = false;
+        for pos in entity.iter(count_rows, count_cols) {
+            self.buf.clear();
+
+            let content = table.get_records().get_text(pos);
+            (self.f)(content, &mut self.buf);
+            if self.buf == content {
+                continue;
+            }
+
+            table
+                .get_records_mut_raw()
+                .set(pos, self.buf.as_str().to_owned(), &width_fn);
+            changed = true;
         }
 
-        table.destroy_width_cache();
+        if changed {
+            table.destroy_width_cache_of(entity);
+            table.destroy_height_cache();
+        }
+    }
+}
+
+/// Sets a new content to cells for which a function returns a different string.
+///
+/// A cache of a table is dropped only if any cell was changed.
+fn format_changed_cells<R, F>(table: &mut Table<R>, entity: Entity, mut f: F)
+where
+    F: for<'a> FnMut(&'a str, (usize, usize)) -> Cow<'a, str>,
+    R: Records + RecordsMut<String>,
+{
+    let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+    let (count_rows, count_cols) = table.shape();
+
+    let mut changed = false;
+    for pos in entity.iter(count_rows, count_cols) {
+        let records = table.get_records();
+        let content = records.get_text(pos);
+        let text = match f(content, pos) {
+            Cow::Borrowed(text) if is_same_str(text, content) => continue,
+            text => text.into_owned(),
+        };
+
+        table.get_records_mut_raw().set(pos, text, &width_fn);
+        changed = true;
+    }
+
+    if changed {
+        table.destroy_width_cache_of(entity);
         table.destroy_height_cache();
     }
 }
 
+/// Checks whether a string is the given one, not just equal to it.
+fn is_same_str(lhs: &str, rhs: &str) -> bool {
+    std::ptr::eq(lhs.as_ptr(), rhs.as_ptr()) && lhs.len() == rhs.len()
+}
+
 impl<F, R> CellOption<R> for F
 where
     F: FnMut(&str) -> String,
@@ -202,10 +441,10 @@ where
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
             let text = self.clone();
-            table.get_records_mut().set(pos, text, &width_fn);
+            table.get_records_mut_raw().set(pos, text, &width_fn);
         }
 
-        table.destroy_width_cache();
+        table.destroy_width_cache_of(entity);
         table.destroy_height_cache();
     }
 }
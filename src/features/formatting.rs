@@ -4,10 +4,17 @@
 //!
 //! [`TabSize`] sets a default tab size.
 //!
+//! [`Charset`] changes a content of cells to fit a particular charset.
+//!
+//! [`Substitution`] replaces characters which terminals often render at a different width.
+//!
 //! [`Alignment`]: crate::Alignment
 
+use std::{borrow::Cow, collections::HashMap};
+
 use papergrid::{
     records::{Records, RecordsMut},
+    width::CfgWidthFunction,
     Entity,
 };
 
@@ -178,7 +185,9 @@ impl<R> CellOption<R> for AlignmentStrategy {
             AlignmentStrategy::PerLine => formatting.allow_lines_alignement = true,
         }
 
-        table.get_config_mut().set_formatting(entity, formatting);
+        table
+            .get_config_mut_raw()
+            .set_formatting(entity, formatting);
     }
 }
 
@@ -282,6 +291,342 @@ impl<R> CellOption<R> for TrimStrategy {
             }
         }
 
-        table.get_config_mut().set_formatting(entity, formatting);
+        table
+            .get_config_mut_raw()
+            .set_formatting(entity, formatting);
+    }
+}
+
+/// `Charset` converts a content of cells so it consists only of characters of a given charset.
+///
+/// Currently only ASCII is supported.
+/// Common typographic characters (smart quotes, dashes, ellipsis, special spaces, box drawings)
+/// and latin letters with diacritics are transliterated,
+/// while the rest of non ASCII characters are removed (or replaced by [`Charset::unknown`]).
+///
+/// Notice that it doesn't change borders; use an ASCII [`Style`] for it.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Style, formatting::Charset};
+///
+/// let data = ["\u{201C}Caf\u{e9}\u{201D} \u{2014} open\u{2026}", "\u{2713} done"];
+///
+/// let table = Table::new(data)
+///     .with(Style::ascii())
+///     .with(Charset::ascii())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------------------+\n\
+///      | &str             |\n\
+///      +------------------+\n\
+///      | \"Cafe\" - open... |\n\
+///      +------------------+\n\
+///      |  done            |\n\
+///      +------------------+"
+/// );
+/// ```
+///
+/// [`Style`]: crate::Style
+#[derive(Debug, Default, Clone)]
+pub struct Charset {
+    unknown: Option<char>,
+}
+
+impl Charset {
+    /// Creates a [`Charset`] which converts a content to ASCII.
+    pub fn ascii() -> Self {
+        Self::default()
+    }
+
+    /// Sets a character which is used instead of a non ASCII character which can't be transliterated.
+    ///
+    /// By default such characters are removed.
+    pub fn unknown(mut self, c: char) -> Self {
+        self.unknown = Some(c);
+        self
+    }
+}
+
+impl<R> CellOption<R> for Charset
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            let text = match to_ascii(text, self.unknown) {
+                Cow::Borrowed(_) => continue,
+                Cow::Owned(text) => text,
+            };
+
+            table.get_records_mut_raw().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache_of(entity);
+        table.destroy_height_cache();
+    }
+}
+
+impl<R> TableOption<R> for Charset
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
     }
 }
+
+/// `Substitution` replaces characters of cells by a given text.
+///
+/// The width of some characters depends on a terminal and a font,
+/// emoji and box drawings being the most common ones,
+/// so a table which is measured correctly may still be printed misaligned.
+/// Replacing such characters by alternatives with a well known width fixes it.
+///
+/// A content is changed when the option is applied, so it's measured after the substitution.
+/// It's better to apply it before [`Width`] settings.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, formatting::Substitution};
+///
+/// let data = ["\u{26A0}\u{FE0F} low disk", "\u{2714} ok"];
+///
+/// let table = Table::new(data)
+///     .with(Substitution::emoji().replace('\u{2714}', "[v]"))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------------+\n\
+///      | &str       |\n\
+///      +------------+\n\
+///      | ! low disk |\n\
+///      +------------+\n\
+///      | [v] ok     |\n\
+///      +------------+"
+/// );
+/// ```
+///
+/// [`Width`]: crate::Width
+#[derive(Debug, Default, Clone)]
+pub struct Substitution {
+    chars: HashMap<char, String>,
+}
+
+impl Substitution {
+    /// Creates an empty [`Substitution`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`Substitution`] for emoji.
+    ///
+    /// It removes characters which join or modify emoji (variation selectors, a zero width joiner, a keycap),
+    /// so each emoji is rendered and measured on its own,
+    /// and replaces a few symbols which are rendered as wide emoji by some terminals by ASCII alternatives.
+    pub fn emoji() -> Self {
+        Self::new()
+            .remove('\u{FE0E}')
+            .remove('\u{FE0F}')
+            .remove('\u{200D}')
+            .remove('\u{20E3}')
+            .replace('\u{26A0}', "!")
+            .replace('\u{2714}', "v")
+            .replace('\u{2716}', "x")
+            .replace('\u{2764}', "<3")
+            .replace('\u{27A1}', "->")
+            .replace('\u{2B05}', "<-")
+    }
+
+    /// Creates a [`Substitution`] which replaces box drawing characters by ASCII ones.
+    ///
+    /// Box drawings are rendered twice as wide in some (mostly CJK) locales.
+    pub fn box_drawing() -> Self {
+        let chars = ('\u{2500}'..='\u{257F}')
+            .flat_map(|c| transliterate(c).map(|s| (c, s.to_owned())))
+            .collect();
+
+        Self { chars }
+    }
+
+    /// Sets a text which replaces a character.
+    ///
+    /// Notice that replacing ASCII characters may break ANSI sequences when a `color` feature is used.
+    pub fn replace<S>(mut self, c: char, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.chars.insert(c, text.into());
+        self
+    }
+
+    /// Sets a character to be removed.
+    pub fn remove(self, c: char) -> Self {
+        self.replace(c, "")
+    }
+}
+
+impl<R> CellOption<R> for Substitution
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            let text = match substitute(text, &self.chars) {
+                Cow::Borrowed(_) => continue,
+                Cow::Owned(text) => text,
+            };
+
+            table.get_records_mut_raw().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache_of(entity);
+        table.destroy_height_cache();
+    }
+}
+
+impl<R> TableOption<R> for Substitution
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+fn substitute<'a>(text: &'a str, chars: &HashMap<char, String>) -> Cow<'a, str> {
+    if !text.chars().any(|c| chars.contains_key(&c)) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match chars.get(&c) {
+            Some(s) => buf.push_str(s),
+            None => buf.push(c),
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+fn to_ascii(text: &str, unknown: Option<char>) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+            continue;
+        }
+
+        match transliterate(c) {
+            Some(s) => buf.push_str(s),
+            None => buf.extend(unknown),
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+fn transliterate(c: char) -> Option<&'static str> {
+    let s = match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' | '\u{B4}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => "\"",
+        '\u{AB}' => "<<",
+        '\u{BB}' => ">>",
+        '\u{2039}' => "<",
+        '\u{203A}' => ">",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{AD}' => "-",
+        '\u{2026}' => "...",
+        '\u{2022}' | '\u{2023}' | '\u{2043}' => "*",
+        '\u{B7}' => ".",
+        '\u{A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => " ",
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' => "",
+        '\u{A9}' => "(c)",
+        '\u{AE}' => "(R)",
+        '\u{2122}' => "TM",
+        '\u{B1}' => "+/-",
+        '\u{D7}' => "x",
+        '\u{F7}' => "/",
+        '\u{2264}' => "<=",
+        '\u{2265}' => ">=",
+        '\u{2260}' => "!=",
+        '\u{2248}' => "~",
+        '\u{2190}' => "<-",
+        '\u{2192}' => "->",
+        '\u{2194}' => "<->",
+        '\u{21D2}' => "=>",
+        '\u{BC}' => "1/4",
+        '\u{BD}' => "1/2",
+        '\u{BE}' => "3/4",
+        '\u{20AC}' => "EUR",
+        '\u{A3}' => "GBP",
+        '\u{2500}' | '\u{2501}' | '\u{2504}' | '\u{2505}' | '\u{2508}' | '\u{2509}'
+        | '\u{2550}' => "-",
+        '\u{2502}' | '\u{2503}' | '\u{2506}' | '\u{2507}' | '\u{250A}' | '\u{250B}'
+        | '\u{2551}' => "|",
+        '\u{2500}'..='\u{257F}' => "+",
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'ð' | 'ď' | 'đ' => "d",
+        'È'..='Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'Ĥ' | 'Ħ' => "H",
+        'ĥ' | 'ħ' => "h",
+        'Ì'..='Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ĵ' => "J",
+        'ĵ' => "j",
+        'Ķ' => "K",
+        'ķ' => "k",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'Ţ' | 'Ť' | 'Ŧ' => "T",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'Þ' => "TH",
+        'þ' => "th",
+        'Ù'..='Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'Ŵ' => "W",
+        'ŵ' => "w",
+        'Ý' | 'Ŷ' | 'Ÿ' => "Y",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+
+    Some(s)
+}
@@ -0,0 +1,197 @@
+//! This module contains a [`HeaderGroups`] structure which groups columns of a [`Table`] by prefixes of their names.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{HeaderGroups, Style, Table};
+//!
+//! let mut builder = Table::builder([["1", "2", "3"]]);
+//! builder.set_columns(["net.rx", "net.tx", "disk.read"]);
+//!
+//! let table = builder
+//!     .build()
+//!     .with(Style::modern())
+//!     .with(HeaderGroups::new("."))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "┌────┬────┬──────┐\n",
+//!         "│ net     │ disk │\n",
+//!         "├────┼────┼──────┤\n",
+//!         "│ rx │ tx │ read │\n",
+//!         "├────┼────┼──────┤\n",
+//!         "│ 1  │ 2  │ 3    │\n",
+//!         "└────┴────┴──────┘",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{
+    features::panel::{move_row_spans, move_rows_aside},
+    Table, TableOption,
+};
+
+/// HeaderGroups splits a header of a [`Table`] into 2 rows by a separator in names of columns.
+///
+/// A name like `net.rx` is split into a group `net` and a label `rx`.
+/// Adjacent columns with the same group get a single group cell spanned over them,
+/// and their labels are put below it.
+/// A name without a separator takes both rows.
+///
+/// It pairs naturally with an inline prefix of the derive macro,
+/// like `#[tabled(inline("net."))]`.
+///
+/// A table is left untouched if it has no header, if its header already has groups
+/// (see [`Table::count_header_rows`]) or if none of the names has a separator.
+/// Otherwise the header consists of 2 rows afterwards.
+///
+/// Notice that a line under the first row of a [`Style`] (like the one of [`Style::psql`])
+/// is put between the groups and the labels.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{HeaderGroups, Style, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Net {
+///     rx: usize,
+///     tx: usize,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Host {
+///     name: &'static str,
+///     #[tabled(inline("net."))]
+///     net: Net,
+/// }
+///
+/// let data = [Host { name: "alpha", net: Net { rx: 10, tx: 2 } }];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(HeaderGroups::new("."))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " name  | net     \n",
+///         "       +----+----\n",
+///         "       | rx | tx \n",
+///         " alpha | 10 | 2  ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+/// [`Style`]: crate::Style
+/// [`Style::psql`]: crate::Style::psql
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderGroups {
+    separator: String,
+}
+
+impl HeaderGroups {
+    /// Creates a grouping of columns by a prefix of their names before a given separator.
+    ///
+    /// A name is split at the first occurrence of the separator.
+    pub fn new<S>(separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            separator: separator.into(),
+        }
+    }
+
+    fn split<'a>(&self, name: &'a str) -> (Option<&'a str>, &'a str) {
+        if self.separator.is_empty() {
+            return (None, name);
+        }
+
+        match name.find(self.separator.as_str()) {
+            Some(pos) if pos > 0 => (Some(&name[..pos]), &name[pos + self.separator.len()..]),
+            _ => (None, name),
+        }
+    }
+}
+
+impl<R> TableOption<R> for HeaderGroups
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_columns = table.count_columns();
+        if table.count_header_rows() != 1 {
+            return;
+        }
+
+        let names = (0..count_columns)
+            .map(|col| table.get_records().get_text((0, col)).to_owned())
+            .collect::<Vec<_>>();
+        let names = names
+            .iter()
+            .map(|name| self.split(name))
+            .collect::<Vec<_>>();
+
+        if names.iter().all(|(group, _)| group.is_none()) {
+            return;
+        }
+
+        move_rows_aside(table, 0);
+        move_row_spans(table, 0);
+
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+        let mut col = 0;
+        while col < count_columns {
+            let (group, label) = names[col];
+            let group = match group {
+                Some(group) => group,
+                None => {
+                    table
+                        .get_records_mut()
+                        .set((0, col), label.to_owned(), &ctrl);
+                    table.get_records_mut().set((1, col), String::new(), &ctrl);
+                    table.get_config_mut().set_row_span((0, col), 2);
+                    col += 1;
+                    continue;
+                }
+            };
+
+            let span = names[col..]
+                .iter()
+                .take_while(|(next, _)| *next == Some(group))
+                .count();
+
+            table
+                .get_records_mut()
+                .set((0, col), group.to_owned(), &ctrl);
+            if span > 1 {
+                table.get_config_mut().set_column_span((0, col), span);
+            }
+
+            for (i, (_, label)) in names[col..col + span].iter().enumerate() {
+                table
+                    .get_records_mut()
+                    .set((1, col + i), (*label).to_owned(), &ctrl);
+            }
+
+            col += span;
+        }
+
+        table.set_header_rows(2);
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
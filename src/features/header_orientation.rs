@@ -0,0 +1,160 @@
+//! This module contains a [`HeaderOrientation`] structure which sets a direction of a text of a header of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{HeaderOrientation, Style, Table};
+//!
+//! let table = Table::new([(1, 2)])
+//!     .with(Style::psql())
+//!     .with(HeaderOrientation::Vertical)
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " i | i \n",
+//!         " 3 | 3 \n",
+//!         " 2 | 2 \n",
+//!         "---+---\n",
+//!         " 1 | 2 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+};
+
+use crate::{Table, TableOption};
+
+/// HeaderOrientation sets whether labels of a header of a [`Table`] are written horizontally or vertically.
+///
+/// A vertical label is written one character per line,
+/// so it adds to a height of a header rather than to a width of a column.
+/// It's handy for a lot of narrow columns, like numeric ones, with long names.
+/// Spaces and line breaks of a label become empty lines.
+///
+/// The labels are changed in place, so options applied afterwards (like [`Width`] or [`Height`])
+/// take the new look into account.
+///
+/// But it also means that a column can't be found by its name after the orientation is changed,
+/// so a [`ByColumnName`] must be used before it.
+///
+/// ```
+/// use tabled::{locator::ByColumnName, Disable, HeaderOrientation, Style, Table};
+///
+/// let table = Table::new([(1, true)])
+///     .with(Disable::column(ByColumnName::new("bool")))
+///     .with(HeaderOrientation::Vertical)
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(table, " i \n 3 \n 2 \n---\n 1 ");
+/// ```
+///
+/// If a header has groups of columns (see [`Table::count_header_rows`])
+/// only labels of single columns are changed, while groups spanned over a few columns are left horizontal.
+/// It has no effect if a table has no header.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{HeaderOrientation, Style, Table};
+///
+/// let table = Table::new([("Tom", 1, 2)])
+///     .with(HeaderOrientation::Vertical)
+///     .with(Style::markdown())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "| &   | i | i |\n",
+///         "| s   | 3 | 3 |\n",
+///         "| t   | 2 | 2 |\n",
+///         "| r   |   |   |\n",
+///         "|-----|---|---|\n",
+///         "| Tom | 1 | 2 |",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+/// [`Width`]: crate::Width
+/// [`Height`]: crate::Height
+/// [`ByColumnName`]: crate::locator::ByColumnName
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderOrientation {
+    /// Labels are written on a line, which is the default.
+    ///
+    /// A header is left as it is.
+    Horizontal,
+    /// Labels are written from top to bottom, one character per line.
+    Vertical,
+}
+
+impl<R> TableOption<R> for HeaderOrientation
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_header_rows = table.count_header_rows();
+        if *self == Self::Horizontal || count_header_rows == 0 {
+            return;
+        }
+
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+        let shape = table.shape();
+        for row in 0..count_header_rows {
+            for col in 0..shape.1 {
+                let cfg = table.get_config();
+                let is_group = cfg.get_column_span((row, col), shape).unwrap_or(1) > 1;
+                if is_group || !cfg.is_cell_visible((row, col), shape) {
+                    continue;
+                }
+
+                let text = table.get_records().get_text((row, col));
+                let text = vertical_text(text);
+                table.get_records_mut().set((row, col), text, &ctrl);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Puts each character of a text on its own line.
+fn vertical_text(text: &str) -> String {
+    #[cfg(feature = "color")]
+    {
+        let stripped = ansi_str::AnsiStr::ansi_strip(text);
+        if stripped != text {
+            let lines = stripped.char_indices().map(|(i, c)| match c {
+                ' ' | '\n' => String::new(),
+                c => ansi_str::AnsiStr::ansi_cut(text, i..i + c.len_utf8()).into_owned(),
+            });
+
+            return join_lines(lines);
+        }
+    }
+
+    let lines = text.chars().map(|c| match c {
+        ' ' | '\n' => String::new(),
+        c => c.to_string(),
+    });
+
+    join_lines(lines)
+}
+
+fn join_lines<I>(lines: I) -> String
+where
+    I: Iterator<Item = String>,
+{
+    lines.collect::<Vec<_>>().join("\n")
+}
@@ -0,0 +1,304 @@
+//! This module contains a [`HeaderPosition`] structure which sets where a header of a [`Table`] is rendered.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{HeaderPosition, Style, Table};
+//!
+//! let data = vec![("Tom", 30), ("Ann", 4)];
+//!
+//! let table = Table::new(data)
+//!     .with(Style::psql())
+//!     .with(HeaderPosition::Bottom)
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " Tom  | 30  \n",
+//!         " Ann  | 4   \n",
+//!         "------+-----\n",
+//!         " &str | i32 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::iter::FromIterator;
+
+use papergrid::{records::Records, Border, GridConfig};
+
+use crate::{builder::Builder, table::copy_cell_settings, Table, TableOption};
+
+/// HeaderPosition sets whether a header of a [`Table`] is rendered above the data, below it or on both sides.
+///
+/// The header is moved only when a table is printed, so records are left untouched
+/// and all other options keep referring to the header as to a first row.
+/// Settings of cells, spans and horizontal lines follow the moved rows,
+/// while borders set to particular cells (for example by [`Highlight`]) stay at their positions.
+///
+/// It has no effect if a table has no header (see [`Table::has_header`]).
+///
+/// # Example
+///
+/// ```
+/// use tabled::{HeaderPosition, Style, Table};
+///
+/// let table = Table::new(["a", "b"])
+///     .with(Style::psql())
+///     .with(HeaderPosition::Both)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str \n",
+///         "------\n",
+///         " a    \n",
+///         " b    \n",
+///         "------\n",
+///         " &str ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::has_header`]: crate::Table::has_header
+/// [`Highlight`]: crate::Highlight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderPosition {
+    /// A header is rendered above the data, which is the default.
+    Top,
+    /// A header is rendered below the data.
+    Bottom,
+    /// A header is rendered both above and below the data.
+    Both,
+}
+
+impl<R> TableOption<R> for HeaderPosition {
+    fn change(&mut self, table: &mut Table<R>) {
+        table.set_header_position(*self);
+    }
+}
+
+/// A layout of rows of a table whose header is moved.
+#[derive(Debug, Clone, Copy)]
+struct MovedHeader {
+    position: HeaderPosition,
+    count_rows: usize,
+    count_header_rows: usize,
+}
+
+impl MovedHeader {
+    /// Returns an amount of rows which are rendered.
+    fn count_rows(&self) -> usize {
+        match self.position {
+            HeaderPosition::Both => self.count_rows + self.count_header_rows,
+            _ => self.count_rows,
+        }
+    }
+
+    /// Returns an original row which is rendered at a given row.
+    fn source_row(&self, row: usize) -> usize {
+        let (count_rows, count_header_rows) = (self.count_rows, self.count_header_rows);
+        let count_data_rows = count_rows - count_header_rows;
+
+        match self.position {
+            HeaderPosition::Top => row,
+            HeaderPosition::Bottom if row >= count_data_rows => row - count_data_rows,
+            HeaderPosition::Bottom => row + count_header_rows,
+            HeaderPosition::Both if row >= count_rows => row - count_rows,
+            HeaderPosition::Both => row,
+        }
+    }
+
+    /// Returns an original horizontal line which is rendered at a given line.
+    ///
+    /// A line under a header goes along with it.
+    fn source_line(&self, line: usize) -> usize {
+        let (count_rows, count_header_rows) = (self.count_rows, self.count_header_rows);
+        let count_data_rows = count_rows - count_header_rows;
+
+        match self.position {
+            HeaderPosition::Top => line,
+            HeaderPosition::Bottom if line == 0 || line == count_rows => line,
+            HeaderPosition::Bottom if line == count_data_rows => count_header_rows,
+            HeaderPosition::Bottom if line > count_data_rows => line - count_data_rows,
+            HeaderPosition::Bottom => line + count_header_rows,
+            HeaderPosition::Both if line == count_rows + count_header_rows => count_rows,
+            HeaderPosition::Both if line == count_rows => count_header_rows,
+            HeaderPosition::Both if line > count_rows => line - count_rows,
+            HeaderPosition::Both => line,
+        }
+    }
+}
+
+/// Builds a copy of a table where header rows are moved according to a [`HeaderPosition`].
+///
+/// `widths` and `heights` are the ones a table would be printed with.
+pub(crate) fn build_moved_header_table<R>(
+    records: &R,
+    cfg: &GridConfig,
+    widths: &[usize],
+    heights: &[usize],
+    position: HeaderPosition,
+    count_header_rows: usize,
+) -> Table
+where
+    R: Records,
+{
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+    let layout = MovedHeader {
+        position,
+        count_rows,
+        count_header_rows,
+    };
+    let new_count_rows = layout.count_rows();
+
+    let rows = (0..new_count_rows).map(|row| {
+        let row = layout.source_row(row);
+        (0..count_columns)
+            .map(|col| records.get_text((row, col)).to_owned())
+            .collect::<Vec<_>>()
+    });
+
+    let mut new_cfg = cfg.clone();
+    copy_cell_settings(
+        &mut new_cfg,
+        cfg,
+        (new_count_rows, count_columns),
+        |(row, col)| (layout.source_row(row), col),
+    );
+    move_spans(&mut new_cfg, cfg, layout, count_columns);
+    move_lines(&mut new_cfg, cfg, layout, count_columns);
+
+    let heights = (0..new_count_rows)
+        .map(|row| heights[layout.source_row(row)])
+        .collect();
+
+    let mut table = Builder::from_iter(rows).build();
+    *table.get_config_mut() = new_cfg;
+    table.cache_width(widths.to_vec());
+    table.cache_height(heights);
+
+    table
+}
+
+fn move_spans(
+    new_cfg: &mut GridConfig,
+    cfg: &GridConfig,
+    layout: MovedHeader,
+    count_columns: usize,
+) {
+    let shape = (layout.count_rows, count_columns);
+    let new_count_rows = layout.count_rows();
+
+    let column_spans = cfg.iter_column_spans(shape).collect::<Vec<_>>();
+    let row_spans = cfg.iter_row_spans(shape).collect::<Vec<_>>();
+
+    for &((row, col), _) in &column_spans {
+        new_cfg.set_column_span((row, col), 1);
+    }
+
+    for &((row, col), _) in &row_spans {
+        new_cfg.set_row_span((row, col), 1);
+    }
+
+    for row in 0..new_count_rows {
+        let src_row = layout.source_row(row);
+
+        for &((_, col), span) in column_spans.iter().filter(|((r, _), _)| *r == src_row) {
+            new_cfg.set_column_span((row, col), span);
+        }
+
+        for &((_, col), span) in row_spans.iter().filter(|((r, _), _)| *r == src_row) {
+            let span = span.min(new_count_rows - row);
+            new_cfg.set_row_span((row, col), span);
+        }
+    }
+}
+
+fn move_lines(
+    new_cfg: &mut GridConfig,
+    cfg: &GridConfig,
+    layout: MovedHeader,
+    count_columns: usize,
+) {
+    let (count_rows, new_count_rows) = (layout.count_rows, layout.count_rows());
+    let shape = (count_rows, count_columns);
+
+    // borders which were set to particular cells are found by a comparison with a bare theme.
+    let mut theme = cfg.clone();
+    set_theme(&mut theme, cfg, shape, |line| line);
+
+    set_theme(new_cfg, cfg, (new_count_rows, count_columns), |line| {
+        layout.source_line(line)
+    });
+
+    for row in 0..count_rows {
+        for col in 0..count_columns {
+            let border = cfg.get_border((row, col), shape);
+            let theme_border = theme.get_border((row, col), shape);
+            let border = diff_border(border, theme_border);
+            if !border.is_empty() {
+                new_cfg.set_border((row, col), border);
+            }
+        }
+    }
+}
+
+/// Sets borders and lines of a `cfg` to a `new_cfg` dropping the ones which were set to particular cells.
+///
+/// A horizontal line is taken from a line which `source_line` returns.
+fn set_theme<F>(new_cfg: &mut GridConfig, cfg: &GridConfig, shape: (usize, usize), source_line: F)
+where
+    F: Fn(usize) -> usize,
+{
+    let (count_rows, count_columns) = shape;
+
+    // a theme is rebuilt as horizontal lines can't be fully removed from a config.
+    new_cfg.clear_theme();
+    new_cfg.set_borders(cfg.get_borders().clone());
+
+    if let Some(&c) = cfg.get_global_border() {
+        new_cfg.set_global_border(c);
+    }
+
+    for col in 0..=count_columns {
+        if let Some(&line) = cfg.get_vertical_line(col) {
+            new_cfg.set_vertical_line(col, line);
+        }
+    }
+
+    for line in 0..=count_rows {
+        let src_line = source_line(line);
+
+        if let Some(&hline) = cfg.get_horizontal_line(src_line) {
+            new_cfg.set_horizontal_line(line, hline);
+        }
+
+        let text = cfg.get_split_line_text(src_line);
+        let offset = cfg.get_split_line_offset(src_line);
+        if let (Some(text), Some(offset)) = (text, offset) {
+            new_cfg.override_split_line(line, text, offset);
+        }
+    }
+}
+
+/// Returns the sides of a `border` which differ from a `theme`.
+fn diff_border(border: Border, theme: Border) -> Border {
+    let diff = |side: Option<char>, theme: Option<char>| side.filter(|_| side != theme);
+
+    Border {
+        top: diff(border.top, theme.top),
+        bottom: diff(border.bottom, theme.bottom),
+        left: diff(border.left, theme.left),
+        right: diff(border.right, theme.right),
+        left_top_corner: diff(border.left_top_corner, theme.left_top_corner),
+        left_bottom_corner: diff(border.left_bottom_corner, theme.left_bottom_corner),
+        right_top_corner: diff(border.right_top_corner, theme.right_top_corner),
+        right_bottom_corner: diff(border.right_bottom_corner, theme.right_bottom_corner),
+    }
+}
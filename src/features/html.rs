@@ -0,0 +1,515 @@
+//! This module contains a [`HtmlTable`] structure which converts a [`Table`] into a HTML `<table>`.
+//!
+//! The module is available only when a `html` feature is on.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, html::HtmlTable};
+//!
+//! let table = Table::new([("Hello", 1), ("World", 2)]);
+//!
+//! assert_eq!(
+//!     HtmlTable::new(&table).id("greetings").to_string(),
+//!     concat!(
+//!         "<table id=\"greetings\">\n",
+//!         "    <thead>\n",
+//!         "        <tr>\n",
+//!         "            <th>&amp;str</th>\n",
+//!         "            <th>i32</th>\n",
+//!         "        </tr>\n",
+//!         "    </thead>\n",
+//!         "    <tbody>\n",
+//!         "        <tr>\n",
+//!         "            <td>Hello</td>\n",
+//!         "            <td>1</td>\n",
+//!         "        </tr>\n",
+//!         "        <tr>\n",
+//!         "            <td>World</td>\n",
+//!         "            <td>2</td>\n",
+//!         "        </tr>\n",
+//!         "    </tbody>\n",
+//!         "</table>",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Write};
+
+use papergrid::{records::Records, AlignmentHorizontal, AlignmentVertical, Entity, GridConfig};
+
+use crate::Table;
+
+const INDENT: &str = "    ";
+
+/// [`HtmlTable`] is a HTML view of a [`Table`].
+///
+/// It emits `<thead>` for a header (if a [`Table`] has one) and `<tbody>` for the rest of rows.
+/// Column and row spans are converted into `colspan` and `rowspan` attributes,
+/// and cell alignment into `text-align` and `vertical-align` styles.
+///
+/// Borders, padding and margin are not converted;
+/// supply your own CSS for the table instead (see [`HtmlTable::id`]).
+///
+/// ANSI sequences are removed from a content by default,
+/// but you can convert them into inline CSS by [`HtmlTable::ansi_to_css`].
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct HtmlTable<'a, R> {
+    table: &'a Table<R>,
+    id: Option<String>,
+    ansi_to_css: bool,
+}
+
+impl<'a, R> HtmlTable<'a, R> {
+    /// Creates a new [`HtmlTable`] view of a [`Table`].
+    ///
+    /// [`Table`]: crate::Table
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            id: None,
+            ansi_to_css: false,
+        }
+    }
+
+    /// Sets an `id` attribute of a `<table>`.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets a flag whether ANSI colors and text styles must be converted into inline CSS.
+    ///
+    /// Otherwise ANSI sequences are removed.
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    /// use tabled::{builder::Builder, html::HtmlTable};
+    ///
+    /// let table = Builder::from_iter([["\u{1b}[31mHello\u{1b}[0m World"]]).build();
+    ///
+    /// assert_eq!(
+    ///     HtmlTable::new(&table).ansi_to_css(true).to_string(),
+    ///     concat!(
+    ///         "<table>\n",
+    ///         "    <tbody>\n",
+    ///         "        <tr>\n",
+    ///         "            <td><span style=\"color: #cd0000;\">Hello</span> World</td>\n",
+    ///         "        </tr>\n",
+    ///         "    </tbody>\n",
+    ///         "</table>",
+    ///     )
+    /// );
+    /// ```
+    pub fn ansi_to_css(mut self, on: bool) -> Self {
+        self.ansi_to_css = on;
+        self
+    }
+}
+
+impl<R> fmt::Display for HtmlTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = (records.count_rows(), records.count_columns());
+
+        f.write_str("<table")?;
+        if let Some(id) = &self.id {
+            write!(f, " id=\"{}\"", escape(id))?;
+        }
+        f.write_char('>')?;
+
+        let body_start = self.table.count_header_rows();
+        let has_header = body_start > 0;
+
+        if has_header {
+            f.write_char('\n')?;
+            write_line(f, 1, "<thead>")?;
+            for row in 0..body_start {
+                write_row(f, records, cfg, row, "th", body_start, self.ansi_to_css)?;
+            }
+            write_line(f, 1, "</thead>")?;
+        }
+
+        if shape.0 > body_start {
+            if !has_header {
+                f.write_char('\n')?;
+            }
+
+            write_line(f, 1, "<tbody>")?;
+            for row in body_start..shape.0 {
+                write_row(f, records, cfg, row, "td", body_start, self.ansi_to_css)?;
+            }
+            write_line(f, 1, "</tbody>")?;
+        }
+
+        f.write_str("</table>")
+    }
+}
+
+fn write_row<R>(
+    f: &mut fmt::Formatter<'_>,
+    records: &R,
+    cfg: &GridConfig,
+    row: usize,
+    tag: &str,
+    body_start: usize,
+    ansi_to_css: bool,
+) -> fmt::Result
+where
+    R: Records,
+{
+    let shape = (records.count_rows(), records.count_columns());
+
+    write_line(f, 2, "<tr>")?;
+
+    for col in 0..shape.1 {
+        if !cfg.is_cell_visible((row, col), shape) {
+            // a cell covered by a header span is rendered as an empty one,
+            // as spans can't cross <thead> and <tbody>.
+            if row >= body_start && is_covered_by_header(cfg, (row, col), shape, body_start) {
+                write_indent(f, 3)?;
+                writeln!(f, "<{}></{}>", tag, tag)?;
+            }
+
+            continue;
+        }
+
+        write_indent(f, 3)?;
+        write!(f, "<{}", tag)?;
+
+        if let Some(span) = cfg.get_column_span((row, col), shape) {
+            write!(f, " colspan=\"{}\"", span)?;
+        }
+
+        if let Some(span) = cfg.get_row_span((row, col), shape) {
+            let max_span = if row < body_start {
+                body_start - row
+            } else {
+                shape.0 - row
+            };
+            let span = std::cmp::min(span, max_span);
+            if span > 1 {
+                write!(f, " rowspan=\"{}\"", span)?;
+            }
+        }
+
+        write_alignment(f, cfg, Entity::Cell(row, col))?;
+
+        f.write_char('>')?;
+        write_text(f, records.get_text((row, col)), ansi_to_css)?;
+        writeln!(f, "</{}>", tag)?;
+    }
+
+    write_line(f, 2, "</tr>")
+}
+
+fn is_covered_by_header(
+    cfg: &GridConfig,
+    (row, col): (usize, usize),
+    shape: (usize, usize),
+    body_start: usize,
+) -> bool {
+    (0..body_start).any(|header_row| {
+        (0..=col).any(|header_col| {
+            let pos = (header_row, header_col);
+            if !cfg.is_cell_visible(pos, shape) {
+                return false;
+            }
+
+            let row_span = cfg.get_row_span(pos, shape).unwrap_or(1);
+            let col_span = cfg.get_column_span(pos, shape).unwrap_or(1);
+
+            header_row + row_span > row && header_col + col_span > col
+        })
+    })
+}
+
+fn write_alignment(f: &mut fmt::Formatter<'_>, cfg: &GridConfig, entity: Entity) -> fmt::Result {
+    let horizontal = match cfg.get_alignment_horizontal(entity) {
+        AlignmentHorizontal::Left => None,
+        AlignmentHorizontal::Center => Some("center"),
+        AlignmentHorizontal::Right => Some("right"),
+    };
+
+    let vertical = match cfg.get_alignment_vertical(entity) {
+        AlignmentVertical::Top => None,
+        AlignmentVertical::Center => Some("middle"),
+        AlignmentVertical::Bottom => Some("bottom"),
+    };
+
+    if horizontal.is_none() && vertical.is_none() {
+        return Ok(());
+    }
+
+    f.write_str(" style=\"")?;
+
+    if let Some(alignment) = horizontal {
+        write!(f, "text-align: {};", alignment)?;
+    }
+
+    if let Some(alignment) = vertical {
+        if horizontal.is_some() {
+            f.write_char(' ')?;
+        }
+
+        write!(f, "vertical-align: {};", alignment)?;
+    }
+
+    f.write_char('"')
+}
+
+fn write_text(f: &mut fmt::Formatter<'_>, text: &str, ansi_to_css: bool) -> fmt::Result {
+    let mut style = SgrStyle::default();
+    let mut css = String::new();
+    let mut opened_css: Option<String> = None;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => match chars.next() {
+                Some('[') => {
+                    let mut params = String::new();
+                    let mut end = None;
+                    for c in chars.by_ref() {
+                        if ('\u{40}'..='\u{7e}').contains(&c) {
+                            end = Some(c);
+                            break;
+                        }
+
+                        params.push(c);
+                    }
+
+                    if end == Some('m') && ansi_to_css {
+                        style.apply(&params);
+                        css = style.to_css();
+                    }
+                }
+                Some(']') => {
+                    // skip an OSC sequence which ends with BEL or ST
+                    while let Some(c) = chars.next() {
+                        if c == '\u{7}' {
+                            break;
+                        }
+
+                        if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\r' => {}
+            c => {
+                let is_style_changed = match &opened_css {
+                    Some(opened) => *opened != css,
+                    None => !css.is_empty(),
+                };
+
+                if is_style_changed {
+                    if opened_css.take().is_some() {
+                        f.write_str("</span>")?;
+                    }
+
+                    if !css.is_empty() {
+                        write!(f, "<span style=\"{}\">", css)?;
+                        opened_css = Some(css.clone());
+                    }
+                }
+
+                write_escaped_char(f, c)?;
+            }
+        }
+    }
+
+    if opened_css.is_some() {
+        f.write_str("</span>")?;
+    }
+
+    Ok(())
+}
+
+fn write_escaped_char(f: &mut fmt::Formatter<'_>, c: char) -> fmt::Result {
+    match c {
+        '&' => f.write_str("&amp;"),
+        '<' => f.write_str("&lt;"),
+        '>' => f.write_str("&gt;"),
+        '"' => f.write_str("&quot;"),
+        '\'' => f.write_str("&#39;"),
+        '\n' => f.write_str("<br>"),
+        c => f.write_char(c),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&#39;"),
+            c => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+fn write_line(f: &mut fmt::Formatter<'_>, indent: usize, text: &str) -> fmt::Result {
+    write_indent(f, indent)?;
+    f.write_str(text)?;
+    f.write_char('\n')
+}
+
+fn write_indent(f: &mut fmt::Formatter<'_>, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        f.write_str(INDENT)?;
+    }
+
+    Ok(())
+}
+
+/// A state of SGR (Select Graphic Rendition) ANSI sequences.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct SgrStyle {
+    fg: Option<(u8, u8, u8)>,
+    bg: Option<(u8, u8, u8)>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strike: bool,
+}
+
+impl SgrStyle {
+    fn apply(&mut self, params: &str) {
+        let mut codes = params.split(';').map(|p| {
+            if p.is_empty() {
+                Some(0)
+            } else {
+                p.parse::<u8>().ok()
+            }
+        });
+
+        while let Some(code) = codes.next() {
+            let code = match code {
+                Some(code) => code,
+                None => continue,
+            };
+
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                9 => self.strike = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                29 => self.strike = false,
+                30..=37 => self.fg = Some(ansi_color(code - 30)),
+                38 => self.fg = parse_extended_color(&mut codes),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(ansi_color(code - 40)),
+                48 => self.bg = parse_extended_color(&mut codes),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(ansi_color(code - 90 + 8)),
+                100..=107 => self.bg = Some(ansi_color(code - 100 + 8)),
+                _ => {}
+            }
+        }
+    }
+
+    fn to_css(&self) -> String {
+        let mut css = String::new();
+        let mut push = |s: &str| {
+            if !css.is_empty() {
+                css.push(' ');
+            }
+
+            css.push_str(s);
+        };
+
+        if let Some((r, g, b)) = self.fg {
+            push(&format!("color: #{:02x}{:02x}{:02x};", r, g, b));
+        }
+
+        if let Some((r, g, b)) = self.bg {
+            push(&format!("background-color: #{:02x}{:02x}{:02x};", r, g, b));
+        }
+
+        if self.bold {
+            push("font-weight: bold;");
+        }
+
+        if self.italic {
+            push("font-style: italic;");
+        }
+
+        match (self.underline, self.strike) {
+            (true, true) => push("text-decoration: underline line-through;"),
+            (true, false) => push("text-decoration: underline;"),
+            (false, true) => push("text-decoration: line-through;"),
+            (false, false) => {}
+        }
+
+        css
+    }
+}
+
+fn parse_extended_color(codes: &mut impl Iterator<Item = Option<u8>>) -> Option<(u8, u8, u8)> {
+    match codes.next().flatten()? {
+        5 => codes.next().flatten().map(ansi_color),
+        2 => {
+            let r = codes.next().flatten()?;
+            let g = codes.next().flatten()?;
+            let b = codes.next().flatten()?;
+            Some((r, g, b))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a 256 palette color into RGB (xterm colors).
+fn ansi_color(n: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0x00, 0x00, 0x00),
+        (0xcd, 0x00, 0x00),
+        (0x00, 0xcd, 0x00),
+        (0xcd, 0xcd, 0x00),
+        (0x00, 0x00, 0xee),
+        (0xcd, 0x00, 0xcd),
+        (0x00, 0xcd, 0xcd),
+        (0xe5, 0xe5, 0xe5),
+        (0x7f, 0x7f, 0x7f),
+        (0xff, 0x00, 0x00),
+        (0x00, 0xff, 0x00),
+        (0xff, 0xff, 0x00),
+        (0x5c, 0x5c, 0xff),
+        (0xff, 0x00, 0xff),
+        (0x00, 0xff, 0xff),
+        (0xff, 0xff, 0xff),
+    ];
+
+    match n {
+        0..=15 => BASIC[n as usize],
+        16..=231 => {
+            let n = n - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(n / 36), level((n / 6) % 6), level(n % 6))
+        }
+        _ => {
+            let v = 8 + (n - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
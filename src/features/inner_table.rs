@@ -0,0 +1,106 @@
+//! This module contains an [`InnerTable`] structure which puts a [`Table`] inside a cell.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Cell, InnerTable, Modify, Table, Width};
+//!
+//! let inner = Table::new(["Hello World"]);
+//!
+//! let table = Table::new([""])
+//!     .with(Modify::new(Cell(1, 0)).with(InnerTable(inner)))
+//!     .with(Width::wrap(11))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+---------+\n\
+//!      | &str    |\n\
+//!      +---------+\n\
+//!      | +-----+ |\n\
+//!      | | &st | |\n\
+//!      | | r   | |\n\
+//!      | +-----+ |\n\
+//!      | | Hel | |\n\
+//!      | | lo  | |\n\
+//!      | | Wor | |\n\
+//!      | | ld  | |\n\
+//!      | +-----+ |\n\
+//!      +---------+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{cell_info::CellInfo, vec_records::VecRecords, Records, RecordsMut},
+    util::string_width_multiline,
+    width::CfgWidthFunction,
+    Entity, Position,
+};
+
+use crate::{CellOption, Table, TableOption};
+
+/// InnerTable sets a [`Table`] as a content of a cell.
+///
+/// Unlike setting a rendered table as a text,
+/// the inner table is re-rendered when a cell is wrapped or truncated by [`Width`],
+/// so its borders are not cut in the middle.
+///
+/// If an inner table can't be shrunk to a given width
+/// it's handled as an ordinary text.
+///
+/// [`Table`]: crate::Table
+/// [`Width`]: crate::Width
+#[derive(Debug, Clone)]
+pub struct InnerTable(pub Table);
+
+impl<R> CellOption<R> for InnerTable
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let text = self.0.to_string();
+
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            table.get_records_mut().set(pos, text.clone(), &width_fn);
+            table.set_inner_table(pos, self.0.clone(), text.clone());
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Re-renders an inner table of a cell with a given option.
+///
+/// It returns a new content of a cell if a cell contains an inner table
+/// and it fits into a given width after the change.
+pub(crate) fn resize_inner_table<R, O>(
+    table: &mut Table<R>,
+    pos: Position,
+    width: usize,
+    mut option: O,
+) -> Option<String>
+where
+    R: Records,
+    O: TableOption<VecRecords<CellInfo<'static>>>,
+{
+    let text = table.get_records().get_text(pos);
+    let inner = table.get_inner_table(pos, text)?;
+
+    let mut resized = inner.clone();
+    option.change(&mut resized);
+    let rendered = resized.to_string();
+
+    if string_width_multiline(&rendered) > width {
+        return None;
+    }
+
+    let inner = inner.clone();
+    table.set_inner_table(pos, inner, rendered.clone());
+
+    Some(rendered)
+}
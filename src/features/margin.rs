@@ -65,6 +65,6 @@ impl Margin {
 
 impl<R> TableOption<R> for Margin {
     fn change(&mut self, table: &mut Table<R>) {
-        table.get_config_mut().set_margin(self.0);
+        table.get_config_mut_raw().set_margin(self.0);
     }
 }
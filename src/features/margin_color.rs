@@ -44,6 +44,8 @@ impl MarginColor {
 
 impl<R> TableOption<R> for MarginColor {
     fn change(&mut self, table: &mut Table<R>) {
-        table.get_config_mut().set_margin_color(self.inner.clone());
+        table
+            .get_config_mut_raw()
+            .set_margin_color(self.inner.clone());
     }
 }
@@ -2,11 +2,44 @@
 //!
 //! [`Span`]: crate::Span
 
-use crate::{papergrid::records::Records, Table, TableOption};
+use papergrid::Entity;
+
+use crate::{papergrid::records::Records, CellOption, Table, TableOption};
 
 /// Merge to combine duplicates together, using [`Span`].
 ///
+/// It can be applied to a whole table or to particular columns (rows for a horizontal merge) via [`Modify`].
+/// In the latter case only duplicates within the selected columns (rows) are merged.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{merge::Merge, object::Columns, ModifyObject, Style, Table};
+///
+/// let data = [["Fruit", "Apple"], ["Fruit", "Pear"], ["Vegetable", "Pear"]];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(Columns::first().modify().with(Merge::vertical()))
+///     .with(Style::correct_spans())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "┌───────────┬───────┐\n\
+///      │ 0         │ 1     │\n\
+///      ├───────────┼───────┤\n\
+///      │ Fruit     │ Apple │\n\
+///      │           ├───────┤\n\
+///      │           │ Pear  │\n\
+///      ├───────────┼───────┤\n\
+///      │ Vegetable │ Pear  │\n\
+///      └───────────┴───────┘"
+/// );
+/// ```
+///
 /// [`Span`]: crate::Span
+/// [`Modify`]: crate::Modify
 #[derive(Debug)]
 pub struct Merge;
 
@@ -35,6 +68,15 @@ where
     R: Records,
 {
     fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+impl<R> CellOption<R> for MergeDuplicatesVertical
+where
+    R: Records,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         if table.is_empty() {
             return;
         }
@@ -50,6 +92,18 @@ where
                     continue;
                 }
 
+                if !is_entity_cell(entity, (row, column)) {
+                    if repeat_is_set && repeat_length > 1 {
+                        table
+                            .get_config_mut()
+                            .set_row_span((row + 1, column), repeat_length);
+                    }
+
+                    repeat_is_set = false;
+                    repeat_length = 0;
+                    continue;
+                }
+
                 // we need to mitigate messing existing spans
                 let is_cell_visible = table
                     .get_config()
@@ -129,6 +183,15 @@ where
     R: Records,
 {
     fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+impl<R> CellOption<R> for MergeDuplicatesHorizontal
+where
+    R: Records,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         if table.is_empty() {
             return;
         }
@@ -144,6 +207,18 @@ where
                     continue;
                 }
 
+                if !is_entity_cell(entity, (row, column)) {
+                    if repeat_is_set && repeat_length > 1 {
+                        table
+                            .get_config_mut()
+                            .set_column_span((row, column + 1), repeat_length);
+                    }
+
+                    repeat_is_set = false;
+                    repeat_length = 0;
+                    continue;
+                }
+
                 // we need to mitigate messing existing spans
                 let is_cell_visible = table
                     .get_config()
@@ -209,3 +284,12 @@ where
         table.destroy_height_cache();
     }
 }
+
+fn is_entity_cell(entity: Entity, (row, col): (usize, usize)) -> bool {
+    match entity {
+        Entity::Global => true,
+        Entity::Column(c) => c == col,
+        Entity::Row(r) => r == row,
+        Entity::Cell(r, c) => r == row && c == col,
+    }
+}
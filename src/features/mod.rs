@@ -1,4 +1,5 @@
 pub mod alignment;
+pub mod custom;
 pub mod format;
 pub mod formatting;
 pub mod height;
@@ -9,21 +10,47 @@ pub mod measurement;
 pub mod peaker;
 pub mod shadow;
 pub mod style;
+pub mod summary;
 pub mod width;
 
 #[cfg(feature = "color")]
 pub mod color;
 #[cfg(feature = "color")]
+pub(crate) mod colorization;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "html")]
+pub mod html;
+#[cfg(feature = "color")]
 pub mod margin_color;
 #[cfg(feature = "color")]
 pub mod padding_color;
+#[cfg(feature = "derive")]
+pub mod selection;
+#[cfg(feature = "color")]
+pub(crate) mod stripe;
 
+pub(crate) mod accessible;
+pub(crate) mod border_region;
+pub(crate) mod caption;
+pub(crate) mod column_format;
 pub(crate) mod concat;
+pub(crate) mod derived_config;
 pub(crate) mod disable;
 pub(crate) mod extract;
+pub(crate) mod filter;
+pub(crate) mod header_groups;
+pub(crate) mod header_orientation;
+pub(crate) mod header_position;
+pub(crate) mod inner_table;
 pub(crate) mod margin;
 pub mod merge;
 pub(crate) mod padding;
 pub(crate) mod panel;
 pub(crate) mod rotate;
+pub(crate) mod row_index;
+pub(crate) mod sort;
 pub(crate) mod span;
+pub(crate) mod sparkline;
+pub(crate) mod table_config;
+pub(crate) mod theme;
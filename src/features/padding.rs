@@ -36,16 +36,13 @@
 //!
 //! [`Table`]: crate::Table
 
-use papergrid::{Entity, Indent};
+use papergrid::{records::Records, Entity, Indent};
 
 use crate::{
     table::{CellOption, Table},
     TableOption,
 };
 
-// #[cfg(feature = "color")]
-// use crate::style::Color;
-
 /// Padding is responsible for a left/right/top/bottom inner indent of a particular cell.
 ///
 /// ```rust,no_run
@@ -53,8 +50,15 @@ use crate::{
 /// # let data: Vec<&'static str> = Vec::new();
 /// let table = Table::new(&data).with(Modify::new(Rows::single(0)).with(Padding::new(0, 0, 1, 1).set_fill('>', '<', '^', 'V')));
 /// ```
-#[derive(Debug)]
-pub struct Padding(papergrid::Padding);
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Padding {
+    #[cfg_attr(feature = "serde", serde(with = "PaddingDef"))]
+    indent: papergrid::Padding,
+    #[cfg(feature = "color")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    colored_as_cell: bool,
+}
 
 impl Padding {
     /// Construct's an Padding object.
@@ -62,7 +66,7 @@ impl Padding {
     /// It uses space(' ') as a default fill character.
     /// To set a custom character you can use [`Self::set_fill`] function.
     pub fn new(left: usize, right: usize, top: usize, bottom: usize) -> Self {
-        Self(papergrid::Padding {
+        Self::from_indent(papergrid::Padding {
             top: Indent::spaced(top),
             bottom: Indent::spaced(bottom),
             left: Indent::spaced(left),
@@ -76,7 +80,7 @@ impl Padding {
     /// To set a custom character you can use [`Self::set_fill`] function.
     pub fn zero() -> Self {
         let indent = Indent::spaced(0);
-        Self(papergrid::Padding {
+        Self::from_indent(papergrid::Padding {
             top: indent,
             bottom: indent,
             left: indent,
@@ -84,26 +88,119 @@ impl Padding {
         })
     }
 
+    fn from_indent(indent: papergrid::Padding) -> Self {
+        Self {
+            indent,
+            #[cfg(feature = "color")]
+            colored_as_cell: false,
+        }
+    }
+
     /// The function, sets a characters for the padding on an each side.
     pub fn set_fill(mut self, left: char, right: char, top: char, bottom: char) -> Self {
-        self.0.left.fill = left;
-        self.0.right.fill = right;
-        self.0.top.fill = top;
-        self.0.bottom.fill = bottom;
+        self.indent.left.fill = left;
+        self.indent.right.fill = right;
+        self.indent.top.fill = top;
+        self.indent.bottom.fill = bottom;
+        self
+    }
+
+    /// Colors the padding of a cell with a background color of its content,
+    /// so a colored cell doesn't have uncolored stripes on its sides.
+    ///
+    /// A background color is taken from the first character of a cell at the moment the option is applied,
+    /// so it's better to apply it after the content is colored.
+    /// Cells without a background color are left untouched.
+    ///
+    /// ```
+    /// use tabled::{Padding, Table};
+    ///
+    /// let table = Table::new(["\u{1b}[44mblue\u{1b}[49m"])
+    ///     .with(Padding::new(1, 1, 0, 0).colored_as_cell())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+\n\
+    ///      | &str |\n\
+    ///      +------+\n\
+    ///      |\u{1b}[44m \u{1b}[49m\u{1b}[44mblue\u{1b}[49m\u{1b}[44m \u{1b}[49m|\n\
+    ///      +------+"
+    /// );
+    /// ```
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn colored_as_cell(mut self) -> Self {
+        self.colored_as_cell = true;
         self
     }
 }
 
-impl<R> CellOption<R> for Padding {
+impl<R> CellOption<R> for Padding
+where
+    R: Records,
+{
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
-        table.get_config_mut().set_padding(entity, self.0);
-        table.destroy_width_cache();
+        table.get_config_mut_raw().set_padding(entity, self.indent);
+        table.destroy_width_cache_of(entity);
+
+        #[cfg(feature = "color")]
+        if self.colored_as_cell {
+            set_cell_background(table, entity);
+        }
     }
 }
 
-impl<R> TableOption<R> for Padding {
+impl<R> TableOption<R> for Padding
+where
+    R: Records,
+{
     fn change(&mut self, table: &mut Table<R>) {
-        table.get_config_mut().set_padding(Entity::Global, self.0);
-        table.destroy_width_cache();
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+#[cfg(feature = "color")]
+fn set_cell_background<R>(table: &mut Table<R>, entity: Entity)
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = table.shape();
+    for pos in entity.iter(count_rows, count_cols) {
+        let text = table.get_records().get_text(pos);
+        if let Some(bg) = crate::color::get_first_background(text) {
+            let color = papergrid::PaddingColor {
+                top: bg.clone(),
+                bottom: bg.clone(),
+                left: bg.clone(),
+                right: bg,
+            };
+
+            table
+                .get_config_mut_raw()
+                .set_padding_color(pos.into(), color);
+        }
     }
 }
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "papergrid::Padding")]
+struct PaddingDef {
+    #[serde(with = "IndentDef")]
+    top: Indent,
+    #[serde(with = "IndentDef")]
+    bottom: Indent,
+    #[serde(with = "IndentDef")]
+    left: Indent,
+    #[serde(with = "IndentDef")]
+    right: Indent,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Indent")]
+struct IndentDef {
+    fill: char,
+    size: usize,
+}
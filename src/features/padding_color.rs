@@ -44,7 +44,7 @@ impl PaddingColor {
 impl<R> CellOption<R> for PaddingColor {
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         table
-            .get_config_mut()
+            .get_config_mut_raw()
             .set_padding_color(entity, self.inner.clone());
     }
 }
@@ -298,7 +298,7 @@ where
     }
 }
 
-fn move_rows_aside<R>(table: &mut Table<R>, row: usize)
+pub(crate) fn move_rows_aside<R>(table: &mut Table<R>, row: usize)
 where
     R: Records + Resizable,
 {
@@ -312,7 +312,7 @@ where
     }
 }
 
-fn move_columns_aside<R>(table: &mut Table<R>, column: usize)
+pub(crate) fn move_columns_aside<R>(table: &mut Table<R>, column: usize)
 where
     R: Records + Resizable,
 {
@@ -326,7 +326,7 @@ where
     }
 }
 
-fn move_row_spans<R>(table: &mut Table<R>, target_row: usize)
+pub(crate) fn move_row_spans<R>(table: &mut Table<R>, target_row: usize)
 where
     R: Records,
 {
@@ -358,7 +358,7 @@ where
     }
 }
 
-fn move_column_spans<R>(table: &mut Table<R>, target_column: usize)
+pub(crate) fn move_column_spans<R>(table: &mut Table<R>, target_column: usize)
 where
     R: Records,
 {
@@ -61,13 +61,11 @@ impl Peaker for PriorityMax {
         Self
     }
 
-    fn peak(&mut self, _: &[usize], widths: &[usize]) -> Option<usize> {
-        let col = (0..widths.len()).max_by_key(|&i| widths[i]).unwrap();
-        if widths[col] == 0 {
-            None
-        } else {
-            Some(col)
-        }
+    fn peak(&mut self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+        // a column which reached its minimum can't be changed anymore so it's skipped
+        (0..widths.len())
+            .filter(|&i| widths[i] > min_widths.get(i).copied().unwrap_or(0))
+            .max_by_key(|&i| widths[i])
     }
 }
 
@@ -81,14 +79,9 @@ impl Peaker for PriorityMin {
     }
 
     fn peak(&mut self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
-        let col = (0..widths.len())
-            .filter(|&i| min_widths.is_empty() || widths[i] > min_widths[i])
+        // an empty column can't be changed anymore so it's skipped
+        (0..widths.len())
+            .filter(|&i| widths[i] > min_widths.get(i).copied().unwrap_or(0))
             .min_by_key(|&i| widths[i])
-            .unwrap();
-        if widths[col] == 0 {
-            None
-        } else {
-            Some(col)
-        }
     }
 }
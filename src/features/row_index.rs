@@ -0,0 +1,146 @@
+//! This module contains a [`RowIndex`] structure which adds a column with numbers of rows to a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{RowIndex, Table};
+//!
+//! let data = vec![("Tom", 30), ("Ann", 4), ("Bob", 100)];
+//!
+//! let table = Table::new(data)
+//!     .with(RowIndex::new().start_at(1).name("#"))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+---+------+-----+\n\
+//!      | # | &str | i32 |\n\
+//!      +---+------+-----+\n\
+//!      | 1 | Tom  | 30  |\n\
+//!      +---+------+-----+\n\
+//!      | 2 | Ann  | 4   |\n\
+//!      +---+------+-----+\n\
+//!      | 3 | Bob  | 100 |\n\
+//!      +---+------+-----+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{Table, TableOption};
+
+use super::panel::{move_column_spans, move_columns_aside};
+
+/// RowIndex prepends a column with an index of each row of a [`Table`].
+///
+/// A header gets a name of the column instead of a number if a table has one (see [`Table::count_header_rows`]),
+/// the name is spanned over all header rows.
+///
+/// As the column becomes a part of a table, it's affected by all options applied afterwards,
+/// like [`Rotate`], [`Extract`] or [`Width`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{RowIndex, Rotate, Style, Table};
+///
+/// let table = Table::new(["a", "b"])
+///     .with(RowIndex::new().name("N"))
+///     .with(Rotate::Left)
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str | a | b \n",
+///         "------+---+---\n",
+///         " N    | 0 | 1 ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+/// [`Rotate`]: crate::Rotate
+/// [`Extract`]: crate::Extract
+/// [`Width`]: crate::Width
+#[derive(Debug, Clone)]
+pub struct RowIndex<S = &'static str> {
+    name: S,
+    start: usize,
+}
+
+impl RowIndex {
+    /// Creates a [`RowIndex`] which numbers rows from 0 and has an empty name.
+    pub fn new() -> Self {
+        Self { name: "", start: 0 }
+    }
+}
+
+impl Default for RowIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> RowIndex<S> {
+    /// Sets a number of a first row.
+    pub fn start_at(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets a name of the index column, which is put in a header row.
+    pub fn name<N>(self, name: N) -> RowIndex<N>
+    where
+        N: AsRef<str>,
+    {
+        RowIndex {
+            name,
+            start: self.start,
+        }
+    }
+}
+
+impl<S, R> TableOption<R> for RowIndex<S>
+where
+    S: AsRef<str>,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_rows = table.get_records().count_rows();
+        if count_rows == 0 {
+            return;
+        }
+
+        move_columns_aside(table, 0);
+        move_column_spans(table, 0);
+
+        let count_header_rows = table.count_header_rows();
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+        let records = table.get_records_mut();
+
+        if count_header_rows > 0 {
+            records.set((0, 0), self.name.as_ref().to_owned(), &ctrl);
+        }
+
+        for (i, row) in (count_header_rows..count_rows).enumerate() {
+            let index = self.start + i;
+            records.set((row, 0), index.to_string(), &ctrl);
+        }
+
+        if count_header_rows > 1 {
+            let cfg = table.get_config_mut();
+            cfg.set_row_span((0, 0), count_header_rows);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
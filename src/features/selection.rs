@@ -0,0 +1,322 @@
+//! This module contains a [`Selection`] structure which picks columns of a [`Tabled`] type by their names.
+//!
+//! It's handy to validate a list of columns given by a user (like a `--columns` flag of a CLI)
+//! against headers of a type.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{selection::Selection, Style, Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Process {
+//!     pid: usize,
+//!     name: &'static str,
+//!     cpu: f32,
+//! }
+//!
+//! let data = [Process { pid: 1, name: "init", cpu: 0.1 }];
+//!
+//! let selection = Selection::<Process>::from_names(["name", "cpu"]).unwrap();
+//! let table = Table::new_masked(&data, &selection.mask())
+//!     .with(Style::psql())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " name | cpu \n",
+//!         "------+-----\n",
+//!         " init | 0.1 ",
+//!     )
+//! );
+//!
+//! let error = Selection::<Process>::from_names(["nmae"]).unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "unknown column \"nmae\", did you mean \"name\"? available columns are: pid, name, cpu",
+//! );
+//! ```
+//!
+//! [`Tabled`]: crate::Tabled
+
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+use papergrid::records::Records;
+
+use crate::{locator::Locator, Tabled};
+
+/// Selection is a list of columns of a [`Tabled`] type chosen by their headers.
+///
+/// A name is matched against [`Tabled::headers`] exactly,
+/// and if there's no such header it's matched ignoring an ASCII case.
+///
+/// A selection can be used either to build a table with only selected columns,
+/// via [`Table::new_masked`] and [`Selection::mask`],
+/// or to remove the rest columns from an already built table,
+/// via [`Disable::column`] and [`Selection::unselected`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{selection::Selection, Disable, Style, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Process {
+///     pid: usize,
+///     #[tabled(rename = "Command Name")]
+///     name: &'static str,
+///     cpu: f32,
+/// }
+///
+/// let data = [Process { pid: 1, name: "init", cpu: 0.1 }];
+///
+/// let selection = Selection::<Process>::from_names(["pid", "command name"]).unwrap();
+/// let table = Table::new(&data)
+///     .with(Disable::column(selection.unselected()))
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " pid | Command Name \n",
+///         "-----+--------------\n",
+///         " 1   | init         ",
+///     )
+/// );
+/// ```
+///
+/// [`Tabled`]: crate::Tabled
+/// [`Tabled::headers`]: crate::Tabled::headers
+/// [`Table::new_masked`]: crate::Table::new_masked
+/// [`Disable::column`]: crate::Disable::column
+pub struct Selection<T> {
+    columns: Vec<usize>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Selection<T>
+where
+    T: Tabled,
+{
+    /// Creates a selection of all columns.
+    pub fn all() -> Self {
+        Self {
+            columns: (0..T::LENGTH).collect(),
+            _type: PhantomData,
+        }
+    }
+
+    /// Creates a selection of columns by their names.
+    ///
+    /// It fails if a name doesn't match any column or if a column is selected more than once.
+    pub fn from_names<I, S>(names: I) -> Result<Self, SelectionError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let headers = T::headers()
+            .into_iter()
+            .map(|header| header.into_owned())
+            .collect::<Vec<_>>();
+
+        let mut columns = Vec::new();
+        for name in names {
+            let name = name.as_ref();
+            let column = find_column(&headers, name)
+                .ok_or_else(|| SelectionError::unknown_column(name, &headers))?;
+
+            if columns.contains(&column) {
+                return Err(SelectionError::DuplicateColumn(name.to_owned()));
+            }
+
+            columns.push(column);
+        }
+
+        Ok(Self {
+            columns,
+            _type: PhantomData,
+        })
+    }
+
+    /// Returns indexes of selected columns in an order they were given.
+    pub fn columns(&self) -> &[usize] {
+        &self.columns
+    }
+
+    /// Returns a mask of columns for [`Table::new_masked`],
+    /// where all not selected columns are hidden.
+    ///
+    /// [`Table::new_masked`]: crate::Table::new_masked
+    pub fn mask(&self) -> Vec<bool> {
+        (0..T::LENGTH)
+            .map(|col| !self.columns.contains(&col))
+            .collect()
+    }
+
+    /// Returns a [`Locator`] of not selected columns for [`Disable::column`].
+    ///
+    /// It's expected to be used on a table built from the same type,
+    /// before any columns of it were removed or added.
+    ///
+    /// [`Locator`]: crate::locator::Locator
+    /// [`Disable::column`]: crate::Disable::column
+    pub fn unselected(&self) -> Unselected {
+        let columns = (0..T::LENGTH)
+            .filter(|col| !self.columns.contains(col))
+            .collect();
+
+        Unselected { columns }
+    }
+}
+
+impl<T> fmt::Debug for Selection<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Selection")
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+impl<T> Clone for Selection<T> {
+    fn clone(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for Selection<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.columns == other.columns
+    }
+}
+
+impl<T> Eq for Selection<T> {}
+
+/// Unselected is a [`Locator`] of columns which are not a part of a [`Selection`].
+///
+/// It's created by [`Selection::unselected`].
+///
+/// [`Locator`]: crate::locator::Locator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unselected {
+    columns: Vec<usize>,
+}
+
+impl Locator for Unselected {
+    type Coordinate = usize;
+    type IntoIter = Vec<usize>;
+
+    fn locate<R>(&mut self, records: R) -> Self::IntoIter
+    where
+        R: Records,
+    {
+        let count_columns = records.count_columns();
+        self.columns
+            .iter()
+            .copied()
+            .filter(|&col| col < count_columns)
+            .collect()
+    }
+}
+
+/// SelectionError is an error of creating a [`Selection`] by names of columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionError {
+    /// A name doesn't match any column.
+    UnknownColumn {
+        /// The given name.
+        name: String,
+        /// The closest name of a column if there's one close enough.
+        suggestion: Option<String>,
+        /// Names of all columns.
+        available: Vec<String>,
+    },
+    /// A column is selected more than once; it holds the name it was selected by the second time.
+    DuplicateColumn(String),
+}
+
+impl SelectionError {
+    fn unknown_column(name: &str, headers: &[String]) -> Self {
+        Self::UnknownColumn {
+            name: name.to_owned(),
+            suggestion: suggest(headers, name),
+            available: headers.to_vec(),
+        }
+    }
+}
+
+impl Display for SelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownColumn {
+                name,
+                suggestion,
+                available,
+            } => {
+                write!(f, "unknown column {:?}", name)?;
+
+                match suggestion {
+                    Some(suggestion) => write!(f, ", did you mean {:?}?", suggestion)?,
+                    None => write!(f, ",")?,
+                }
+
+                write!(f, " available columns are: {}", available.join(", "))
+            }
+            Self::DuplicateColumn(name) => {
+                write!(f, "column {:?} is selected more than once", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SelectionError {}
+
+fn find_column(headers: &[String], name: &str) -> Option<usize> {
+    headers
+        .iter()
+        .position(|header| header == name)
+        .or_else(|| {
+            headers
+                .iter()
+                .position(|header| header.eq_ignore_ascii_case(name))
+        })
+}
+
+/// Returns a header closest to a name, if it's close enough to be a typo.
+fn suggest(headers: &[String], name: &str) -> Option<String> {
+    let name = name.to_lowercase();
+    let max_distance = std::cmp::max(2, name.chars().count() / 3);
+
+    headers
+        .iter()
+        .map(|header| (edit_distance(&header.to_lowercase(), &name), header))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, header)| header.clone())
+}
+
+/// Returns a Levenshtein distance between 2 strings.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs = rhs.chars().collect::<Vec<_>>();
+
+    let mut prev = (0..=rhs.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; rhs.len() + 1];
+    for (i, a) in lhs.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, b) in rhs.iter().enumerate() {
+            let cost = if a == *b { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[rhs.len()]
+}
@@ -0,0 +1,195 @@
+//! This module contains a [`Sort`] structure which reorders rows of a [`Table`] by a column.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Sort, Table};
+//!
+//! let data = vec![("Tom", 30), ("Ann", 4), ("Bob", 100)];
+//!
+//! let table = Table::new(data).with(Sort::by_column(1).numeric()).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+------+-----+\n\
+//!      | &str | i32 |\n\
+//!      +------+-----+\n\
+//!      | Ann  | 4   |\n\
+//!      +------+-----+\n\
+//!      | Tom  | 30  |\n\
+//!      +------+-----+\n\
+//!      | Bob  | 100 |\n\
+//!      +------+-----+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::cmp::Ordering;
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{Table, TableOption};
+
+/// Sort reorders rows of a [`Table`] by a content of a given column.
+///
+/// Header rows are left in place if a table has a header (see [`Table::count_header_rows`]).
+///
+/// The sort is stable, so rows with equal values keep their relative order.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Builder, Sort};
+///
+/// let mut builder = Builder::default();
+/// builder.set_columns(["name", "version"]);
+/// builder.add_record(["tabled", "0.10.0"]);
+/// builder.add_record(["papergrid", "0.7.1"]);
+/// builder.add_record(["ansi-str", "0.5.0"]);
+///
+/// let table = builder
+///     .build()
+///     .with(Sort::by_column(0).by(|a, b| a.len().cmp(&b.len())).descending())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-----------+---------+\n\
+///      | name      | version |\n\
+///      +-----------+---------+\n\
+///      | papergrid | 0.7.1   |\n\
+///      +-----------+---------+\n\
+///      | ansi-str  | 0.5.0   |\n\
+///      +-----------+---------+\n\
+///      | tabled    | 0.10.0  |\n\
+///      +-----------+---------+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+#[derive(Debug, Clone)]
+pub struct Sort<F = fn(&str, &str) -> Ordering> {
+    column: usize,
+    compare: F,
+    descending: bool,
+}
+
+impl Sort {
+    /// Creates a [`Sort`] which orders rows by a given column.
+    ///
+    /// By default the values are compared lexicographically in ascending order.
+    pub fn by_column(column: usize) -> Self {
+        Self {
+            column,
+            compare: compare_lexicographic,
+            descending: false,
+        }
+    }
+
+    /// Compare values as numbers.
+    ///
+    /// Values which can't be parsed as a number are placed after the numeric ones
+    /// and compared lexicographically.
+    pub fn numeric(self) -> Self {
+        self.by(compare_numeric)
+    }
+}
+
+impl<F> Sort<F> {
+    /// Sets a custom comparator of values.
+    pub fn by<FF>(self, compare: FF) -> Sort<FF>
+    where
+        FF: FnMut(&str, &str) -> Ordering,
+    {
+        Sort {
+            column: self.column,
+            compare,
+            descending: self.descending,
+        }
+    }
+
+    /// Reverses the order.
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+}
+
+impl<F, R> TableOption<R> for Sort<F>
+where
+    F: FnMut(&str, &str) -> Ordering,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        if self.column >= count_columns {
+            return;
+        }
+
+        let start = table.count_header_rows();
+        if count_rows <= start {
+            return;
+        }
+
+        let records = table.get_records();
+        let mut order = (start..count_rows).collect::<Vec<_>>();
+        order.sort_by(|&lhs, &rhs| {
+            let lhs = records.get_text((lhs, self.column));
+            let rhs = records.get_text((rhs, self.column));
+            let ordering = (self.compare)(lhs, rhs);
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        reorder_rows(table.get_records_mut(), start, &order);
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Moves rows so a row `order[i]` ends up at `start + i`.
+fn reorder_rows<R>(records: &mut R, start: usize, order: &[usize])
+where
+    R: Resizable,
+{
+    // position[row] is a current position of an original row.
+    // current[pos] is an original row which is located at a position.
+    let mut position = (start..start + order.len()).collect::<Vec<_>>();
+    let mut current = position.clone();
+
+    for (i, &row) in order.iter().enumerate() {
+        let target = start + i;
+        let pos = position[row - start];
+        if pos == target {
+            continue;
+        }
+
+        records.swap_row(target, pos);
+
+        let displaced = current[i];
+        current.swap(i, pos - start);
+        position[row - start] = target;
+        position[displaced - start] = pos;
+    }
+}
+
+fn compare_lexicographic(lhs: &str, rhs: &str) -> Ordering {
+    lhs.cmp(rhs)
+}
+
+fn compare_numeric(lhs: &str, rhs: &str) -> Ordering {
+    let lhs_num = lhs.trim().parse::<f64>().ok();
+    let rhs_num = rhs.trim().parse::<f64>().ok();
+    match (lhs_num, rhs_num) {
+        (Some(l), Some(r)) => l.partial_cmp(&r).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => lhs.cmp(rhs),
+    }
+}
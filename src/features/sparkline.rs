@@ -0,0 +1,151 @@
+//! This module contains a [`Sparkline`] structure which shows a trend of numeric cells across renders.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Columns, Sparkline, Style, Table};
+//!
+//! let mut trend = Sparkline::new(Columns::single(1));
+//!
+//! let mut render = |load: usize| {
+//!     let mut table = Table::new([("cpu", load)]);
+//!     table.with(Style::psql()).with(&mut trend);
+//!     table.to_string()
+//! };
+//!
+//! render(10);
+//! render(40);
+//! let table = render(25);
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str | usize  \n",
+//!         "------+--------\n",
+//!         " cpu  | 25 ▁█▅ ",
+//!     )
+//! );
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity, Position,
+};
+
+use crate::{object::Object, Table, TableOption};
+
+/// Symbols a trend is drawn with, from the lowest to the highest value.
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A default number of values kept for each cell.
+const DEFAULT_LENGTH: usize = 8;
+
+/// Sparkline records numeric values of target cells each time it's applied to a [`Table`],
+/// and puts a mini trend of the recorded values (like `▁▂▃▅`) next to a current value.
+///
+/// It's meant for tables which are rebuilt and printed again and again (see [`Live`]),
+/// so the same [`Sparkline`] must be applied to each new table by a reference (`&mut`).
+///
+/// A history is kept for each cell by its position,
+/// so rows are expected to keep their order between renders.
+/// Cells which are not numbers are left untouched and their history is dropped.
+///
+/// Only columns which got a trend are measured again,
+/// as widths of other columns are kept between changes of a table.
+///
+/// [`Table`]: crate::Table
+/// [`Live`]: crate::display::Live
+#[derive(Debug, Clone)]
+pub struct Sparkline<O> {
+    target: O,
+    length: usize,
+    history: HashMap<Position, VecDeque<f64>>,
+}
+
+impl<O> Sparkline<O> {
+    /// Creates a [`Sparkline`] for the given cells, which keeps 8 last values of each cell.
+    pub fn new(target: O) -> Self {
+        Self {
+            target,
+            length: DEFAULT_LENGTH,
+            history: HashMap::new(),
+        }
+    }
+
+    /// Sets a number of last values which are kept and shown for each cell.
+    ///
+    /// A length of 0 is considered to be 1.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = std::cmp::max(length, 1);
+        self
+    }
+
+    /// Drops all recorded values.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    fn record(&mut self, pos: Position, value: f64) -> &VecDeque<f64> {
+        let history = self.history.entry(pos).or_default();
+        history.push_back(value);
+        while history.len() > self.length {
+            history.pop_front();
+        }
+
+        history
+    }
+}
+
+impl<O, R> TableOption<R> for Sparkline<O>
+where
+    O: Object,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+
+        let entities = self.target.cells(table).collect::<Vec<_>>();
+        for entity in entities {
+            for pos in entity.iter(count_rows, count_cols) {
+                let text = table.get_records().get_text(pos).to_owned();
+                let value = match text.trim().parse::<f64>() {
+                    Ok(value) if value.is_finite() => value,
+                    _ => {
+                        self.history.remove(&pos);
+                        continue;
+                    }
+                };
+
+                let trend = build_trend(self.record(pos, value));
+                let text = format!("{} {}", text, trend);
+
+                table.get_records_mut_raw().set(pos, text, &ctrl);
+                table.destroy_width_cache_of(Entity::Cell(pos.0, pos.1));
+            }
+        }
+
+        table.destroy_height_cache();
+    }
+}
+
+fn build_trend(values: &VecDeque<f64>) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            if range == 0.0 {
+                return BARS[0];
+            }
+
+            let level = (value - min) / range * (BARS.len() - 1) as f64;
+            BARS[level.round() as usize]
+        })
+        .collect()
+}
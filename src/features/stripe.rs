@@ -0,0 +1,269 @@
+//! This module contains a [`Stripe`] structure which colors rows of a [`Table`] one after another.
+//!
+//! The module is available only when a `color` feature is on.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{color::Color, Stripe, Table};
+//!
+//! let table = Table::new(["a", "bb", "ccc"])
+//!     .with(Stripe::new(Color::BG_BLUE, Color::default()).skip_header())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+------+\n\
+//!      | &str |\n\
+//!      +------+\n\
+//!      |\u{1b}[44m \u{1b}[49m\u{1b}[44ma   \u{1b}[49m\u{1b}[44m \u{1b}[49m|\n\
+//!      +------+\n\
+//!      | bb   |\n\
+//!      +------+\n\
+//!      |\u{1b}[44m \u{1b}[49m\u{1b}[44mccc \u{1b}[49m\u{1b}[44m \u{1b}[49m|\n\
+//!      +------+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::iter::FromIterator;
+
+use papergrid::{
+    records::Records,
+    util::string_trim,
+    width::{CfgWidthFunction, WidthFunc},
+    AlignmentHorizontal, AnsiColor, GridConfig, Position,
+};
+
+use crate::{
+    builder::Builder,
+    color::{to_static_padding_color, Color},
+    features::alignment::cell_width,
+    Table, TableOption,
+};
+
+/// Stripe sets a background color of rows, using an even and an odd color one after another.
+///
+/// A whole row is colored, including a padding and an alignment space,
+/// so the color is kept when a row consists of several lines.
+/// It's done when a table is printed, so it doesn't matter whether [`Width`] or [`Alignment`]
+/// settings are applied before or after the option.
+///
+/// Colors which are set by a content of a cell are kept;
+/// the stripe is restored after them.
+///
+/// [`Width`]: crate::Width
+/// [`Alignment`]: crate::Alignment
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug, Clone)]
+pub struct Stripe {
+    even: AnsiColor<'static>,
+    odd: AnsiColor<'static>,
+    skip_header: bool,
+}
+
+impl Stripe {
+    /// Creates a [`Stripe`] with a color for even and odd rows.
+    ///
+    /// An empty color ([`Color::default`]) leaves rows uncolored.
+    pub fn new(even: Color, odd: Color) -> Self {
+        Self {
+            even: even.into(),
+            odd: odd.into(),
+            skip_header: false,
+        }
+    }
+
+    /// Leaves header rows uncolored, counting rows from the next one.
+    ///
+    /// All rows of a header with groups of columns are skipped (see [`Table::count_header_rows`]),
+    /// and a first row is skipped if a table has no header.
+    ///
+    /// [`Table::count_header_rows`]: crate::Table::count_header_rows
+    pub fn skip_header(mut self) -> Self {
+        self.skip_header = true;
+        self
+    }
+
+    fn row_color(&self, row: usize, count_header_rows: usize) -> Option<&AnsiColor<'static>> {
+        let skipped = match self.skip_header {
+            true => std::cmp::max(count_header_rows, 1),
+            false => 0,
+        };
+
+        if row < skipped {
+            return None;
+        }
+
+        let index = row - skipped;
+        let color = if index % 2 == 1 {
+            &self.odd
+        } else {
+            &self.even
+        };
+        if color.get_prefix().is_empty() {
+            return None;
+        }
+
+        Some(color)
+    }
+}
+
+impl<R> TableOption<R> for Stripe {
+    fn change(&mut self, table: &mut Table<R>) {
+        table.set_stripe(self.clone());
+    }
+}
+
+/// Builds a copy of a table where each line of a striped cell is aligned to the width of its column,
+/// so an alignment space can be colored.
+///
+/// `widths` and `heights` are the ones a table would be printed with.
+pub(crate) fn build_striped_table<R>(
+    records: &R,
+    cfg: &GridConfig,
+    widths: &[usize],
+    heights: &[usize],
+    stripe: &Stripe,
+    count_header_rows: usize,
+) -> Table
+where
+    R: Records,
+{
+    let shape = (records.count_rows(), records.count_columns());
+    let width_fn = CfgWidthFunction::from_cfg(cfg);
+
+    let mut striped_cfg = cfg.clone();
+    let rows = (0..shape.0).map(|row| {
+        (0..shape.1)
+            .map(|col| {
+                let pos = (row, col);
+                let text = records.get_text(pos);
+                let color = match stripe.row_color(row, count_header_rows) {
+                    Some(color) if cfg.is_cell_visible(pos, shape) => color,
+                    _ => return text.to_owned(),
+                };
+
+                let width = cell_width(cfg, widths, pos, shape);
+                set_cell_color(&mut striped_cfg, pos, color);
+                stripe_text(records, cfg, pos, width, color, &width_fn)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut table = Builder::from_iter(rows).build();
+    *table.get_config_mut() = striped_cfg;
+    table.cache_width(widths.to_vec());
+    table.cache_height(heights.to_vec());
+
+    table
+}
+
+fn stripe_text<R, W>(
+    records: &R,
+    cfg: &GridConfig,
+    pos: Position,
+    width: usize,
+    color: &AnsiColor<'_>,
+    width_fn: &W,
+) -> String
+where
+    R: Records,
+    W: WidthFunc,
+{
+    let formatting = cfg.get_formatting(pos.into());
+    let alignment = *cfg.get_alignment_horizontal(pos.into());
+
+    let lines = (0..records.count_lines(pos))
+        .map(|i| {
+            let line = records.get_line(pos, i);
+            let line = if formatting.horizontal_trim {
+                string_trim(line).into_owned()
+            } else {
+                line.to_owned()
+            };
+
+            let line_width = width_fn.width(&line);
+            (line, line_width)
+        })
+        .collect::<Vec<_>>();
+
+    let text_width = lines.iter().map(|(_, w)| *w).max().unwrap_or(0);
+
+    let mut buf = String::new();
+    for (i, (line, line_width)) in lines.iter().enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        let block_width = if formatting.allow_lines_alignement {
+            *line_width
+        } else {
+            text_width
+        };
+
+        let (left, right) = calculate_indent(alignment, block_width, width);
+        let right = right + block_width - line_width;
+
+        buf.push_str(color.get_prefix());
+        buf.push_str(&" ".repeat(left));
+        buf.push_str(&restore_color(line, color));
+        buf.push_str(&" ".repeat(right));
+        buf.push_str(color.get_suffix());
+    }
+
+    buf
+}
+
+/// Sets a color of a padding of a cell, keeping the sides which are already colored,
+/// and turns off a trim as a text is already aligned.
+fn set_cell_color(cfg: &mut GridConfig, pos: Position, color: &AnsiColor<'static>) {
+    let mut padding = cfg.get_padding_color(pos.into()).clone();
+    for side in [
+        &mut padding.top,
+        &mut padding.bottom,
+        &mut padding.left,
+        &mut padding.right,
+    ] {
+        if side.get_prefix().is_empty() {
+            *side = color.clone();
+        }
+    }
+
+    let padding = to_static_padding_color(&padding);
+
+    let mut formatting = *cfg.get_formatting(pos.into());
+    formatting.horizontal_trim = false;
+
+    cfg.set_padding_color(pos.into(), padding);
+    cfg.set_formatting(pos.into(), formatting);
+}
+
+/// Puts a stripe color back after a text resets a background.
+fn restore_color(line: &str, color: &AnsiColor<'_>) -> String {
+    let mut line = line.to_owned();
+    for reset in ["\u{1b}[0m", "\u{1b}[49m"] {
+        if line.contains(reset) {
+            line = line.replace(reset, &format!("{}{}", reset, color.get_prefix()));
+        }
+    }
+
+    line
+}
+
+fn calculate_indent(
+    alignment: AlignmentHorizontal,
+    text_width: usize,
+    available: usize,
+) -> (usize, usize) {
+    let diff = available.saturating_sub(text_width);
+    match alignment {
+        AlignmentHorizontal::Left => (0, diff),
+        AlignmentHorizontal::Right => (diff, 0),
+        AlignmentHorizontal::Center => {
+            let left = diff / 2;
+            (left, diff - left)
+        }
+    }
+}
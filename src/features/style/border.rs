@@ -12,7 +12,13 @@ use crate::{CellOption, Table};
 ///     .with(Modify::new(Rows::single(0)).with(Border::default().top('x')));
 /// ```
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Border {
+    #[cfg_attr(feature = "serde", serde(with = "option_border"))]
     border: Option<papergrid::Border<char>>,
 }
 
@@ -145,3 +151,53 @@ impl From<Border> for Option<papergrid::Border> {
         val.border
     }
 }
+
+/// (De)serializes an optional border,
+/// where `None` is a border which is removed from a cell.
+#[cfg(feature = "serde")]
+mod option_border {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct BorderRepr(#[serde(with = "BorderDef")] papergrid::Border<char>);
+
+    pub(super) fn serialize<S>(
+        border: &Option<papergrid::Border<char>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        border.clone().map(BorderRepr).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<Option<papergrid::Border<char>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<BorderRepr>::deserialize(deserializer).map(|border| border.map(|b| b.0))
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "papergrid::Border")]
+    struct BorderDef {
+        #[serde(default)]
+        top: Option<char>,
+        #[serde(default)]
+        bottom: Option<char>,
+        #[serde(default)]
+        left: Option<char>,
+        #[serde(default)]
+        right: Option<char>,
+        #[serde(default)]
+        left_top_corner: Option<char>,
+        #[serde(default)]
+        left_bottom_corner: Option<char>,
+        #[serde(default)]
+        right_top_corner: Option<char>,
+        #[serde(default)]
+        right_bottom_corner: Option<char>,
+    }
+}
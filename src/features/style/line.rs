@@ -1,5 +1,6 @@
 /// The structure represent a vertical or horizontal line.
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     pub(crate) main: Option<char>,
     pub(crate) intersection: Option<char>,
@@ -14,7 +14,9 @@ use crate::{
 ///
 /// It can be useful in order to not have a generics and be able to use it as a variable more conveniently.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawStyle {
+    #[cfg_attr(feature = "serde", serde(with = "BordersDef"))]
     borders: Borders<char>,
     horizontals: HashMap<usize, Line>,
     verticals: HashMap<usize, Line>,
@@ -359,3 +361,24 @@ where
         }
     }
 }
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Borders")]
+struct BordersDef {
+    top: Option<char>,
+    top_left: Option<char>,
+    top_right: Option<char>,
+    top_intersection: Option<char>,
+    bottom: Option<char>,
+    bottom_left: Option<char>,
+    bottom_right: Option<char>,
+    bottom_intersection: Option<char>,
+    horizontal: Option<char>,
+    horizontal_left: Option<char>,
+    horizontal_right: Option<char>,
+    vertical: Option<char>,
+    vertical_left: Option<char>,
+    vertical_right: Option<char>,
+    intersection: Option<char>,
+}
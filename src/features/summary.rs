@@ -0,0 +1,418 @@
+//! This module contains a [`Summary`] structure which appends a row computed from columns of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Columns, summary::Summary, Style, Table};
+//!
+//! let data = [("apple", 3, 1.5), ("melon", 1, 4.25), ("lemon", 12, 0.75)];
+//!
+//! let table = Table::new(data)
+//!     .with(Summary::new().sum(Columns::new(1..)).label("Total"))
+//!     .with(Style::psql())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str  | i32 | f64  \n",
+//!         "-------+-----+------\n",
+//!         " apple | 3   | 1.5  \n",
+//!         " melon | 1   | 4.25 \n",
+//!         " lemon | 12  | 0.75 \n",
+//!         " Total | 16  | 6.50 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::collections::BTreeMap;
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{builder::NumberFormat, object::Object, Table, TableOption};
+
+/// Summary appends a row to a [`Table`] whose cells are computed from cells of its columns,
+/// like a sum or a count.
+///
+/// Values are taken from cells of given columns except header rows (see [`Table::count_header_rows`]).
+/// Cells which are not numbers are skipped, while numbers may have a thousands separator,
+/// like `1,234.50` or `1_000`.
+///
+/// A result is formatted the same way as numbers of its column:
+/// it gets the biggest number of digits after a decimal point found in a column
+/// and the same thousands separator.
+/// An average of integers is printed with 2 digits after a decimal point.
+///
+/// If a few aggregations are set for the same column the last one is used.
+/// A [`Summary::label`] is put into a first cell of the row which doesn't have an aggregation.
+///
+/// As the row is added to records, widths and styles of a table take it into account.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{object::Columns, summary::Summary, Style, Table};
+///
+/// let data = [("ann", "1,200", "x"), ("bob", "800.6", ""), ("tom", "n/a", "y")];
+///
+/// let table = Table::new(data)
+///     .with(
+///         Summary::new()
+///             .avg(Columns::single(1))
+///             .count(Columns::single(2))
+///             .label("Avg"),
+///     )
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str | &str    | &str \n",
+///         "------+---------+------\n",
+///         " ann  | 1,200   | x    \n",
+///         " bob  | 800.6   |      \n",
+///         " tom  | n/a     | y    \n",
+///         " Avg  | 1,000.3 | 2    ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+#[derive(Debug, Clone)]
+pub struct Summary<A = ()> {
+    label: Option<String>,
+    aggregations: A,
+}
+
+impl Summary {
+    /// Creates a [`Summary`] without any aggregations.
+    pub fn new() -> Self {
+        Self {
+            label: None,
+            aggregations: (),
+        }
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Summary<A> {
+    /// Sets a text which is put into a first cell without an aggregation.
+    pub fn label<S>(mut self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Adds a sum of numbers of given columns.
+    pub fn sum<O>(self, columns: O) -> Summary<AggregationList<A, Aggregate<O>>> {
+        self.aggregate(columns, AggregateKind::Sum)
+    }
+
+    /// Adds a count of not empty cells of given columns.
+    pub fn count<O>(self, columns: O) -> Summary<AggregationList<A, Aggregate<O>>> {
+        self.aggregate(columns, AggregateKind::Count)
+    }
+
+    /// Adds an average of numbers of given columns.
+    pub fn avg<O>(self, columns: O) -> Summary<AggregationList<A, Aggregate<O>>> {
+        self.aggregate(columns, AggregateKind::Avg)
+    }
+
+    /// Adds a minimum of numbers of given columns.
+    pub fn min<O>(self, columns: O) -> Summary<AggregationList<A, Aggregate<O>>> {
+        self.aggregate(columns, AggregateKind::Min)
+    }
+
+    /// Adds a maximum of numbers of given columns.
+    pub fn max<O>(self, columns: O) -> Summary<AggregationList<A, Aggregate<O>>> {
+        self.aggregate(columns, AggregateKind::Max)
+    }
+
+    fn aggregate<O>(
+        self,
+        target: O,
+        kind: AggregateKind,
+    ) -> Summary<AggregationList<A, Aggregate<O>>> {
+        Summary {
+            label: self.label,
+            aggregations: AggregationList {
+                a1: self.aggregations,
+                a2: Aggregate { target, kind },
+            },
+        }
+    }
+}
+
+impl<A, R> TableOption<R> for Summary<A>
+where
+    A: Aggregation,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_columns = table.get_records().count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        let mut cells = vec![None; count_columns];
+        self.aggregations.aggregate(table, &mut cells);
+
+        if let Some(label) = &self.label {
+            if let Some(cell) = cells.iter_mut().find(|cell| cell.is_none()) {
+                *cell = Some(label.clone());
+            }
+        }
+
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+        let records = table.get_records_mut();
+        records.push_row();
+
+        let row = records.count_rows() - 1;
+        for (col, text) in cells.into_iter().enumerate() {
+            records.set((row, col), text.unwrap_or_default(), &ctrl);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Aggregation computes cells of a [`Summary`] row.
+///
+/// It's implemented for [`Aggregate`] and lists of them.
+pub trait Aggregation {
+    /// Sets cells of a row by values of a table columns.
+    fn aggregate<R>(&self, table: &Table<R>, row: &mut [Option<String>])
+    where
+        R: Records;
+}
+
+impl Aggregation for () {
+    fn aggregate<R>(&self, _: &Table<R>, _: &mut [Option<String>])
+    where
+        R: Records,
+    {
+    }
+}
+
+/// A list of [`Aggregation`]s of a [`Summary`], which are applied in order.
+#[derive(Debug, Clone)]
+pub struct AggregationList<A1, A2> {
+    a1: A1,
+    a2: A2,
+}
+
+impl<A1, A2> Aggregation for AggregationList<A1, A2>
+where
+    A1: Aggregation,
+    A2: Aggregation,
+{
+    fn aggregate<R>(&self, table: &Table<R>, row: &mut [Option<String>])
+    where
+        R: Records,
+    {
+        self.a1.aggregate(table, row);
+        self.a2.aggregate(table, row);
+    }
+}
+
+/// An aggregation of cells of an [`Object`] of a [`Summary`].
+///
+/// Cells are aggregated by columns, so each column gets its own result.
+#[derive(Debug, Clone)]
+pub struct Aggregate<O> {
+    target: O,
+    kind: AggregateKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AggregateKind {
+    Sum,
+    Count,
+    Avg,
+    Min,
+    Max,
+}
+
+impl<O> Aggregation for Aggregate<O>
+where
+    O: Object,
+{
+    fn aggregate<R>(&self, table: &Table<R>, row: &mut [Option<String>])
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+        let first_row = table.count_header_rows();
+
+        let mut columns: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+        for entity in self.target.cells(table) {
+            for (r, c) in entity.iter(count_rows, count_cols) {
+                if c >= row.len() {
+                    continue;
+                }
+
+                // a header cell is not aggregated, but its column still gets a result.
+                let texts = columns.entry(c).or_default();
+                if r >= first_row {
+                    texts.push(table.get_records().get_text((r, c)));
+                }
+            }
+        }
+
+        for (col, texts) in columns {
+            row[col] = Some(self.kind.compute(&texts));
+        }
+    }
+}
+
+impl AggregateKind {
+    fn compute(self, texts: &[&str]) -> String {
+        if let AggregateKind::Count = self {
+            let count = texts.iter().filter(|text| !text.trim().is_empty()).count();
+            return count.to_string();
+        }
+
+        let numbers = texts
+            .iter()
+            .filter_map(|text| parse_number(text))
+            .collect::<Vec<_>>();
+
+        let precision = numbers.iter().map(|n| n.precision).max().unwrap_or(0);
+        let separator = numbers.iter().find_map(|n| n.separator);
+        let integers = numbers
+            .iter()
+            .map(|n| n.integer)
+            .collect::<Option<Vec<_>>>();
+
+        let is_integers = integers.is_some();
+        let text = match (self, integers) {
+            (AggregateKind::Sum, Some(values)) => match checked_sum(&values) {
+                Some(sum) => sum.to_string(),
+                // a sum which doesn't fit into an integer is computed as a float.
+                None => match self.compute_float(&numbers, true, precision) {
+                    Some(text) => text,
+                    None => return String::new(),
+                },
+            },
+            (AggregateKind::Min, Some(values)) => match values.iter().min() {
+                Some(value) => value.to_string(),
+                None => return String::new(),
+            },
+            (AggregateKind::Max, Some(values)) => match values.iter().max() {
+                Some(value) => value.to_string(),
+                None => return String::new(),
+            },
+            _ => match self.compute_float(&numbers, is_integers, precision) {
+                Some(text) => text,
+                None => return String::new(),
+            },
+        };
+
+        match separator {
+            Some(separator) => NumberFormat::new()
+                .thousands_separator(separator)
+                .format_text(&text)
+                .unwrap_or(text),
+            None => text,
+        }
+    }
+
+    fn compute_float(
+        self,
+        numbers: &[Number],
+        is_integers: bool,
+        precision: usize,
+    ) -> Option<String> {
+        let values = numbers.iter().map(|n| n.value);
+        let value = match self {
+            AggregateKind::Sum => values.sum(),
+            AggregateKind::Min => values.fold(f64::INFINITY, f64::min),
+            AggregateKind::Max => values.fold(f64::NEG_INFINITY, f64::max),
+            _ if numbers.is_empty() => return None,
+            _ => values.sum::<f64>() / numbers.len() as f64,
+        };
+
+        if !value.is_finite() {
+            return None;
+        }
+
+        let precision = match self {
+            AggregateKind::Avg if is_integers => 2,
+            _ => precision,
+        };
+
+        // a shortest representation is used for integers,
+        // as digits beyond a float precision would be made up.
+        match precision {
+            0 => Some(value.round().to_string()),
+            _ => Some(format!("{:.*}", precision, value)),
+        }
+    }
+}
+
+fn checked_sum(values: &[i128]) -> Option<i128> {
+    values
+        .iter()
+        .try_fold(0_i128, |sum, &value| sum.checked_add(value))
+}
+
+struct Number {
+    value: f64,
+    integer: Option<i128>,
+    precision: usize,
+    separator: Option<char>,
+}
+
+fn parse_number(text: &str) -> Option<Number> {
+    let text = text.trim();
+    let unsigned = text.strip_prefix('-').unwrap_or(text);
+    let int_end = unsigned.find('.').unwrap_or(unsigned.len());
+    let (int, fraction) = unsigned.split_at(int_end);
+
+    if !int.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let separator = int.chars().find(|c| !c.is_ascii_digit());
+    if let Some(separator) = separator {
+        let is_separator = matches!(separator, ',' | '_' | ' ' | '\'');
+        let is_single = int.chars().all(|c| c.is_ascii_digit() || c == separator);
+        if !is_separator || !is_single {
+            return None;
+        }
+    }
+
+    let cleaned = match separator {
+        Some(separator) => text.replace(separator, ""),
+        None => text.to_owned(),
+    };
+
+    let value = cleaned
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())?;
+    let integer = cleaned.parse::<i128>().ok();
+    let precision = fraction.len().saturating_sub(1);
+
+    Some(Number {
+        value,
+        integer,
+        precision,
+        separator,
+    })
+}
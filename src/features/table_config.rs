@@ -0,0 +1,208 @@
+//! This module contains a [`TableConfig`] structure which sets a look of a [`Table`] and its columns at once.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Alignment, ColumnConfig, Padding, Style, Table, TableConfig, Theme};
+//!
+//! let config = TableConfig::new()
+//!     .theme(Theme::new().style(Style::modern()))
+//!     .column(
+//!         ColumnConfig::new(1)
+//!             .alignment(Alignment::right())
+//!             .padding(Padding::new(3, 1, 0, 0)),
+//!     );
+//!
+//! let table = Table::new([("Hello", 1), ("World", 22)])
+//!     .with(config)
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "┌───────┬───────┐\n",
+//!         "│ &str  │   i32 │\n",
+//!         "├───────┼───────┤\n",
+//!         "│ Hello │     1 │\n",
+//!         "├───────┼───────┤\n",
+//!         "│ World │    22 │\n",
+//!         "└───────┴───────┘",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    Entity,
+};
+
+use crate::{Alignment, CellOption, Padding, Table, TableOption, Theme};
+
+#[cfg(feature = "color")]
+use papergrid::{width::CfgWidthFunction, AnsiColor};
+
+#[cfg(feature = "color")]
+use crate::{color::Color, features::colorization::colorize_lines};
+
+/// TableConfig is a [`Theme`] of a whole table together with settings of particular columns.
+///
+/// It's meant to keep a look of a table outside of a program,
+/// so when a `serde` feature is on it can be serialized and deserialized,
+/// for example from a TOML configuration file.
+/// Any missing setting is taken from a default one.
+///
+/// ```json
+/// {
+///     "theme": {
+///         "padding": { "left": 1, "right": 1, "top": 0, "bottom": 0 },
+///         "alignment": "Left"
+///     },
+///     "columns": [
+///         { "index": 1, "alignment": { "Horizontal": "Right" } }
+///     ]
+/// }
+/// ```
+///
+/// Settings of a column override the ones of a theme.
+/// Alignment and color of a column are not applied to a header,
+/// which is styled by a theme.
+/// Columns which are not present in a table are ignored.
+///
+/// [`Theme`]: crate::Theme
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct TableConfig {
+    theme: Theme,
+    columns: Vec<ColumnConfig>,
+}
+
+impl TableConfig {
+    /// Creates a config with a default [`Theme`] and no column settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a theme of a table.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Adds settings of a column.
+    pub fn column(mut self, column: ColumnConfig) -> Self {
+        self.columns.push(column);
+        self
+    }
+}
+
+impl<R> TableOption<R> for TableConfig
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        // column colors are put before the ones of a theme, so they take precedence.
+        #[cfg(feature = "color")]
+        for column in &self.columns {
+            column.change_color(table);
+        }
+
+        self.theme.change(table);
+
+        for column in &mut self.columns {
+            column.change(table);
+        }
+    }
+}
+
+/// ColumnConfig is a set of settings of a single column of a [`TableConfig`].
+///
+/// Settings which are not set are left as a [`Theme`] sets them.
+///
+/// [`Theme`]: crate::Theme
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnConfig {
+    index: usize,
+    alignment: Option<Alignment>,
+    padding: Option<Padding>,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
+}
+
+impl ColumnConfig {
+    /// Creates settings of a column with a given index.
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            alignment: None,
+            padding: None,
+            #[cfg(feature = "color")]
+            color: None,
+        }
+    }
+
+    /// Sets an alignment of a column cells except a header.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets a padding of a column cells.
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Sets a color of a column text except a header.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn change<R>(&mut self, table: &mut Table<R>)
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+        if self.index >= count_cols {
+            return;
+        }
+
+        if let Some(padding) = &mut self.padding {
+            padding.change_cell(table, Entity::Column(self.index));
+        }
+
+        if let Some(alignment) = &mut self.alignment {
+            for row in table.count_header_rows()..count_rows {
+                alignment.change_cell(table, Entity::Cell(row, self.index));
+            }
+        }
+    }
+
+    #[cfg(feature = "color")]
+    fn change_color<R>(&self, table: &mut Table<R>)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        let (count_rows, count_cols) = table.shape();
+        let color = match &self.color {
+            Some(color) if self.index < count_cols => AnsiColor::from(color.clone()),
+            _ => return,
+        };
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        for row in table.count_header_rows()..count_rows {
+            let pos = (row, self.index);
+            let text = colorize_lines(table.get_records().get_text(pos), &color);
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+    }
+}
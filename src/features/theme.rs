@@ -0,0 +1,298 @@
+//! This module contains a [`Theme`] structure which sets a complete look of a [`Table`] at once.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{alignment::AlignmentHorizontal, Style, Table, Theme};
+//!
+//! let theme = Theme::new()
+//!     .style(Style::modern())
+//!     .padding(2, 2, 0, 0)
+//!     .alignment(AlignmentHorizontal::Right);
+//!
+//! let table = Table::new([("Hello", 1), ("World", 2)])
+//!     .with(theme)
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "┌─────────┬───────┐\n\
+//!      │  &str   │  i32  │\n\
+//!      ├─────────┼───────┤\n\
+//!      │  Hello  │    1  │\n\
+//!      ├─────────┼───────┤\n\
+//!      │  World  │    2  │\n\
+//!      └─────────┴───────┘"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    AlignmentHorizontal, Entity,
+};
+
+#[cfg(feature = "color")]
+use papergrid::{width::CfgWidthFunction, AnsiColor};
+
+use crate::{
+    style::{HorizontalLine, RawStyle, Style},
+    Padding, Table, TableOption,
+};
+
+#[cfg(feature = "color")]
+use crate::{color::Color, features::colorization::colorize_lines};
+
+#[cfg(feature = "serde")]
+use crate::features::alignment::AlignmentHorizontalDef;
+
+/// Theme is a bundle of settings which define a look of a [`Table`]:
+/// borders, padding, alignment of a header and of the rest of cells,
+/// and when a `color` feature is on a color of borders, a header and alternating rows.
+///
+/// A header is a first row of a table built with one, like [`Table::new`] does.
+/// Rows are colored starting from the first row after a header.
+///
+/// Applying a theme overrides the according settings made before,
+/// so it's better to apply it first and tweak the result after.
+///
+/// When a `serde` feature is on a theme can be serialized and deserialized,
+/// so the same look can be shared by different programs.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Theme};
+///
+/// let table = Table::new([("Hello", 1), ("World", 2)])
+///     .with(Theme::modern_dark())
+///     .to_string();
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table,
+///     "┌───────┬─────┐\n\
+///      │ &str  │ i32 │\n\
+///      ├───────┼─────┤\n\
+///      │ Hello │ 1   │\n\
+///      │ World │ 2   │\n\
+///      └───────┴─────┘"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::new`]: crate::Table::new
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Theme {
+    style: RawStyle,
+    padding: Indents,
+    #[cfg_attr(feature = "serde", serde(with = "AlignmentHorizontalDef"))]
+    alignment: AlignmentHorizontal,
+    #[cfg_attr(feature = "serde", serde(with = "AlignmentHorizontalDef"))]
+    header_alignment: AlignmentHorizontal,
+    #[cfg(feature = "color")]
+    border_color: Option<Color>,
+    #[cfg(feature = "color")]
+    header_color: Option<Color>,
+    #[cfg(feature = "color")]
+    row_colors: Vec<Color>,
+}
+
+impl Theme {
+    /// Creates a theme which looks like a default [`Table`].
+    ///
+    /// [`Table`]: crate::Table
+    pub fn new() -> Self {
+        Self {
+            style: RawStyle::from(Style::ascii()),
+            padding: Indents {
+                left: 1,
+                right: 1,
+                top: 0,
+                bottom: 0,
+            },
+            alignment: AlignmentHorizontal::Left,
+            header_alignment: AlignmentHorizontal::Left,
+            #[cfg(feature = "color")]
+            border_color: None,
+            #[cfg(feature = "color")]
+            header_color: None,
+            #[cfg(feature = "color")]
+            row_colors: Vec::new(),
+        }
+    }
+
+    /// Creates a theme with [`Style::modern`] borders, with a line only after a header,
+    /// with a bold header, dimmed borders and alternating rows.
+    ///
+    /// Colors are set only when a `color` feature is on.
+    pub fn modern_dark() -> Self {
+        let style = Style::modern()
+            .off_horizontal()
+            .horizontals([HorizontalLine::new(1, Style::modern().get_horizontal())]);
+
+        Self {
+            style: style.into(),
+            #[cfg(feature = "color")]
+            border_color: Some(Color::FG_BRIGHT_BLACK),
+            #[cfg(feature = "color")]
+            header_color: Some(Color::new("\u{1b}[1m".into(), "\u{1b}[22m".into())),
+            #[cfg(feature = "color")]
+            row_colors: vec![Color::FG_WHITE, Color::FG_BRIGHT_WHITE],
+            ..Self::new()
+        }
+    }
+
+    /// Sets borders of a theme.
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Into<RawStyle>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets a padding of all cells.
+    pub fn padding(mut self, left: usize, right: usize, top: usize, bottom: usize) -> Self {
+        self.padding = Indents {
+            left,
+            right,
+            top,
+            bottom,
+        };
+        self
+    }
+
+    /// Sets a horizontal alignment of all cells except a header.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets a horizontal alignment of a header.
+    pub fn header_alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.header_alignment = alignment;
+        self
+    }
+
+    /// Sets a color of borders.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Sets a color of a header text.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn header_color(mut self, color: Color) -> Self {
+        self.header_color = Some(color);
+        self
+    }
+
+    /// Sets colors of rows text which are used one after another.
+    ///
+    /// An empty list leaves rows uncolored.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn row_colors<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator<Item = Color>,
+    {
+        self.row_colors = colors.into_iter().collect();
+        self
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> TableOption<R> for Theme
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let padding = self.padding;
+        Padding::new(padding.left, padding.right, padding.top, padding.bottom).change(table);
+        self.style.change(table);
+
+        let count_header_rows = table.count_header_rows();
+
+        let cfg = table.get_config_mut();
+        cfg.set_alignment_horizontal(Entity::Global, self.alignment);
+        for row in 0..count_header_rows {
+            cfg.set_alignment_horizontal(Entity::Row(row), self.header_alignment);
+        }
+
+        #[cfg(feature = "color")]
+        self.change_colors(table, count_header_rows);
+    }
+}
+
+#[cfg(feature = "color")]
+impl Theme {
+    fn change_colors<R>(&self, table: &mut Table<R>, count_header_rows: usize)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        if let Some(color) = &self.border_color {
+            let color = AnsiColor::from(color.clone());
+            table.get_config_mut().set_border_color_global(color);
+        }
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+
+        if let Some(color) = &self.header_color {
+            let color = AnsiColor::from(color.clone());
+            for row in 0..count_header_rows {
+                colorize_row(table, row, count_cols, &color, &width_fn);
+            }
+        }
+
+        if !self.row_colors.is_empty() {
+            for (i, row) in (count_header_rows..count_rows).enumerate() {
+                let color = &self.row_colors[i % self.row_colors.len()];
+                let color = AnsiColor::from(color.clone());
+                colorize_row(table, row, count_cols, &color, &width_fn);
+            }
+        }
+
+        table.destroy_width_cache();
+    }
+}
+
+#[cfg(feature = "color")]
+fn colorize_row<R>(
+    table: &mut Table<R>,
+    row: usize,
+    count_cols: usize,
+    color: &AnsiColor<'_>,
+    width_fn: &CfgWidthFunction,
+) where
+    R: Records + RecordsMut<String>,
+{
+    for col in 0..count_cols {
+        let text = colorize_lines(table.get_records().get_text((row, col)), color);
+        table.get_records_mut().set((row, col), text, width_fn);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Indents {
+    left: usize,
+    right: usize,
+    top: usize,
+    bottom: usize,
+}
@@ -0,0 +1,193 @@
+//! This module contains [`LayoutFallback`] which defines what happens when a [`Table`] can't be made as narrow as requested.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+    AlignmentHorizontal, Entity,
+};
+
+use crate::{display::ExpandedDisplay, Table};
+
+use super::{get_min_widths, get_total_width, measure_table_widths};
+
+/// LayoutFallback defines what is done when a [`Table`] can't be decreased to a given width.
+///
+/// It happens when a width is less than a sum of minimal widths of columns,
+/// which are widths of their padding, borders between them
+/// and minimums set by [`Wrap::min_per_column`].
+///
+/// A fallback can be set by [`Wrap::fallback`], [`Truncate::fallback`] and [`FitTerminal::fallback`].
+///
+/// ```
+/// use tabled::{width::LayoutFallback, Style, Table, Width};
+///
+/// let data = [("Hello", "World", "!")];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(
+///         Width::wrap(16)
+///             .min_per_column([Some(5), Some(5), Some(5)])
+///             .fallback(LayoutFallback::HideColumns),
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str  | &str  \n",
+///         "-------+-------\n",
+///         " Hello | World ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Wrap::min_per_column`]: crate::width::Wrap::min_per_column
+/// [`Wrap::fallback`]: crate::width::Wrap::fallback
+/// [`Truncate::fallback`]: crate::width::Truncate::fallback
+/// [`FitTerminal::fallback`]: crate::width::FitTerminal::fallback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutFallback {
+    /// Columns are decreased to their minimal widths,
+    /// so a table ends up wider than a given width.
+    ///
+    /// It's what is done by default.
+    Overflow,
+    /// The last columns are removed until the rest fit a given width.
+    ///
+    /// The first column is never removed.
+    HideColumns,
+    /// A table is turned into a single cell with an expanded layout of [`ExpandedDisplay`],
+    /// where each record is shown as a list of `header | value` rows
+    /// following a `-[ RECORD N ]-` row.
+    ///
+    /// A table without a header uses indexes of columns as names.
+    /// Texts are escaped the same way [`ExpandedDisplay`] does it.
+    /// A table without records is left as it is.
+    ///
+    /// [`ExpandedDisplay`]: crate::display::ExpandedDisplay
+    Expanded,
+}
+
+/// Fallback is applied when a [`Table`] can't be decreased to a given width.
+///
+/// It's implemented for [`LayoutFallback`], which requires records to be [`Resizable`],
+/// and for `()` which leaves a table overflowing and is used by default,
+/// so options without a fallback work with any records.
+///
+/// [`Table`]: crate::Table
+/// [`Resizable`]: papergrid::records::Resizable
+pub trait Fallback<R> {
+    /// Applies a fallback if a table can't be decreased to a given width.
+    ///
+    /// It returns `true` if a table was turned into a different layout,
+    /// so settings of its columns are no longer relevant.
+    fn apply(&self, table: &mut Table<R>, width: usize, min_widths: &[Option<usize>]) -> bool;
+}
+
+impl<R> Fallback<R> for () {
+    fn apply(&self, _: &mut Table<R>, _: usize, _: &[Option<usize>]) -> bool {
+        false
+    }
+}
+
+impl<R> Fallback<R> for LayoutFallback
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn apply(&self, table: &mut Table<R>, width: usize, min_widths: &[Option<usize>]) -> bool {
+        apply_fallback(table, *self, width, min_widths)
+    }
+}
+
+fn apply_fallback<R>(
+    table: &mut Table<R>,
+    fallback: LayoutFallback,
+    width: usize,
+    columns_min_widths: &[Option<usize>],
+) -> bool
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    match fallback {
+        LayoutFallback::Overflow => false,
+        LayoutFallback::HideColumns => {
+            while table.count_columns() > 1
+                && get_min_total_width(table, columns_min_widths) > width
+            {
+                let last = table.count_columns() - 1;
+                table.get_records_mut().remove_column(last);
+            }
+
+            false
+        }
+        LayoutFallback::Expanded => {
+            let has_records = table.count_rows() > table.count_header_rows();
+            if !has_records || get_min_total_width(table, columns_min_widths) <= width {
+                return false;
+            }
+
+            expand(table);
+
+            true
+        }
+    }
+}
+
+fn get_min_total_width<R>(table: &mut Table<R>, columns_min_widths: &[Option<usize>]) -> usize
+where
+    R: Records,
+{
+    let (widths, _) = measure_table_widths(table);
+    let min_widths = get_min_widths(table, &widths, columns_min_widths);
+
+    get_total_width(table.get_config(), &min_widths)
+}
+
+fn expand<R>(table: &mut Table<R>)
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    let text = ExpandedDisplay::from_table(table).to_string();
+
+    let shape = table.shape();
+    let spans = table
+        .get_config()
+        .iter_column_spans(shape)
+        .chain(table.get_config().iter_row_spans(shape))
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+    let cfg = table.get_config_mut();
+    for pos in spans {
+        cfg.set_column_span(pos, 1);
+        cfg.set_row_span(pos, 1);
+    }
+
+    // lines set between rows would end up below the only row,
+    // so only a frame of the table is kept.
+    let borders = cfg.get_borders().clone();
+    cfg.clear_theme();
+    cfg.set_borders(borders);
+
+    // lines of the layout are aligned by themselves.
+    cfg.set_alignment_horizontal(Entity::Cell(0, 0), AlignmentHorizontal::Left);
+
+    let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+    let records = table.get_records_mut();
+    while records.count_columns() > 1 {
+        records.remove_column(records.count_columns() - 1);
+    }
+
+    while records.count_rows() > 1 {
+        records.remove_row(records.count_rows() - 1);
+    }
+
+    records.set((0, 0), text, &ctrl);
+
+    table.set_header_rows(0);
+    table.destroy_width_cache();
+    table.destroy_height_cache();
+}
@@ -0,0 +1,164 @@
+//! This module contains [`FitTerminal`] structure, used to fit a [`Table`] into a current terminal width.
+//!
+//! [`Table`]: crate::Table
+
+use std::marker::PhantomData;
+
+use papergrid::records::{Records, RecordsMut};
+
+use crate::{
+    peaker::{Peaker, PriorityNone},
+    Table, TableOption,
+};
+
+use super::{Fallback, MinWidth, Truncate, Wrap};
+
+/// FitTerminal sets a total width of a [`Table`] to a width of a current terminal.
+///
+/// A bigger table is decreased by [`Wrap`] (or [`Truncate`]),
+/// a smaller one is increased by [`MinWidth`].
+///
+/// If a terminal width can't be detected (for example when output is redirected to a file)
+/// a table is left untouched.
+///
+/// It's an environment dependent option,
+/// so it panics if a `strict` feature is turned on.
+///
+/// ## Example
+///
+/// ```no_run
+/// use tabled::{peaker::PriorityMax, Table, Width};
+///
+/// let table = Table::new(&["Hello World!"])
+///     .with(Width::fit_terminal().keep_words().priority::<PriorityMax>())
+///     .to_string();
+/// ```
+///
+/// [`Table`]: crate::Table
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal_size")))]
+#[derive(Debug, Clone)]
+pub struct FitTerminal<P = PriorityNone, F = ()> {
+    truncate: bool,
+    keep_words: bool,
+    increase: bool,
+    fallback: F,
+    _priority: PhantomData<P>,
+}
+
+impl FitTerminal {
+    /// Creates a [`FitTerminal`] object which wraps a content and increases a table if necessary.
+    pub fn new() -> Self {
+        Self {
+            truncate: false,
+            keep_words: false,
+            increase: true,
+            fallback: (),
+            _priority: PhantomData,
+        }
+    }
+}
+
+impl Default for FitTerminal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P, F> FitTerminal<P, F> {
+    /// Priority defines the logic by which a width will be changed.
+    ///
+    /// - [`PriorityNone`] which changes the columns one after another.
+    /// - [`PriorityMax`] changes the biggest columns first.
+    /// - [`PriorityMin`] changes the lowest columns first.
+    ///
+    /// [`PriorityMax`]: crate::peaker::PriorityMax
+    /// [`PriorityMin`]: crate::peaker::PriorityMin
+    pub fn priority<PP: Peaker>(self) -> FitTerminal<PP, F> {
+        FitTerminal {
+            truncate: self.truncate,
+            keep_words: self.keep_words,
+            increase: self.increase,
+            fallback: self.fallback,
+            _priority: PhantomData,
+        }
+    }
+
+    /// Use [`Truncate`] instead of [`Wrap`] to decrease a table.
+    pub fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
+    }
+
+    /// Set the keep words option of [`Wrap`].
+    ///
+    /// It has no affect in case [`FitTerminal::truncate`] is used.
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+
+    /// Turns off an increase of a table which is narrower than a terminal.
+    pub fn no_increase(mut self) -> Self {
+        self.increase = false;
+        self
+    }
+
+    /// Sets a [`Fallback`], like a [`LayoutFallback`], which is used when a table can't be decreased to a terminal width.
+    ///
+    /// By default a table overflows a terminal.
+    /// Notice that a [`LayoutFallback`] can be used only with [`Resizable`] records.
+    ///
+    /// [`LayoutFallback`]: crate::width::LayoutFallback
+    /// [`Resizable`]: papergrid::records::Resizable
+    pub fn fallback<FF>(self, fallback: FF) -> FitTerminal<P, FF> {
+        FitTerminal {
+            truncate: self.truncate,
+            keep_words: self.keep_words,
+            increase: self.increase,
+            fallback,
+            _priority: PhantomData,
+        }
+    }
+}
+
+impl<P, F, R> TableOption<R> for FitTerminal<P, F>
+where
+    P: Peaker,
+    F: Fallback<R> + Clone,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        crate::strict::env_dependent("Width::fit_terminal");
+
+        let width = match terminal_width() {
+            Some(width) => width,
+            None => return,
+        };
+
+        if self.truncate {
+            Truncate::new(width)
+                .priority::<P>()
+                .fallback(self.fallback.clone())
+                .change(table);
+        } else if self.keep_words {
+            Wrap::new(width)
+                .priority::<P>()
+                .keep_words()
+                .fallback(self.fallback.clone())
+                .change(table);
+        } else {
+            Wrap::new(width)
+                .priority::<P>()
+                .fallback(self.fallback.clone())
+                .change(table);
+        }
+
+        if self.increase {
+            MinWidth::new(width).priority::<P>().change(table);
+        }
+    }
+}
+
+fn terminal_width() -> Option<usize> {
+    terminal_size::terminal_size().map(|(width, _)| width.0 as usize)
+}
@@ -0,0 +1,121 @@
+//! This module contains [`HeaderWrap`] structure, used to wrap either a header of a [`Table`] or the rest of it.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    Entity,
+};
+
+use crate::{measurement::Measurement, CellOption, Table, TableOption, Width};
+
+use super::Wrap;
+
+/// HeaderWrap wraps cells of a header to a given width, leaving the rest of cells untouched.
+/// Or the other way around it wraps data cells leaving a header untouched.
+///
+/// Headers are often long descriptive names while data is short,
+/// so wrapping a header makes a table narrower without touching a data.
+///
+/// A header is a first row of a table built with one, like [`Table::new`] does,
+/// or a few first rows if it has groups of columns (see [`Table::count_header_rows`]).
+/// If a table has no header [`HeaderWrap::new`] does nothing,
+/// and [`HeaderWrap::data`] wraps all rows.
+///
+/// Be aware that it doesn't consider padding, just like [`Wrap`].
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{width::HeaderWrap, Table};
+///
+/// let data = [(1, "on"), (2, "off")];
+///
+/// let table = Table::new(data)
+///     .with(HeaderWrap::new(3))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-----+-----+\n\
+///      | i32 | &st |\n\
+///      |     | r   |\n\
+///      +-----+-----+\n\
+///      | 1   | on  |\n\
+///      +-----+-----+\n\
+///      | 2   | off |\n\
+///      +-----+-----+"
+/// );
+/// ```
+///
+/// [`Table::new`]: crate::Table::new
+/// [`Table::count_header_rows`]: crate::Table::count_header_rows
+#[derive(Debug, Clone)]
+pub struct HeaderWrap<W = usize> {
+    width: W,
+    keep_words: bool,
+    wrap_data: bool,
+}
+
+impl<W> HeaderWrap<W>
+where
+    W: Measurement<Width>,
+{
+    /// Creates a [`HeaderWrap`] which wraps a header.
+    pub fn new(width: W) -> Self {
+        Self {
+            width,
+            keep_words: false,
+            wrap_data: false,
+        }
+    }
+
+    /// Creates a [`HeaderWrap`] which wraps all rows but a header.
+    pub fn data(width: W) -> Self {
+        Self {
+            width,
+            keep_words: false,
+            wrap_data: true,
+        }
+    }
+}
+
+impl<W> HeaderWrap<W> {
+    /// Set the keep words option.
+    ///
+    /// See [`Wrap::keep_words`].
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+}
+
+impl<W, R> TableOption<R> for HeaderWrap<W>
+where
+    W: Measurement<Width> + Clone,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.is_empty() {
+            return;
+        }
+
+        let mut wrap = Wrap::new(self.width.clone());
+        if self.keep_words {
+            wrap = wrap.keep_words();
+        }
+
+        let count_header_rows = table.count_header_rows();
+        let rows = if self.wrap_data {
+            count_header_rows..table.count_rows()
+        } else {
+            0..count_header_rows
+        };
+
+        for row in rows {
+            wrap.change_cell(table, Entity::Row(row));
+        }
+
+        table.destroy_height_cache();
+    }
+}
@@ -0,0 +1,164 @@
+//! This module contains a [`WidthLayout`] which keeps widths of columns of a [`Table`] between its changes.
+//!
+//! [`Table`]: crate::Table
+
+use std::cmp::Ordering;
+
+use papergrid::{records::Records, width::CfgWidthFunction, Entity, GridConfig, Position};
+
+use super::count_borders;
+
+/// WidthLayout keeps measured widths of columns,
+/// so after a change only the columns which were affected by it are measured again.
+///
+/// It keeps widths of cells which don't span over several columns.
+/// Spanned cells are applied on top of them each time widths are requested,
+/// as there are usually just a few of them.
+///
+/// It measures cells via [`Records::get_width`],
+/// so it works with any kind of records whether they cache a width of a cell or not.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WidthLayout {
+    shape: (usize, usize),
+    columns: Vec<Option<usize>>,
+}
+
+impl WidthLayout {
+    /// Marks all columns as changed.
+    pub(crate) fn invalidate(&mut self) {
+        self.columns.clear();
+    }
+
+    /// Marks columns which an entity is located in as changed.
+    pub(crate) fn invalidate_entity(&mut self, entity: Entity) {
+        match entity {
+            Entity::Cell(_, col) | Entity::Column(col) => {
+                if let Some(width) = self.columns.get_mut(col) {
+                    *width = None;
+                }
+            }
+            Entity::Global | Entity::Row(_) => self.invalidate(),
+        }
+    }
+
+    /// Measures changed columns, remembers them, and returns widths of all columns.
+    pub(crate) fn update<R>(&mut self, records: R, cfg: &GridConfig) -> Vec<usize>
+    where
+        R: Records,
+    {
+        let shape = (records.count_rows(), records.count_columns());
+        if !self.is_shape_of(shape) {
+            self.shape = shape;
+            self.columns = vec![None; shape.1];
+        }
+
+        let ctrl = CfgWidthFunction::from_cfg(cfg);
+        for (col, width) in self.columns.iter_mut().enumerate() {
+            if width.is_none() {
+                *width = Some(column_width(&records, cfg, &ctrl, col, shape));
+            }
+        }
+
+        self.measure(records, cfg)
+    }
+
+    /// Returns widths of all columns, measuring changed columns without remembering them.
+    pub(crate) fn measure<R>(&self, records: R, cfg: &GridConfig) -> Vec<usize>
+    where
+        R: Records,
+    {
+        let mut widths = self.measure_span_free(&records, cfg);
+
+        let ctrl = CfgWidthFunction::from_cfg(cfg);
+        adjust_spans(&records, cfg, &ctrl, &mut widths);
+
+        widths
+    }
+
+    /// Returns widths of all columns as if there were no spanned cells,
+    /// measuring changed columns without remembering them.
+    pub(crate) fn measure_span_free<R>(&self, records: R, cfg: &GridConfig) -> Vec<usize>
+    where
+        R: Records,
+    {
+        let shape = (records.count_rows(), records.count_columns());
+        let is_valid = self.is_shape_of(shape);
+
+        let ctrl = CfgWidthFunction::from_cfg(cfg);
+        (0..shape.1)
+            .map(|col| match self.columns.get(col) {
+                Some(Some(width)) if is_valid => *width,
+                _ => column_width(&records, cfg, &ctrl, col, shape),
+            })
+            .collect()
+    }
+
+    fn is_shape_of(&self, shape: (usize, usize)) -> bool {
+        self.shape == shape && self.columns.len() == shape.1
+    }
+}
+
+fn column_width<R>(
+    records: &R,
+    cfg: &GridConfig,
+    ctrl: &CfgWidthFunction,
+    col: usize,
+    shape: (usize, usize),
+) -> usize
+where
+    R: Records,
+{
+    (0..shape.0)
+        .filter(|&row| is_simple_cell(cfg, (row, col), shape))
+        .map(|row| cell_width(records, cfg, ctrl, (row, col)))
+        .max()
+        .unwrap_or(0)
+}
+
+fn adjust_spans<R>(records: &R, cfg: &GridConfig, ctrl: &CfgWidthFunction, widths: &mut [usize])
+where
+    R: Records,
+{
+    if !cfg.has_column_spans() {
+        return;
+    }
+
+    let shape = (records.count_rows(), records.count_columns());
+
+    // smaller spans are applied first,
+    // the same way as it's done when a table is printed.
+    let mut spans = cfg.iter_column_spans(shape).collect::<Vec<_>>();
+    spans.sort_unstable_by(|a, b| match a.1.cmp(&b.1) {
+        Ordering::Equal => a.0.cmp(&b.0),
+        o => o,
+    });
+
+    for ((row, col), span) in spans {
+        let end = col + span;
+        let width = cell_width(records, cfg, ctrl, (row, col));
+        let range_width =
+            widths[col..end].iter().sum::<usize>() + count_borders(cfg, col, end, widths.len());
+        if range_width >= width {
+            continue;
+        }
+
+        let rest = width - range_width;
+        let one = rest / span;
+        widths[col] += rest - one * (span - 1);
+        for width in &mut widths[col + 1..end] {
+            *width += one;
+        }
+    }
+}
+
+fn is_simple_cell(cfg: &GridConfig, pos: Position, shape: (usize, usize)) -> bool {
+    cfg.is_cell_visible(pos, shape) && matches!(cfg.get_column_span(pos, shape), None | Some(1))
+}
+
+fn cell_width<R>(records: &R, cfg: &GridConfig, ctrl: &CfgWidthFunction, pos: Position) -> usize
+where
+    R: Records,
+{
+    let padding = cfg.get_padding(pos.into());
+    records.get_width(pos, ctrl) + padding.left.size + padding.right.size
+}
@@ -14,7 +14,7 @@ use crate::{
     CellOption, Table, TableOption, Width,
 };
 
-use super::get_table_widths_with_total;
+use super::measure_table_widths;
 
 /// [`MinWidth`] changes a content in case if it's length is lower then the boundary.
 ///
@@ -55,6 +55,9 @@ use super::get_table_widths_with_total;
 pub struct MinWidth<W = usize, P = PriorityNone> {
     width: W,
     fill: char,
+    weights: Vec<usize>,
+    #[cfg(feature = "color")]
+    colored_fill: bool,
     _priority: PhantomData<P>,
 }
 
@@ -67,6 +70,9 @@ where
         Self {
             width,
             fill: ' ',
+            weights: Vec::new(),
+            #[cfg(feature = "color")]
+            colored_fill: false,
             _priority: PhantomData::default(),
         }
     }
@@ -94,9 +100,75 @@ impl<W, P> MinWidth<W, P> {
         MinWidth {
             fill: self.fill,
             width: self.width,
+            weights: self.weights,
+            #[cfg(feature = "color")]
+            colored_fill: self.colored_fill,
             _priority: PhantomData::default(),
         }
     }
+
+    /// Sets weights of columns by which an extra space is shared between them when is done for the whole table.
+    ///
+    /// A column gets a part of the space proportional to its weight,
+    /// so a column with a weight of 3 gets 3 times more than a column with a weight of 1.
+    /// Columns which aren't given a weight, or given 0, are left as they are.
+    ///
+    /// A priority is used if all weights are 0.
+    ///
+    /// ```
+    /// use tabled::{Style, Table, Width};
+    ///
+    /// let data = [("a", "description", "b")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::markdown())
+    ///     .with(Width::increase(40).weights([1, 3, 1]))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "| &str   | &str               | &str   |\n",
+    ///         "|--------|--------------------|--------|\n",
+    ///         "| a      | description        | b      |",
+    ///     )
+    /// );
+    /// ```
+    pub fn weights<I>(mut self, weights: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.weights = weights.into_iter().collect();
+        self
+    }
+
+    /// Colors the fill of a line with a background color of its last character,
+    /// so a colored cell doesn't have uncolored stripes.
+    ///
+    /// Used only if changing cells.
+    ///
+    /// ```
+    /// use tabled::{object::Segment, Modify, Table, Width};
+    ///
+    /// let table = Table::new(["\u{1b}[44mblue\u{1b}[49m"])
+    ///     .with(Modify::new(Segment::all()).with(Width::increase(6).colored_fill()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+\n\
+    ///      | &str   |\n\
+    ///      +--------+\n\
+    ///      | \u{1b}[44mblue\u{1b}[49m\u{1b}[44m  \u{1b}[49m |\n\
+    ///      +--------+"
+    /// );
+    /// ```
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn colored_fill(mut self) -> Self {
+        self.colored_fill = true;
+        self
+    }
 }
 
 impl<W, R> CellOption<R> for MinWidth<W>
@@ -117,12 +189,18 @@ where
             }
 
             let content = records.get_text(pos);
-            let content = increase_width(content, width, self.fill);
-            let records = table.get_records_mut();
+            let content = increase_width(
+                content,
+                width,
+                self.fill,
+                #[cfg(feature = "color")]
+                self.colored_fill,
+            );
+            let records = table.get_records_mut_raw();
             records.set(pos, content, &width_ctrl);
         }
 
-        table.destroy_width_cache();
+        table.destroy_width_cache_of(entity);
     }
 }
 
@@ -138,12 +216,17 @@ where
         }
 
         let width = self.width.measure(table.get_records(), table.get_config());
-        let (widths, total_width) =
-            get_table_widths_with_total(table.get_records(), table.get_config());
+        let (widths, total_width) = measure_table_widths(table);
         if total_width >= width {
             return;
         }
 
+        if self.weights.iter().any(|&weight| weight > 0) {
+            let list = get_weighted_increase_list(widths, width - total_width, &self.weights);
+            table.cache_width(list);
+            return;
+        }
+
         increase_total_width(table, widths, total_width, width, P::create());
     }
 }
@@ -170,7 +253,7 @@ fn increase_width(s: &str, width: usize, fill_with: char) -> String {
 }
 
 #[cfg(feature = "color")]
-fn increase_width(s: &str, width: usize, fill_with: char) -> String {
+fn increase_width(s: &str, width: usize, fill_with: char, colored: bool) -> String {
     use papergrid::util::string_width;
 
     ansi_str::AnsiStr::ansi_split(s, "\n")
@@ -179,7 +262,23 @@ fn increase_width(s: &str, width: usize, fill_with: char) -> String {
             if length < width {
                 let mut line = line.into_owned();
                 let remain = width - length;
-                line.extend(std::iter::repeat(fill_with).take(remain));
+                let fill = std::iter::repeat(fill_with)
+                    .take(remain)
+                    .collect::<String>();
+                let bg = match colored {
+                    true => crate::color::get_last_background(&line),
+                    false => None,
+                };
+
+                match bg {
+                    Some(bg) => {
+                        line.push_str(bg.get_prefix());
+                        line.push_str(&fill);
+                        line.push_str(bg.get_suffix());
+                    }
+                    None => line.push_str(&fill),
+                }
+
                 std::borrow::Cow::Owned(line)
             } else {
                 line
@@ -224,3 +323,37 @@ where
 
     widths
 }
+
+/// Shares a space between columns proportionally to their weights.
+///
+/// A space which is left after an even share is given one by one
+/// to columns with the biggest remainders of a share.
+fn get_weighted_increase_list(
+    mut widths: Vec<usize>,
+    space: usize,
+    weights: &[usize],
+) -> Vec<usize> {
+    let weights = &weights[..std::cmp::min(weights.len(), widths.len())];
+    let total_weight = weights.iter().sum::<usize>();
+
+    let mut rest = space;
+    for (width, &weight) in widths.iter_mut().zip(weights) {
+        let share = space * weight / total_weight;
+        *width += share;
+        rest -= share;
+    }
+
+    let mut remainders = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, &weight)| weight > 0)
+        .map(|(col, &weight)| (space * weight % total_weight, col))
+        .collect::<Vec<_>>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    for (_, col) in remainders.into_iter().take(rest) {
+        widths[col] += 1;
+    }
+
+    widths
+}
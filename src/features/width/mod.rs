@@ -3,6 +3,10 @@
 //! - [`Truncate`] cuts a cell content to limit width.
 //! - [`Wrap`] split the content via new lines in order to fit max width.
 //! - [`Justify`] sets columns width to the same value.
+//! - [`HeaderWrap`] wraps only a header, or only the rest of a table.
+//! - [`FitTerminal`] sets a table width to a terminal width (`terminal_size` feature).
+//!
+//! A [`LayoutFallback`] defines what happens when a table can't be made as narrow as requested.
 //!
 //! To set a a table width, a combination of [`Width::truncate`] or [`Width::wrap`] and [`Width::increase`] can be used.
 //!
@@ -32,25 +36,40 @@
 //! );
 //! ```
 
+mod fallback;
+#[cfg(feature = "terminal_size")]
+mod fit_terminal;
+mod header_wrap;
 mod justify;
+mod layout;
 mod min_width;
+mod segment;
 mod truncate;
 mod width_list;
 mod wrap;
 
-use crate::measurement::Measurement;
+use crate::{measurement::Measurement, Table};
+
+#[cfg(feature = "terminal_size")]
+pub use self::fit_terminal::FitTerminal;
 
 pub use self::{
+    fallback::{Fallback, LayoutFallback},
+    header_wrap::HeaderWrap,
     justify::Justify,
     min_width::MinWidth,
-    truncate::{SuffixLimit, Truncate},
+    truncate::{CutSide, Measure, SuffixLimit, Truncate},
     width_list::WidthList,
     wrap::Wrap,
 };
 
-use papergrid::{records::Records, width::WidthEstimator, Estimate, GridConfig};
+use papergrid::{
+    records::{empty::EmptyRecords, Records},
+    width::WidthEstimator,
+    Estimate, GridConfig,
+};
 
-pub(crate) use wrap::wrap_text;
+pub(crate) use self::{layout::WidthLayout, wrap::wrap_text};
 
 /// Width allows you to set a min and max width of an object on a [`Table`]
 /// using different strategies.
@@ -139,12 +158,22 @@ impl Width {
         Justify::new(width)
     }
 
+    /// Returns a [`FitTerminal`] structure.
+    #[cfg(feature = "terminal_size")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "terminal_size")))]
+    pub fn fit_terminal() -> FitTerminal {
+        FitTerminal::new()
+    }
+
     /// Create [`WidthList`] to set a table width to a constant list of column widths.
     ///
     /// Notice if you provide a list with `.len()` smaller than `Table::count_columns` then it will have no affect.
     ///
     /// Also notice that you must provide values bigger than or equal to a real content width, otherwise it may panic.
     ///
+    /// A list of [`Percent`]s splits a total width of a table among columns instead,
+    /// wrapping (or truncating) a content which doesn't fit.
+    ///
     /// # Example
     ///
     /// ```
@@ -172,9 +201,34 @@ impl Width {
     ///      +--------------------+----------+------------+"
     /// )
     /// ```
-    pub fn list<I>(rows: I) -> WidthList
+    ///
+    /// ```
+    /// use tabled::{measurement::Percent, Table, Width};
+    ///
+    /// let data = [("Hello World", "1"), ("Hi", "2")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Width::list([Percent(70), Percent(30)]).total(20))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------------+-----+\n\
+    ///      | &str       | &st |\n\
+    ///      |            | r   |\n\
+    ///      +------------+-----+\n\
+    ///      | Hello Worl | 1   |\n\
+    ///      | d          |     |\n\
+    ///      +------------+-----+\n\
+    ///      | Hi         | 2   |\n\
+    ///      +------------+-----+"
+    /// )
+    /// ```
+    ///
+    /// [`Percent`]: crate::measurement::Percent
+    pub fn list<I, W>(rows: I) -> WidthList<W>
     where
-        I: IntoIterator<Item = usize>,
+        I: IntoIterator<Item = W>,
     {
         WidthList::new(rows.into_iter().collect())
     }
@@ -212,6 +266,55 @@ where
         + cfg.get_margin().right.size
 }
 
+/// Returns widths of columns of a table and its total width,
+/// measuring only columns which were changed since the last time.
+pub(crate) fn measure_table_widths<R>(table: &mut Table<R>) -> (Vec<usize>, usize)
+where
+    R: Records,
+{
+    let widths = table.measure_widths();
+    let total_width = get_total_width(table.get_config(), &widths);
+
+    (widths, total_width)
+}
+
+/// Returns a total width of a table with given widths of columns.
+pub(crate) fn get_total_width(cfg: &GridConfig, widths: &[usize]) -> usize {
+    widths.iter().sum::<usize>()
+        + cfg.count_vertical(widths.len())
+        + cfg.get_margin().left.size
+        + cfg.get_margin().right.size
+}
+
+/// Returns widths which columns can't be decreased below,
+/// which are widths of their padding and the given minimums of their content.
+///
+/// A minimum can't make a column wider than it is.
+pub(crate) fn get_min_widths<R>(
+    table: &Table<R>,
+    widths: &[usize],
+    columns_min_widths: &[Option<usize>],
+) -> Vec<usize>
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = table.shape();
+    let mut min_widths = get_table_widths(
+        EmptyRecords::new(count_rows, count_cols),
+        table.get_config(),
+    );
+
+    let columns_min_widths = columns_min_widths.iter().zip(widths);
+    for (min, (&column_min, &width)) in min_widths.iter_mut().zip(columns_min_widths) {
+        if let Some(column_min) = column_min {
+            let column_min = std::cmp::min(*min + column_min, width);
+            *min = std::cmp::max(*min, column_min);
+        }
+    }
+
+    min_widths
+}
+
 pub(crate) fn get_table_widths_with_total<R>(records: R, cfg: &GridConfig) -> (Vec<usize>, usize)
 where
     R: Records,
@@ -0,0 +1,86 @@
+//! This module contains functions which split a text into units a width of a text is changed by.
+//!
+//! By default a unit is a `char`.
+//! With a `grapheme` feature a unit is an extended grapheme cluster,
+//! so a cluster like an emoji with a skin tone modifier is never split apart.
+
+use std::borrow::Cow;
+
+const REPLACEMENT: char = '\u{FFFD}';
+
+/// Returns units of a text together with their widths.
+#[cfg(not(feature = "grapheme"))]
+pub(crate) fn units(text: &str) -> impl DoubleEndedIterator<Item = (&str, usize)> {
+    text.char_indices().map(move |(i, c)| {
+        let width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        (&text[i..i + c.len_utf8()], width)
+    })
+}
+
+/// Returns units of a text together with their widths.
+#[cfg(feature = "grapheme")]
+pub(crate) fn units(text: &str) -> impl DoubleEndedIterator<Item = (&str, usize)> {
+    unicode_segmentation::UnicodeSegmentation::graphemes(text, true)
+        .map(|grapheme| (grapheme, unicode_width::UnicodeWidthStr::width(grapheme)))
+}
+
+/// Splits a text at a given width.
+///
+/// It returns a number of bytes before the position,
+/// and in case the position is inside of a wide unit,
+/// a width of the unit which is left before the position and a size of the unit in bytes.
+///
+/// The text must not have ANSI sequences.
+pub(crate) fn split_at_width(text: &str, pos: usize) -> (usize, usize, usize) {
+    let mut length = 0;
+    let mut i = 0;
+    for (unit, width) in units(text) {
+        if i == pos {
+            break;
+        }
+
+        // We cut the units which take more then 1 symbol to display,
+        // in order to archive the necessary width.
+        if i + width > pos {
+            return (length, pos - i, unit.len());
+        }
+
+        i += width;
+        length += unit.len();
+    }
+
+    (length, 0, 0)
+}
+
+/// Cuts a text to a given width.
+///
+/// If a unit is split, its rest is filled by replacement characters.
+pub(crate) fn cut_str(text: &str, width: usize) -> Cow<'_, str> {
+    #[cfg(feature = "color")]
+    {
+        let stripped = ansi_str::AnsiStr::ansi_strip(text);
+        let (length, count_unknowns, _) = split_at_width(&stripped, width);
+
+        let buf = ansi_str::AnsiStr::ansi_cut(text, ..length);
+        fill_unknowns(buf, count_unknowns)
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        let (length, count_unknowns, _) = split_at_width(text, width);
+        fill_unknowns(Cow::Borrowed(&text[..length]), count_unknowns)
+    }
+}
+
+fn fill_unknowns(text: Cow<'_, str>, count: usize) -> Cow<'_, str> {
+    if count == 0 {
+        return text;
+    }
+
+    let mut buf = text.into_owned();
+    for _ in 0..count {
+        buf.push(REPLACEMENT);
+    }
+
+    Cow::Owned(buf)
+}
@@ -3,15 +3,16 @@
 use std::{borrow::Cow, marker::PhantomData};
 
 use papergrid::{
-    records::{empty::EmptyRecords, Records, RecordsMut},
-    util::cut_str,
+    records::{Records, RecordsMut},
+    util::{get_lines, string_width},
     width::{CfgWidthFunction, WidthFunc},
-    Entity, GridConfig,
+    AlignmentHorizontal, Entity, GridConfig,
 };
 
 use crate::{
+    features::inner_table::resize_inner_table,
     peaker::{Peaker, PriorityNone},
-    width::{count_borders, get_table_widths, get_table_widths_with_total, Measurement},
+    width::{count_borders, get_min_widths, measure_table_widths, segment, Fallback, Measurement},
     CellOption, Table, TableOption, Width,
 };
 
@@ -34,9 +35,12 @@ use crate::{
 ///
 /// [`Padding`]: crate::Padding
 #[derive(Debug)]
-pub struct Truncate<'a, W = usize, P = PriorityNone> {
+pub struct Truncate<'a, W = usize, P = PriorityNone, F = ()> {
     width: W,
     suffix: Option<TruncateSuffix<'a>>,
+    measure: Measure,
+    side: CutSide,
+    fallback: F,
     _priority: PhantomData<P>,
 }
 
@@ -46,6 +50,8 @@ struct TruncateSuffix<'a> {
     limit: SuffixLimit,
     #[cfg(feature = "color")]
     try_color: bool,
+    #[cfg(feature = "color")]
+    color: Option<papergrid::AnsiColor<'static>>,
 }
 
 impl Default for TruncateSuffix<'_> {
@@ -55,6 +61,8 @@ impl Default for TruncateSuffix<'_> {
             limit: SuffixLimit::Cut,
             #[cfg(feature = "color")]
             try_color: false,
+            #[cfg(feature = "color")]
+            color: None,
         }
     }
 }
@@ -70,6 +78,153 @@ pub enum SuffixLimit {
     Replace(char),
 }
 
+/// A unit in which [`Truncate`] measures a content of a cell.
+///
+/// Whatever the unit is a content is never cut in the middle of a UTF-8 code point,
+/// and when a `color` feature is on in the middle of an ANSI sequence.
+/// ANSI sequences are not counted then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    /// A number of bytes of a UTF-8 encoded text.
+    Bytes,
+    /// A number of `char`s.
+    Chars,
+    /// A number of columns a text takes on a terminal.
+    ///
+    /// It's the default.
+    Columns,
+}
+
+/// A side from which [`Truncate`] cuts a content of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CutSide {
+    /// The beginning of a text is cut off, and a suffix is put before the rest.
+    Left,
+    /// The end of a text is cut off, and a suffix is put after the rest.
+    ///
+    /// It's the default.
+    Right,
+    /// A side is chosen by an alignment of a cell,
+    /// right aligned cells are cut from the left and all others from the right.
+    Auto,
+}
+
+impl CutSide {
+    fn resolve(self, alignment: AlignmentHorizontal) -> CutSide {
+        match (self, alignment) {
+            (CutSide::Auto, AlignmentHorizontal::Right) => CutSide::Left,
+            (CutSide::Auto, _) => CutSide::Right,
+            (side, _) => side,
+        }
+    }
+}
+
+impl Measure {
+    fn size(self, text: &str) -> usize {
+        match self {
+            Measure::Columns => string_width(text),
+            Measure::Bytes | Measure::Chars => self.count(&strip_ansi(text)),
+        }
+    }
+
+    fn size_multiline(self, text: &str) -> usize {
+        get_lines(text)
+            .map(|line| self.size(&line))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn count(self, text: &str) -> usize {
+        match self {
+            Measure::Bytes => text.len(),
+            Measure::Chars => text.chars().count(),
+            Measure::Columns => string_width(text),
+        }
+    }
+
+    fn count_char(self, c: char) -> usize {
+        match self {
+            Measure::Bytes => c.len_utf8(),
+            Measure::Chars => 1,
+            Measure::Columns => unicode_width::UnicodeWidthChar::width(c).unwrap_or(0),
+        }
+    }
+
+    fn count_unit(self, unit: &str, width: usize) -> usize {
+        match self {
+            Measure::Columns => width,
+            Measure::Bytes | Measure::Chars => self.count(unit),
+        }
+    }
+
+    fn cut(self, text: &str, limit: usize) -> Cow<'_, str> {
+        if let Measure::Columns = self {
+            return segment::cut_str(text, limit);
+        }
+
+        let stripped = strip_ansi(text);
+
+        let mut size = 0;
+        let mut length = 0;
+        for (unit, width) in segment::units(&stripped) {
+            size += self.count_unit(unit, width);
+            if size > limit {
+                break;
+            }
+
+            length += unit.len();
+        }
+
+        #[cfg(feature = "color")]
+        {
+            ansi_str::AnsiStr::ansi_cut(text, ..length)
+        }
+
+        #[cfg(not(feature = "color"))]
+        {
+            Cow::Borrowed(&text[..length])
+        }
+    }
+
+    /// Cuts a beginning of a text so the rest fits a given limit.
+    fn cut_left(self, text: &str, limit: usize) -> Cow<'_, str> {
+        let stripped = strip_ansi(text);
+
+        let mut size = 0;
+        let mut start = stripped.len();
+        for (unit, width) in segment::units(&stripped).rev() {
+            size += self.count_unit(unit, width);
+            if size > limit {
+                break;
+            }
+
+            start -= unit.len();
+        }
+
+        #[cfg(feature = "color")]
+        {
+            ansi_str::AnsiStr::ansi_cut(text, start..)
+        }
+
+        #[cfg(not(feature = "color"))]
+        {
+            Cow::Borrowed(&text[start..])
+        }
+    }
+}
+
+fn strip_ansi(text: &str) -> Cow<'_, str> {
+    #[cfg(feature = "color")]
+    {
+        ansi_str::AnsiStr::ansi_strip(text)
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        Cow::Borrowed(text)
+    }
+}
+
 impl<W> Truncate<'static, W>
 where
     W: Measurement<Width>,
@@ -79,12 +234,15 @@ where
         Self {
             width,
             suffix: None,
+            measure: Measure::Columns,
+            side: CutSide::Right,
+            fallback: (),
             _priority: PhantomData::default(),
         }
     }
 }
 
-impl<'a, W, P> Truncate<'a, W, P> {
+impl<'a, W, P, F> Truncate<'a, W, P, F> {
     /// Sets a suffix which will be appended to a resultant string.
     ///
     /// The suffix is used in 3 circamstances:
@@ -93,44 +251,83 @@ impl<'a, W, P> Truncate<'a, W, P> {
     ///     2. If suffix is bigger than the original string.
     ///        We cut the suffix to fit in the width by default.
     ///        But you can peak the behaviour by using [`Truncate::suffix_limit`]
-    pub fn suffix<S: Into<Cow<'a, str>>>(self, suffix: S) -> Truncate<'a, W, P> {
+    pub fn suffix<S: Into<Cow<'a, str>>>(self, suffix: S) -> Truncate<'a, W, P, F> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.text = suffix.into();
 
         Truncate {
             width: self.width,
             suffix: Some(suff),
+            measure: self.measure,
+            side: self.side,
+            fallback: self.fallback,
             _priority: PhantomData::default(),
         }
     }
 
     /// Sets a suffix limit, which is used when the suffix is too big to be used.
-    pub fn suffix_limit(self, limit: SuffixLimit) -> Truncate<'a, W, P> {
+    pub fn suffix_limit(self, limit: SuffixLimit) -> Truncate<'a, W, P, F> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.limit = limit;
 
         Truncate {
             width: self.width,
             suffix: Some(suff),
+            measure: self.measure,
+            side: self.side,
+            fallback: self.fallback,
             _priority: PhantomData::default(),
         }
     }
 
     #[cfg(feature = "color")]
     /// Sets a optional logic to try to colorize a suffix.
-    pub fn suffix_try_color(self, color: bool) -> Truncate<'a, W, P> {
+    ///
+    /// The suffix inherits a color of the end of a truncated text.
+    pub fn suffix_try_color(self, color: bool) -> Truncate<'a, W, P, F> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.try_color = color;
 
         Truncate {
             width: self.width,
             suffix: Some(suff),
+            measure: self.measure,
+            side: self.side,
+            fallback: self.fallback,
             _priority: PhantomData::default(),
         }
     }
+
+    #[cfg(feature = "color")]
+    /// Sets a color of a suffix.
+    ///
+    /// It takes precedence over [`Truncate::suffix_try_color`].
+    ///
+    /// ```
+    /// use tabled::{color::Color, object::Segment, Modify, Table, Width};
+    ///
+    /// let table = Table::new(["\u{1b}[31mHello World\u{1b}[39m"])
+    ///     .with(Modify::new(Segment::all()).with(Width::truncate(5).suffix("..").suffix_color(Color::FG_BLUE)))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[31mHel\u{1b}[39m\u{1b}[34m..\u{1b}[39m"));
+    /// ```
+    pub fn suffix_color(self, color: crate::color::Color) -> Truncate<'a, W, P, F> {
+        let mut suff = self.suffix.unwrap_or_default();
+        suff.color = Some(color.into());
+
+        Truncate {
+            width: self.width,
+            suffix: Some(suff),
+            measure: self.measure,
+            side: self.side,
+            fallback: self.fallback,
+            _priority: PhantomData,
+        }
+    }
 }
 
-impl<'a, W, P> Truncate<'a, W, P> {
+impl<'a, W, P, F> Truncate<'a, W, P, F> {
     /// Priority defines the logic by which a truncate will be applied when is done for the whole table.
     ///
     /// - [`PriorityNone`] which cuts the columns one after another.
@@ -139,16 +336,95 @@ impl<'a, W, P> Truncate<'a, W, P> {
     ///
     /// [`PriorityMax`]: crate::peaker::PriorityMax
     /// [`PriorityMin`]: crate::peaker::PriorityMin
-    pub fn priority<PP: Peaker>(self) -> Truncate<'a, W, PP> {
+    pub fn priority<PP: Peaker>(self) -> Truncate<'a, W, PP, F> {
+        Truncate {
+            width: self.width,
+            suffix: self.suffix,
+            measure: self.measure,
+            side: self.side,
+            fallback: self.fallback,
+            _priority: PhantomData::default(),
+        }
+    }
+
+    /// Sets a unit in which a width of a cell and a suffix are measured.
+    ///
+    /// By default it's [`Measure::Columns`].
+    /// It's used only when a [`Truncate`] is applied to cells,
+    /// a width of a whole table is always measured in columns.
+    ///
+    /// ```
+    /// use tabled::{object::Segment, width::Measure, Modify, Table, Width};
+    ///
+    /// let table = Table::new(["Привет"])
+    ///     .with(Modify::new(Segment::all()).with(Width::truncate(5).by(Measure::Bytes)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+\n\
+    ///      | &str |\n\
+    ///      +------+\n\
+    ///      | Пр   |\n\
+    ///      +------+"
+    /// );
+    /// ```
+    pub fn by(mut self, measure: Measure) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Sets a side from which a content is cut.
+    ///
+    /// By default it's [`CutSide::Right`].
+    /// With [`CutSide::Auto`] right aligned cells are cut from the left,
+    /// which keeps an end of numbers or paths visible.
+    ///
+    /// ```
+    /// use tabled::{object::Segment, width::CutSide, Alignment, Modify, Style, Table, Width};
+    ///
+    /// let table = Table::new(["/home/user/file.txt"])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Segment::all()).with(Alignment::right()))
+    ///     .with(Modify::new(Segment::all()).with(Width::truncate(10).suffix("…").cut_side(CutSide::Auto)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "       &str \n",
+    ///         "------------\n",
+    ///         " …/file.txt ",
+    ///     )
+    /// );
+    /// ```
+    pub fn cut_side(mut self, side: CutSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets a [`Fallback`], like a [`LayoutFallback`], which is used when a table can't be truncated to a given width.
+    ///
+    /// By default a table overflows the width.
+    /// Notice that a [`LayoutFallback`] can be used only with [`Resizable`] records.
+    ///
+    /// It's not used when [`Truncate`] is applied to cells.
+    ///
+    /// [`LayoutFallback`]: crate::width::LayoutFallback
+    /// [`Resizable`]: papergrid::records::Resizable
+    pub fn fallback<FF>(self, fallback: FF) -> Truncate<'a, W, P, FF> {
         Truncate {
             width: self.width,
             suffix: self.suffix,
+            measure: self.measure,
+            side: self.side,
+            fallback,
             _priority: PhantomData::default(),
         }
     }
 }
 
-impl<W, P, R> CellOption<R> for Truncate<'_, W, P>
+impl<W, P, F, R> CellOption<R> for Truncate<'_, W, P, F>
 where
     W: Measurement<Width>,
     R: Records + RecordsMut<String>,
@@ -157,10 +433,15 @@ where
         let width_ctrl = CfgWidthFunction::from_cfg(table.get_config());
         let set_width = self.width.measure(table.get_records(), table.get_config());
 
+        let measure = self.measure;
+
         let mut width = set_width;
         let suffix = match self.suffix.as_ref() {
             Some(suffix) => {
-                let suffix_length = width_ctrl.width(&suffix.text);
+                let suffix_length = match measure {
+                    Measure::Columns => width_ctrl.width(&suffix.text),
+                    _ => measure.size(&suffix.text),
+                };
                 if width > suffix_length {
                     width -= suffix_length;
                     Cow::Borrowed(suffix.text.as_ref())
@@ -169,11 +450,16 @@ where
                         SuffixLimit::Ignore => Cow::Borrowed(""),
                         SuffixLimit::Cut => {
                             width = 0;
-                            cut_str(&suffix.text, set_width)
+                            measure.cut(&suffix.text, set_width)
                         }
                         SuffixLimit::Replace(c) => {
                             width = 0;
-                            Cow::Owned(std::iter::repeat(c).take(set_width).collect())
+                            let count = match measure {
+                                Measure::Columns => set_width,
+                                _ => set_width / measure.count_char(c),
+                            };
+
+                            Cow::Owned(std::iter::repeat(c).take(count).collect())
                         }
                     }
                 }
@@ -181,13 +467,35 @@ where
             None => Cow::Borrowed(""),
         };
 
+        #[cfg(feature = "color")]
+        let suffix = match self.suffix.as_ref().and_then(|s| s.color.as_ref()) {
+            Some(color) if !suffix.is_empty() => Cow::Owned(format!(
+                "{}{}{}",
+                color.get_prefix(),
+                suffix,
+                color.get_suffix()
+            )),
+            _ => suffix,
+        };
+
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
-            let cell_width = table.get_records().get_width(pos, &width_ctrl);
+            let cell_width = match measure {
+                Measure::Columns => table.get_records().get_width(pos, &width_ctrl),
+                _ => measure.size_multiline(table.get_records().get_text(pos)),
+            };
             if set_width >= cell_width {
                 continue;
             }
 
+            if measure == Measure::Columns {
+                let truncate = Truncate::new(set_width);
+                if let Some(text) = resize_inner_table(table, pos, set_width, truncate) {
+                    table.get_records_mut_raw().set(pos, text, &width_ctrl);
+                    continue;
+                }
+            }
+
             let suffix_color_try_keeping;
             #[cfg(not(feature = "color"))]
             {
@@ -195,30 +503,43 @@ where
             }
             #[cfg(feature = "color")]
             {
-                suffix_color_try_keeping = self.suffix.as_ref().map_or(false, |s| s.try_color);
+                suffix_color_try_keeping =
+                    matches!(&self.suffix, Some(s) if s.try_color && s.color.is_none());
             }
 
+            let alignment = *table.get_config().get_alignment_horizontal(pos.into());
+            let side = self.side.resolve(alignment);
+
             let records = table.get_records();
             let text = records.get_text(pos);
             // todo: Think about it.
             //       We could eliminate this allocation if we would be allowed to cut '\t' with unknown characters.
             //       Currently we don't do that.
             let text = papergrid::util::replace_tab(text, table.get_config().get_tab_width());
-            let text = truncate_text(&text, width, set_width, &suffix, suffix_color_try_keeping)
-                .into_owned();
-
-            let records = table.get_records_mut();
+            let text = truncate_text(
+                &text,
+                width,
+                set_width,
+                &suffix,
+                suffix_color_try_keeping,
+                measure,
+                side,
+            )
+            .into_owned();
+
+            let records = table.get_records_mut_raw();
             records.set(pos, text, &width_ctrl);
         }
 
-        table.destroy_width_cache();
+        table.destroy_width_cache_of(entity);
     }
 }
 
-impl<W, P, R> TableOption<R> for Truncate<'_, W, P>
+impl<W, P, F, R> TableOption<R> for Truncate<'_, W, P, F>
 where
     W: Measurement<Width>,
     P: Peaker,
+    F: Fallback<R>,
     R: Records + RecordsMut<String>,
 {
     fn change(&mut self, table: &mut Table<R>) {
@@ -227,8 +548,13 @@ where
         }
 
         let width = self.width.measure(table.get_records(), table.get_config());
-        let (widths, total_width) =
-            get_table_widths_with_total(table.get_records(), table.get_config());
+        let (_, total_width) = measure_table_widths(table);
+        if total_width <= width {
+            return;
+        }
+
+        self.fallback.apply(table, width, &[]);
+        let (widths, total_width) = measure_table_widths(table);
         if total_width <= width {
             return;
         }
@@ -238,61 +564,134 @@ where
             text: Cow::Borrowed(&s.text),
             #[cfg(feature = "color")]
             try_color: s.try_color,
+            #[cfg(feature = "color")]
+            color: s.color.clone(),
         });
 
-        truncate_total_width(table, widths, total_width, width, suffix, P::create());
+        truncate_total_width(
+            table,
+            widths,
+            total_width,
+            width,
+            suffix,
+            self.side,
+            P::create(),
+        );
     }
 }
 
 fn truncate_text<'a>(
+    content: &'a str,
+    width: usize,
+    original_width: usize,
+    suffix: &'a str,
+    suffix_color_try_keeping: bool,
+    measure: Measure,
+    side: CutSide,
+) -> Cow<'a, str> {
+    if !content.contains('\n') {
+        return truncate_line(
+            content,
+            width,
+            original_width,
+            suffix,
+            suffix_color_try_keeping,
+            measure,
+            side,
+        );
+    }
+
+    // each line is truncated on its own,
+    // so a suffix is added only to the lines which were actually cut.
+    let mut buf = String::with_capacity(content.len());
+    for (i, line) in get_lines(content).enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        if measure.size(&line) <= original_width {
+            buf.push_str(&line);
+            continue;
+        }
+
+        let line = truncate_line(
+            &line,
+            width,
+            original_width,
+            suffix,
+            suffix_color_try_keeping,
+            measure,
+            side,
+        );
+        buf.push_str(&line);
+    }
+
+    Cow::Owned(buf)
+}
+
+fn truncate_line<'a>(
     content: &'a str,
     width: usize,
     original_width: usize,
     suffix: &'a str,
     _suffix_color_try_keeping: bool,
+    measure: Measure,
+    side: CutSide,
 ) -> Cow<'a, str> {
     if width == 0 {
         if original_width == 0 {
             Cow::Borrowed("")
-        } else {
-            Cow::Borrowed(suffix)
-        }
-    } else {
-        let content = cut_str(content, width);
-
-        if suffix.is_empty() {
-            content
         } else {
             #[cfg(feature = "color")]
             {
-                if _suffix_color_try_keeping {
-                    if let Some(clr) = ansi_str::get_blocks(&content).last() {
+                // nothing of a text is left so the suffix gets a color of its beginning.
+                if _suffix_color_try_keeping && !suffix.is_empty() {
+                    if let Some(clr) = ansi_str::get_blocks(content).next() {
                         if clr.has_ansi() {
-                            Cow::Owned(format!("{}{}{}{}", content, clr.start(), suffix, clr.end()))
-                        } else {
-                            let mut content = content.into_owned();
-                            content.push_str(suffix);
-                            Cow::Owned(content)
+                            return Cow::Owned(format!("{}{}{}", clr.start(), suffix, clr.end()));
                         }
-                    } else {
-                        let mut content = content.into_owned();
-                        content.push_str(suffix);
-                        Cow::Owned(content)
                     }
-                } else {
-                    let mut content = content.into_owned();
-                    content.push_str(suffix);
-                    Cow::Owned(content)
                 }
             }
 
-            #[cfg(not(feature = "color"))]
-            {
-                let mut content = content.into_owned();
-                content.push_str(suffix);
-                Cow::Owned(content)
+            Cow::Borrowed(suffix)
+        }
+    } else {
+        let content = match side {
+            CutSide::Left => measure.cut_left(content, width),
+            _ => measure.cut(content, width),
+        };
+
+        if suffix.is_empty() {
+            return content;
+        }
+
+        #[cfg(feature = "color")]
+        {
+            if _suffix_color_try_keeping {
+                // the suffix gets a color of a text next to it.
+                let block = match side {
+                    CutSide::Left => ansi_str::get_blocks(&content).next(),
+                    _ => ansi_str::get_blocks(&content).last(),
+                };
+
+                if let Some(clr) = block {
+                    if clr.has_ansi() {
+                        let suffix = format!("{}{}{}", clr.start(), suffix, clr.end());
+                        return Cow::Owned(join_suffix(&content, &suffix, side));
+                    }
+                }
             }
         }
+
+        Cow::Owned(join_suffix(&content, suffix, side))
+    }
+}
+
+fn join_suffix(content: &str, suffix: &str, side: CutSide) -> String {
+    match side {
+        CutSide::Left => format!("{}{}", suffix, content),
+        _ => format!("{}{}", content, suffix),
     }
 }
 
@@ -330,13 +729,42 @@ pub(crate) fn get_decrease_cell_list(
     points
 }
 
+/// Decreases widths of columns until their total width is reduced from `total_width` to `width`.
+///
+/// At first only a width which spanned cells add to columns is taken away,
+/// so a column is made narrower than its own cells only when there's no other choice.
+/// `span_free_widths` are widths of columns measured without spanned cells.
 pub(crate) fn decrease_widths<F>(
+    widths: &mut [usize],
+    min_widths: &[usize],
+    span_free_widths: &[usize],
+    total_width: usize,
+    width: usize,
+    peeaker: F,
+) where
+    F: Peaker,
+{
+    let span_min_widths = widths
+        .iter()
+        .zip(min_widths)
+        .zip(span_free_widths)
+        .map(|((&width, &min_width), &span_free_width)| {
+            std::cmp::max(min_width, std::cmp::min(width, span_free_width))
+        })
+        .collect::<Vec<_>>();
+
+    let width = decrease_widths_to(widths, &span_min_widths, total_width, width, peeaker);
+    decrease_widths_to(widths, min_widths, total_width, width, F::create());
+}
+
+fn decrease_widths_to<F>(
     widths: &mut [usize],
     min_widths: &[usize],
     total_width: usize,
     mut width: usize,
     mut peeaker: F,
-) where
+) -> usize
+where
     F: Peaker,
 {
     let mut empty_list = 0;
@@ -368,6 +796,8 @@ pub(crate) fn decrease_widths<F>(
 
         width += 1;
     }
+
+    width
 }
 
 fn truncate_total_width<P, R>(
@@ -376,27 +806,36 @@ fn truncate_total_width<P, R>(
     widths_total: usize,
     width: usize,
     suffix: Option<TruncateSuffix<'_>>,
+    side: CutSide,
     priority: P,
 ) where
     P: Peaker,
     R: Records + RecordsMut<String>,
 {
     let (count_rows, count_cols) = table.shape();
-    let cfg = table.get_config();
-    let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_cols), cfg);
-
-    decrease_widths(&mut widths, &min_widths, widths_total, width, priority);
+    let min_widths = get_min_widths(table, &widths, &[]);
+    let span_free_widths = table.measure_span_free_widths();
+
+    decrease_widths(
+        &mut widths,
+        &min_widths,
+        &span_free_widths,
+        widths_total,
+        width,
+        priority,
+    );
 
+    let cfg = table.get_config();
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, (count_rows, count_cols));
 
     let mut truncate = Truncate::new(0);
     truncate.suffix = suffix;
+    truncate.side = side;
     for ((row, col), width) in points {
         truncate.width = width;
         truncate.change_cell(table, (row, col).into());
     }
 
-    table.destroy_width_cache();
     table.destroy_height_cache();
     table.cache_width(widths);
 }
@@ -1,32 +1,64 @@
-use std::iter::FromIterator;
+use std::{cmp::Reverse, iter::FromIterator};
 
-use papergrid::records::Records;
+use papergrid::{
+    records::{Records, RecordsMut},
+    Entity,
+};
 
-use crate::{Table, TableOption};
+use crate::{measurement::Percent, CellOption, Table, TableOption};
+
+use super::{Truncate, Wrap};
 
 /// A structure used to set [`Table`] width via a list of columns widths.
+///
+/// A list can consist either of constant widths (`usize`)
+/// or of [`Percent`]s of a total width of a table.
+///
+/// [`Percent`]: crate::measurement::Percent
 #[derive(Debug)]
-pub struct WidthList {
-    list: Vec<usize>,
+pub struct WidthList<W = usize> {
+    list: Vec<W>,
+    total: Option<usize>,
+    truncate: bool,
 }
 
-impl WidthList {
+impl<W> WidthList<W> {
     /// Creates a new object.
-    pub fn new(list: Vec<usize>) -> Self {
-        Self { list }
+    pub fn new(list: Vec<W>) -> Self {
+        Self {
+            list,
+            total: None,
+            truncate: false,
+        }
+    }
+}
+
+impl WidthList<Percent> {
+    /// Sets a total width of a table, including borders and a margin, which is split among columns.
+    ///
+    /// By default a current width of a table is used.
+    pub fn total(mut self, width: usize) -> Self {
+        self.total = Some(width);
+        self
+    }
+
+    /// Makes a content which doesn't fit a column to be truncated instead of being wrapped.
+    pub fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
     }
 }
 
-impl From<Vec<usize>> for WidthList {
-    fn from(list: Vec<usize>) -> Self {
+impl<W> From<Vec<W>> for WidthList<W> {
+    fn from(list: Vec<W>) -> Self {
         Self::new(list)
     }
 }
 
-impl FromIterator<usize> for WidthList {
+impl<W> FromIterator<W> for WidthList<W> {
     fn from_iter<T>(iter: T) -> Self
     where
-        T: IntoIterator<Item = usize>,
+        T: IntoIterator<Item = W>,
     {
         Self::new(iter.into_iter().collect())
     }
@@ -45,3 +77,62 @@ where
         table.destroy_height_cache();
     }
 }
+
+impl<R> TableOption<R> for WidthList<Percent>
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_columns = table.count_columns();
+        if self.list.len() < count_columns || table.is_empty() {
+            return;
+        }
+
+        let total = self.total.unwrap_or_else(|| table.total_width());
+        let cfg = table.get_config();
+        let margin = cfg.get_margin();
+        let borders = cfg.count_vertical(count_columns) + margin.left.size + margin.right.size;
+        let width = total.saturating_sub(borders);
+
+        let percents = self.list[..count_columns].iter().map(|p| p.0);
+        let mut widths = split_width(width, percents.collect());
+
+        for (col, width) in widths.iter_mut().enumerate() {
+            let padding = table.get_config().get_padding(Entity::Column(col));
+            let padding = padding.left.size + padding.right.size;
+            *width = std::cmp::max(*width, padding);
+
+            let text_width = *width - padding;
+            if self.truncate || text_width == 0 {
+                Truncate::new(text_width).change_cell(table, Entity::Column(col));
+            } else {
+                Wrap::new(text_width).change_cell(table, Entity::Column(col));
+            }
+        }
+
+        table.cache_width(widths);
+        table.destroy_height_cache();
+    }
+}
+
+/// Splits a width by percents.
+///
+/// If percents sum up to 100 the whole width is used,
+/// a rest of a division is given to columns with the biggest fractional part.
+fn split_width(width: usize, percents: Vec<usize>) -> Vec<usize> {
+    let mut widths = percents.iter().map(|p| width * p / 100).collect::<Vec<_>>();
+
+    if percents.iter().sum::<usize>() != 100 {
+        return widths;
+    }
+
+    let rest = width - widths.iter().sum::<usize>();
+
+    let mut order = (0..percents.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| Reverse(width * percents[i] % 100));
+    for i in order.into_iter().take(rest) {
+        widths[i] += 1;
+    }
+
+    widths
+}
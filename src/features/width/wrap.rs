@@ -4,21 +4,23 @@
 use std::marker::PhantomData;
 
 use papergrid::{
-    records::{empty::EmptyRecords, Records, RecordsMut},
+    records::{Records, RecordsMut},
     util::string_width_multiline,
     width::CfgWidthFunction,
     Entity,
 };
 
 use crate::{
+    features::inner_table::resize_inner_table,
     measurement::Measurement,
     peaker::{Peaker, PriorityNone},
     CellOption, Table, TableOption, Width,
 };
 
 use super::{
-    get_table_widths, get_table_widths_with_total,
+    get_min_widths, measure_table_widths, segment,
     truncate::{decrease_widths, get_decrease_cell_list},
+    Fallback,
 };
 
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
@@ -40,9 +42,12 @@ use super::{
 ///
 /// [`Padding`]: crate::Padding
 #[derive(Debug, Clone)]
-pub struct Wrap<W = usize, P = PriorityNone> {
+pub struct Wrap<W = usize, P = PriorityNone, F = ()> {
     width: W,
     keep_words: bool,
+    hyphenate: bool,
+    min_widths: Vec<Option<usize>>,
+    fallback: F,
     _priority: PhantomData<P>,
 }
 
@@ -55,12 +60,15 @@ where
         Self {
             width,
             keep_words: false,
+            hyphenate: false,
+            min_widths: Vec::new(),
+            fallback: (),
             _priority: PhantomData::default(),
         }
     }
 }
 
-impl<W, P> Wrap<W, P> {
+impl<W, P, F> Wrap<W, P, F> {
     /// Priority defines the logic by which a truncate will be applied when is done for the whole table.
     ///
     /// - [`PriorityNone`] which cuts the columns one after another.
@@ -73,10 +81,13 @@ impl<W, P> Wrap<W, P> {
     /// [`Padding`]: crate::Padding
     /// [`PriorityMax`]: crate::peaker::PriorityMax
     /// [`PriorityMin`]: crate::peaker::PriorityMin
-    pub fn priority<PP>(self) -> Wrap<W, PP> {
+    pub fn priority<PP>(self) -> Wrap<W, PP, F> {
         Wrap {
             width: self.width,
             keep_words: self.keep_words,
+            hyphenate: self.hyphenate,
+            min_widths: self.min_widths,
+            fallback: self.fallback,
             _priority: PhantomData::default(),
         }
     }
@@ -89,9 +100,134 @@ impl<W, P> Wrap<W, P> {
         self.keep_words = true;
         self
     }
+
+    /// Set the hyphenate option, it implies [`Wrap::keep_words`].
+    ///
+    /// Words which don't fit a line are split rather than cut at an arbitrary position.
+    /// A word is split after an existing `-`, `/` or `_` if possible,
+    /// otherwise it's split with an inserted `-`.
+    /// A word is cut without a `-` only if there's no space for it, which happens with a width of 1.
+    ///
+    /// ```
+    /// use tabled::{object::Segment, Modify, Style, Table, Width};
+    ///
+    /// let data = ["tabled/src/features", "hyphenation"];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::markdown())
+    ///     .with(Modify::new(Segment::all()).with(Width::wrap(8).hyphenate()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "| &str     |\n\
+    ///      |----------|\n\
+    ///      | tabled/  |\n\
+    ///      | src/     |\n\
+    ///      | features |\n\
+    ///      | hyphena- |\n\
+    ///      | tion     |"
+    /// );
+    /// ```
+    pub fn hyphenate(mut self) -> Self {
+        self.keep_words = true;
+        self.hyphenate = true;
+        self
+    }
+
+    /// Sets a minimum width of a content of columns, by index.
+    ///
+    /// When a whole table is wrapped the listed columns are never made narrower than their minimum,
+    /// even if a table ends up wider than a given width.
+    /// A `None` leaves a column without a limit.
+    ///
+    /// It's not used when [`Wrap`] is applied to cells.
+    ///
+    /// ```
+    /// use tabled::{Style, Table, Width};
+    ///
+    /// let data = [("AB-1234", "Some long description")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::markdown())
+    ///     .with(Width::wrap(20).min_per_column([Some(7), None]))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "| &str    | &str   |\n\
+    ///      |---------|--------|\n\
+    ///      | AB-1234 | Some l |\n\
+    ///      |         | ong de |\n\
+    ///      |         | script |\n\
+    ///      |         | ion    |"
+    /// );
+    /// ```
+    pub fn min_per_column<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator<Item = Option<usize>>,
+    {
+        self.min_widths = widths.into_iter().collect();
+        self
+    }
+
+    /// Sets a [`Fallback`], like a [`LayoutFallback`], which is used when a table can't be wrapped to a given width.
+    ///
+    /// By default a table overflows the width.
+    /// Notice that a [`LayoutFallback`] can be used only with [`Resizable`] records.
+    ///
+    /// It's not used when [`Wrap`] is applied to cells.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, width::LayoutFallback, Style, Width};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name", "designed by", "invented", "typing"]);
+    /// builder.add_record(["C", "Dennis Ritchie", "1972", "static"]);
+    /// builder.add_record(["Go", "Rob Pike", "2009", "static"]);
+    ///
+    /// let table = builder
+    ///     .build()
+    ///     .with(Style::psql())
+    ///     .with(
+    ///         Width::wrap(30)
+    ///             .min_per_column([Some(4), Some(14), Some(8), Some(6)])
+    ///             .fallback(LayoutFallback::Expanded),
+    ///     )
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " -[ RECORD 0 ]--------------- \n",
+    ///         " name        | C              \n",
+    ///         " designed by | Dennis Ritchie \n",
+    ///         " invented    | 1972           \n",
+    ///         " typing      | static         \n",
+    ///         " -[ RECORD 1 ]--------------- \n",
+    ///         " name        | Go             \n",
+    ///         " designed by | Rob Pike       \n",
+    ///         " invented    | 2009           \n",
+    ///         " typing      | static         ",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`LayoutFallback`]: crate::width::LayoutFallback
+    /// [`Resizable`]: papergrid::records::Resizable
+    pub fn fallback<FF>(self, fallback: FF) -> Wrap<W, P, FF> {
+        Wrap {
+            width: self.width,
+            keep_words: self.keep_words,
+            hyphenate: self.hyphenate,
+            min_widths: self.min_widths,
+            fallback,
+            _priority: PhantomData::default(),
+        }
+    }
 }
 
-impl<W, P, R> CellOption<R> for Wrap<W, P>
+impl<W, P, F, R> CellOption<R> for Wrap<W, P, F>
 where
     W: Measurement<Width>,
     R: Records + RecordsMut<String>,
@@ -108,12 +244,25 @@ where
                 continue;
             }
 
+            let mut wrap = Wrap::new(width);
+            wrap.keep_words = self.keep_words;
+            wrap.hyphenate = self.hyphenate;
+            if let Some(text) = resize_inner_table(table, pos, width, wrap) {
+                table.get_records_mut_raw().set(pos, text, &width_ctrl);
+                continue;
+            }
+
+            let records = table.get_records();
             let text = records.get_text(pos);
             // todo: Think about it.
             //       We could eliminate this allocation if we would be allowed to cut '\t' with unknown characters.
             //       Currently we don't do that.
             let text = papergrid::util::replace_tab(text, table.get_config().get_tab_width());
-            let wrapped = wrap_text(&text, width, self.keep_words);
+            let wrapped = if self.hyphenate {
+                wrap_text_hyphenated(&text, width)
+            } else {
+                wrap_text(&text, width, self.keep_words)
+            };
 
             debug_assert!(
                 width >= string_width_multiline(&wrapped),
@@ -123,18 +272,19 @@ where
                 wrapped
             );
 
-            let records = table.get_records_mut();
+            let records = table.get_records_mut_raw();
             records.set(pos, wrapped, &width_ctrl);
         }
 
-        table.destroy_width_cache();
+        table.destroy_width_cache_of(entity);
     }
 }
 
-impl<W, P, R> TableOption<R> for Wrap<W, P>
+impl<W, P, F, R> TableOption<R> for Wrap<W, P, F>
 where
     W: Measurement<Width>,
     P: Peaker,
+    F: Fallback<R>,
     R: Records + RecordsMut<String>,
 {
     fn change(&mut self, table: &mut Table<R>) {
@@ -143,15 +293,32 @@ where
         }
 
         let width = self.width.measure(table.get_records(), table.get_config());
-        let (widths, total_width) =
-            get_table_widths_with_total(table.get_records(), table.get_config());
+        let (_, total_width) = measure_table_widths(table);
+        if width >= total_width {
+            return;
+        }
+
+        let is_relaid = self.fallback.apply(table, width, &self.min_widths);
+        let (widths, total_width) = measure_table_widths(table);
         if width >= total_width {
             return;
         }
 
         let priority = P::create();
-        let keep_words = self.keep_words;
-        wrap_total_width(table, widths, total_width, width, keep_words, priority);
+        let mut wrap = Wrap::new(0);
+        wrap.keep_words = self.keep_words;
+        wrap.hyphenate = self.hyphenate;
+        // minimums of columns are meaningless for columns of a different layout.
+        let min_widths = if is_relaid { &[] } else { &self.min_widths[..] };
+        wrap_total_width(
+            table,
+            widths,
+            total_width,
+            width,
+            wrap,
+            min_widths,
+            priority,
+        );
     }
 }
 
@@ -160,28 +327,34 @@ fn wrap_total_width<R, P>(
     mut widths: Vec<usize>,
     total_width: usize,
     width: usize,
-    keep_words: bool,
+    mut wrap: Wrap<usize>,
+    columns_min_widths: &[Option<usize>],
     priority: P,
 ) where
     P: Peaker,
     R: Records + RecordsMut<String>,
 {
     let (count_rows, count_cols) = table.shape();
-    let cfg = table.get_config();
-    let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_cols), cfg);
-
-    decrease_widths(&mut widths, &min_widths, total_width, width, priority);
+    let min_widths = get_min_widths(table, &widths, columns_min_widths);
+
+    let span_free_widths = table.measure_span_free_widths();
+    decrease_widths(
+        &mut widths,
+        &min_widths,
+        &span_free_widths,
+        total_width,
+        width,
+        priority,
+    );
 
+    let cfg = table.get_config();
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, (count_rows, count_cols));
-    let mut wrap = Wrap::new(0);
-    wrap.keep_words = keep_words;
     for ((row, col), width) in points {
         wrap.width = width;
         wrap.change_cell(table, (row, col).into());
     }
 
     table.destroy_height_cache();
-    table.destroy_width_cache();
     table.cache_width(widths);
 }
 
@@ -241,15 +414,14 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
     let mut buf = String::with_capacity(width);
     let mut list = Vec::new();
     let mut i = 0;
-    for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-        if i + c_width > width {
+    for (unit, unit_width) in segment::units(s) {
+        if i + unit_width > width {
             let count_unknowns = width - i;
             buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
             i += count_unknowns;
         } else {
-            buf.push(c);
-            i += c_width;
+            buf.push_str(unit);
+            i += unit_width;
         }
 
         if i == width {
@@ -439,9 +611,12 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     let mut word_length = 0;
     let mut is_empty_buf = true;
 
-    let split = |buf: &mut String, block: &AnsiBlock<'_>| {
+    // a color is closed before a line is padded,
+    // so the padding is never colored and a line width is always equal to the given one.
+    let split = |buf: &mut String, block: &AnsiBlock<'_>, line_width: usize| {
         let _ = write!(buf, "{}", block.end());
         buf.push_str(suffix);
+        buf.push_str(&" ".repeat(width - line_width));
         buf.push('\n');
         buf.push_str(prefix);
         let _ = write!(buf, "{}", block.start());
@@ -458,21 +633,20 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
 
         let _ = write!(buf, "{}", block.start());
 
-        for c in block.text().chars() {
-            let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        for (c, c_width) in segment::units(block.text()) {
             let is_enough_space = line_width + c_width <= width;
 
-            let is_space = c == ' ';
+            let is_space = c == " ";
             if is_space {
                 word_length = 0;
                 word_begin_pos = 0;
 
                 if !is_enough_space {
-                    split(&mut buf, &block);
+                    split(&mut buf, &block, line_width);
                     line_width = 0;
                 }
 
-                buf.push(c);
+                buf.push_str(c);
                 line_width += 1;
 
                 if is_empty_buf {
@@ -487,7 +661,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
             }
 
             if is_enough_space {
-                buf.push(c);
+                buf.push_str(c);
                 word_length += c_width;
                 line_width += c_width;
 
@@ -503,13 +677,20 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                     // move it to other line
 
                     if !is_empty_buf {
-                        // we don't fill the rest of the prev line here
-
-                        let sep = format!("{}{}\n{}{}", block.end(), suffix, prefix, block.start());
+                        let prev_line_width = line_width - word_length;
+                        let rest = " ".repeat(width - prev_line_width);
+                        let sep = format!(
+                            "{}{}{}\n{}{}",
+                            block.end(),
+                            suffix,
+                            rest,
+                            prefix,
+                            block.start()
+                        );
                         buf.insert_str(word_begin_pos, &sep);
                     }
 
-                    buf.push(c);
+                    buf.push_str(c);
                     line_width = partial_word_width;
                     word_length += c_width;
 
@@ -520,7 +701,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                     // it's not small so we can't do anything about it.
 
                     if !is_empty_buf {
-                        split(&mut buf, &block);
+                        split(&mut buf, &block, line_width);
                     }
 
                     let is_big_char = c_width > width;
@@ -530,7 +711,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                         line_width = width;
                         word_length = width;
                     } else {
-                        buf.push(c);
+                        buf.push_str(c);
                         line_width = c_width;
                         word_length += c_width;
                     }
@@ -558,10 +739,238 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     buf
 }
 
-fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
-    use papergrid::util::split_at_pos;
+#[cfg(not(feature = "color"))]
+fn wrap_text_hyphenated(text: &str, width: usize) -> String {
+    const REPLACEMENT: char = '\u{FFFD}';
+
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut lines = Vec::new();
+    for line in split_hyphenated(text, width) {
+        let mut buf = text[line.range].to_owned();
+        if line.hyphen {
+            buf.push('-');
+        }
+
+        buf.extend(std::iter::repeat(REPLACEMENT).take(line.unknowns));
+
+        let buf_width = unicode_width::UnicodeWidthStr::width(buf.as_str());
+        buf.extend(std::iter::repeat(' ').take(width - buf_width));
+
+        lines.push(buf);
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "color")]
+fn wrap_text_hyphenated(text: &str, width: usize) -> String {
+    use std::fmt::Write;
 
-    let (length, count_unknowns, split_char_size) = split_at_pos(text, at);
+    use ansi_str::AnsiStr;
+    use papergrid::util::strip_osc;
+
+    const REPLACEMENT: char = '\u{FFFD}';
+
+    if width == 0 {
+        return String::new();
+    }
+
+    let (text, url): (String, Option<String>) = strip_osc(text);
+    let (prefix, suffix) = build_link_prefix_suffix(url);
+
+    let stripped = text.ansi_strip();
+
+    let mut lines = Vec::new();
+    for line in split_hyphenated(&stripped, width) {
+        let content = &stripped[line.range.clone()];
+        let mut line_width = unicode_width::UnicodeWidthStr::width(content);
+
+        let mut buf = String::new();
+        buf.push_str(&prefix);
+        buf.push_str(&text.ansi_cut(line.range.clone()));
+
+        if line.hyphen {
+            // a hyphen gets a color of a last character.
+            let last_char_size = content.chars().last().map_or(0, char::len_utf8);
+            let last_char = text.ansi_cut(line.range.end - last_char_size..line.range.end);
+            match ansi_str::get_blocks(&last_char).last() {
+                Some(block) => {
+                    let _ = write!(buf, "{}-{}", block.start(), block.end());
+                }
+                None => buf.push('-'),
+            }
+
+            line_width += 1;
+        }
+
+        buf.extend(std::iter::repeat(REPLACEMENT).take(line.unknowns));
+        line_width += line.unknowns;
+
+        buf.push_str(&suffix);
+        buf.extend(std::iter::repeat(' ').take(width - line_width));
+
+        lines.push(buf);
+    }
+
+    lines.join("\n")
+}
+
+/// A line of a hyphenated text.
+struct HyphenatedLine {
+    /// A range of a text which is put on a line.
+    range: std::ops::Range<usize>,
+    /// Whether a line ends with an inserted hyphen.
+    hyphen: bool,
+    /// A number of replacement characters, used when a character is wider than a line.
+    unknowns: usize,
+}
+
+/// Splits a text to lines keeping words,
+/// and splits words which don't fit a line with a hyphen.
+///
+/// The text must not have ANSI sequences.
+fn split_hyphenated(text: &str, width: usize) -> Vec<HyphenatedLine> {
+    let mut lines = Vec::new();
+
+    let mut offset = 0;
+    for text_line in text.split('\n') {
+        let count_lines = lines.len();
+
+        let mut line = HyphenatedLine {
+            range: offset..offset,
+            hyphen: false,
+            unknowns: 0,
+        };
+        let mut line_width = 0;
+        let mut is_empty_line = true;
+
+        let mut word_start = offset;
+        for word in text_line.split(' ') {
+            let mut word = word;
+            let mut start = word_start;
+            word_start += word.len() + 1;
+
+            loop {
+                let space = !is_empty_line as usize;
+                let available_space = width.saturating_sub(line_width + space);
+
+                let word_width = unicode_width::UnicodeWidthStr::width(word);
+                if word_width <= available_space {
+                    if is_empty_line {
+                        line.range.start = start;
+                    }
+
+                    line.range.end = start + word.len();
+                    line_width += space + word_width;
+                    is_empty_line = false;
+                    break;
+                }
+
+                if word_width <= width {
+                    // the word can be fit to 'width' so we put it on new line
+                    lines.push(line);
+                    line = HyphenatedLine {
+                        range: start..start,
+                        hyphen: false,
+                        unknowns: 0,
+                    };
+                    line_width = 0;
+                    is_empty_line = true;
+                    continue;
+                }
+
+                match find_hyphenation_point(word, available_space, is_empty_line) {
+                    Some((length, hyphen)) => {
+                        if is_empty_line {
+                            line.range.start = start;
+                        }
+
+                        line.range.end = start + length;
+                        line.hyphen = hyphen;
+                        word = &word[length..];
+                        start += length;
+                    }
+                    None if is_empty_line => {
+                        // a character is wider than a line
+                        let c_size = segment::units(word).next().map_or(0, |(c, _)| c.len());
+                        line.range = start..start;
+                        line.unknowns = width;
+                        word = &word[c_size..];
+                        start += c_size;
+                    }
+                    None => {}
+                }
+
+                lines.push(line);
+                line = HyphenatedLine {
+                    range: start..start,
+                    hyphen: false,
+                    unknowns: 0,
+                };
+                line_width = 0;
+                is_empty_line = true;
+
+                if word.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        if !is_empty_line || lines.len() == count_lines {
+            lines.push(line);
+        }
+
+        offset += text_line.len() + 1;
+    }
+
+    lines
+}
+
+/// Finds a position at which a word can be split to fit a given width.
+///
+/// It prefers a position after a punctuation.
+/// Otherwise it returns a position leaving a space for a hyphen,
+/// and only if `allow_cut` is set a position without one.
+fn find_hyphenation_point(word: &str, width: usize, allow_cut: bool) -> Option<(usize, bool)> {
+    let mut punctuation = None;
+    let mut hyphen = None;
+    let mut cut = None;
+
+    let mut word_width = 0;
+    let mut end = 0;
+    for (c, c_width) in segment::units(word) {
+        word_width += c_width;
+        if word_width > width {
+            break;
+        }
+
+        end += c.len();
+        if end == word.len() {
+            break;
+        }
+
+        if matches!(c, "-" | "/" | "_") {
+            punctuation = Some(end);
+        }
+
+        if word_width < width {
+            hyphen = Some(end);
+        }
+
+        cut = Some(end);
+    }
+
+    punctuation
+        .map(|pos| (pos, false))
+        .or_else(|| hyphen.map(|pos| (pos, true)))
+        .or_else(|| cut.filter(|_| allow_cut).map(|pos| (pos, false)))
+}
+
+fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
+    let (length, count_unknowns, split_char_size) = segment::split_at_width(text, at);
     let (lhs, rhs) = text.split_at(length);
 
     (lhs, rhs, (count_unknowns, split_char_size))
@@ -676,7 +1085,7 @@ mod tests {
         println!("{}", split_keeping_words(text, 2));
         println!("{}", split_keeping_words(text, 1));
 
-        assert_eq!(split_keeping_words(text, 2), "\u{1b}[36mJa\u{1b}[39m\n\u{1b}[36mpa\u{1b}[39m\n\u{1b}[36mne\u{1b}[39m\n\u{1b}[36mse\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36m“v\u{1b}[39m\n\u{1b}[36mac\u{1b}[39m\n\u{1b}[36man\u{1b}[39m\n\u{1b}[36mcy\u{1b}[39m\n\u{1b}[36m” \u{1b}[39m\n\u{1b}[36mbu\u{1b}[39m\n\u{1b}[36mtt\u{1b}[39m\n\u{1b}[36mon\u{1b}[39m");
+        assert_eq!(split_keeping_words(text, 2), "\u{1b}[36mJa\u{1b}[39m\n\u{1b}[36mpa\u{1b}[39m\n\u{1b}[36mne\u{1b}[39m\n\u{1b}[36mse\u{1b}[39m\n\u{1b}[36m \u{1b}[39m \n\u{1b}[36m“v\u{1b}[39m\n\u{1b}[36mac\u{1b}[39m\n\u{1b}[36man\u{1b}[39m\n\u{1b}[36mcy\u{1b}[39m\n\u{1b}[36m” \u{1b}[39m\n\u{1b}[36mbu\u{1b}[39m\n\u{1b}[36mtt\u{1b}[39m\n\u{1b}[36mon\u{1b}[39m");
         assert_eq!(split_keeping_words(text, 1), "\u{1b}[36mJ\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mp\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36ms\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36m“\u{1b}[39m\n\u{1b}[36mv\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36my\u{1b}[39m\n\u{1b}[36m”\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36mb\u{1b}[39m\n\u{1b}[36mu\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mo\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m");
     }
 
@@ -708,13 +1117,13 @@ mod tests {
                 "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mOM\u{1b}[39m",
                 "\u{1b}[37mYA\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
+                "\u{1b}[37m \u{1b}[39m ",
                 "\u{1b}[37mAn\u{1b}[39m",
                 "\u{1b}[37mdi\u{1b}[39m",
                 "\u{1b}[37mna\u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
+                "\u{1b}[37m \u{1b}[39m ",
                 "\u{1b}[37m38\u{1b}[39m",
                 "\u{1b}[37m24\u{1b}[39m",
                 "\u{1b}[37m90\u{1b}[39m",
@@ -843,34 +1252,43 @@ mod tests {
 
         println!(
             "{}",
-            split_keeping_words(
-                "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
-                3,
-            ),
+            split_keeping_words("\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m", 3,),
         );
 
+        #[cfg(not(feature = "grapheme"))]
         assert_eq!(
             split_keeping_words(
                 "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
                 3,
             ),
-            "\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m\n\u{1b}[37m🚵\u{1b}[39m\n\u{1b}[37m🏻\u{1b}[39m ",
+            "\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m \n\u{1b}[37m🚵\u{1b}[39m \n\u{1b}[37m🏻\u{1b}[39m ",
         );
         assert_eq!(
             split_keeping_words("\u{1b}[37mthis is a long sentence\u{1b}[0m", 7),
-            "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37m a long\u{1b}[39m\n\u{1b}[37m \u{1b}[39m\n\u{1b}[37msentenc\u{1b}[39m\n\u{1b}[37me\u{1b}[39m      "
+            "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37m a long\u{1b}[39m\n\u{1b}[37m \u{1b}[39m      \n\u{1b}[37msentenc\u{1b}[39m\n\u{1b}[37me\u{1b}[39m      "
         );
         assert_eq!(
             split_keeping_words("\u{1b}[37mHello World\u{1b}[0m", 7),
-            "\u{1b}[37mHello \u{1b}[39m\n\u{1b}[37mWorld\u{1b}[39m  "
+            "\u{1b}[37mHello \u{1b}[39m \n\u{1b}[37mWorld\u{1b}[39m  "
         );
         assert_eq!(
             split_keeping_words("\u{1b}[37mHello Wo\u{1b}[37mrld\u{1b}[0m", 7),
-            "\u{1b}[37mHello \u{1b}[39m\n\u{1b}[37mWo\u{1b}[39m\u{1b}[37mrld\u{1b}[39m  "
+            "\u{1b}[37mHello \u{1b}[39m \n\u{1b}[37mWo\u{1b}[39m\u{1b}[37mrld\u{1b}[39m  "
         );
         assert_eq!(
             split_keeping_words("\u{1b}[37mHello Wo\u{1b}[37mrld\u{1b}[0m", 8),
-            "\u{1b}[37mHello \u{1b}[39m\n\u{1b}[37mWo\u{1b}[39m\u{1b}[37mrld\u{1b}[39m   "
+            "\u{1b}[37mHello \u{1b}[39m  \n\u{1b}[37mWo\u{1b}[39m\u{1b}[37mrld\u{1b}[39m   "
+        );
+    }
+
+    #[cfg(all(feature = "color", feature = "grapheme"))]
+    #[test]
+    fn split_by_line_keeping_words_color_grapheme_test() {
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+
+        assert_eq!(
+            split_keeping_words("\u{1b}[37m🚵🏻🚵🏻🚵🏻\u{1b}[0m", 3),
+            "\u{1b}[37m🚵🏻\u{1b}[39m \n\u{1b}[37m🚵🏻\u{1b}[39m \n\u{1b}[37m🚵🏻\u{1b}[39m ",
         );
     }
 
@@ -965,13 +1383,13 @@ mod tests {
                 "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37mOM\u{1b}[39m$",
                 "^\u{1b}[37mYA\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
+                "^\u{1b}[37m \u{1b}[39m$ ",
                 "^\u{1b}[37mAn\u{1b}[39m$",
                 "^\u{1b}[37mdi\u{1b}[39m$",
                 "^\u{1b}[37mna\u{1b}[39m$",
                 "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
+                "^\u{1b}[37m \u{1b}[39m$ ",
                 "^\u{1b}[37m38\u{1b}[39m$",
                 "^\u{1b}[37m24\u{1b}[39m$",
                 "^\u{1b}[37m90\u{1b}[39m$",
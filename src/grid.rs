@@ -0,0 +1,92 @@
+//! The module contains a stable subset of [`papergrid`] primitives.
+//!
+//! It's intended to be used by authors of custom [`TableOption`]s and [`CellOption`]s,
+//! so they don't depend on an internal layout of [`papergrid`] which may change from release to release.
+//!
+//! Everything listed here is a part of `tabled` public API and follows its versioning.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     grid::{
+//!         records::{Records, RecordsMut},
+//!         util::string_width,
+//!         width::CfgWidthFunction,
+//!         Entity,
+//!     },
+//!     CellOption, Modify, Table, object::Columns,
+//! };
+//!
+//! struct Stars;
+//!
+//! impl<R> CellOption<R> for Stars
+//! where
+//!     R: Records + RecordsMut<String>,
+//! {
+//!     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+//!         let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+//!         let (count_rows, count_cols) = table.shape();
+//!         for pos in entity.iter(count_rows, count_cols) {
+//!             let text = table.get_records().get_text(pos);
+//!             let stars = "*".repeat(string_width(text));
+//!             table.get_records_mut().set(pos, stars, &width_fn);
+//!         }
+//!     }
+//! }
+//!
+//! let table = Table::new(["Hello", "World!"])
+//!     .with(Modify::new(Columns::first()).with(Stars))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+--------+\n\
+//!      | ****   |\n\
+//!      +--------+\n\
+//!      | *****  |\n\
+//!      +--------+\n\
+//!      | ****** |\n\
+//!      +--------+"
+//! );
+//! ```
+//!
+//! [`TableOption`]: crate::TableOption
+//! [`CellOption`]: crate::CellOption
+
+pub use papergrid::{
+    AlignmentHorizontal, AlignmentVertical, Entity, EntityIterator, Estimate, GridConfig, Indent,
+    Offset, Position, Sides,
+};
+
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+pub use papergrid::{AnsiColor, Color};
+
+pub mod records {
+    //! Traits which describe a data set of a table and its default implementation.
+
+    pub use papergrid::records::{
+        cell_info::CellInfo, vec_records::VecRecords, Records, RecordsMut, Resizable,
+    };
+}
+
+pub mod width {
+    //! Width estimation of cells.
+
+    pub use papergrid::width::{CfgWidthFunction, WidthEstimator, WidthFunc};
+}
+
+pub mod height {
+    //! Height estimation of cells.
+
+    pub use papergrid::height::HeightEstimator;
+}
+
+pub mod util {
+    //! Text utilities, which are aware of ANSI sequences when `color` feature is on.
+
+    pub use papergrid::util::{
+        count_lines, cut_str, get_lines, string_width, string_width_multiline,
+    };
+}
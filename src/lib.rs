@@ -240,13 +240,17 @@
 
 mod features;
 mod modify;
+mod pool_table;
+mod strict;
 mod table;
 mod table_iterator_ext;
 mod tabled;
 
 pub mod builder;
 pub mod display;
+pub mod grid;
 pub mod object;
+pub mod records;
 
 #[cfg(feature = "macros")]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
@@ -256,13 +260,24 @@ pub use papergrid;
 
 pub use crate::{
     features::{
+        accessible::Accessible,
         alignment::{self, Alignment},
+        border_region::BorderRegion,
+        caption::Caption,
+        column_format::ColumnFormat,
         concat::Concat,
+        custom::{self, CellMap, TableInspect},
+        derived_config::DerivedConfig,
         disable::Disable,
         extract::Extract,
+        filter::Filter,
         format, formatting,
+        header_groups::HeaderGroups,
+        header_orientation::HeaderOrientation,
+        header_position::HeaderPosition,
         height::{self, Height},
         highlight::Highlight,
+        inner_table::InnerTable,
         locator,
         margin::Margin,
         measurement, merge,
@@ -270,13 +285,20 @@ pub use crate::{
         panel::{Footer, Header, Panel},
         peaker,
         rotate::Rotate,
+        row_index::RowIndex,
         shadow,
+        sort::Sort,
         span::Span,
+        sparkline::Sparkline,
         style::{self, Border, BorderText, Style},
+        summary::{self, Summary},
+        table_config::{ColumnConfig, TableConfig},
+        theme::Theme,
         width::{self, Width},
     },
     modify::{CellSettingsList, Modify, ModifyList, ModifyObject},
-    table::{CellOption, Table, TableOption},
+    pool_table::PoolTable,
+    table::{CellOption, Table, TableOption, TableRows},
     table_iterator_ext::TableIteratorExt,
     tabled::Tabled,
 };
@@ -285,6 +307,22 @@ pub use crate::{
 #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
 pub use crate::features::{color, highlight, margin_color, padding_color};
 
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+pub use crate::features::{colorization::Colorization, stripe::Stripe};
+
+#[cfg(feature = "html")]
+#[cfg_attr(docsrs, doc(cfg(feature = "html")))]
+pub use crate::features::html;
+
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub use crate::features::csv;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use crate::features::selection;
+
 /// A derive to implement a [`Tabled`] trait.
 ///
 /// The macros available only when `derive` feature in turned on (and it is by default).
@@ -338,6 +376,24 @@ pub use crate::features::{color, highlight, margin_color, padding_color};
 /// }
 /// ```
 ///
+/// A column marked as `hideable` is present by default,
+/// but it can be left out at runtime by [`Table::new_masked`].
+///
+/// ```rust,no_run
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Person {
+///    id: u8,
+///    #[tabled(hideable)]
+///    number: &'static str,
+///    name: &'static str,
+/// }
+///
+/// let data: Vec<Person> = Vec::new();
+/// let table = Table::new_masked(&data, &Person::hideable());
+/// ```
+///
 /// ### Set column order
 ///
 /// You can change the order in which they will be displayed in table.
@@ -473,6 +529,52 @@ pub use crate::features::{color, highlight, margin_color, padding_color};
 ///     price: f32,
 /// }
 /// ```
+///
+/// ### Inline collections
+///
+/// A collection field can be put into a single cell using `#[tabled(inline_list)]`,
+/// its items are joined by `, ` or by a separator set by `#[tabled(inline_list(sep = " | "))]`.
+/// Entries of a map are displayed as `key: value`.
+///
+/// It can be also expanded into a fixed number of columns by `#[tabled(inline_list(columns = 3))]`,
+/// named by a field name and an index unless a `prefix` is set.
+/// Missing items are left empty, and the ones which don't fit are joined into the last column.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Package {
+///     name: &'static str,
+///     #[tabled(inline_list(sep = "\n"))]
+///     authors: Vec<&'static str>,
+///     #[tabled(inline_list(columns = 3, prefix = "tag_"))]
+///     tags: Vec<&'static str>,
+/// }
+/// ```
+///
+/// ### Group columns
+///
+/// An inlined field can be marked as a `group`,
+/// so its columns get a common header above them, named by a field name or by `#[tabled(group = "name")]`.
+/// [`Table::new`] puts groups in rows above a header.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Item {
+///     name: &'static str,
+///     #[tabled(inline, group = "Price")]
+///     price: Price,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Price {
+///     net: f32,
+///     gross: f32,
+/// }
+/// ```
 // @todo: Move the comment to tabled_derive
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
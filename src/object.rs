@@ -8,7 +8,7 @@ use std::{
     ops::{Add, Bound, RangeBounds, RangeFull, Sub},
 };
 
-use papergrid::records::Records;
+use papergrid::{records::Records, Position};
 pub use papergrid::{Entity, EntityIterator};
 
 use crate::Table;
@@ -54,6 +54,70 @@ pub trait Object: Sized {
     fn inverse(self) -> InversionCombination<Self> {
         InversionCombination { obj: self }
     }
+
+    /// Returns positions of all cells of an [`Object`] on a [`Table`].
+    ///
+    /// Rows, columns and a whole table are expanded into cells,
+    /// each cell is returned once in the order it's met.
+    /// Cells which are out of a table are left out.
+    ///
+    /// ```
+    /// use tabled::{object::{Cell, Columns, Object, Rows}, Table};
+    ///
+    /// let table = Table::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let target = Rows::first().and(Columns::last()).not(Cell(0, 0));
+    ///
+    /// assert_eq!(target.positions(&table), [(0, 1), (0, 2), (1, 2), (2, 2)]);
+    /// ```
+    ///
+    /// [`Table`]: crate::Table
+    fn positions<R>(&self, table: &Table<R>) -> Vec<Position>
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+
+        let mut seen = HashSet::new();
+        let mut positions = Vec::new();
+        for entity in self.cells(table) {
+            for pos in entity.iter(count_rows, count_cols) {
+                let is_inside = pos.0 < count_rows && pos.1 < count_cols;
+                if is_inside && seen.insert(pos) {
+                    positions.push(pos);
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Checks whether a cell is a part of an [`Object`] on a [`Table`].
+    ///
+    /// ```
+    /// use tabled::{object::{Cell, Columns, Object, Rows}, Table};
+    ///
+    /// let table = Table::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let target = Rows::first().and(Columns::last()).not(Cell(0, 0));
+    ///
+    /// assert!(target.contains(&table, (2, 2)));
+    /// assert!(!target.contains(&table, (0, 0)));
+    /// ```
+    ///
+    /// [`Table`]: crate::Table
+    fn contains<R>(&self, table: &Table<R>, pos: Position) -> bool
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+        if pos.0 >= count_rows || pos.1 >= count_cols {
+            return false;
+        }
+
+        self.cells(table)
+            .any(|entity| entity.iter(count_rows, count_cols).any(|p| p == pos))
+    }
 }
 
 /// Combination struct used for chaining [`Object`]'s.
@@ -1364,6 +1428,42 @@ mod tests {
         assert_eq!(vec_cells(Rows::first().inverse(), 0, 0), []);
     }
 
+    #[test]
+    fn object_positions_test() {
+        let data = vec![vec![String::default(); 3]; 2];
+        let table = crate::builder::Builder::from(data).build();
+
+        assert_eq!(
+            Rows::first().and(Columns::last()).positions(&table),
+            [(0, 0), (0, 1), (0, 2), (1, 2)]
+        );
+        assert_eq!(
+            Segment::all().not(Cell(1, 1)).positions(&table),
+            [(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+        assert_eq!(
+            Columns::new(1..).inverse().positions(&table),
+            [(0, 0), (1, 0)]
+        );
+        assert_eq!(Cell(5, 5).positions(&table), []);
+
+        let empty = crate::builder::Builder::default().build();
+        assert_eq!(Segment::all().positions(&empty), []);
+    }
+
+    #[test]
+    fn object_contains_test() {
+        let data = vec![vec![String::default(); 3]; 2];
+        let table = crate::builder::Builder::from(data).build();
+
+        let target = Rows::first().and(Columns::last()).not(Cell(0, 0));
+        assert!(target.contains(&table, (0, 1)));
+        assert!(target.contains(&table, (1, 2)));
+        assert!(!target.contains(&table, (0, 0)));
+        assert!(!target.contains(&table, (1, 0)));
+        assert!(!target.contains(&table, (5, 5)));
+    }
+
     fn vec_cells<O: Object>(o: O, count_rows: usize, count_cols: usize) -> Vec<Entity> {
         let data = vec![vec![String::default(); count_cols]; count_rows];
         let table = crate::builder::Builder::from(data).build();
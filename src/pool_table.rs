@@ -0,0 +1,220 @@
+//! This module contains a [`PoolTable`] structure, a table where each row has its own columns.
+
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+    iter::FromIterator,
+};
+
+use papergrid::{
+    records::{cell_info::CellInfo, vec_records::VecRecords},
+    GridConfig,
+};
+
+use crate::{builder::Builder, Table, TableOption, Width};
+
+/// PoolTable is a table where cells of each row are sized independently,
+/// so the columns of different rows aren't aligned.
+///
+/// It's handy for dashboards and "card" like groupings of values,
+/// where rows have different number of cells or cells of very different sizes.
+///
+/// Each row is kept as a separate [`Table`] with a single row,
+/// so the usual options like [`Style`], [`Padding`], [`Alignment`] or [`Width`] can be used,
+/// and they are applied to each row via [`PoolTable::with`].
+///
+/// When printed, rows are stretched to the width of the widest one
+/// and joined by a horizontal line of a style,
+/// with intersections put where the vertical lines of adjacent rows are.
+/// Empty rows are skipped.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{PoolTable, Style};
+///
+/// let mut table = PoolTable::new([
+///     vec!["CPU", "12%"],
+///     vec!["Memory", "1.2G", "of", "16G"],
+///     vec!["Uptime: 3 days"],
+/// ]);
+/// table.with(Style::modern());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "┌─────────────┬────────────┐\n",
+///         "│ CPU         │ 12%        │\n",
+///         "├────────┬────┴─┬────┬─────┤\n",
+///         "│ Memory │ 1.2G │ of │ 16G │\n",
+///         "├────────┴──────┴────┴─────┤\n",
+///         "│ Uptime: 3 days           │\n",
+///         "└──────────────────────────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Style`]: crate::Style
+/// [`Padding`]: crate::Padding
+/// [`Alignment`]: crate::Alignment
+/// [`Width`]: crate::Width
+#[derive(Debug, Clone)]
+pub struct PoolTable {
+    rows: Vec<Table>,
+}
+
+impl PoolTable {
+    /// Creates a [`PoolTable`] from a list of rows, each of which may have its own number of cells.
+    pub fn new<I, R, T>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect::<Vec<String>>())
+            .filter(|row| !row.is_empty())
+            .map(|row| Builder::from_iter([row]).build())
+            .collect();
+
+        Self { rows }
+    }
+
+    /// Applies an option to each row of the table.
+    ///
+    /// The same option is used for all rows one after another,
+    /// so its [`TableOption::change`] is called once per row.
+    ///
+    /// [`TableOption::change`]: crate::TableOption::change
+    pub fn with<O>(&mut self, mut option: O) -> &mut Self
+    where
+        O: TableOption<VecRecords<CellInfo<'static>>>,
+    {
+        for row in &mut self.rows {
+            option.change(row);
+        }
+
+        self
+    }
+
+    /// Returns a number of rows.
+    pub fn count_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns a table of a row, which can be used to change a particular row.
+    ///
+    /// # Panics
+    ///
+    /// It panics if there's no such row.
+    pub fn get_row_mut(&mut self, row: usize) -> &mut Table {
+        &mut self.rows[row]
+    }
+}
+
+impl Display for PoolTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows = self.rows.clone();
+
+        let width = rows.iter().map(Table::total_width).max().unwrap_or(0);
+        for row in &mut rows {
+            if row.total_width() < width {
+                row.with(Width::increase(width));
+            }
+        }
+
+        let mut prev_verticals = None;
+        let mut lines = Vec::new();
+        let count_rows = rows.len();
+        for (i, row) in rows.iter_mut().enumerate() {
+            let verticals = get_verticals(row);
+
+            let cfg = row.get_config();
+            let has_top = cfg.has_horizontal(0, 1);
+            let has_bottom = cfg.has_horizontal(1, 1);
+            let borders = cfg.get_borders();
+            let has_split = borders.horizontal.is_some() || borders.intersection.is_some();
+
+            let text = row.to_string();
+            let mut text_lines = text.lines().collect::<Vec<_>>();
+            let top = if has_top {
+                Some(text_lines.remove(0))
+            } else {
+                None
+            };
+            let bottom = if has_bottom { text_lines.pop() } else { None };
+
+            match &prev_verticals {
+                None => lines.extend(top.map(ToOwned::to_owned)),
+                Some(prev) if has_split => {
+                    lines.push(build_split_line(cfg, prev, &verticals, width));
+                }
+                Some(_) => {}
+            }
+
+            lines.extend(text_lines.into_iter().map(ToOwned::to_owned));
+
+            let is_last = i + 1 == count_rows;
+            if is_last && cfg.get_borders().has_bottom() {
+                lines.extend(bottom.map(ToOwned::to_owned));
+            }
+
+            prev_verticals = Some(verticals);
+        }
+
+        f.write_str(&lines.join("\n"))
+    }
+}
+
+/// Returns positions of vertical lines of a table with a single row.
+fn get_verticals(table: &mut Table) -> BTreeSet<usize> {
+    let widths = table.get_widths();
+    let count_columns = widths.len();
+    let cfg = table.get_config();
+
+    let mut verticals = BTreeSet::new();
+    let mut x = 0;
+    for (col, width) in widths.into_iter().enumerate() {
+        if cfg.has_vertical(col, count_columns) {
+            verticals.insert(x);
+            x += 1;
+        }
+
+        x += width;
+    }
+
+    if cfg.has_vertical(count_columns, count_columns) {
+        verticals.insert(x);
+    }
+
+    verticals
+}
+
+/// Builds a line between 2 rows,
+/// with intersections put where any of the rows has a vertical line.
+fn build_split_line(
+    cfg: &GridConfig,
+    above: &BTreeSet<usize>,
+    below: &BTreeSet<usize>,
+    width: usize,
+) -> String {
+    let borders = cfg.get_borders();
+    let horizontal = borders.horizontal.unwrap_or(' ');
+
+    (0..width)
+        .map(|x| {
+            let c = match (above.contains(&x), below.contains(&x)) {
+                _ if x == 0 && cfg.has_vertical(0, 1) => borders.horizontal_left,
+                _ if x + 1 == width && cfg.has_vertical(1, 1) => borders.horizontal_right,
+                (true, true) => borders.intersection,
+                (true, false) => borders.bottom_intersection,
+                (false, true) => borders.top_intersection,
+                (false, false) => borders.horizontal,
+            };
+
+            c.unwrap_or(horizontal)
+        })
+        .collect()
+}
@@ -0,0 +1,322 @@
+//! The module contains [`InternedRecords`] a [`Records`] implementation which deduplicates cells.
+
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    sync::Arc,
+};
+
+use papergrid::{
+    records::{cell_info::CellInfo, vec_records::Cell, Records, RecordsMut, Resizable},
+    width::{CfgWidthFunction, WidthFunc},
+    Position,
+};
+
+/// A [`Records`] implementation which keeps a single copy of each distinct cell text.
+///
+/// Cells with equal content share one allocation (together with its pre calculated widths),
+/// which cuts memory usage a lot for tables with many repeated values,
+/// like categorical columns.
+///
+/// All modifications go through the same per table pool,
+/// so a cell set by a [`TableOption`] is deduplicated as well,
+/// and a text no longer used by any cell is released.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{builder::Builder, records::InternedRecords};
+///
+/// let data = (0..1000).map(|i| [if i % 2 == 0 { "even" } else { "odd" }]);
+///
+/// let records = InternedRecords::from_iter(data);
+/// assert_eq!(records.count_unique(), 2);
+///
+/// let table = Builder::custom(records).build();
+/// assert_eq!(table.shape(), (1000, 1));
+/// ```
+///
+/// [`TableOption`]: crate::TableOption
+#[derive(Debug, Default, Clone)]
+pub struct InternedRecords {
+    records: Vec<Vec<InternedCell>>,
+    size: (usize, usize),
+    pool: HashSet<InternedCell>,
+}
+
+impl InternedRecords {
+    /// Creates an empty [`InternedRecords`] of a given shape.
+    pub fn new(count_rows: usize, count_columns: usize) -> Self {
+        let mut records = Self::default();
+        let empty = records.intern(String::new(), CfgWidthFunction::new(4));
+        records.records = vec![vec![empty; count_columns]; count_rows];
+        records.size = (count_rows, count_columns);
+        records
+    }
+
+    /// Returns a shape of [`Records`].
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Returns an amount of distinct texts stored.
+    pub fn count_unique(&self) -> usize {
+        self.pool.len()
+    }
+
+    fn intern<W>(&mut self, text: String, width_ctrl: W) -> InternedCell
+    where
+        W: WidthFunc,
+    {
+        if let Some(cell) = self.pool.get(text.as_str()) {
+            return cell.clone();
+        }
+
+        let cell = InternedCell(Arc::new(CellInfo::new(text, width_ctrl)));
+        self.pool.insert(cell.clone());
+        cell
+    }
+
+    fn empty_cell(&mut self) -> InternedCell {
+        self.intern(String::new(), CfgWidthFunction::new(4))
+    }
+
+    /// Removes a cell from a pool in case it's not used anymore.
+    fn release(&mut self, cell: InternedCell) {
+        // one reference is kept by the pool and one is the given cell
+        if Arc::strong_count(&cell.0) == 2 {
+            let pooled = self.pool.get(cell.as_ref());
+            if matches!(pooled, Some(c) if Arc::ptr_eq(&c.0, &cell.0)) {
+                self.pool.remove(cell.as_ref());
+            }
+        }
+    }
+}
+
+impl<R, T> FromIterator<R> for InternedRecords
+where
+    R: IntoIterator<Item = T>,
+    T: AsRef<str>,
+{
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let ctrl = CfgWidthFunction::new(4);
+
+        let mut records = Self::default();
+        for row in iter {
+            let row = row
+                .into_iter()
+                .map(|text| records.intern(text.as_ref().to_owned(), &ctrl))
+                .collect::<Vec<_>>();
+
+            records.size.1 = std::cmp::max(records.size.1, row.len());
+            records.records.push(row);
+        }
+
+        records.size.0 = records.records.len();
+
+        let count_columns = records.size.1;
+        if records.records.iter().any(|row| row.len() < count_columns) {
+            let empty = records.empty_cell();
+            for row in &mut records.records {
+                row.resize(count_columns, empty.clone());
+            }
+        }
+
+        records
+    }
+}
+
+impl Records for InternedRecords {
+    fn count_rows(&self) -> usize {
+        self.size.0
+    }
+
+    fn count_columns(&self) -> usize {
+        self.size.1
+    }
+
+    fn get_text(&self, (row, col): Position) -> &str {
+        self.records[row][col].as_ref()
+    }
+
+    fn get_line(&self, (row, col): Position, i: usize) -> &str {
+        self.records[row][col].0.get_line(i)
+    }
+
+    fn count_lines(&self, (row, col): Position) -> usize {
+        self.records[row][col].0.count_lines()
+    }
+
+    fn get_width<W>(&self, (row, col): Position, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.records[row][col].0.width(width_ctrl)
+    }
+
+    fn get_line_width<W>(&self, (row, col): Position, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.records[row][col].0.line_width(i, width_ctrl)
+    }
+
+    fn fmt_text_prefix(&self, _: &mut std::fmt::Formatter<'_>, _: Position) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn fmt_text_suffix(&self, _: &mut std::fmt::Formatter<'_>, _: Position) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T> RecordsMut<T> for InternedRecords
+where
+    T: Into<String>,
+{
+    fn set<W>(&mut self, (row, col): Position, text: T, width_ctrl: W)
+    where
+        W: WidthFunc,
+    {
+        let cell = self.intern(text.into(), width_ctrl);
+        let old = std::mem::replace(&mut self.records[row][col], cell);
+        self.release(old);
+    }
+
+    fn update<W>(&mut self, (row, col): Position, width_ctrl: W)
+    where
+        W: WidthFunc,
+    {
+        let old = self.records[row][col].clone();
+
+        // A shared cell may be already updated through another position,
+        // in which case we just point to the updated one.
+        let cell = match self.pool.get(old.as_ref()) {
+            Some(cell) if !Arc::ptr_eq(&cell.0, &old.0) => cell.clone(),
+            _ => {
+                self.pool.remove(old.as_ref());
+                self.intern(old.as_ref().to_owned(), width_ctrl)
+            }
+        };
+
+        self.records[row][col] = cell;
+    }
+}
+
+impl Resizable for InternedRecords {
+    fn swap(&mut self, lhs: Position, rhs: Position) {
+        if lhs.0 >= self.size.0
+            || lhs.1 >= self.size.1
+            || rhs.0 >= self.size.0
+            || rhs.1 >= self.size.1
+        {
+            return;
+        }
+
+        let cell = self.records[lhs.0][lhs.1].clone();
+        let cell = std::mem::replace(&mut self.records[rhs.0][rhs.1], cell);
+        self.records[lhs.0][lhs.1] = cell;
+    }
+
+    fn swap_row(&mut self, lhs: usize, rhs: usize) {
+        if lhs >= self.size.0 || rhs >= self.size.0 {
+            return;
+        }
+
+        self.records.swap(lhs, rhs);
+    }
+
+    fn swap_column(&mut self, lhs: usize, rhs: usize) {
+        if lhs >= self.size.1 || rhs >= self.size.1 {
+            return;
+        }
+
+        for row in &mut self.records {
+            row.swap(lhs, rhs);
+        }
+    }
+
+    fn push_row(&mut self) {
+        let empty = self.empty_cell();
+        self.records.push(vec![empty; self.size.1]);
+        self.size.0 += 1;
+    }
+
+    fn push_column(&mut self) {
+        let empty = self.empty_cell();
+        for row in &mut self.records {
+            row.push(empty.clone());
+        }
+
+        self.size.1 += 1;
+    }
+
+    fn remove_row(&mut self, row: usize) {
+        if row >= self.size.0 {
+            return;
+        }
+
+        let cells = self.records.remove(row);
+        for cell in cells {
+            self.release(cell);
+        }
+
+        self.size.0 -= 1;
+    }
+
+    fn remove_column(&mut self, column: usize) {
+        if column >= self.size.1 {
+            return;
+        }
+
+        let cells = self
+            .records
+            .iter_mut()
+            .map(|row| row.remove(column))
+            .collect::<Vec<_>>();
+        for cell in cells {
+            self.release(cell);
+        }
+
+        self.size.1 -= 1;
+    }
+
+    fn insert_row(&mut self, row: usize) {
+        let empty = self.empty_cell();
+        self.records.insert(row, vec![empty; self.size.1]);
+        self.size.0 += 1;
+    }
+}
+
+/// A shared cell which is compared and hashed by its text.
+#[derive(Debug, Clone)]
+struct InternedCell(Arc<CellInfo<'static>>);
+
+impl AsRef<str> for InternedCell {
+    fn as_ref(&self) -> &str {
+        (*self.0).as_ref()
+    }
+}
+
+impl Borrow<str> for InternedCell {
+    fn borrow(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+impl PartialEq for InternedCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for InternedCell {}
+
+impl Hash for InternedCell {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
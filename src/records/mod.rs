@@ -0,0 +1,12 @@
+//! The module contains [`Records`] implementations which can be used as a backend of a [`Table`].
+//!
+//! By default a [`Table`] uses [`VecRecords`],
+//! but in some cases a different representation may be more suitable.
+//!
+//! [`Records`]: papergrid::records::Records
+//! [`VecRecords`]: papergrid::records::vec_records::VecRecords
+//! [`Table`]: crate::Table
+
+mod interned_records;
+
+pub use interned_records::InternedRecords;
@@ -0,0 +1,31 @@
+//! This module contains a guard of an environment dependent behaviour.
+//!
+//! When a `strict` feature is turned on any environment dependent behaviour results in a panic,
+//! which guarantees that a table output is reproducible on any machine.
+//!
+//! The guarded behaviour is:
+//!
+//! - a terminal size detection of [`Width::fit_terminal`];
+//! - an output to the process stdout of [`Live::run`].
+//!
+//! There's no locale or color support detection in the crate, so there's nothing to guard there.
+//!
+//! [`Width::fit_terminal`]: crate::Width::fit_terminal
+//! [`Live::run`]: crate::display::Live::run
+
+/// Reports a usage of an environment dependent behaviour.
+///
+/// # Panics
+///
+/// It panics when a `strict` feature is turned on.
+#[track_caller]
+pub(crate) fn env_dependent(behaviour: &str) {
+    #[cfg(feature = "strict")]
+    panic!(
+        "tabled: {} depends on an environment, which is forbidden by the `strict` feature",
+        behaviour
+    );
+
+    #[cfg(not(feature = "strict"))]
+    let _ = behaviour;
+}
@@ -1,6 +1,6 @@
 //! This module contains a main table representation of this crate [`Table`].
 
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{borrow::Cow, fmt, iter::FromIterator, ops::RangeBounds};
 
 use papergrid::{
     height::HeightEstimator,
@@ -10,14 +10,30 @@ use papergrid::{
         Records, RecordsMut,
     },
     width::{CfgWidthFunction, WidthEstimator},
-    Estimate, Grid, GridConfig,
+    Estimate, Grid, GridConfig, Position,
 };
 
 use crate::{
-    builder::Builder, height::get_table_total_height, object::Entity, width::get_table_total_width,
+    builder::{span_header_rows, Builder},
+    display::{
+        is_fast_render_applicable, print_grid, write_accessible, write_layout_json, write_markdown,
+    },
+    features::{
+        accessible::Accessible,
+        alignment::{self, Justification},
+        caption::{self, Caption},
+        derived_config::DerivedConfig,
+        header_position::{self, HeaderPosition},
+    },
+    height::get_table_total_height,
+    object::{bounds_to_usize, Entity},
+    width::{get_table_total_width, WidthLayout},
     Tabled,
 };
 
+#[cfg(feature = "color")]
+use crate::features::stripe::{self, Stripe};
+
 /// A trait which is responsilbe for configuration of a [`Table`].
 pub trait TableOption<R> {
     /// The function modifies a [`Grid`] object.
@@ -81,41 +97,195 @@ pub trait CellOption<R> {
 pub struct Table<R = VecRecords<CellInfo<'static>>> {
     records: R,
     cfg: GridConfig,
-    has_header: bool,
+    header_rows: usize,
     widths: Option<Vec<usize>>,
     heights: Option<Vec<usize>>,
+    layout: WidthLayout,
+    inner_tables: Vec<(Position, Table, String)>,
+    captions: Vec<Caption>,
+    header_position: HeaderPosition,
+    accessible: Option<Accessible>,
+    justifications: Vec<(Entity, Option<Justification>)>,
+    #[cfg(feature = "color")]
+    stripe: Option<Stripe>,
 }
 
 impl Table<VecRecords<CellInfo<'static>>> {
     /// New creates a Table instance.
     ///
-    /// If you use a reference iterator you'd better use [`FromIterator`] instead.
-    /// As it has a different lifetime constraints and make less copies therefore.
+    /// It accepts owned items as well as references to them,
+    /// so a collection can be passed either by value or by reference.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = vec![("Hello", 1), ("World", 2)];
+    ///
+    /// let borrowed = Table::new(&data).to_string();
+    /// let filtered = Table::new(data.iter().filter(|(_, i)| *i > 0)).to_string();
+    /// let owned = Table::new(data).to_string();
+    ///
+    /// assert_eq!(borrowed, owned);
+    /// assert_eq!(filtered, owned);
+    /// ```
+    ///
+    /// The same can be done by [`Table::with_iter`].
     pub fn new<I, T>(iter: I) -> Self
     where
         I: IntoIterator<Item = T>,
         T: Tabled,
     {
-        let ctrl = CfgWidthFunction::new(4);
+        Self::new_masked(iter, &[])
+    }
 
-        let mut header = vec![CellInfo::default(); T::LENGTH];
-        for (text, cell) in T::headers().into_iter().zip(header.iter_mut()) {
-            CellMut::set(cell, text, &ctrl);
-        }
+    /// Creates a Table instance leaving out columns which are set in a `mask`.
+    ///
+    /// A column is hidden if its index in a `mask` is `true`;
+    /// columns which are out of the `mask` are shown.
+    /// [`Tabled::hideable`] can be used as the `mask`
+    /// to hide all columns marked by `#[tabled(hideable)]`,
+    /// so the same type can be used for a short and a wide output.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "derive", doc = "```")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// use tabled::{Table, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Process {
+    ///     pid: usize,
+    ///     name: &'static str,
+    ///     #[tabled(hideable)]
+    ///     command: &'static str,
+    /// }
+    ///
+    /// let data = [Process { pid: 1, name: "init", command: "/sbin/init splash" }];
+    ///
+    /// let wide = false;
+    /// let mask = if wide { Vec::new() } else { Process::hideable() };
+    ///
+    /// let table = Table::new_masked(&data, &mask).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-----+------+\n\
+    ///      | pid | name |\n\
+    ///      +-----+------+\n\
+    ///      | 1   | init |\n\
+    ///      +-----+------+"
+    /// );
+    /// ```
+    pub fn new_masked<I, T>(iter: I, mask: &[bool]) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        let is_hidden = |i: usize| mask.get(i).copied().unwrap_or(false);
+        let count_columns = (0..T::LENGTH).filter(|&i| !is_hidden(i)).count();
 
-        let mut records = vec![header];
-        for row in iter.into_iter() {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
+        let mut records = build_header_rows::<T, _>(is_hidden);
+        let count_header_rows = records.len();
+
+        let ctrl = CfgWidthFunction::new(4);
+        for row in iter {
+            let mut list = vec![CellInfo::default(); count_columns];
+            let fields = row
+                .fields()
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| !is_hidden(*i));
+            for ((_, text), cell) in fields.zip(list.iter_mut()) {
                 CellMut::set(cell, text.into_owned(), &ctrl);
             }
 
             records.push(list);
         }
 
-        let mut b = Builder::custom(VecRecords::from(records));
-        b.with_header();
-        b.build()
+        build_tabled_table(records, count_header_rows)
+    }
+
+    /// Creates a Table instance from owned items as well as from references to them.
+    ///
+    /// It's the same as [`Table::new`],
+    /// so an iterator of references doesn't need to be adapted to owned values.
+    /// Texts of cells are copied, so the table doesn't borrow the items;
+    /// collecting references via [`FromIterator`] keeps borrowed texts instead.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = vec![("Hello", 1), ("World", 2)];
+    ///
+    /// let borrowed = Table::with_iter(data.iter().filter(|(_, i)| *i > 1)).to_string();
+    /// let owned = Table::with_iter(data.into_iter().filter(|(_, i)| *i > 1)).to_string();
+    ///
+    /// assert_eq!(borrowed, owned);
+    /// ```
+    pub fn with_iter<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        Self::new(iter)
+    }
+
+    /// Creates a Table instance where records are laid out vertically.
+    ///
+    /// The first column contains field names of [`Tabled`],
+    /// and each following column contains the values of a single record.
+    /// So a single record is rendered as a list of `field | value` rows,
+    /// like the expanded mode of `psql`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "derive", doc = "```")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// use tabled::{Table, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Language {
+    ///     name: &'static str,
+    ///     designed_by: &'static str,
+    ///     invented_year: usize,
+    /// }
+    ///
+    /// let language = Language { name: "C", designed_by: "Dennis Ritchie", invented_year: 1972 };
+    ///
+    /// let table = Table::kv([language]).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---------------+----------------+\n\
+    ///      | name          | C              |\n\
+    ///      +---------------+----------------+\n\
+    ///      | designed_by   | Dennis Ritchie |\n\
+    ///      +---------------+----------------+\n\
+    ///      | invented_year | 1972           |\n\
+    ///      +---------------+----------------+"
+    /// );
+    /// ```
+    pub fn kv<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        let ctrl = CfgWidthFunction::new(4);
+
+        let mut records = vec![vec![CellInfo::default()]; T::LENGTH];
+        for (text, row) in T::headers().into_iter().zip(records.iter_mut()) {
+            CellMut::set(&mut row[0], text, &ctrl);
+        }
+
+        for record in iter.into_iter() {
+            let mut fields = record.fields().into_iter();
+            for row in &mut records {
+                let text = fields.next().unwrap_or_default().into_owned();
+                row.push(CellInfo::new(text, &ctrl));
+            }
+        }
+
+        Builder::custom(VecRecords::from(records)).build()
     }
 }
 
@@ -185,10 +355,43 @@ impl Table<()> {
 
         let mut b = Builder::from(records);
         b.hint_column_size(T::LENGTH);
-        b.set_columns(T::headers());
+
+        let mut header = build_group_rows(T::header_groups());
+        header.push(T::headers());
+        b.set_header_rows(header);
 
         b
     }
+
+    /// Creates a [`Table`] from a comma separated data, using the first row as a header.
+    ///
+    /// It's a shortcut for [`CsvReader`] with default settings,
+    /// use it directly to read TSV or data without a header.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::from_csv("name,version\ntabled,0.10.0".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+---------+\n\
+    ///      | name   | version |\n\
+    ///      +--------+---------+\n\
+    ///      | tabled | 0.10.0  |\n\
+    ///      +--------+---------+"
+    /// );
+    /// ```
+    ///
+    /// [`CsvReader`]: crate::csv::CsvReader
+    #[cfg(feature = "csv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn from_csv<R>(reader: R) -> std::io::Result<Table>
+    where
+        R: std::io::Read,
+    {
+        Builder::from_csv(reader).map(Builder::build)
+    }
 }
 
 impl<R> Table<R> {
@@ -198,7 +401,11 @@ impl<R> Table<R> {
     }
 
     /// Get a reference to the table's cfg.
+    ///
+    /// Widths of all columns are measured again after it,
+    /// as a change made through it may affect any of them.
     pub fn get_config_mut(&mut self) -> &mut GridConfig {
+        self.layout.invalidate();
         &mut self.cfg
     }
 
@@ -208,7 +415,23 @@ impl<R> Table<R> {
     }
 
     /// Get a reference to the table's records.
+    ///
+    /// Widths of all columns are measured again after it,
+    /// as a change made through it may affect any of them.
     pub fn get_records_mut(&mut self) -> &mut R {
+        self.layout.invalidate();
+        &mut self.records
+    }
+
+    /// Returns the table's cfg without resetting measured widths of columns,
+    /// so a caller must destroy the caches which its change affects.
+    pub(crate) fn get_config_mut_raw(&mut self) -> &mut GridConfig {
+        &mut self.cfg
+    }
+
+    /// Returns the table's records without resetting measured widths of columns,
+    /// so a caller must destroy the caches which its change affects.
+    pub(crate) fn get_records_mut_raw(&mut self) -> &mut R {
         &mut self.records
     }
 
@@ -228,7 +451,7 @@ impl<R> Table<R> {
     /// It's `true` when [`Table::new`] and [`Table::builder`] is used.
     /// In many other cases it's `false`.
     pub fn has_header(&self) -> bool {
-        self.has_header
+        self.header_rows > 0
     }
 
     pub(crate) fn cache_width(&mut self, widths: Vec<usize>) {
@@ -237,6 +460,14 @@ impl<R> Table<R> {
 
     pub(crate) fn destroy_width_cache(&mut self) {
         self.widths = None;
+        self.layout.invalidate();
+    }
+
+    /// Destroys a width cache of columns which an entity is located in,
+    /// so the rest of columns aren't measured again.
+    pub(crate) fn destroy_width_cache_of(&mut self, entity: Entity) {
+        self.widths = None;
+        self.layout.invalidate_entity(entity);
     }
 
     pub(crate) fn cache_height(&mut self, widths: Vec<usize>) {
@@ -247,8 +478,62 @@ impl<R> Table<R> {
         self.heights = None;
     }
 
-    pub(crate) fn set_header_flag(&mut self, has_header: bool) {
-        self.has_header = has_header;
+    pub(crate) fn set_header_rows(&mut self, count: usize) {
+        self.header_rows = count;
+    }
+
+    pub(crate) fn add_caption(&mut self, caption: Caption) {
+        self.captions.push(caption);
+    }
+
+    pub(crate) fn set_header_position(&mut self, position: HeaderPosition) {
+        self.header_position = position;
+    }
+
+    pub(crate) fn set_accessible(&mut self, accessible: Option<Accessible>) {
+        self.accessible = accessible;
+    }
+
+    /// Sets a justification of cells, `None` removes it.
+    pub(crate) fn set_justification(
+        &mut self,
+        entity: Entity,
+        justification: Option<Justification>,
+    ) {
+        if justification.is_none() && self.justifications.is_empty() {
+            return;
+        }
+
+        if entity == Entity::Global {
+            self.justifications.clear();
+            if justification.is_none() {
+                return;
+            }
+        }
+
+        self.justifications.push((entity, justification));
+    }
+
+    #[cfg(feature = "color")]
+    pub(crate) fn set_stripe(&mut self, stripe: Stripe) {
+        self.stripe = Some(stripe);
+    }
+
+    /// Remembers that a cell contains a rendered inner table.
+    pub(crate) fn set_inner_table(&mut self, pos: Position, table: Table, text: String) {
+        self.inner_tables.retain(|(p, _, _)| *p != pos);
+        self.inner_tables.push((pos, table, text));
+    }
+
+    /// Returns an inner table of a cell.
+    ///
+    /// It's returned only if a cell still contains the rendered table,
+    /// so a table is not used when a cell was changed or moved.
+    pub(crate) fn get_inner_table(&self, pos: Position, text: &str) -> Option<&Table> {
+        self.inner_tables
+            .iter()
+            .find(|(p, _, rendered)| *p == pos && rendered == text)
+            .map(|(_, table, _)| table)
     }
 }
 
@@ -272,12 +557,217 @@ where
         self.get_records().count_columns()
     }
 
-    /// Returns a table shape (count rows, count columns).
+    /// Checks whether a table has no cells.
+    ///
+    /// Notice that a table which consists only of a header is not empty,
+    /// though its [`Table::len`] is 0.
     pub fn is_empty(&self) -> bool {
         let (count_rows, count_cols) = self.shape();
         count_rows == 0 || count_cols == 0
     }
 
+    /// Returns an amount of data rows in the table, not counting a header.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello", "World"]);
+    ///
+    /// assert_eq!(table.len(), 2);
+    /// assert_eq!(table.count_rows(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.count_rows() - self.count_header_rows()
+    }
+
+    /// Returns an amount of rows a header consists of.
+    ///
+    /// It's more than 1 for a header with groups of columns,
+    /// like the one set by [`Builder::set_header_rows`] or by `#[tabled(inline, group)]`,
+    /// in which case the last header row holds names of columns.
+    /// It's 0 if a table has no header.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, Table};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder
+    ///     .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+    ///     .add_record(["apple", "10", "12"]);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(table.count_header_rows(), 2);
+    /// assert_eq!(table.len(), 1);
+    /// assert_eq!(Table::new([1, 2]).count_header_rows(), 1);
+    /// ```
+    ///
+    /// [`Builder::set_header_rows`]: crate::builder::Builder::set_header_rows
+    pub fn count_header_rows(&self) -> usize {
+        std::cmp::min(self.header_rows, self.count_rows())
+    }
+
+    /// Returns a header of the table if it has one.
+    ///
+    /// If a header has groups of columns (see [`Table::count_header_rows`]),
+    /// names of columns are returned rather than groups.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([(1, "a")]);
+    ///
+    /// assert_eq!(table.header(), Some(vec!["i32", "&str"]));
+    /// ```
+    pub fn header(&self) -> Option<Vec<&str>> {
+        let shape = self.shape();
+        match self.count_header_rows() {
+            0 => None,
+            1 => Some(get_row(&self.records, 0)),
+            count => {
+                // a name of a column is the lowest header cell over it,
+                // as a cell which has nothing below is spanned down to a last header row.
+                let header = (0..shape.1).map(|col| {
+                    header_cells(&self.cfg, shape, count, col)
+                        .rev()
+                        .map(|pos| self.records.get_text(pos))
+                        .find(|text| !text.is_empty())
+                        .unwrap_or("")
+                });
+
+                Some(header.collect())
+            }
+        }
+    }
+
+    /// Returns an iterator over data rows of the table, not including a header.
+    ///
+    /// Each row is a list of texts of its cells,
+    /// as they are at the moment, after all applied settings.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([(1, "a"), (2, "b")]);
+    /// let rows = table.rows().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(rows, [["1", "a"], ["2", "b"]]);
+    /// ```
+    pub fn rows(&self) -> TableRows<'_, R> {
+        TableRows {
+            records: &self.records,
+            row: self.count_header_rows(),
+            end: self.count_rows(),
+        }
+    }
+
+    /// Copies a segment of the table into a new independent [`Table`].
+    ///
+    /// Unlike [`Extract`] it doesn't change the table,
+    /// and the result owns its content so it can be modified, combined or embedded into other tables.
+    ///
+    /// Settings of cells and spans which lay inside the segment are kept.
+    /// Ranges which exceed the table are cut to its shape.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new([(1, "a", true), (2, "b", false)]);
+    /// let slice = table.slice(1.., 1..).with(Style::psql()).to_string();
+    ///
+    /// assert_eq!(
+    ///     slice,
+    ///     concat!(
+    ///         " a | true  \n",
+    ///         "---+-------\n",
+    ///         " b | false ",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Extract`]: crate::Extract
+    pub fn slice<RB, CB>(&self, rows: RB, columns: CB) -> Table
+    where
+        RB: RangeBounds<usize>,
+        CB: RangeBounds<usize>,
+    {
+        let (count_rows, count_cols) = self.shape();
+        let (start_row, end_row) =
+            bounds_to_usize(rows.start_bound(), rows.end_bound(), count_rows);
+        let (start_col, end_col) =
+            bounds_to_usize(columns.start_bound(), columns.end_bound(), count_cols);
+
+        let end_row = std::cmp::min(end_row, count_rows);
+        let end_col = std::cmp::min(end_col, count_cols);
+        let start_row = std::cmp::min(start_row, end_row);
+        let start_col = std::cmp::min(start_col, end_col);
+
+        let shape = (end_row - start_row, end_col - start_col);
+
+        let data = (start_row..end_row).map(|row| {
+            (start_col..end_col)
+                .map(|col| self.records.get_text((row, col)).to_owned())
+                .collect::<Vec<_>>()
+        });
+
+        let mut cfg = self.cfg.clone();
+        copy_cell_settings(&mut cfg, &self.cfg, shape, |(row, col)| {
+            (row + start_row, col + start_col)
+        });
+        slice_spans(
+            &mut cfg,
+            &self.cfg,
+            self.shape(),
+            (start_row, end_row),
+            (start_col, end_col),
+        );
+
+        let mut table = Builder::from_iter(data).build();
+        *table.get_config_mut() = cfg;
+        if start_row == 0 {
+            table.set_header_rows(std::cmp::min(self.count_header_rows(), shape.0));
+        }
+
+        table
+    }
+
+    /// Returns a look of the table which can be applied to other tables,
+    /// so sub-tables built from it look consistent without reapplying every setting.
+    ///
+    /// See [`DerivedConfig`] for what is carried.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let mut table = Table::new([(1, "a"), (2, "b")]);
+    /// table.with(Style::markdown());
+    ///
+    /// let slice = table.slice(1.., ..).with(table.derive_config()).to_string();
+    ///
+    /// assert_eq!(
+    ///     slice,
+    ///     concat!(
+    ///         "| 1 | a |\n",
+    ///         "|---|---|\n",
+    ///         "| 2 | b |",
+    ///     )
+    /// );
+    /// ```
+    pub fn derive_config(&self) -> DerivedConfig {
+        #[allow(unused_mut)]
+        let mut config = DerivedConfig::new(
+            self.cfg.clone(),
+            self.shape(),
+            self.count_header_rows(),
+            self.header_position,
+        );
+
+        #[cfg(feature = "color")]
+        config.set_stripe(self.stripe.clone());
+
+        config
+    }
+
     /// Returns total widths of a table, including margin and vertical lines.
     pub fn total_width(&self) -> usize {
         let ctrl = self.get_width_ctrl();
@@ -290,13 +780,159 @@ where
         get_table_total_height(&self.records, &self.cfg, &ctrl)
     }
 
+    /// Builds a GitHub flavored markdown representation of the table.
+    ///
+    /// The first row is used as a header.
+    /// Pipes (`|`) inside cells are escaped and multiline cells are joined by `<br>`.
+    /// A column alignment marker is derived from a configured [`Alignment`] of a first data cell.
+    ///
+    /// Spans, borders, padding and margin are not respected as markdown doesn't support them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, Alignment, ModifyObject, object::Columns};
+    ///
+    /// let data = [("Hello|World", 1), ("multi\nline", 100)];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Columns::last().modify().with(Alignment::right()));
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     concat!(
+    ///         "| &str          | i32 |\n",
+    ///         "|---------------|----:|\n",
+    ///         "| Hello\\|World  |   1 |\n",
+    ///         "| multi<br>line | 100 |",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Alignment`]: crate::Alignment
+    pub fn to_markdown(&self) -> String {
+        let mut buf = String::new();
+        write_markdown(&mut buf, &self.records, &self.cfg)
+            .expect("writing to a String is not expected to fail");
+        buf
+    }
+
+    /// Describes a resolved layout of the table in JSON,
+    /// so an external renderer (like a web page or a PDF generator) could reproduce the table.
+    ///
+    /// It's a compact JSON object with the following fields:
+    ///
+    /// - `version` - a version of the format, it's increased on a breaking change;
+    /// - `rows`, `columns` - a shape of the table;
+    /// - `header` - whether the first row is a header;
+    /// - `widths`, `heights` - sizes of columns and rows including padding but not borders;
+    /// - `horizontal_lines`, `vertical_lines` - indexes of lines which are present;
+    /// - `margin` - a margin of the table;
+    /// - `cells` - a list of visible cells with their
+    ///   `row`, `column`, `row_span`, `column_span`, `text`, `alignment`, `padding` and `border`.
+    ///
+    /// Cells covered by a span are not listed.
+    /// Colors, captions and a header position are not described.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, Style};
+    ///
+    /// let mut table = Table::new([["Hello"]]);
+    /// table.with(Style::blank());
+    ///
+    /// let json: serde_json::Value = serde_json::from_str(&table.layout_json()).unwrap();
+    ///
+    /// assert_eq!(json["version"], 1);
+    /// assert_eq!(json["rows"], 2);
+    /// assert_eq!(json["widths"], serde_json::json!([7]));
+    /// assert_eq!(json["cells"][1]["text"], "Hello");
+    /// assert_eq!(json["cells"][1]["alignment"]["horizontal"], "left");
+    /// ```
+    pub fn layout_json(&self) -> String {
+        let widths = collect_estimation::<&R, _>(&self.get_width_ctrl(), self.count_columns());
+        let heights = collect_estimation::<&R, _>(&self.get_height_ctrl(), self.count_rows());
+
+        let mut buf = String::new();
+        write_layout_json(
+            &mut buf,
+            &self.records,
+            &self.cfg,
+            &widths,
+            &heights,
+            self.has_header(),
+        )
+        .expect("writing to a String is not expected to fail");
+        buf
+    }
+
+    /// Builds a HTML `<table>` representation of the table.
+    ///
+    /// It's a shortcut for [`HtmlTable`] with default settings,
+    /// use it directly for more control over the output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, Span, ModifyObject, object::Cell};
+    ///
+    /// let mut table = Table::new([("Hello", "World")]);
+    /// table.with(Cell(1, 0).modify().with(Span::column(2)));
+    ///
+    /// assert_eq!(
+    ///     table.to_html(),
+    ///     concat!(
+    ///         "<table>\n",
+    ///         "    <thead>\n",
+    ///         "        <tr>\n",
+    ///         "            <th>&amp;str</th>\n",
+    ///         "            <th>&amp;str</th>\n",
+    ///         "        </tr>\n",
+    ///         "    </thead>\n",
+    ///         "    <tbody>\n",
+    ///         "        <tr>\n",
+    ///         "            <td colspan=\"2\">Hello</td>\n",
+    ///         "        </tr>\n",
+    ///         "    </tbody>\n",
+    ///         "</table>",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`HtmlTable`]: crate::html::HtmlTable
+    #[cfg(feature = "html")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
+    pub fn to_html(&self) -> String {
+        crate::html::HtmlTable::new(self).to_string()
+    }
+
+    /// Returns widths of columns measured by their content,
+    /// only the columns which were changed since the last call are measured again.
+    pub(crate) fn measure_widths(&mut self) -> Vec<usize> {
+        self.layout.update(&self.records, &self.cfg)
+    }
+
+    /// Returns widths of columns measured only by cells which don't span over several columns.
+    pub(crate) fn measure_span_free_widths(&self) -> Vec<usize> {
+        self.layout.measure_span_free(&self.records, &self.cfg)
+    }
+
+    /// Returns widths of columns a table is printed with,
+    /// which are the ones set by a width option if there are any.
+    pub(crate) fn get_widths(&mut self) -> Vec<usize> {
+        match &self.widths {
+            Some(widths) => widths.clone(),
+            None => self.measure_widths(),
+        }
+    }
+
     fn get_width_ctrl(&self) -> CachedEstimator<'_, WidthEstimator> {
         match &self.widths {
             Some(widths) => CachedEstimator::Cached(widths),
             None => {
-                let mut w = WidthEstimator::default();
-                w.estimate(&self.records, &self.cfg);
-                CachedEstimator::Ctrl(w)
+                let widths = self.layout.measure(&self.records, &self.cfg);
+                CachedEstimator::Ctrl(WidthEstimator::from(widths))
             }
         }
     }
@@ -334,14 +970,122 @@ where
     R: Records,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(accessible) = &self.accessible {
+            return write_accessible(
+                f,
+                &self.records,
+                &self.cfg,
+                self.count_header_rows(),
+                &self.captions,
+                accessible,
+            );
+        }
+
         let mut cfg = Cow::Borrowed(&self.cfg);
         set_align_table(f, &mut cfg);
         set_width_table(f, &mut cfg, self);
 
+        if self.captions.is_empty() {
+            return self.print(f, &cfg);
+        }
+
+        let width = caption::table_width(self);
+        let top = self.captions.iter().filter(|c| c.is_top());
+        let bottom = self.captions.iter().filter(|c| !c.is_top());
+        let has_top = top.clone().next().is_some();
+        let has_bottom = bottom.clone().next().is_some();
+
+        if has_top {
+            let captions = caption::build_captions_table(top, &cfg, width, true);
+            writeln!(f, "{}", captions)?;
+        }
+
+        let mut table_cfg = cfg.clone().into_owned();
+        caption::strip_margin(&mut table_cfg, has_top, has_bottom);
+        self.print(f, &table_cfg)?;
+
+        if has_bottom {
+            let captions = caption::build_captions_table(bottom, &cfg, width, false);
+            write!(f, "\n{}", captions)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R> Table<R>
+where
+    R: Records,
+{
+    fn print(&self, f: &mut fmt::Formatter<'_>, cfg: &GridConfig) -> fmt::Result {
         let width = self.get_width_ctrl();
         let height = self.get_height_ctrl();
 
-        let grid = Grid::new(&self.records, &cfg, &width, &height);
+        if !self.justifications.is_empty() {
+            let widths = collect_estimation::<&R, _>(&width, self.count_columns());
+            let heights = collect_estimation::<&R, _>(&height, self.count_rows());
+            let mut table = alignment::build_justified_table(
+                &self.records,
+                cfg,
+                &widths,
+                &heights,
+                &self.justifications,
+            );
+            table.header_rows = self.header_rows;
+            table.header_position = self.header_position;
+            #[cfg(feature = "color")]
+            {
+                table.stripe = self.stripe.clone();
+            }
+
+            return table.print(f, table.get_config());
+        }
+
+        let moves_header = self.header_position != HeaderPosition::Top;
+        let count_header_rows = self.count_header_rows();
+        if moves_header && count_header_rows > 0 && self.count_rows() > count_header_rows {
+            let widths = collect_estimation::<&R, _>(&width, self.count_columns());
+            let heights = collect_estimation::<&R, _>(&height, self.count_rows());
+            #[allow(unused_mut)]
+            let mut table = header_position::build_moved_header_table(
+                &self.records,
+                cfg,
+                &widths,
+                &heights,
+                self.header_position,
+                count_header_rows,
+            );
+
+            #[cfg(feature = "color")]
+            if let Some(stripe) = &self.stripe {
+                table.set_stripe(stripe.clone());
+            }
+
+            return table.print(f, table.get_config());
+        }
+
+        #[cfg(feature = "color")]
+        if let Some(stripe) = &self.stripe {
+            let widths = collect_estimation::<&R, _>(&width, self.count_columns());
+            let heights = collect_estimation::<&R, _>(&height, self.count_rows());
+            let table = stripe::build_striped_table(
+                &self.records,
+                cfg,
+                &widths,
+                &heights,
+                stripe,
+                count_header_rows,
+            );
+            return table.print(f, table.get_config());
+        }
+
+        if is_fast_render_applicable(cfg, &self.records) {
+            let widths = collect_estimation::<&R, _>(&width, self.count_columns());
+            let heights = collect_estimation::<&R, _>(&height, self.count_rows());
+            return print_grid(f, cfg, &self.records, &widths, &heights);
+        }
+
+        let grid = Grid::new(&self.records, cfg, &width, &height);
 
         write!(f, "{}", grid)
     }
@@ -355,9 +1099,17 @@ where
         Self {
             records,
             cfg: GridConfig::default(),
-            has_header: false,
+            header_rows: 0,
             widths: None,
             heights: None,
+            layout: WidthLayout::default(),
+            inner_tables: Vec::new(),
+            captions: Vec::new(),
+            header_position: HeaderPosition::Top,
+            accessible: None,
+            justifications: Vec::new(),
+            #[cfg(feature = "color")]
+            stripe: None,
         }
     }
 }
@@ -372,12 +1124,9 @@ where
     {
         let ctrl = CfgWidthFunction::new(4);
 
-        let mut header = vec![CellInfo::default(); T::LENGTH];
-        for (text, cell) in T::headers().into_iter().zip(header.iter_mut()) {
-            CellMut::set(cell, text, &ctrl);
-        }
+        let mut records = build_header_rows::<T, _>(|_| false);
+        let count_header_rows = records.len();
 
-        let mut records = vec![header];
         for row in iter.into_iter() {
             let mut list = vec![CellInfo::default(); T::LENGTH];
             for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
@@ -387,9 +1136,245 @@ where
             records.push(list);
         }
 
-        let mut b = Builder::custom(VecRecords::from(records));
-        b.with_header();
-        b.build()
+        build_tabled_table(records, count_header_rows)
+    }
+}
+
+/// Builds rows of a header of a [`Tabled`] type, leaving out hidden columns.
+///
+/// Rows of groups of columns go first, and names of columns are the last row.
+fn build_header_rows<'a, T, F>(is_hidden: F) -> Vec<Vec<CellInfo<'a>>>
+where
+    T: Tabled,
+    F: Fn(usize) -> bool,
+{
+    let ctrl = CfgWidthFunction::new(4);
+
+    let groups = T::header_groups()
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !is_hidden(*i))
+        .map(|(_, groups)| groups);
+    let header = T::headers()
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !is_hidden(*i))
+        .map(|(_, text)| text)
+        .collect::<Vec<_>>();
+
+    build_group_rows(groups)
+        .into_iter()
+        .chain(Some(header))
+        .map(|row| {
+            row.into_iter()
+                .map(|text| CellInfo::new(text, &ctrl))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn build_tabled_table(
+    records: Vec<Vec<CellInfo<'_>>>,
+    count_header_rows: usize,
+) -> Table<VecRecords<CellInfo<'_>>> {
+    let mut b = Builder::custom(VecRecords::from(records));
+    b.with_header();
+
+    let mut table = b.build();
+    span_header_rows(&mut table, count_header_rows);
+    table.set_header_rows(count_header_rows);
+
+    table
+}
+
+/// An iterator over rows of a [`Table`].
+///
+/// It's created by [`Table::rows`].
+#[derive(Debug, Clone)]
+pub struct TableRows<'a, R> {
+    records: &'a R,
+    row: usize,
+    end: usize,
+}
+
+impl<'a, R> Iterator for TableRows<'a, R>
+where
+    R: Records,
+{
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.end {
+            return None;
+        }
+
+        let row = get_row(self.records, self.row);
+        self.row += 1;
+
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.row;
+        (len, Some(len))
+    }
+}
+
+impl<R> ExactSizeIterator for TableRows<'_, R> where R: Records {}
+
+/// Lays out groups of columns (see [`Tabled::header_groups`]) as rows,
+/// where a column without a group at some level gets an empty cell.
+fn build_group_rows<I>(groups: I) -> Vec<Vec<Cow<'static, str>>>
+where
+    I: IntoIterator<Item = Vec<Cow<'static, str>>>,
+{
+    let groups = groups.into_iter().collect::<Vec<_>>();
+    let depth = groups.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..depth)
+        .map(|level| {
+            groups
+                .iter()
+                .map(|groups| groups.get(level).cloned().unwrap_or_default())
+                .collect()
+        })
+        .collect()
+}
+
+fn get_row<R>(records: &R, row: usize) -> Vec<&str>
+where
+    R: Records,
+{
+    (0..records.count_columns())
+        .map(|col| records.get_text((row, col)))
+        .collect()
+}
+
+/// Returns positions of cells of the first `count_header_rows` rows which cover a given column,
+/// from the top one.
+///
+/// A cell spanned over a few columns covers each of them.
+pub(crate) fn header_cells(
+    cfg: &GridConfig,
+    shape: (usize, usize),
+    count_header_rows: usize,
+    col: usize,
+) -> impl DoubleEndedIterator<Item = Position> + '_ {
+    (0..count_header_rows).filter_map(move |row| {
+        let origin = (0..=col)
+            .rev()
+            .find(|&c| cfg.is_cell_visible((row, c), shape))?;
+        let span = cfg.get_column_span((row, origin), shape).unwrap_or(1);
+
+        if origin + span > col {
+            Some((row, origin))
+        } else {
+            None
+        }
+    })
+}
+
+/// Copies settings of cells of a `cfg` to a `new_cfg`,
+/// where a cell of a `new_cfg` gets settings of a cell which `source` returns.
+///
+/// `shape` is a shape of a `new_cfg`.
+pub(crate) fn copy_cell_settings<F>(
+    new_cfg: &mut GridConfig,
+    cfg: &GridConfig,
+    (count_rows, count_cols): (usize, usize),
+    source: F,
+) where
+    F: Fn(Position) -> Position,
+{
+    for row in 0..count_rows {
+        for col in 0..count_cols {
+            let src = source((row, col));
+            if src == (row, col) {
+                continue;
+            }
+
+            copy_cell_config(new_cfg, (row, col), cfg, src);
+        }
+    }
+}
+
+/// Copies settings of a `src` cell of a `cfg` to a `dst` cell of a `new_cfg`.
+///
+/// Only the settings which differ from the ones a `dst` cell already has are set.
+pub(crate) fn copy_cell_config(
+    new_cfg: &mut GridConfig,
+    dst: Position,
+    cfg: &GridConfig,
+    src: Position,
+) {
+    let src = Entity::from(src);
+    let dst = Entity::from(dst);
+
+    let padding = *cfg.get_padding(src);
+    if &padding != new_cfg.get_padding(dst) {
+        new_cfg.set_padding(dst, padding);
+    }
+
+    let alignment = *cfg.get_alignment_horizontal(src);
+    if &alignment != new_cfg.get_alignment_horizontal(dst) {
+        new_cfg.set_alignment_horizontal(dst, alignment);
+    }
+
+    let alignment = *cfg.get_alignment_vertical(src);
+    if &alignment != new_cfg.get_alignment_vertical(dst) {
+        new_cfg.set_alignment_vertical(dst, alignment);
+    }
+
+    let formatting = *cfg.get_formatting(src);
+    if &formatting != new_cfg.get_formatting(dst) {
+        new_cfg.set_formatting(dst, formatting);
+    }
+
+    #[cfg(feature = "color")]
+    {
+        let color = cfg.get_padding_color(src);
+        if color != new_cfg.get_padding_color(dst) {
+            let color = crate::color::to_static_padding_color(color);
+            new_cfg.set_padding_color(dst, color);
+        }
+    }
+}
+
+/// Moves spans which start inside a segment to its new position, cutting them by the segment.
+fn slice_spans(
+    new_cfg: &mut GridConfig,
+    cfg: &GridConfig,
+    shape: (usize, usize),
+    (start_row, end_row): (usize, usize),
+    (start_col, end_col): (usize, usize),
+) {
+    let column_spans = cfg.iter_column_spans(shape).collect::<Vec<_>>();
+    let row_spans = cfg.iter_row_spans(shape).collect::<Vec<_>>();
+
+    for &(pos, _) in &column_spans {
+        new_cfg.set_column_span(pos, 1);
+    }
+
+    for &(pos, _) in &row_spans {
+        new_cfg.set_row_span(pos, 1);
+    }
+
+    let is_inside = |(row, col): Position| {
+        (start_row..end_row).contains(&row) && (start_col..end_col).contains(&col)
+    };
+
+    for ((row, col), span) in column_spans {
+        if is_inside((row, col)) {
+            let span = std::cmp::min(span, end_col - col);
+            new_cfg.set_column_span((row - start_row, col - start_col), span);
+        }
+    }
+
+    for ((row, col), span) in row_spans {
+        if is_inside((row, col)) {
+            let span = std::cmp::min(span, end_row - row);
+            new_cfg.set_row_span((row - start_row, col - start_col), span);
+        }
     }
 }
 
@@ -421,6 +1406,13 @@ where
     }
 }
 
+fn collect_estimation<R, E>(estimation: &E, count: usize) -> Vec<usize>
+where
+    E: Estimate<R>,
+{
+    (0..count).map(|i| estimation.get(i).unwrap_or(0)).collect()
+}
+
 fn set_align_table(f: &fmt::Formatter<'_>, cfg: &mut Cow<'_, GridConfig>) {
     if let Some(alignment) = f.align() {
         let alignment = convert_fmt_alignment(alignment);
@@ -18,6 +18,28 @@ pub trait Tabled {
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+    /// Hideable returns which columns can be hidden at runtime,
+    /// like a column marked by `#[tabled(hideable)]`.
+    ///
+    /// It's used as a mask for [`Table::new_masked`].
+    /// By default none of columns is hideable.
+    ///
+    /// [`Table::new_masked`]: crate::Table::new_masked
+    fn hideable() -> Vec<bool> {
+        vec![false; Self::LENGTH]
+    }
+    /// Header groups return names of groups each column belongs to,
+    /// starting from the outermost one,
+    /// like a field marked by `#[tabled(inline, group)]`.
+    ///
+    /// Groups are put in rows above a header by [`Table::new`],
+    /// with a group spanned over all its columns.
+    /// By default none of columns is grouped.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn header_groups() -> Vec<Vec<Cow<'static, str>>> {
+        vec![Vec::new(); Self::LENGTH]
+    }
 }
 
 impl<T> Tabled for &T
@@ -32,6 +54,12 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn hideable() -> Vec<bool> {
+        T::hideable()
+    }
+    fn header_groups() -> Vec<Vec<Cow<'static, str>>> {
+        T::header_groups()
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -46,6 +74,12 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn hideable() -> Vec<bool> {
+        T::hideable()
+    }
+    fn header_groups() -> Vec<Vec<Cow<'static, str>>> {
+        T::header_groups()
+    }
 }
 
 macro_rules! tuple_table {
@@ -66,6 +100,18 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn hideable() -> Vec<bool> {
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name::hideable());)+
+                fields
+            }
+
+            fn header_groups() -> Vec<Vec<Cow<'static, str>>> {
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name::header_groups());)+
+                fields
+            }
         }
     };
 }
@@ -11,7 +11,19 @@ pub struct Attributes {
     pub rename_all: Option<CasingStyle>,
     pub display_with: Option<String>,
     pub display_with_use_self: bool,
+    pub option_fmt: Option<String>,
     pub order: Option<usize>,
+    pub hideable: bool,
+    pub inline_list: Option<InlineList>,
+    pub group: bool,
+    pub group_name: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct InlineList {
+    pub separator: Option<String>,
+    pub columns: Option<usize>,
+    pub prefix: Option<String>,
 }
 
 impl Attributes {
@@ -57,7 +69,19 @@ impl Attributes {
                 self.display_with = Some(path.value());
                 self.display_with_use_self = use_self;
             }
+            parse::TabledAttrKind::OptionFmt(value) => self.option_fmt = Some(value.value()),
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            parse::TabledAttrKind::Hideable(b) => self.hideable = b.value,
+            parse::TabledAttrKind::Group(name) => {
+                self.group = true;
+                self.group_name = name.map(|name| name.value());
+            }
+            parse::TabledAttrKind::InlineList(args) => {
+                let list = self.inline_list.get_or_insert_with(InlineList::default);
+                for arg in args {
+                    list.insert_argument(arg)?;
+                }
+            }
         }
 
         Ok(())
@@ -68,8 +92,33 @@ impl Attributes {
     }
 }
 
+impl InlineList {
+    fn insert_argument(&mut self, arg: parse::InlineListArg) -> Result<(), Error> {
+        match arg {
+            parse::InlineListArg::Separator(lit) => self.separator = Some(lit.value()),
+            parse::InlineListArg::Prefix(lit) => self.prefix = Some(lit.value()),
+            parse::InlineListArg::Columns(lit) => {
+                let columns = lit_int_to_usize(&lit)?;
+                if columns == 0 {
+                    return Err(Error::new(
+                        "A number of inline_list columns must be greater than 0",
+                        lit.span(),
+                        None,
+                    ));
+                }
+
+                self.columns = Some(columns);
+            }
+        }
+
+        Ok(())
+    }
+}
+
 pub struct ObjectAttributes {
     pub rename_all: Option<CasingStyle>,
+    pub option_fmt: Option<String>,
+    pub hideable: bool,
 }
 
 impl ObjectAttributes {
@@ -77,6 +126,8 @@ impl ObjectAttributes {
         let attrs = Attributes::parse(attrs)?;
         Ok(Self {
             rename_all: attrs.rename_all,
+            option_fmt: attrs.option_fmt,
+            hideable: attrs.hideable,
         })
     }
 }
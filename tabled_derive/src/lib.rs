@@ -12,11 +12,11 @@ use proc_macro_error::proc_macro_error;
 use quote::{quote, ToTokens, TokenStreamExt};
 use std::{collections::HashMap, str};
 use syn::{
-    parse_macro_input, token, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Ident, Index,
-    Type, Variant,
+    parse_macro_input, spanned::Spanned, token, Data, DataEnum, DataStruct, DeriveInput, Field,
+    Fields, Ident, Index, Type, Variant,
 };
 
-use attributes::{Attributes, ObjectAttributes};
+use attributes::{Attributes, InlineList, ObjectAttributes};
 use error::Error;
 
 #[proc_macro_derive(Tabled, attributes(tabled))]
@@ -36,6 +36,8 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let info = collect_info(ast, &attrs).map_err(error::abort).unwrap();
     let fields = info.values;
     let headers = info.headers;
+    let hideable = info.hideable;
+    let header_groups = info.header_groups;
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -50,6 +52,14 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            fn hideable() -> Vec<bool> {
+                #hideable
+            }
+
+            fn header_groups() -> Vec<Vec<::std::borrow::Cow<'static, str>>> {
+                #header_groups
+            }
         }
     };
 
@@ -78,6 +88,8 @@ fn get_fields_length(fields: &Fields) -> Result<TokenStream, Error> {
             if attr.inline {
                 let field_type = &field.ty;
                 quote!({<#field_type as Tabled>::LENGTH})
+            } else if let Some(columns) = attr.inline_list.as_ref().and_then(|l| l.columns) {
+                quote!({ #columns })
             } else {
                 quote!({ 1 })
             }
@@ -159,12 +171,15 @@ fn info_from_fields(
         .map(|(i, field)| -> Result<_, Error> {
             let mut attributes = Attributes::parse(&field.attrs)?;
             merge_attributes(&mut attributes, attrs);
+            merge_field_attributes(&mut attributes, field, attrs);
 
             Ok((i, field, attributes))
         });
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut hideable = Vec::new();
+    let mut header_groups = Vec::new();
     let mut reorder = HashMap::new();
 
     let mut skipped = 0;
@@ -175,6 +190,24 @@ fn info_from_fields(
             continue;
         }
 
+        if attributes.inline_list.is_some()
+            && (attributes.inline || attributes.display_with.is_some())
+        {
+            return Err(Error::new(
+                "inline_list can't be used together with inline or display_with",
+                field.span(),
+                None,
+            ));
+        }
+
+        if attributes.group && !attributes.inline {
+            return Err(Error::new(
+                "group can be used only together with inline",
+                field.span(),
+                None,
+            ));
+        }
+
         if let Some(order) = attributes.order {
             if order >= count_fields {
                 return Err(Error::message(format!(
@@ -188,15 +221,19 @@ fn info_from_fields(
 
         let header = field_headers(field, i, &attributes, header_prefix);
         headers.push(header);
+        hideable.push(field_hideable(field, &attributes));
+        header_groups.push(field_header_groups(field, i, &attributes));
 
         let field_name = field_name(i, field);
-        let value = get_field_fields(&field_name, &attributes);
+        let value = get_field_fields(&field_name, &field.ty, &attributes);
         values.push(value);
     }
 
     if !reorder.is_empty() {
         values = reorder_fields(&reorder, &values);
         headers = reorder_fields(&reorder, &headers);
+        hideable = reorder_fields(&reorder, &hideable);
+        header_groups = reorder_fields(&reorder, &header_groups);
     }
 
     let headers = quote!({
@@ -211,7 +248,24 @@ fn info_from_fields(
         out
     });
 
-    Ok(Impl { headers, values })
+    let hideable = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#hideable);)*
+        out
+    });
+
+    let header_groups = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#header_groups);)*
+        out
+    });
+
+    Ok(Impl {
+        headers,
+        values,
+        hideable,
+        header_groups,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -257,6 +311,17 @@ fn field_headers(
     }
 
     let header_name = field_header_name(field, attributes, index);
+
+    if let Some(columns) = attributes.inline_list.as_ref().and_then(|l| l.columns) {
+        let list_prefix = attributes
+            .inline_list
+            .as_ref()
+            .and_then(|l| l.prefix.clone())
+            .unwrap_or_else(|| format!("{}_", header_name));
+        let names = (0..columns).map(|i| format!("{}{}{}", prefix, list_prefix, i));
+        return quote!(vec![#(::std::borrow::Cow::Borrowed(#names),)*]);
+    }
+
     if prefix.is_empty() {
         quote!(vec![::std::borrow::Cow::Borrowed(#header_name)])
     } else {
@@ -265,8 +330,62 @@ fn field_headers(
     }
 }
 
+fn field_hideable(field: &Field, attributes: &Attributes) -> TokenStream {
+    let hideable = attributes.hideable;
+    if let Some(columns) = attributes.inline_list.as_ref().and_then(|l| l.columns) {
+        return quote!(vec![#hideable; #columns]);
+    }
+
+    if !attributes.inline {
+        return quote!(vec![#hideable]);
+    }
+
+    let field_type = &field.ty;
+    if hideable {
+        quote!(vec![true; <#field_type as Tabled>::LENGTH])
+    } else {
+        quote!(<#field_type as Tabled>::hideable())
+    }
+}
+
+fn field_header_groups(field: &Field, index: usize, attributes: &Attributes) -> TokenStream {
+    if let Some(columns) = attributes.inline_list.as_ref().and_then(|l| l.columns) {
+        return quote!(vec![Vec::new(); #columns]);
+    }
+
+    if !attributes.inline {
+        return quote!(vec![Vec::new()]);
+    }
+
+    let field_type = &field.ty;
+    let groups = quote!(<#field_type as Tabled>::header_groups());
+    if !attributes.group {
+        return groups;
+    }
+
+    let name = attributes
+        .group_name
+        .clone()
+        .unwrap_or_else(|| field_header_name(field, attributes, index));
+    add_header_group(groups, &name)
+}
+
+fn add_header_group(groups: TokenStream, name: &str) -> TokenStream {
+    quote! {
+        #groups
+            .into_iter()
+            .map(|mut groups| {
+                groups.insert(0, ::std::borrow::Cow::Borrowed(#name));
+                groups
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
 fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, Error> {
     let mut headers_list = Vec::new();
+    let mut hideable_list = Vec::new();
+    let mut header_groups_list = Vec::new();
     let mut variants = Vec::new();
     for variant in &ast.variants {
         let mut attributes = Attributes::parse(&variant.attrs)?;
@@ -278,6 +397,8 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         let info = info_from_variant(variant, &attributes, attrs)?;
         variants.push((variant, info.values));
         headers_list.push(info.headers);
+        hideable_list.push(info.hideable);
+        header_groups_list.push(info.header_groups);
     }
 
     let variant_sizes = get_enum_variant_length(ast)
@@ -292,7 +413,26 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    let hideable = quote! {
+        vec![
+            #(#hideable_list,)*
+        ]
+        .concat()
+    };
+
+    let header_groups = quote! {
+        vec![
+            #(#header_groups_list,)*
+        ]
+        .concat()
+    };
+
+    Ok(Impl {
+        headers,
+        values,
+        hideable,
+        header_groups,
+    })
 }
 
 fn info_from_variant(
@@ -305,7 +445,29 @@ fn info_from_variant(
             .inline_prefix
             .as_ref()
             .map_or_else(|| "", |s| s.as_str());
-        return info_from_fields(&variant.fields, attrs, variant_var_name, prefix);
+        let mut info = info_from_fields(&variant.fields, attrs, variant_var_name, prefix)?;
+        if attributes.hideable {
+            let length = get_fields_length(&variant.fields)?;
+            info.hideable = quote!(vec![true; #length]);
+        }
+
+        if attributes.group {
+            let name = attributes
+                .group_name
+                .clone()
+                .unwrap_or_else(|| variant_name(variant, attributes));
+            info.header_groups = add_header_group(info.header_groups, &name);
+        }
+
+        return Ok(info);
+    }
+
+    if attributes.group {
+        return Err(Error::new(
+            "group can be used only together with inline",
+            variant.span(),
+            None,
+        ));
     }
 
     let variant_name = variant_name(variant, attributes);
@@ -315,13 +477,23 @@ fn info_from_variant(
     let headers = quote! { vec![::std::borrow::Cow::Borrowed(#variant_name)] };
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![::std::borrow::Cow::Borrowed(#value)] };
-
-    Ok(Impl { headers, values })
+    let hideable = attributes.hideable;
+    let hideable = quote! { vec![#hideable] };
+    let header_groups = quote! { vec![Vec::new()] };
+
+    Ok(Impl {
+        headers,
+        values,
+        hideable,
+        header_groups,
+    })
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    hideable: TokenStream,
+    header_groups: TokenStream,
 }
 
 fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> TokenStream {
@@ -339,11 +511,15 @@ fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> Tok
     }
 }
 
-fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
+fn get_field_fields(field: &TokenStream, field_type: &Type, attr: &Attributes) -> TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
     }
 
+    if let Some(list) = &attr.inline_list {
+        return get_inline_list_fields(field, field_type, list);
+    }
+
     if let Some(func) = &attr.display_with {
         let func_call = match attr.display_with_use_self {
             true => use_function_with_self(func),
@@ -353,9 +529,49 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
         return quote!(vec![::std::borrow::Cow::from(#func_call)]);
     }
 
+    if let Some(placeholder) = &attr.option_fmt {
+        return quote! {
+            vec![match &#field {
+                ::std::option::Option::Some(value) => ::std::borrow::Cow::Owned(format!("{}", value)),
+                ::std::option::Option::None => ::std::borrow::Cow::Borrowed(#placeholder),
+            }]
+        };
+    }
+
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
+fn get_inline_list_fields(
+    field: &TokenStream,
+    field_type: &Type,
+    list: &InlineList,
+) -> TokenStream {
+    let separator = list.separator.as_deref().unwrap_or(", ");
+
+    let items = if is_map_type(field_type) {
+        quote! { #field.iter().map(|(key, value)| format!("{}: {}", key, value)) }
+    } else {
+        quote! { #field.iter().map(|item| format!("{}", item)) }
+    };
+
+    match list.columns {
+        Some(columns) => quote! {{
+            let mut items = #items.collect::<Vec<String>>();
+            // items which don't fit are joined into the last column.
+            if items.len() > #columns {
+                let rest = items.split_off(#columns - 1).join(#separator);
+                items.push(rest);
+            }
+
+            items.resize(#columns, String::new());
+            items.into_iter().map(::std::borrow::Cow::Owned).collect::<Vec<_>>()
+        }},
+        None => quote! {
+            vec![::std::borrow::Cow::Owned(#items.collect::<Vec<String>>().join(#separator))]
+        },
+    }
+}
+
 fn use_function_for(field: &TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
@@ -521,4 +737,34 @@ fn merge_attributes(attr: &mut Attributes, global_attr: &ObjectAttributes) {
     if attr.rename_all.is_none() {
         attr.rename_all = global_attr.rename_all;
     }
+
+    if global_attr.hideable {
+        attr.hideable = true;
+    }
+}
+
+fn merge_field_attributes(attr: &mut Attributes, field: &Field, global_attr: &ObjectAttributes) {
+    if attr.option_fmt.is_none() && is_option_type(&field.ty) {
+        attr.option_fmt = global_attr.option_fmt.clone();
+    }
+}
+
+fn is_option_type(ty: &Type) -> bool {
+    is_type_named(ty, &["Option"])
+}
+
+fn is_map_type(ty: &Type) -> bool {
+    is_type_named(ty, &["HashMap", "BTreeMap", "IndexMap"])
+}
+
+fn is_type_named(ty: &Type, names: &[&str]) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) => names.iter().any(|name| segment.ident == name),
+            None => false,
+        },
+        // a type passed through `macro_rules` is wrapped into an invisible group.
+        Type::Group(group) => is_type_named(&group.elem, names),
+        _ => false,
+    }
 }
@@ -32,7 +32,18 @@ pub enum TabledAttrKind {
     Rename(LitStr),
     RenameAll(LitStr),
     DisplayWith(LitStr, bool),
+    OptionFmt(LitStr),
     Order(LitInt),
+    Hideable(LitBool),
+    InlineList(Vec<InlineListArg>),
+    Group(Option<LitStr>),
+}
+
+#[derive(Clone)]
+pub enum InlineListArg {
+    Separator(LitStr),
+    Columns(LitInt),
+    Prefix(LitStr),
 }
 
 impl Parse for TabledAttr {
@@ -52,6 +63,8 @@ impl Parse for TabledAttr {
                     "rename" => return Ok(Self::new(name, Rename(lit))),
                     "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
                     "display_with" => return Ok(Self::new(name, DisplayWith(lit, false))),
+                    "option_fmt" => return Ok(Self::new(name, OptionFmt(lit))),
+                    "group" => return Ok(Self::new(name, Group(Some(lit)))),
                     _ => {}
                 }
             }
@@ -62,6 +75,7 @@ impl Parse for TabledAttr {
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(name, Skip(lit))),
                     "inline" => return Ok(Self::new(name, Inline(lit, None))),
+                    "hideable" => return Ok(Self::new(name, Hideable(lit))),
                     _ => {}
                 }
             }
@@ -84,6 +98,11 @@ impl Parse for TabledAttr {
             let nested;
             let _paren = parenthesized!(nested in input);
 
+            if name_str == "inline_list" {
+                let args = Punctuated::<InlineListArg, Token![,]>::parse_terminated(&nested)?;
+                return Ok(Self::new(name, InlineList(args.into_iter().collect())));
+            }
+
             if nested.peek(LitStr) {
                 let lit = nested.parse::<LitStr>()?;
 
@@ -127,6 +146,14 @@ impl Parse for TabledAttr {
                     Inline(LitBool::new(true, Span::call_site()), None),
                 ))
             }
+            "hideable" => {
+                return Ok(Self::new(
+                    name,
+                    Hideable(LitBool::new(true, Span::call_site())),
+                ))
+            }
+            "inline_list" => return Ok(Self::new(name, InlineList(Vec::new()))),
+            "group" => return Ok(Self::new(name, Group(None))),
             _ => {}
         }
 
@@ -136,3 +163,23 @@ impl Parse for TabledAttr {
         ))
     }
 }
+
+impl Parse for InlineListArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        let _assign_token = input.parse::<Token![=]>()?;
+
+        match name.to_string().as_str() {
+            "sep" => Ok(Self::Separator(input.parse()?)),
+            "columns" => Ok(Self::Columns(input.parse()?)),
+            "prefix" => Ok(Self::Prefix(input.parse()?)),
+            name_str => Err(syn::Error::new(
+                name.span(),
+                format!(
+                    "unexpected inline_list argument: {}; expected `sep`, `columns` or `prefix`",
+                    name_str
+                ),
+            )),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Accessible, Caption, Style, Table};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    accessible_default,
+    create_table::<2, 2>().with(Accessible::new()),
+    "N: 0"
+    "column 0: 0-0"
+    "column 1: 0-1"
+    ""
+    "N: 1"
+    "column 0: 1-0"
+    "column 1: 1-1"
+);
+
+test_table!(
+    accessible_ignores_style,
+    create_table::<1, 2>()
+        .with(Style::modern())
+        .with(Accessible::new()),
+    "N: 0"
+    "column 0: 0-0"
+    "column 1: 0-1"
+);
+
+test_table!(
+    accessible_separators,
+    create_table::<2, 1>().with(Accessible::new().separator(" = ").record_separator("----")),
+    "N = 0"
+    "column 0 = 0-0"
+    "----"
+    "N = 1"
+    "column 0 = 1-0"
+);
+
+test_table!(
+    accessible_disabled,
+    create_table::<1, 1>()
+        .with(Accessible::new())
+        .with(Accessible::new().enabled(false)),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
+
+test_table!(
+    accessible_without_header,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Accessible::new()),
+    "a"
+    "b"
+    ""
+    "c"
+    "d"
+);
+
+test_table!(
+    accessible_multiline,
+    Table::new([("Hello\nWorld", "")]).with(Accessible::new()),
+    "&str: Hello World"
+    "&str:"
+);
+
+test_table!(
+    accessible_captions,
+    Table::new([1, 2])
+        .with(Caption::top("Numbers"))
+        .with(Caption::bottom("Total: 2"))
+        .with(Accessible::new()),
+    "Numbers"
+    ""
+    "i32: 1"
+    ""
+    "i32: 2"
+    ""
+    "Total: 2"
+);
+
+test_table!(
+    accessible_only_header,
+    Table::new(Vec::<(i32, i32)>::new()).with(Accessible::new()),
+    ""
+);
+
+test_table!(
+    accessible_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(Accessible::new()),
+    "item: pear"
+    "price net: 8"
+    "price gross: 9"
+    ""
+    "item: apple"
+    "price net: 10"
+    "price gross: 12"
+);
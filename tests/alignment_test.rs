@@ -1,7 +1,7 @@
 use tabled::{
     locator::ByColumnName,
-    object::{Columns, Rows, Segment},
-    Alignment, Modify, Padding, Style,
+    object::{Cell, Columns, Rows, Segment},
+    Alignment, Modify, Padding, Span, Style, Table, Width,
 };
 
 use crate::util::{create_table, init_table, test_table};
@@ -136,3 +136,106 @@ test_table!(
         " 1 |   1-0    |   1-1    |   1-2    "
         " 2 |   2-0    |   2-1    |   2-2    "
 );
+
+test_table!(
+    justify_column,
+    Table::new([("Introduction\t1", 1), ("Getting started\t12", 2), ("Appendix", 3)])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Alignment::justify('.'))),
+    " &str                   | i32 "
+    "------------------------+-----"
+    " Introduction.........1 | 1   "
+    " Getting started.....12 | 2   "
+    " Appendix               | 3   "
+);
+
+test_table!(
+    justify_multiline,
+    Table::new(["a\t1\nno separator\nbb\t22"])
+        .with(Style::psql())
+        .with(Alignment::justify('-')),
+    " &str         "
+    "--------------"
+    " a----------1 "
+    " no separator "
+    " bb--------22 "
+);
+
+test_table!(
+    justify_separator,
+    Table::new(["Introduction | 1", "Getting started | 12"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::justify('.').separator(" | "))),
+    " &str                 "
+    "----------------------"
+    " Introduction.......1 "
+    " Getting started...12 "
+);
+
+test_table!(
+    justify_wide_fill,
+    Table::new(["a\t1", "bbb\t2"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::justify('ー'))),
+    " &str      "
+    "-----------"
+    " aーーー 1 "
+    " bbbーー 2 "
+);
+
+test_table!(
+    justify_after_wrap,
+    Table::new(["Introduction~1"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::justify('.').separator("~")))
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(8))),
+    " &str     "
+    "----------"
+    " Introduc "
+    " tion...1 "
+);
+
+test_table!(
+    justify_removed_by_alignment,
+    Table::new(["a~1", "bbbbbb~2"])
+        .with(Style::psql())
+        .with(Alignment::justify('.').separator("~"))
+        .with(Modify::new(Cell(2, 0)).with(Alignment::right())),
+    " &str     "
+    "----------"
+    " a......1 "
+    " bbbbbb~2 "
+);
+
+test_table!(
+    justify_spanned_cell,
+    Table::new([("a\t1", "b"), ("ccc", "dddd")])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)).with(Alignment::justify('.'))),
+    " &str | &str "
+    "------+------"
+    " a.........1 "
+    " ccc  | dddd "
+);
+
+#[cfg(feature = "color")]
+#[test]
+fn justify_colored() {
+    use owo_colors::OwoColorize;
+
+    let text = format!("{}\t{}", "red".red(), 1.blue());
+    let table = Table::new([text, String::from("a very long line")])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::justify('.')))
+        .to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            " String           "
+            "------------------"
+            " \u{1b}[31mred\u{1b}[39m............\u{1b}[34m1\u{1b}[39m "
+            " a very long line "
+        )
+    );
+}
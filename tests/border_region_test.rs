@@ -0,0 +1,132 @@
+use tabled::{
+    builder::Builder,
+    object::{Cell, Columns, Object, Rows, Segment},
+    Border, BorderRegion, Highlight, Style,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    border_region_erase_rows,
+    create_table::<3, 3>().with(Style::modern()).with(BorderRegion::erase(Rows::new(2..))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "                                      "
+    "  1     1-0        1-1        1-2     "
+    "                                      "
+    "  2     2-0        2-1        2-2     "
+    "                                      "
+);
+
+test_table!(
+    border_region_erase_column,
+    create_table::<3, 3>().with(Style::modern()).with(BorderRegion::erase(Columns::first())),
+    "     ──────────┬──────────┬──────────┐"
+    "  N   column 0 │ column 1 │ column 2 │"
+    "     ──────────┼──────────┼──────────┤"
+    "  0     0-0    │   0-1    │   0-2    │"
+    "     ──────────┼──────────┼──────────┤"
+    "  1     1-0    │   1-1    │   1-2    │"
+    "     ──────────┼──────────┼──────────┤"
+    "  2     2-0    │   2-1    │   2-2    │"
+    "     ──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    border_region_restyle,
+    create_table::<3, 3>().with(Style::modern()).with(BorderRegion::new(Rows::last(), Border::filled('='))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "======================================"
+    "= 2 =   2-0    =   2-1    =   2-2    ="
+    "======================================"
+);
+
+test_table!(
+    border_region_restyle_sides,
+    create_table::<3, 3>()
+        .with(Style::modern())
+        .with(BorderRegion::new(Rows::new(1..3), Border::default().top('.').bottom('.'))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├...┼..........┼..........┼..........┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├...┼..........┼..........┼..........┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├...┼..........┼..........┼..........┤"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    border_region_keeps_missing_lines,
+    create_table::<3, 3>().with(Style::psql()).with(BorderRegion::new(Segment::all(), Border::filled('#'))),
+    " N # column 0 # column 1 # column 2 "
+    "####################################"
+    " 0 #   0-0    #   0-1    #   0-2    "
+    " 1 #   1-0    #   1-1    #   1-2    "
+    " 2 #   2-0    #   2-1    #   2-2    "
+);
+
+test_table!(
+    border_region_rectangle,
+    create_table::<3, 3>()
+        .with(Style::ascii())
+        .with(BorderRegion::erase(Cell(1, 1).and(Cell(1, 2)).and(Cell(2, 1)).and(Cell(2, 2)))),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---                       ----------+"
+    "| 0     0-0        0-1        0-2    |"
+    "+---                       ----------+"
+    "| 1     1-0        1-1        1-2    |"
+    "+---                       ----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    border_region_with_highlight,
+    create_table::<3, 3>()
+        .with(Style::modern())
+        .with(BorderRegion::erase(Rows::new(1..3)))
+        .with(Highlight::new(Rows::new(1..3), Border::filled('*'))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "**************************************"
+    "* 0     0-0        0-1        0-2    *"
+    "*                                    *"
+    "* 1     1-0        1-1        1-2    *"
+    "**************************************"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    border_region_object_exceeds_boundaries,
+    create_table::<3, 3>().with(Style::modern()).with(BorderRegion::erase(Cell(1000, 0))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    border_region_empty_table,
+    Builder::default()
+        .build()
+        .with(BorderRegion::erase(Segment::all())),
+    ""
+);
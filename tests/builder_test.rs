@@ -1,6 +1,16 @@
-use std::iter::FromIterator;
+use std::{
+    fmt,
+    iter::FromIterator,
+    panic::{RefUnwindSafe, UnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
-use tabled::builder::Builder;
+use tabled::{
+    alignment::AlignmentHorizontal,
+    builder::{Builder, CasingStyle, CellLimit, NumberFormat, TableDef, TypedCell},
+    locator::ByColumnName,
+    Disable, Style,
+};
 
 use util::test_table;
 
@@ -738,6 +748,876 @@ test_table!(
     "+---+------+---------+-----------+"
 );
 
+test_table!(
+    push_record_typed,
+    Builder::default()
+        .push_record_typed(("id", "value", "price"))
+        .push_record_typed((1u8, -1234567i64, 0.5f32))
+        .add_record(["2", "3"])
+        .push_record_typed((3usize, 1000u32, -12345.678))
+        .set_number_format(1, NumberFormat::new().thousands_separator(','))
+        .set_number_format(2, NumberFormat::new().precision(1).thousands_separator(' '))
+        .clone()
+        .build(),
+    "+----+------------+-----------+"
+    "| id | value      | price     |"
+    "+----+------------+-----------+"
+    "| 1  | -1,234,567 | 0.5       |"
+    "+----+------------+-----------+"
+    "| 2  | 3          |           |"
+    "+----+------------+-----------+"
+    "| 3  | 1,000      | -12 345.7 |"
+    "+----+------------+-----------+"
+);
+
+#[test]
+fn builder_is_unwind_safe() {
+    fn check<T: UnwindSafe + RefUnwindSafe + Send + Sync>() {}
+
+    check::<TypedCell<'static>>();
+    check::<Builder<'static>>();
+}
+
+#[test]
+fn push_record_typed_borrowed() {
+    let names = vec![String::from("apple"), String::from("melon")];
+    let prices = vec![1234.5, 2.0];
+
+    let mut builder = Builder::default();
+    for (name, price) in names.iter().zip(&prices) {
+        builder.push_record_typed((name, price, "1.25"));
+    }
+    builder.set_number_format(1, NumberFormat::new().precision(1).thousands_separator(','));
+    builder.set_number_format(2, NumberFormat::new().precision(1));
+
+    assert_eq!(
+        builder.build().to_string(),
+        "+-------+---------+------+\n\
+         | apple | 1,234.5 | 1.25 |\n\
+         +-------+---------+------+\n\
+         | melon | 2.0     | 1.25 |\n\
+         +-------+---------+------+"
+    );
+}
+
+#[test]
+fn push_record_typed_is_formatted_on_build() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted;
+
+    impl fmt::Display for Counted {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            COUNT.fetch_add(1, Ordering::SeqCst);
+            f.write_str("counted")
+        }
+    }
+
+    let mut builder = Builder::default();
+    builder.push_record_typed((Counted,));
+    assert_eq!(COUNT.load(Ordering::SeqCst), 0);
+
+    let table = builder.build().to_string();
+    assert!(COUNT.load(Ordering::SeqCst) > 0);
+    assert!(table.contains("counted"));
+}
+
+test_table!(
+    push_record_typed_without_format,
+    Builder::default()
+        .push_record_typed((1u8, "text", 1.25))
+        .push_record_typed((true, 'c'))
+        .clone()
+        .build(),
+    "+------+------+------+"
+    "| 1    | text | 1.25 |"
+    "+------+------+------+"
+    "| true | c    |      |"
+    "+------+------+------+"
+);
+
+test_table!(
+    push_record_typed_index,
+    {
+        let mut b = Builder::default();
+        b.set_columns(["a", "b"]);
+        b.push_record_typed((1.0, 2));
+        b.push_record_typed((10000.0, 3));
+        b.set_number_format(0, NumberFormat::new().precision(2).thousands_separator('.'));
+        b.index().build()
+    },
+    "+---+-----------+---+"
+    "|   | a         | b |"
+    "+---+-----------+---+"
+    "| 0 | 1.00      | 2 |"
+    "+---+-----------+---+"
+    "| 1 | 10.000.00 | 3 |"
+    "+---+-----------+---+"
+);
+
+test_table!(
+    normalize_headers,
+    Builder::from_iter([["user id", "FirstName", "last-name", "AGE", ""]])
+        .set_columns(["user id", "FirstName", "last-name", "AGE", ""])
+        .normalize_headers(CasingStyle::Snake)
+        .clone()
+        .build()
+        .with(Style::psql()),
+    " user_id | first_name | last_name | age |  "
+    "---------+------------+-----------+-----+--"
+    " user id | FirstName  | last-name | AGE |  "
+);
+
+test_table!(
+    normalize_headers_all_styles,
+    {
+        let mut b = Builder::default();
+        let casings = [
+            CasingStyle::Camel,
+            CasingStyle::Kebab,
+            CasingStyle::Pascal,
+            CasingStyle::ScreamingSnake,
+            CasingStyle::Snake,
+            CasingStyle::Lower,
+            CasingStyle::Upper,
+        ];
+        for casing in casings {
+            b.add_record([casing.cast("some_HeaderName 1")]);
+        }
+
+        b.build().with(Style::psql()).clone()
+    },
+    " someHeaderName1    "
+    "--------------------"
+    " some-header-name-1 "
+    " SomeHeaderName1    "
+    " SOME_HEADER_NAME_1 "
+    " some_header_name_1 "
+    " someheadername1    "
+    " SOMEHEADERNAME1    "
+);
+
+test_table!(
+    normalize_headers_without_columns,
+    Builder::from_iter([["FirstName"]]).normalize_headers(CasingStyle::Snake).clone().build(),
+    "+-----------+"
+    "| FirstName |"
+    "+-----------+"
+);
+
+test_table!(
+    normalize_headers_lookup,
+    Builder::from_iter([["1", "2"]])
+        .set_columns(["First Name", "Last Name"])
+        .normalize_headers(CasingStyle::Kebab)
+        .clone()
+        .build()
+        .with(Disable::column(ByColumnName::new("last-name"))),
+    "+------------+"
+    "| first-name |"
+    "+------------+"
+    "| 1          |"
+    "+------------+"
+);
+
+test_table!(
+    on_push_fix_record,
+    Builder::default()
+        .on_push(|cells| {
+            cells.iter_mut().for_each(|cell| *cell = cell.trim().to_owned());
+            Ok(())
+        })
+        .add_record([" a ", "b  "])
+        .clone()
+        .build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+);
+
+test_table!(
+    on_push_reject_record,
+    Builder::default()
+        .on_push(|cells| match cells.len() {
+            2 => Ok(()),
+            n => Err(format!("expected 2 cells but got {}", n)),
+        })
+        .add_record(["a", "b"])
+        .add_record(["c"])
+        .add_record(["d", "e"])
+        .clone()
+        .build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| d | e |"
+    "+---+---+"
+);
+
+#[test]
+fn on_push_errors() {
+    let mut builder = Builder::default();
+    builder.add_record(["", ""]);
+    builder.on_push(|cells| match cells.iter().any(|cell| cell.is_empty()) {
+        true => Err(String::from("empty cell")),
+        false => Ok(()),
+    });
+    builder.add_record(["a", ""]);
+    builder.add_record(["b", "c"]);
+    builder.add_record(["", "d"]);
+
+    let rows = builder
+        .errors()
+        .iter()
+        .map(|err| err.row())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [1, 3]);
+    assert_eq!(builder.errors()[0].message(), "empty cell");
+    assert_eq!(builder.errors()[1].to_string(), "record 3: empty cell");
+
+    let errors = builder.clone().try_build().unwrap_err();
+    assert_eq!(errors, builder.errors());
+
+    assert_eq!(
+        builder.build().to_string(),
+        "+---+---+\n\
+         |   |   |\n\
+         +---+---+\n\
+         | b | c |\n\
+         +---+---+"
+    );
+}
+
+#[test]
+fn on_push_validates_inserted_records() {
+    let mut builder = Builder::default();
+    builder.on_push(|cells| match cells.iter().any(|cell| cell.is_empty()) {
+        true => Err(String::from("empty cell")),
+        false => Ok(()),
+    });
+    builder.add_record(["a", "b"]);
+    builder.insert_record(0, ["", "c"]);
+    builder.insert_record(1, ["d", "e"]);
+    builder.add_record(["f", ""]);
+
+    let rows = builder
+        .errors()
+        .iter()
+        .map(|err| err.row())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [1, 3]);
+
+    assert_eq!(
+        builder.build().to_string(),
+        "+---+---+\n\
+         | a | b |\n\
+         +---+---+\n\
+         | d | e |\n\
+         +---+---+"
+    );
+}
+
+#[test]
+fn on_push_try_build_ok() {
+    let mut builder = Builder::default();
+    builder.on_push(|_| Ok(()));
+    builder.add_record(["a"]);
+
+    assert!(builder.try_build().is_ok());
+}
+
+#[test]
+fn on_push_validates_typed_records() {
+    let mut builder = Builder::default();
+    builder.on_push(|cells| match cells[1].parse::<f64>() {
+        Ok(value) if value < 0.0 => Err(String::from("negative price")),
+        Ok(_) => Ok(()),
+        Err(_) => {
+            cells[1] = String::from("?");
+            Ok(())
+        }
+    });
+    builder.add_record(["a", "1"]);
+    builder.push_record_typed(("b", -2.5));
+    builder.push_record_typed(("c", 1234.5));
+    builder.push_record_typed(("d", "free"));
+    builder.set_number_format(1, NumberFormat::new().precision(2).thousands_separator(','));
+
+    let rows = builder
+        .errors()
+        .iter()
+        .map(|err| err.row())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [1]);
+
+    assert_eq!(
+        builder.build().to_string(),
+        "+---+----------+\n\
+         | a | 1        |\n\
+         +---+----------+\n\
+         | c | 1,234.50 |\n\
+         +---+----------+\n\
+         | d | ?        |\n\
+         +---+----------+"
+    );
+}
+
+test_table!(
+    insert_record,
+    Builder::default()
+        .set_columns(["a", "b"])
+        .add_record(["1", "2"])
+        .insert_record(0, ["3", "4"])
+        .insert_record(2, ["5", "6"])
+        .clone()
+        .build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 3 | 4 |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 5 | 6 |"
+    "+---+---+"
+);
+
+test_table!(
+    insert_record_different_size,
+    Builder::default()
+        .set_default_text("?")
+        .add_record(["1", "2"])
+        .insert_record(1, ["3", "4", "5"])
+        .clone()
+        .build(),
+    "+---+---+---+"
+    "| 1 | 2 | ? |"
+    "+---+---+---+"
+    "| 3 | 4 | 5 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    insert_record_typed,
+    Builder::default()
+        .push_record_typed((1.5,))
+        .insert_record(0, ["a"])
+        .set_number_format(0, NumberFormat::new().precision(2))
+        .clone()
+        .build(),
+    "+------+"
+    "| a    |"
+    "+------+"
+    "| 1.50 |"
+    "+------+"
+);
+
+test_table!(
+    remove_record,
+    Builder::default()
+        .set_columns(["a", "b"])
+        .add_record(["1", "2"])
+        .add_record(["3", "4"])
+        .add_record(["5", "6"])
+        .remove_record(1)
+        .clone()
+        .build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 5 | 6 |"
+    "+---+---+"
+);
+
+test_table!(
+    remove_record_typed,
+    Builder::default()
+        .push_record_typed((1,))
+        .push_record_typed((2,))
+        .push_record_typed((3,))
+        .remove_record(0)
+        .clone()
+        .build(),
+    "+---+"
+    "| 2 |"
+    "+---+"
+    "| 3 |"
+    "+---+"
+);
+
+test_table!(
+    insert_column,
+    Builder::default()
+        .set_columns(["a", "b"])
+        .add_record(["1", "2"])
+        .add_record(["3", "4"])
+        .insert_column(0, "c", ["5", "6"])
+        .insert_column(3, "d", ["7", "8"])
+        .clone()
+        .build(),
+    "+---+---+---+---+"
+    "| c | a | b | d |"
+    "+---+---+---+---+"
+    "| 5 | 1 | 2 | 7 |"
+    "+---+---+---+---+"
+    "| 6 | 3 | 4 | 8 |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    insert_column_typed,
+    Builder::default()
+        .set_columns(["a", "b"])
+        .push_record_typed((1.5, 1000))
+        .insert_column(0, "c", ["1000"])
+        .set_number_format(0, NumberFormat::new().thousands_separator(','))
+        .set_number_format(1, NumberFormat::new().precision(2))
+        .set_number_format(2, NumberFormat::new().thousands_separator(','))
+        .clone()
+        .build(),
+    "+------+------+-------+"
+    "| c    | a    | b     |"
+    "+------+------+-------+"
+    "| 1000 | 1.50 | 1,000 |"
+    "+------+------+-------+"
+);
+
+test_table!(
+    remove_column_typed,
+    Builder::default()
+        .set_columns(["a", "b", "c"])
+        .push_record_typed(("x", 1.5, 1000))
+        .remove_column(0)
+        .set_number_format(0, NumberFormat::new().precision(2))
+        .set_number_format(1, NumberFormat::new().thousands_separator(','))
+        .clone()
+        .build(),
+    "+------+-------+"
+    "| b    | c     |"
+    "+------+-------+"
+    "| 1.50 | 1,000 |"
+    "+------+-------+"
+);
+
+test_table!(
+    insert_column_missing_values,
+    Builder::default()
+        .set_default_text("?")
+        .set_columns(["a"])
+        .add_record(["1"])
+        .add_record(["2", "3"])
+        .insert_column(1, "b", ["4"])
+        .clone()
+        .build(),
+    "+---+---+---+"
+    "| a | b | ? |"
+    "+---+---+---+"
+    "| 1 | 4 | ? |"
+    "+---+---+---+"
+    "| 2 | ? | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    insert_column_without_columns,
+    Builder::default()
+        .add_record(["1"])
+        .add_record(["2"])
+        .insert_column(1, "header", ["3", "4", "5"])
+        .clone()
+        .build(),
+    "+---+---+"
+    "| 1 | 3 |"
+    "+---+---+"
+    "| 2 | 4 |"
+    "+---+---+"
+);
+
+test_table!(
+    insert_column_empty,
+    Builder::default()
+        .insert_column(0, "a", ["1"])
+        .set_columns(["b"])
+        .add_record(["2"])
+        .clone()
+        .build(),
+    "+---+"
+    "| b |"
+    "+---+"
+    "| 2 |"
+    "+---+"
+);
+
+test_table!(
+    remove_column,
+    Builder::default()
+        .set_columns(["a", "b", "c"])
+        .add_record(["1", "2", "3"])
+        .add_record(["4"])
+        .remove_column(0)
+        .clone()
+        .build(),
+    "+---+---+"
+    "| b | c |"
+    "+---+---+"
+    "| 2 | 3 |"
+    "+---+---+"
+    "|   |   |"
+    "+---+---+"
+);
+
+test_table!(
+    remove_column_keeps_number_format,
+    Builder::default()
+        .set_columns(["a", "b"])
+        .set_number_format(1, NumberFormat::new().precision(1))
+        .remove_column(0)
+        .push_record_typed((2.0,))
+        .clone()
+        .build(),
+    "+-----+"
+    "| b   |"
+    "+-----+"
+    "| 2.0 |"
+    "+-----+"
+);
+
+#[test]
+#[should_panic]
+fn insert_column_out_of_bounds() {
+    Builder::default()
+        .add_record(["1"])
+        .insert_column(2, "a", ["2"]);
+}
+
+#[test]
+#[should_panic]
+fn remove_column_out_of_bounds() {
+    Builder::default().add_record(["1"]).remove_column(1);
+}
+
+test_table!(
+    table_def,
+    TableDef::new()
+        .column("name", AlignmentHorizontal::Left)
+        .column("size", AlignmentHorizontal::Right)
+        .column("kind", AlignmentHorizontal::Center)
+        .build([["a", "1", "file"], ["bbbbbb", "1000", "directory"]]),
+    "+--------+------+-----------+"
+    "| name   | size |   kind    |"
+    "+--------+------+-----------+"
+    "| a      |    1 |   file    |"
+    "+--------+------+-----------+"
+    "| bbbbbb | 1000 | directory |"
+    "+--------+------+-----------+"
+);
+
+test_table!(
+    table_def_format,
+    TableDef::new()
+        .column("name", AlignmentHorizontal::Left)
+        .column_with("size", AlignmentHorizontal::Right, |s| format!("{} KiB", s))
+        .build([vec!["a", "1"], vec!["b", "20"]]),
+    "+------+--------+"
+    "| name |   size |"
+    "+------+--------+"
+    "| a    |  1 KiB |"
+    "+------+--------+"
+    "| b    | 20 KiB |"
+    "+------+--------+"
+);
+
+test_table!(
+    table_def_ragged_rows,
+    TableDef::new()
+        .column("a", AlignmentHorizontal::Left)
+        .column("b", AlignmentHorizontal::Left)
+        .build([vec!["1"], vec!["2", "3", "4"]]),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 |   |"
+    "+---+---+"
+    "| 2 | 3 |"
+    "+---+---+"
+);
+
+test_table!(
+    table_def_without_rows,
+    TableDef::new()
+        .column("a", AlignmentHorizontal::Left)
+        .build(Vec::<Vec<String>>::new()),
+    "+---+"
+    "| a |"
+    "+---+"
+);
+
+#[test]
+fn table_def_is_reusable() {
+    let def = TableDef::new()
+        .column("id", AlignmentHorizontal::Right)
+        .column_with("name", AlignmentHorizontal::Left, str::to_uppercase);
+
+    assert_eq!(def.count_columns(), 2);
+
+    let table1 = def.build([["1", "tom"]]).with(Style::psql()).to_string();
+    let table2 = def.build([["22", "ann"]]).with(Style::psql()).to_string();
+
+    assert_eq!(
+        table1,
+        concat!(" id | name \n", "----+------\n", "  1 | TOM  ")
+    );
+    assert_eq!(
+        table2,
+        concat!(" id | name \n", "----+------\n", " 22 | ANN  ")
+    );
+}
+
+test_table!(
+    cell_limit_bytes,
+    Builder::default()
+        .set_cell_limit(CellLimit::new().bytes(4))
+        .set_columns(["id", "message"])
+        .add_record(["1", "Hello World"])
+        .add_record(["2", "Hi"])
+        .clone()
+        .build(),
+    "+----+----------------------+"
+    "| id | mess [truncated 3 B] |"
+    "+----+----------------------+"
+    "| 1  | Hell [truncated 7 B] |"
+    "+----+----------------------+"
+    "| 2  | Hi                   |"
+    "+----+----------------------+"
+);
+
+test_table!(
+    cell_limit_lines,
+    Builder::default()
+        .set_cell_limit(CellLimit::new().lines(2))
+        .add_record(["trace"])
+        .add_record(["at a\nat b\nat c\nat d"])
+        .clone()
+        .build(),
+    "+------------------+"
+    "| trace            |"
+    "+------------------+"
+    "| at a             |"
+    "| at b             |"
+    "| [truncated 10 B] |"
+    "+------------------+"
+);
+
+test_table!(
+    cell_limit_applies_to_added_cells,
+    Builder::from_iter([["1", "Hello World"]])
+        .set_columns(["id", "message"])
+        .set_cell_limit(CellLimit::new().bytes(7))
+        .insert_record(0, ["0", "Good morning"])
+        .insert_column(1, "note", ["a very long note", "short"])
+        .clone()
+        .build(),
+    "+----+-------------------------+-------------------------+"
+    "| id | note                    | message                 |"
+    "+----+-------------------------+-------------------------+"
+    "| 0  | a very  [truncated 9 B] | Good mo [truncated 5 B] |"
+    "+----+-------------------------+-------------------------+"
+    "| 1  | short                   | Hello W [truncated 4 B] |"
+    "+----+-------------------------+-------------------------+"
+);
+
+test_table!(
+    cell_limit_typed_records,
+    Builder::default()
+        .set_cell_limit(CellLimit::new().bytes(3))
+        .set_columns(["n"])
+        .push_record_typed((123456,))
+        .clone()
+        .build(),
+    "+---------------------+"
+    "| n                   |"
+    "+---------------------+"
+    "| 123 [truncated 3 B] |"
+    "+---------------------+"
+);
+
+#[test]
+fn cell_limit_marker() {
+    let limit = CellLimit::new().bytes(1);
+    assert_eq!(limit.apply("a"), "a");
+    assert_eq!(limit.apply(""), "");
+    assert_eq!(limit.apply("ab"), "a [truncated 1 B]");
+    assert_eq!(limit.apply("ж"), "[truncated 2 B]");
+
+    let text = "a".repeat(1_234_568);
+    assert_eq!(limit.apply(&text), "a [truncated 1.2 MB]");
+    let text = "a".repeat(1_001);
+    assert_eq!(limit.apply(&text), "a [truncated 1.0 KB]");
+    let text = "a".repeat(999_999);
+    assert_eq!(limit.apply(&text), "a [truncated 1.0 MB]");
+
+    let limit = CellLimit::new().lines(0);
+    assert_eq!(limit.apply("line"), "[truncated 4 B]");
+
+    let limit = CellLimit::new().lines(1).bytes(3);
+    assert_eq!(limit.apply("ab\ncd"), "ab\n[truncated 3 B]");
+    assert_eq!(limit.apply("abcd\nef"), "abc [truncated 4 B]");
+
+    assert_eq!(CellLimit::new().apply("a\nb\nc"), "a\nb\nc");
+}
+
+test_table!(
+    header_rows,
+    Builder::default()
+        .set_header_rows(vec![
+            vec!["", "price", "price", "tax"],
+            vec!["item", "net", "gross", "tax"],
+        ])
+        .add_record(["apple", "10", "12", "2"])
+        .clone()
+        .build(),
+    "+-------+-----+-------+-----+"
+    "| item  | price       | tax |"
+    "+       +-----+-------+-----+"
+    "|       | net | gross | tax |"
+    "+-------+-----+-------+-----+"
+    "| apple | 10  | 12    | 2   |"
+    "+-------+-----+-------+-----+"
+);
+
+test_table!(
+    header_rows_hierarchical,
+    Builder::default()
+        .set_header_rows(vec![
+            vec!["", "money", "money", "money"],
+            vec!["", "usd", "usd", ""],
+            vec!["item", "net", "gross", "rate"],
+        ])
+        .add_record(["apple", "10", "12", "0.9"])
+        .clone()
+        .build()
+        .with(Style::modern()),
+    "┌───────┬─────┬───────┬──────┐"
+    "│ item  │ money              │"
+    "├       ┼─────┼───────┼──────┤"
+    "│       │ usd         │ rate │"
+    "├       ┼─────┼───────┼      ┤"
+    "│       │ net │ gross │      │"
+    "├───────┼─────┼───────┼──────┤"
+    "│ apple │ 10  │ 12    │ 0.9  │"
+    "└───────┴─────┴───────┴──────┘"
+);
+
+test_table!(
+    header_rows_same_name_in_different_groups,
+    Builder::default()
+        .set_header_rows(vec![
+            vec!["a", "a", "b", "b"],
+            vec!["x", "y", "y", "x"],
+            vec!["1", "2", "3", "4"],
+        ])
+        .clone()
+        .build()
+        .with(Style::psql()),
+    " a     | b     "
+    "---+---+---+---"
+    " x | y | y | x "
+    " 1 | 2 | 3 | 4 "
+);
+
+test_table!(
+    header_rows_single_row_is_columns,
+    Builder::default()
+        .set_header_rows(vec![vec!["a", "b"]])
+        .add_record(["1", "2"])
+        .clone()
+        .build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
+
+test_table!(
+    header_rows_ragged,
+    Builder::default()
+        .set_header_rows(vec![vec!["group", "group"], vec!["a", "b", "c"]])
+        .add_record(["1"])
+        .clone()
+        .build(),
+    "+---+---+---+"
+    "| group | c |"
+    "+---+---+   +"
+    "| a | b |   |"
+    "+---+---+---+"
+    "| 1 |   |   |"
+    "+---+---+---+"
+);
+
+test_table!(
+    header_rows_insert_and_remove_column,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "g", "g"], vec!["a", "b", "c"]])
+        .add_record(["1", "2", "3"])
+        .insert_column(0, "i", ["0"])
+        .remove_column(2)
+        .clone()
+        .build(),
+    "+---+---+---+"
+    "| i | a | g |"
+    "+   +   +---+"
+    "|   |   | c |"
+    "+---+---+---+"
+    "| 0 | 1 | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    header_rows_removed_by_remove_columns,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "g"], vec!["a", "b"]])
+        .add_record(["1", "2"])
+        .remove_columns()
+        .clone()
+        .build(),
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
+
+#[test]
+fn header_rows_interned() {
+    let mut builder = Builder::default();
+    builder
+        .set_header_rows(vec![vec!["", "g", "g"], vec!["a", "b", "c"]])
+        .add_record(["1", "2", "3"]);
+
+    assert_eq!(
+        builder.clone().build_interned().to_string(),
+        builder.build().to_string()
+    );
+}
+
+test_table!(
+    header_rows_cell_limit,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "a long group"], vec!["id", "name"]])
+        .add_record(["1", "tom"])
+        .set_cell_limit(CellLimit::new().bytes(6))
+        .clone()
+        .build(),
+    "+----+------------------------+"
+    "| id | a long [truncated 6 B] |"
+    "+    +------------------------+"
+    "|    | name                   |"
+    "+----+------------------------+"
+    "| 1  | tom                    |"
+    "+----+------------------------+"
+);
+
 #[quickcheck_macros::quickcheck]
 #[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
 fn qc_table_is_consistent(data: Vec<Vec<isize>>) -> bool {
@@ -0,0 +1,214 @@
+use tabled::{alignment::AlignmentHorizontal, Caption, Margin, Panel, Style, Table, Width};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    caption_top,
+    create_table::<2, 2>().with(Caption::top("Numbers")),
+    "+-------------------------+"
+    "| Numbers                 |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    caption_bottom,
+    create_table::<2, 2>().with(Caption::bottom("Numbers")),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+    "| Numbers                 |"
+    "+-------------------------+"
+);
+
+test_table!(
+    caption_long_text_does_not_widen_columns,
+    create_table::<2, 2>().with(Caption::top("A caption which is a lot longer than the table")),
+    "+-------------------------+"
+    "| A caption which is a lo |"
+    "| t longer than the table |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    caption_keep_words,
+    create_table::<2, 2>().with(Caption::top("A caption which is a lot longer than the table").keep_words()),
+    "+-------------------------+"
+    "| A caption which is a    |"
+    "| lot longer than the     |"
+    "| table                   |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    caption_alignment,
+    create_table::<2, 2>()
+        .with(Caption::top("center").alignment(AlignmentHorizontal::Center))
+        .with(Caption::bottom("right").alignment(AlignmentHorizontal::Right)),
+    "+-------------------------+"
+    "|         center          |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+    "|                   right |"
+    "+-------------------------+"
+);
+
+test_table!(
+    caption_stacked,
+    create_table::<1, 2>()
+        .with(Caption::top("1"))
+        .with(Caption::top("2"))
+        .with(Caption::bottom("3"))
+        .with(Caption::bottom("4")),
+    "+-------------------------+"
+    "| 2                       |"
+    "+-------------------------+"
+    "| 1                       |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 3                       |"
+    "+-------------------------+"
+    "| 4                       |"
+    "+-------------------------+"
+);
+
+test_table!(
+    caption_after_width,
+    create_table::<2, 2>()
+        .with(Caption::top("A caption which is a lot longer than the table"))
+        .with(Width::wrap(15)),
+    "+-------------+"
+    "| A caption w |"
+    "| hich is a l |"
+    "| ot longer t |"
+    "| han the tab |"
+    "| le          |"
+    "+--+----+-----+"
+    "|  | co | col |"
+    "|  | lu | umn |"
+    "|  | mn |  1  |"
+    "|  |  0 |     |"
+    "+--+----+-----+"
+    "|  | 0- | 0-1 |"
+    "|  | 0  |     |"
+    "+--+----+-----+"
+    "|  | 1- | 1-1 |"
+    "|  | 0  |     |"
+    "+--+----+-----+"
+);
+
+test_table!(
+    caption_after_width_increase,
+    create_table::<1, 2>()
+        .with(Caption::bottom("caption"))
+        .with(Width::increase(30)),
+    "+----+-----------+-----------+"
+    "| N  | column 0  | column 1  |"
+    "+----+-----------+-----------+"
+    "| 0  |    0-0    |    0-1    |"
+    "+----+-----------+-----------+"
+    "| caption                    |"
+    "+----------------------------+"
+);
+
+test_table!(
+    caption_style_psql,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Caption::top("top"))
+        .with(Caption::bottom("bottom")),
+    " top                     "
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+    " bottom                  "
+);
+
+test_table!(
+    caption_style_markdown,
+    create_table::<1, 2>()
+        .with(Style::markdown())
+        .with(Caption::top("top")),
+    "| top                     |"
+    "| N | column 0 | column 1 |"
+    "|---|----------|----------|"
+    "| 0 |   0-0    |   0-1    |"
+);
+
+test_table!(
+    caption_margin,
+    create_table::<1, 2>()
+        .with(Margin::new(2, 1, 1, 1).set_fill('>', '<', 'V', '^'))
+        .with(Caption::top("top"))
+        .with(Caption::bottom("bottom")),
+    "VVVVVVVVVVVVVVVVVVVVVVVVVVVVVV"
+    ">>+-------------------------+<"
+    ">>| top                     |<"
+    ">>+---+----------+----------+<"
+    ">>| N | column 0 | column 1 |<"
+    ">>+---+----------+----------+<"
+    ">>| 0 |   0-0    |   0-1    |<"
+    ">>+---+----------+----------+<"
+    ">>| bottom                  |<"
+    ">>+-------------------------+<"
+    "^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^"
+);
+
+test_table!(
+    caption_with_panel,
+    create_table::<1, 2>()
+        .with(Panel::header("panel"))
+        .with(Caption::top("caption")),
+    "+-------------------------+"
+    "| caption                 |"
+    "+---+----------+----------+"
+    "|          panel          |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    caption_empty_table,
+    Table::new(Vec::<String>::new()).with(Caption::top("caption")),
+    "+--------+"
+    "| captio |"
+    "| n      |"
+    "+--------+"
+    "| String |"
+    "+--------+"
+);
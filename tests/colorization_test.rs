@@ -0,0 +1,101 @@
+#![cfg(feature = "color")]
+
+use tabled::{color::Color, object::Columns, Colorization, Modify, Style, Table, Width};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn negative_red(_: (usize, usize), text: &str) -> Option<Color> {
+    match text.parse::<f64>() {
+        Ok(n) if n < 0.0 => Some(Color::FG_RED),
+        _ => None,
+    }
+}
+
+test_table!(
+    colorization_by_value,
+    Table::new([("a", "-1"), ("b", "2"), ("c", "-3.5")])
+        .with(Style::psql())
+        .with(Colorization::by(negative_red)),
+    " &str | &str "
+    "------+------"
+    " a    | \u{1b}[31m-1\u{1b}[39m   "
+    " b    | 2    "
+    " c    | \u{1b}[31m-3.5\u{1b}[39m "
+);
+
+test_table!(
+    colorization_by_position,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Colorization::by(|pos, _| match pos {
+            (0, 1) => Some(Color::FG_BLUE),
+            _ => None,
+        })),
+    " N | \u{1b}[34mcolumn 0\u{1b}[39m | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    colorization_modify,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(0)).with(Colorization::by(|_, _| Some(Color::BG_BLUE)))),
+    " \u{1b}[44mN\u{1b}[49m | column 0 | column 1 "
+    "---+----------+----------"
+    " \u{1b}[44m0\u{1b}[49m |   0-0    |   0-1    "
+    " \u{1b}[44m1\u{1b}[49m |   1-0    |   1-1    "
+);
+
+test_table!(
+    colorization_multiline,
+    Table::new(["a\nb"])
+        .with(Style::psql())
+        .with(Colorization::by(|_, _| Some(Color::FG_GREEN))),
+    " \u{1b}[32m&str\u{1b}[39m "
+    "------"
+    " \u{1b}[32ma\u{1b}[39m    "
+    " \u{1b}[32mb\u{1b}[39m    "
+);
+
+test_table!(
+    colorization_then_wrap,
+    Table::new(["-123456"])
+        .with(Style::psql())
+        .with(Colorization::by(negative_red))
+        .with(Width::wrap(5)),
+    " &st "
+    " r   "
+    "-----"
+    " \u{1b}[31m-12\u{1b}[39m "
+    " \u{1b}[31m345\u{1b}[39m "
+    " \u{1b}[31m6\u{1b}[39m   "
+);
+
+test_table!(
+    colorization_then_truncate,
+    Table::new(["-123456"])
+        .with(Style::psql())
+        .with(Colorization::by(negative_red))
+        .with(Width::truncate(5)),
+    " &st "
+    "-----"
+    " \u{1b}[31m-12\u{1b}[39m "
+);
+
+test_table!(
+    wrap_then_colorization,
+    Table::new(["-123456"])
+        .with(Style::psql())
+        .with(Width::wrap(5))
+        .with(Colorization::by(negative_red)),
+    " &st "
+    " r   "
+    "-----"
+    " -12 "
+    " 345 "
+    " 6   "
+);
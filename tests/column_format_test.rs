@@ -0,0 +1,120 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    object::{Columns, Segment},
+    ColumnFormat, Modify, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    column_format_number,
+    Table::new([1234567, -1234, 12, 0])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::number().thousands_separator('_'))),
+    " i32       "
+    "-----------"
+    " 1_234_567 "
+    " -1_234    "
+    " 12        "
+    " 0         "
+);
+
+test_table!(
+    column_format_number_precision,
+    Table::new(["1.5", "-2000.125", "3", "1e3"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::number().precision(2).thousands_separator(','))),
+    " &str      "
+    "-----------"
+    " 1.50      "
+    " -2,000.12 "
+    " 3         "
+    " 1,000.00  "
+);
+
+test_table!(
+    column_format_number_skips_not_numbers,
+    Table::new(["123456", "abc", "", "inf", " 1000 "])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::number().thousands_separator(' '))),
+    " &str    "
+    "---------"
+    " 123 456 "
+    " abc     "
+    "         "
+    " inf     "
+    " 1 000   "
+);
+
+test_table!(
+    column_format_number_without_header,
+    Builder::from_iter([["1000", "a"], ["2000", "b"]])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(0)).with(ColumnFormat::number().thousands_separator(','))),
+    " 1,000 | a "
+    "-------+---"
+    " 2,000 | b "
+);
+
+test_table!(
+    column_format_datetime,
+    Table::new(["2022-10-05", "2020-02-29 23:05:09", "2000-01-01T01:02:03.456+02:00"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::datetime("%a %e %B %Y %T (%j)"))),
+    " &str                                "
+    "-------------------------------------"
+    " Wed  5 October 2022 00:00:00 (278)  "
+    " Sat 29 February 2020 23:05:09 (060) "
+    " Sat  1 January 2000 01:02:03 (001)  "
+);
+
+test_table!(
+    column_format_datetime_timestamp,
+    Table::new([0i64, 1665000000, -86401])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::datetime("%F %I:%M %p %A"))),
+    " i64                           "
+    "-------------------------------"
+    " 1970-01-01 12:00 AM Thursday  "
+    " 2022-10-05 08:00 PM Wednesday "
+    " 1969-12-30 11:59 PM Tuesday   "
+);
+
+test_table!(
+    column_format_datetime_skips_invalid_dates,
+    Table::new(["2021-02-29", "2022-13-01", "2022-1-1", "2022-01-01 24:00", "today"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::datetime("%d.%m.%y 100%%"))),
+    " &str             "
+    "------------------"
+    " 2021-02-29       "
+    " 2022-13-01       "
+    " 2022-1-1         "
+    " 2022-01-01 24:00 "
+    " today            "
+);
+
+test_table!(
+    column_format_datetime_unknown_specifier,
+    Table::new(["2022-10-05"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::datetime("%Y %Q %"))),
+    " &str      "
+    "-----------"
+    " 2022 %Q % "
+);
+
+test_table!(
+    column_format_precision_has_no_effect_on_dates,
+    Table::new(["2022-10-05"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(ColumnFormat::datetime("%y/%m/%d").precision(2))),
+    " &str     "
+    "----------"
+    " 22/10/05 "
+);
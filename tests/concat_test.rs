@@ -1,4 +1,7 @@
-use tabled::{Concat, Style};
+use tabled::{
+    object::{Cell, Rows, Segment},
+    Alignment, Concat, Modify, Padding, Span, Style,
+};
 
 use crate::util::{create_table, init_table, test_table};
 
@@ -144,3 +147,67 @@ test_table!(
     " 0 |   0-0    |   0-1    |   0-2    "
     " 1 |   1-0    |   1-1    |   1-2    "
 );
+
+test_table!(
+    join_vertical_keeps_alignment,
+    {
+        let mut table1 = create_table::<2, 2>();
+        table1
+            .with(Style::psql())
+            .with(Modify::new(Segment::all()).with(Alignment::left()));
+        let mut table2 = create_table::<1, 2>();
+        table2.with(Modify::new(Rows::new(1..)).with(Alignment::right()));
+        table1.with(Concat::vertical(table2)).to_string()
+    },
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 | 0-0      | 0-1      "
+    " 1 | 1-0      | 1-1      "
+    " N | column 0 | column 1 "
+    " 0 |      0-0 |      0-1 "
+);
+
+test_table!(
+    join_horizontal_keeps_padding,
+    {
+        let mut table1 = create_table::<1, 1>();
+        table1.with(Style::psql());
+        let mut table2 = create_table::<1, 1>();
+        table2.with(Modify::new(Cell(1, 1)).with(Padding::new(5, 5, 0, 0)));
+        table1.with(Concat::horizontal(table2)).to_string()
+    },
+    " N | column 0 | N |  column 0   "
+    "---+----------+---+-------------"
+    " 0 |   0-0    | 0 |     0-0     "
+);
+
+test_table!(
+    join_vertical_keeps_spans,
+    {
+        let mut table1 = create_table::<1, 2>();
+        table1.with(Style::psql());
+        let mut table2 = create_table::<1, 2>();
+        table2.with(Modify::new(Cell(1, 1)).with(Span::column(2)));
+        table1.with(Concat::vertical(table2)).to_string()
+    },
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " N | column 0 | column 1 "
+    " 0 |         0-0         "
+);
+
+test_table!(
+    join_horizontal_keeps_spans_of_lhs,
+    {
+        let mut table1 = create_table::<1, 2>();
+        table1
+            .with(Style::psql())
+            .with(Modify::new(Cell(1, 1)).with(Span::column(2)));
+        let table2 = create_table::<1, 1>();
+        table1.with(Concat::horizontal(table2)).to_string()
+    },
+    " N | column 0 | column 1 | N | column 0 "
+    "---+----------+----------+---+----------"
+    " 0 |         0-0         | 0 |   0-0    "
+);
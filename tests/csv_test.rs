@@ -0,0 +1,93 @@
+#![cfg(feature = "csv")]
+
+use tabled::{builder::Builder, csv::CsvReader, Style, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    csv_table,
+    Table::from_csv("name,version\ntabled,0.10.0\npapergrid,0.7.1\n".as_bytes()).unwrap(),
+    "+-----------+---------+"
+    "| name      | version |"
+    "+-----------+---------+"
+    "| tabled    | 0.10.0  |"
+    "+-----------+---------+"
+    "| papergrid | 0.7.1   |"
+    "+-----------+---------+"
+);
+
+test_table!(
+    csv_has_header,
+    Table::from_csv("a,b\n1,2".as_bytes()).unwrap().has_header(),
+    "true"
+);
+
+test_table!(
+    csv_without_headers,
+    CsvReader::new()
+        .has_headers(false)
+        .read("a,b\n1,2".as_bytes())
+        .unwrap()
+        .build()
+        .with(Style::psql()),
+    " a | b "
+    "---+---"
+    " 1 | 2 "
+);
+
+test_table!(
+    csv_ragged_rows,
+    Builder::from_csv("a,b,c\n1\n1,2,3,4\n".as_bytes()).unwrap().build().with(Style::psql()),
+    " a | b | c |   "
+    "---+---+---+---"
+    " 1 |   |   |   "
+    " 1 | 2 | 3 | 4 "
+);
+
+test_table!(
+    csv_quoted_fields,
+    Table::from_csv("name,quote\r\n\"Smith, John\",\"He said \"\"hi\"\"\nand left\"\r\n".as_bytes())
+        .unwrap()
+        .with(Style::psql()),
+    " name        | quote        "
+    "-------------+--------------"
+    " Smith, John | He said \"hi\" "
+    "             | and left     "
+);
+
+test_table!(
+    csv_empty_fields_and_lines,
+    Table::from_csv("a,b,c\n\n,,\n\n1,,3".as_bytes()).unwrap().with(Style::psql()),
+    " a | b | c "
+    "---+---+---"
+    "   |   |   "
+    " 1 |   | 3 "
+);
+
+test_table!(
+    tsv_table,
+    CsvReader::tsv()
+        .read("a\tb,c\n1\t2,3".as_bytes())
+        .unwrap()
+        .build()
+        .with(Style::psql()),
+    " a | b,c "
+    "---+-----"
+    " 1 | 2,3 "
+);
+
+test_table!(csv_empty, Table::from_csv("".as_bytes()).unwrap(), "");
+
+#[test]
+fn csv_unclosed_quote() {
+    let err = Table::from_csv("a,\"b\n1,2".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn csv_invalid_utf8() {
+    let err = Table::from_csv(&[b'a', 0xff, b'\n'][..]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,58 @@
+use tabled::{
+    custom::{CellMap, TableInspect},
+    object::{Columns, Rows},
+    Modify, Style, Table, Width,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    cell_map_table,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(CellMap::new(|(row, col), text| format!("{}{}:{}", row, col, text))),
+    " 00:N | 01:column 0 | 02:column 1 "
+    "------+-------------+-------------"
+    " 10:0 |   11:0-0    |   12:0-1    "
+    " 20:1 |   21:1-0    |   22:1-1    "
+);
+
+test_table!(
+    cell_map_modify,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(CellMap::new(|_, text| text.repeat(2)))),
+    " N | column 0column 0 | column 1 "
+    "---+------------------+----------"
+    " 0 |      0-00-0      |   0-1    "
+    " 1 |      1-01-0      |   1-1    "
+);
+
+test_table!(
+    cell_map_invalidates_width_cache,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Width::increase(30))
+        .with(Modify::new(Rows::first()).with(CellMap::new(|_, _| String::from("a long long header")))),
+    " a long long header | a long long header | a long long header "
+    "--------------------+--------------------+--------------------"
+    "         0          |        0-0         |        0-1         "
+    "         1          |        1-0         |        1-1         "
+);
+
+#[test]
+fn table_inspect_does_not_change_table() {
+    let mut shapes = Vec::new();
+
+    let mut table = create_table::<2, 2>();
+    let expected = table.to_string();
+
+    table.with(TableInspect::new(|table: &Table| {
+        shapes.push(table.shape())
+    }));
+
+    assert_eq!(shapes, [(3, 3)]);
+    assert_eq!(table.to_string(), expected);
+}
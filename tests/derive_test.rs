@@ -628,6 +628,34 @@ mod structure {
         init: { f1: 0, f2: Some("v2") }
         expected: ["f1", "f2"], ["0", "some v2"]
     );
+    test_struct!(option_fmt_some, t: { f1: u8, #[tabled(option_fmt = "-")] f2: Option<sstr> } init: { f1: 0, f2: Some("v2") } expected: ["f1", "f2"], ["0", "v2"]);
+    test_struct!(option_fmt_none, t: { f1: u8, #[tabled(option_fmt = "-")] f2: Option<sstr> } init: { f1: 0, f2: None } expected: ["f1", "f2"], ["0", "-"]);
+    test_struct!(
+        option_fmt_display_with_has_priority,
+        t: {
+            #[tabled(option_fmt = "-", display_with = "display_option")]
+            f1: Option<u8>,
+        }
+        pre: {
+            fn display_option(o: &Option<u8>) -> String {
+                match o {
+                    Some(s) => format!("some {}", s),
+                    None => "none".to_string(),
+                }
+            }
+        }
+        init: { f1: None }
+        expected: ["f1"], ["none"]
+    );
+    test_struct!(inline_list, t: { f1: u8, #[tabled(inline_list)] f2: Vec<sstr> } init: { f1: 0, f2: vec!["a", "b"] } expected: ["f1", "f2"], ["0", "a, b"]);
+    test_struct!(inline_list_sep, t: { f1: u8, #[tabled(inline_list(sep = "|"))] f2: Vec<u8> } init: { f1: 0, f2: vec![1, 2, 3] } expected: ["f1", "f2"], ["0", "1|2|3"]);
+    test_struct!(inline_list_empty, t: { f1: u8, #[tabled(inline_list)] f2: Vec<u8> } init: { f1: 0, f2: vec![] } expected: ["f1", "f2"], ["0", ""]);
+    test_struct!(inline_list_columns, t: { f1: u8, #[tabled(inline_list(columns = 3))] f2: Vec<sstr> } init: { f1: 0, f2: vec!["a", "b"] } expected: ["f1", "f2_0", "f2_1", "f2_2"], ["0", "a", "b", ""]);
+    test_struct!(inline_list_columns_prefix, t: { #[tabled(inline_list(columns = 2, prefix = "tag_"))] f1: Vec<sstr>, f2: u8 } init: { f1: vec!["a", "b"], f2: 0 } expected: ["tag_0", "tag_1", "f2"], ["a", "b", "0"]);
+    test_struct!(inline_list_columns_overflow, t: { #[tabled(inline_list(columns = 2, sep = "/"))] f1: Vec<sstr> } init: { f1: vec!["a", "b", "c", "d"] } expected: ["f1_0", "f1_1"], ["a", "b/c/d"]);
+    test_struct!(inline_list_columns_rename, t: { #[tabled(rename = "tag", inline_list(columns = 2))] f1: Vec<sstr> } init: { f1: vec!["a"] } expected: ["tag_0", "tag_1"], ["a", ""]);
+    test_struct!(inline_list_map, t: { #[tabled(inline_list)] f1: std::collections::BTreeMap<sstr, u8> } init: { f1: vec![("b", 2), ("a", 1)].into_iter().collect() } expected: ["f1"], ["a: 1, b: 2"]);
+    test_struct!(inline_list_order, t: { f1: u8, #[tabled(inline_list(columns = 2), order = 0)] f2: Vec<u8> } init: { f1: 0, f2: vec![1, 2] } expected: ["f2_0", "f2_1", "f1"], ["1", "2", "0"]);
     test_struct!(order_0, t: { #[tabled(order = 0)] f0: u8, f1: u8, f2: u8 } init: { f0: 0, f1: 1, f2: 2 } expected: ["f0", "f1", "f2"], ["0", "1", "2"]);
     test_struct!(order_1, t: { #[tabled(order = 1)] f0: u8, f1: u8, f2: u8 } init: { f0: 0, f1: 1, f2: 2 } expected: ["f1", "f0", "f2"], ["1", "0", "2"]);
     test_struct!(order_2, t: { #[tabled(order = 2)] f0: u8, f1: u8, f2: u8 } init: { f0: 0, f1: 1, f2: 2 } expected: ["f1", "f2", "f0"], ["1", "2", "0"]);
@@ -761,6 +789,29 @@ test_struct!(
     expected: ["f1", "f2", "F3"], ["123", "456", ""],
 );
 
+#[test]
+fn option_fmt_on_type() {
+    #[derive(Tabled)]
+    #[tabled(option_fmt = "N/A")]
+    struct Struct {
+        f1: Option<u8>,
+        f2: u8,
+        f3: std::option::Option<&'static str>,
+        #[tabled(option_fmt = "")]
+        f4: Option<u8>,
+    }
+
+    let st = Struct {
+        f1: None,
+        f2: 1,
+        f3: Some("v3"),
+        f4: None,
+    };
+
+    assert_eq!(Struct::headers(), vec!["f1", "f2", "f3", "f4"]);
+    assert_eq!(st.fields(), vec!["N/A", "1", "v3", ""]);
+}
+
 #[test]
 fn rename_all_variants() {
     macro_rules! test_case {
@@ -860,3 +911,326 @@ fn test_order_skip_usage() {
     assert_eq!(Example::headers(), vec!["details", "name"],);
     assert_eq!(Example::default().fields(), vec!["", ""]);
 }
+
+#[test]
+fn hideable() {
+    #[derive(Tabled)]
+    struct Struct {
+        f1: u8,
+        #[tabled(hideable)]
+        f2: u8,
+        #[tabled(hideable = false)]
+        f3: u8,
+        #[tabled(skip, hideable)]
+        #[allow(dead_code)]
+        f4: u8,
+    }
+
+    assert_eq!(Struct::headers(), vec!["f1", "f2", "f3"]);
+    assert_eq!(Struct::hideable(), vec![false, true, false]);
+}
+
+#[test]
+fn hideable_on_type() {
+    #[derive(Tabled)]
+    #[tabled(hideable)]
+    struct Struct {
+        f1: u8,
+        f2: u8,
+    }
+
+    assert_eq!(Struct::hideable(), vec![true, true]);
+}
+
+#[test]
+fn hideable_inline() {
+    #[derive(Tabled)]
+    struct Inner {
+        f1: u8,
+        #[tabled(hideable)]
+        f2: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Struct {
+        #[tabled(inline)]
+        f1: Inner,
+        #[tabled(inline, hideable)]
+        f2: Inner,
+        f3: u8,
+    }
+
+    assert_eq!(Struct::hideable(), vec![false, true, true, true, false]);
+}
+
+#[test]
+fn hideable_order() {
+    #[derive(Tabled)]
+    struct Struct {
+        #[tabled(hideable)]
+        f1: u8,
+        f2: u8,
+        #[tabled(order = 0)]
+        f3: u8,
+    }
+
+    assert_eq!(Struct::headers(), vec!["f3", "f1", "f2"]);
+    assert_eq!(Struct::hideable(), vec![false, true, false]);
+}
+
+#[test]
+fn hideable_enum() {
+    #[allow(dead_code)]
+    #[derive(Tabled)]
+    enum Enum {
+        A,
+        #[tabled(hideable)]
+        B,
+        #[tabled(inline)]
+        C {
+            #[tabled(hideable)]
+            f1: u8,
+            f2: u8,
+        },
+        #[tabled(inline, hideable)]
+        D(u8, u8),
+    }
+
+    assert_eq!(Enum::headers(), vec!["A", "B", "f1", "f2", "0", "1"]);
+    assert_eq!(Enum::hideable(), vec![false, true, true, false, true, true]);
+}
+
+#[test]
+fn hideable_tuple() {
+    #[derive(Tabled)]
+    struct Struct {
+        f1: u8,
+        #[tabled(hideable)]
+        f2: u8,
+    }
+
+    assert_eq!(
+        <(Struct, u8, Struct)>::hideable(),
+        vec![false, true, false, false, true]
+    );
+}
+
+#[test]
+fn inline_list_hideable() {
+    #[derive(Tabled)]
+    struct Struct {
+        f1: u8,
+        #[tabled(hideable, inline_list(columns = 2))]
+        f2: Vec<u8>,
+    }
+
+    assert_eq!(Struct::headers(), vec!["f1", "f2_0", "f2_1"]);
+    assert_eq!(Struct::hideable(), vec![false, true, true]);
+}
+
+#[test]
+fn inline_list_enum() {
+    #[allow(dead_code)]
+    #[derive(Tabled)]
+    enum Enum {
+        A,
+        #[tabled(inline("B::"))]
+        B {
+            #[tabled(inline_list(columns = 2))]
+            tags: Vec<&'static str>,
+        },
+    }
+
+    assert_eq!(Enum::headers(), vec!["A", "B::tags_0", "B::tags_1"]);
+    assert_eq!(Enum::B { tags: vec!["x"] }.fields(), vec!["", "x", ""]);
+}
+
+#[test]
+fn hideable_masked_table() {
+    #[derive(Tabled)]
+    struct Process {
+        pid: usize,
+        #[tabled(hideable)]
+        user: &'static str,
+        name: &'static str,
+        #[tabled(hideable)]
+        command: &'static str,
+    }
+
+    let data = [
+        Process {
+            pid: 1,
+            user: "root",
+            name: "init",
+            command: "/sbin/init",
+        },
+        Process {
+            pid: 42,
+            user: "guest",
+            name: "bash",
+            command: "-bash",
+        },
+    ];
+
+    let table = tabled::Table::new_masked(&data, &Process::hideable()).to_string();
+    assert_eq!(
+        table,
+        "+-----+------+\n\
+         | pid | name |\n\
+         +-----+------+\n\
+         | 1   | init |\n\
+         +-----+------+\n\
+         | 42  | bash |\n\
+         +-----+------+"
+    );
+
+    let table = tabled::Table::new_masked(&data, &[]).to_string();
+    assert_eq!(table, tabled::Table::new(&data).to_string());
+}
+
+#[test]
+fn header_groups() {
+    #[derive(Tabled)]
+    struct Price {
+        net: usize,
+        gross: usize,
+    }
+
+    #[derive(Tabled)]
+    #[tabled(rename_all = "UPPERCASE")]
+    struct Item {
+        name: &'static str,
+        #[tabled(inline, group)]
+        price: Price,
+        #[tabled(inline, group = "Tax")]
+        tax: Price,
+        #[tabled(inline)]
+        discount: Price,
+    }
+
+    assert_eq!(
+        Item::header_groups(),
+        vec![
+            vec![],
+            vec!["PRICE"],
+            vec!["PRICE"],
+            vec!["Tax"],
+            vec!["Tax"],
+            vec![],
+            vec![]
+        ]
+    );
+    assert_eq!(Price::header_groups(), vec![Vec::<&str>::new(); 2]);
+}
+
+#[test]
+fn header_groups_nested() {
+    #[derive(Tabled)]
+    struct Price {
+        net: usize,
+        gross: usize,
+    }
+
+    #[derive(Tabled)]
+    struct Money {
+        #[tabled(inline, group)]
+        usd: Price,
+        #[tabled(rename = "rate")]
+        rate: f32,
+    }
+
+    #[derive(Tabled)]
+    struct Item {
+        #[tabled(inline, group = "money")]
+        money: Money,
+        #[tabled(order = 0)]
+        name: &'static str,
+    }
+
+    assert_eq!(
+        Item::header_groups(),
+        vec![
+            vec![],
+            vec!["money", "usd"],
+            vec!["money", "usd"],
+            vec!["money"],
+        ]
+    );
+}
+
+#[test]
+fn header_groups_enum() {
+    #[derive(Tabled)]
+    enum Status {
+        #[tabled(inline, group)]
+        Done {
+            at: usize,
+            by: &'static str,
+        },
+        Pending,
+        #[tabled(inline)]
+        Failed(&'static str),
+    }
+
+    assert_eq!(
+        Status::header_groups(),
+        vec![vec!["Done"], vec!["Done"], vec![], vec![]]
+    );
+}
+
+#[test]
+fn header_groups_table() {
+    #[derive(Tabled)]
+    struct Price {
+        net: usize,
+        gross: usize,
+    }
+
+    #[derive(Tabled)]
+    struct Item {
+        name: &'static str,
+        #[tabled(inline, group)]
+        price: Price,
+    }
+
+    let data = [
+        Item {
+            name: "apple",
+            price: Price { net: 10, gross: 12 },
+        },
+        Item {
+            name: "melon",
+            price: Price { net: 5, gross: 6 },
+        },
+    ];
+
+    let table = tabled::Table::new(&data).to_string();
+    assert_eq!(
+        table,
+        "+-------+-----+-------+\n\
+         | name  | price       |\n\
+         +       +-----+-------+\n\
+         |       | net | gross |\n\
+         +-------+-----+-------+\n\
+         | apple | 10  | 12    |\n\
+         +-------+-----+-------+\n\
+         | melon | 5   | 6     |\n\
+         +-------+-----+-------+"
+    );
+
+    assert_eq!(tabled::Table::builder(&data).build().to_string(), table);
+
+    let table = tabled::Table::new_masked(&data, &[false, true]).to_string();
+    assert_eq!(
+        table,
+        "+-------+-------+\n\
+         | name  | price |\n\
+         +       +-------+\n\
+         |       | gross |\n\
+         +-------+-------+\n\
+         | apple | 12    |\n\
+         +-------+-------+\n\
+         | melon | 6     |\n\
+         +-------+-------+"
+    );
+}
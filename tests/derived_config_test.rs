@@ -0,0 +1,112 @@
+use tabled::{
+    alignment::AlignmentHorizontal,
+    object::{Cell, Segment},
+    style::HorizontalLine,
+    Alignment, Margin, Modify, Span, Style, Table, Theme,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    derive_config_slice,
+    {
+        let mut table = create_table::<3, 2>();
+        table
+            .with(Style::psql())
+            .with(Modify::new(Segment::all()).with(Alignment::right()));
+
+        table.slice(2.., ..).with(table.derive_config()).to_string()
+    },
+    " 1 | 1-0 | 1-1 "
+    "---+-----+-----"
+    " 2 | 2-0 | 2-1 "
+);
+
+test_table!(
+    derive_config_header,
+    {
+        let mut table = create_table::<1, 2>();
+        table.with(
+            Theme::new()
+                .style(Style::psql())
+                .header_alignment(AlignmentHorizontal::Right),
+        );
+
+        let mut other = Table::new([("Hello", "World")]);
+        other.with(table.derive_config()).to_string()
+    },
+    "  &str |  &str "
+    "-------+-------"
+    " Hello | World "
+);
+
+test_table!(
+    derive_config_header_is_not_set_to_a_table_without_one,
+    {
+        let mut table = create_table::<1, 2>();
+        table.with(
+            Theme::new()
+                .style(Style::psql())
+                .header_alignment(AlignmentHorizontal::Right),
+        );
+
+        table.slice(1.., ..).with(table.derive_config()).to_string()
+    },
+    " 0 | 0-0 | 0-1 "
+);
+
+test_table!(
+    derive_config_drops_cell_settings,
+    {
+        let mut table = create_table::<2, 2>();
+        table
+            .with(Style::markdown())
+            .with(Modify::new(Cell(1, 1)).with(Alignment::right()))
+            .with(Modify::new(Cell(2, 1)).with(Span::column(2)));
+
+        let mut other = Table::new([["aaaa", "b", "c"], ["d", "e", "f"]]);
+        other.with(table.derive_config()).to_string()
+    },
+    "|  0   | 1 | 2 |"
+    "|------|---|---|"
+    "| aaaa | b | c |"
+    "|  d   | e | f |"
+);
+
+test_table!(
+    derive_config_lines,
+    {
+        let mut table = create_table::<1, 1>();
+        table.with(
+            Style::modern()
+                .off_horizontal()
+                .horizontals([HorizontalLine::new(1, Style::modern().get_horizontal())]),
+        );
+
+        let mut other = create_table::<3, 1>();
+        other.with(table.derive_config()).to_string()
+    },
+    "┌───┬──────────┐"
+    "│ N │ column 0 │"
+    "├───┼──────────┤"
+    "│ 0 │   0-0    │"
+    "│ 1 │   1-0    │"
+    "│ 2 │   2-0    │"
+    "└───┴──────────┘"
+);
+
+test_table!(
+    derive_config_margin,
+    {
+        let mut table = create_table::<1, 1>();
+        table
+            .with(Style::blank())
+            .with(Margin::new(2, 0, 1, 0));
+
+        table.slice(1.., 1..).with(table.derive_config()).to_string()
+    },
+    "       "
+    "   0-0 "
+);
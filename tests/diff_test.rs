@@ -0,0 +1,273 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, display::TableDiff, Style, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+fn settings(rows: &[(&'static str, &'static str)]) -> Table {
+    Table::new(rows)
+}
+
+test_table!(
+    diff_same_tables,
+    TableDiff::new(
+        &settings(&[("timeout", "30"), ("retries", "3")]),
+        &settings(&[("timeout", "30"), ("retries", "3")]),
+    )
+    .build()
+    .with(Style::psql()),
+    "  | &str    | &str "
+    "--+---------+------"
+    "  | timeout | 30   "
+    "  | retries | 3    "
+);
+
+test_table!(
+    diff_without_key,
+    TableDiff::new(
+        &settings(&[("timeout", "30"), ("retries", "3"), ("debug", "false")]),
+        &settings(&[("timeout", "60"), ("retries", "3"), ("region", "eu")]),
+    )
+    .build()
+    .with(Style::psql()),
+    "   | &str    | &str  "
+    "---+---------+-------"
+    " - | timeout | 30    "
+    " + | timeout | 60    "
+    "   | retries | 3     "
+    " - | debug   | false "
+    " + | region  | eu    "
+);
+
+test_table!(
+    diff_with_key,
+    TableDiff::new(
+        &settings(&[("timeout", "30"), ("retries", "3"), ("debug", "false")]),
+        &settings(&[("region", "eu"), ("timeout", "60"), ("retries", "3")]),
+    )
+    .key([0])
+    .build()
+    .with(Style::psql()),
+    "   | &str    | &str     "
+    "---+---------+----------"
+    " + | region  | eu       "
+    " ~ | timeout | 30 -> 60 "
+    "   | retries | 3        "
+    " - | debug   | false    "
+);
+
+test_table!(
+    diff_with_multi_column_key,
+    TableDiff::new(
+        &Table::new([("db", "host", "a"), ("db", "port", "1"), ("web", "host", "b")]),
+        &Table::new([("db", "host", "c"), ("web", "host", "b"), ("web", "port", "2")]),
+    )
+    .key([0, 1])
+    .build()
+    .with(Style::psql()),
+    "   | &str | &str | &str   "
+    "---+------+------+--------"
+    " ~ | db   | host | a -> c "
+    " - | db   | port | 1      "
+    "   | web  | host | b      "
+    " + | web  | port | 2      "
+);
+
+test_table!(
+    diff_with_key_reordered,
+    TableDiff::new(
+        &settings(&[("timeout", "30"), ("retries", "3"), ("debug", "false")]),
+        &settings(&[("debug", "true"), ("retries", "3"), ("timeout", "30")]),
+    )
+    .key([0])
+    .build()
+    .with(Style::psql()),
+    "   | &str    | &str          "
+    "---+---------+---------------"
+    " ~ | debug   | false -> true "
+    "   | retries | 3             "
+    "   | timeout | 30            "
+);
+
+test_table!(
+    diff_only_changes,
+    TableDiff::new(
+        &settings(&[("timeout", "30"), ("retries", "3"), ("debug", "false")]),
+        &settings(&[("timeout", "60"), ("retries", "3")]),
+    )
+    .key([0])
+    .only_changes()
+    .build()
+    .with(Style::psql()),
+    "   | &str    | &str     "
+    "---+---------+----------"
+    " ~ | timeout | 30 -> 60 "
+    " - | debug   | false    "
+);
+
+test_table!(
+    diff_no_marks,
+    TableDiff::new(
+        &settings(&[("timeout", "30")]),
+        &settings(&[("timeout", "60")]),
+    )
+    .key([0])
+    .no_marks()
+    .build()
+    .with(Style::psql()),
+    " &str    | &str     "
+    "---------+----------"
+    " timeout | 30 -> 60 "
+);
+
+test_table!(
+    diff_empty_tables,
+    TableDiff::new(&settings(&[]), &settings(&[("timeout", "60")]))
+        .build()
+        .with(Style::psql()),
+    "   | &str    | &str "
+    "---+---------+------"
+    " + | timeout | 60   "
+);
+
+test_table!(
+    diff_tables_without_header,
+    TableDiff::new(
+        &Builder::from_iter([["a", "1"], ["b", "2"]]).build(),
+        &Builder::from_iter([["b", "2"], ["c", "3"]]).build(),
+    )
+    .build()
+    .with(Style::psql()),
+    " - | a | 1 "
+    "---+---+---"
+    "   | b | 2 "
+    " + | c | 3 "
+);
+
+test_table!(
+    diff_display,
+    TableDiff::new(&settings(&[("timeout", "30")]), &settings(&[])),
+    "+---+---------+------+"
+    "|   | &str    | &str |"
+    "+---+---------+------+"
+    "| - | timeout | 30   |"
+    "+---+---------+------+"
+);
+
+#[cfg(feature = "derive")]
+#[test]
+fn diff_from_tabled() {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct Setting {
+        name: &'static str,
+        value: usize,
+    }
+
+    let before = vec![
+        Setting {
+            name: "workers",
+            value: 4,
+        },
+        Setting {
+            name: "threads",
+            value: 2,
+        },
+    ];
+    let after = vec![Setting {
+        name: "workers",
+        value: 8,
+    }];
+
+    let table = TableDiff::from_tabled(before, after)
+        .key([0])
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            "   | name    | value  "
+            "---+---------+--------"
+            " ~ | workers | 4 -> 8 "
+            " - | threads | 2      "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn diff_colors() {
+    use tabled::color::Color;
+
+    let table = TableDiff::new(
+        &settings(&[("timeout", "30"), ("debug", "false")]),
+        &settings(&[("timeout", "60"), ("region", "eu")]),
+    )
+    .key([0])
+    .colors(Color::FG_GREEN, Color::FG_RED, Color::FG_YELLOW)
+    .build()
+    .with(Style::psql())
+    .to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            "   | &str    | &str     "
+            "---+---------+----------"
+            " \u{1b}[33m~\u{1b}[39m | timeout | \u{1b}[33m30 -> 60\u{1b}[39m "
+            " \u{1b}[31m-\u{1b}[39m | \u{1b}[31mdebug\u{1b}[39m   | \u{1b}[31mfalse\u{1b}[39m    "
+            " \u{1b}[32m+\u{1b}[39m | \u{1b}[32mregion\u{1b}[39m  | \u{1b}[32meu\u{1b}[39m       "
+        )
+    );
+}
+
+test_table!(
+    diff_header_rows,
+    {
+        let table = |price: &'static str| {
+            Builder::default()
+                .set_header_rows(vec![vec!["", "price"], vec!["item", "net"]])
+                .add_record(["apple", price])
+                .clone()
+                .build()
+        };
+
+        TableDiff::new(&table("10"), &table("12"))
+            .key([0])
+            .build()
+            .with(Style::psql())
+    },
+    "   | item  | net      "
+    "---+-------+----------"
+    " ~ | apple | 10 -> 12 "
+);
+
+#[test]
+fn diff_big_tables_without_key() {
+    let old = (0..1000).map(|i| [i.to_string()]).collect::<Vec<_>>();
+    let mut new = old.clone();
+    new[500] = [String::from("changed")];
+
+    let table = TableDiff::new(
+        &Builder::from_iter(old).build(),
+        &Builder::from_iter(new).build(),
+    )
+    .only_changes()
+    .build()
+    .with(Style::psql())
+    .to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            " - | 500     "
+            "---+---------"
+            " + | changed "
+        )
+    );
+}
@@ -1,8 +1,8 @@
 use tabled::{
     builder::Builder,
     format::Format,
-    object::{Rows, Segment},
-    Alignment, Disable, Extract, Modify, Padding,
+    object::{Cell, Rows, Segment},
+    Alignment, Disable, Extract, Modify, Padding, Span,
 };
 
 use crate::util::{create_table, test_table};
@@ -238,3 +238,60 @@ test_table!(
     Builder::default().build().with(Extract::segment(.., ..)),
     ""
 );
+
+test_table!(
+    slice_test,
+    create_table::<3, 3>().slice(1..3, 1..),
+    "+-----+-----+-----+"
+    "| 0-0 | 0-1 | 0-2 |"
+    "+-----+-----+-----+"
+    "| 1-0 | 1-1 | 1-2 |"
+    "+-----+-----+-----+"
+);
+
+test_table!(
+    slice_keeps_cell_settings_test,
+    create_table::<3, 3>()
+        .with(Modify::new(Cell(2, 2)).with(Padding::new(3, 1, 0, 0)))
+        .with(Modify::new(Cell(3, 1)).with(Format::new(|s| format!("[{}]", s))))
+        .slice(2.., 1..3),
+    "+-------+-------+"
+    "|  1-0  |   1-1 |"
+    "+-------+-------+"
+    "| [2-0] |  2-1  |"
+    "+-------+-------+"
+);
+
+test_table!(
+    slice_keeps_spans_test,
+    create_table::<3, 3>()
+        .with(Modify::new(Cell(1, 1)).with(Span::column(3)))
+        .slice(1..3, 1..3),
+    "+-----+-----+"
+    "|    0-0    |"
+    "+-----+-----+"
+    "| 1-0 | 1-1 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    slice_out_of_bounds_test,
+    create_table::<3, 3>().slice(3..10, ..10),
+    "+---+-----+-----+-----+"
+    "| 2 | 2-0 | 2-1 | 2-2 |"
+    "+---+-----+-----+-----+"
+);
+
+test_table!(slice_empty_test, create_table::<3, 3>().slice(10.., ..), "");
+
+#[test]
+fn slice_is_independent_test() {
+    let table = create_table::<3, 3>();
+    let mut slice = table.slice(..2, ..2);
+    slice.with(Disable::row(Rows::first()));
+
+    assert!(slice.has_header());
+    assert_eq!(slice.shape(), (1, 2));
+    assert_eq!(table.shape(), (4, 4));
+    assert!(!table.slice(1.., ..).has_header());
+}
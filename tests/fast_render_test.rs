@@ -0,0 +1,164 @@
+use tabled::{
+    format::Format,
+    formatting::{AlignmentStrategy, TabSize, TrimStrategy},
+    object::{Cell, Columns, Rows, Segment},
+    papergrid::{height::HeightEstimator, records::Records, width::WidthEstimator, Estimate, Grid},
+    Alignment, Height, Modify, ModifyObject, Padding, Style, Table,
+};
+
+use crate::util::{create_table, init_table, test_table};
+
+mod util;
+
+/// Renders a table by a general [`Grid`] algorithm.
+fn grid_render<R>(table: &Table<R>) -> String
+where
+    R: Records,
+{
+    let records = table.get_records();
+    let cfg = table.get_config();
+
+    let mut width = WidthEstimator::default();
+    width.estimate(records, cfg);
+    let mut height = HeightEstimator::default();
+    height.estimate(records, cfg);
+
+    Grid::new(records, cfg, &width, &height).to_string()
+}
+
+fn assert_fast_render(table: &Table) {
+    assert_eq!(table.to_string(), grid_render(table));
+}
+
+fn multiline_table() -> Table {
+    init_table::<3, 3, _, _>([
+        ((0, 1), "Hello\nWorld"),
+        ((1, 2), "\n\n  1  \n  22\n\n"),
+        ((2, 0), "\ttab"),
+    ])
+}
+
+test_table!(
+    fast_render_blank,
+    create_table::<2, 2>().with(Style::blank()),
+    " N   column 0   column 1 "
+    " 0     0-0        0-1    "
+    " 1     1-0        1-1    "
+);
+
+test_table!(
+    fast_render_empty,
+    multiline_table()
+        .with(Style::empty())
+        .with(Cell(1, 0).modify().with(Padding::new(0, 2, 1, 1)))
+        .with(Alignment::bottom()),
+    "    N      column 0  column 1  column 2 "
+    "                                        "
+    "            Hello                       "
+    "   0        World      0-1       0-2    "
+    "                                        "
+    "                                        "
+    "                        1               "
+    "                        22              "
+    "                                        "
+    "    1        1-0                 1-2    "
+    "     tab    2-0       2-1       2-2    "
+);
+
+#[test]
+fn fast_render_same_as_grid() {
+    assert_fast_render(create_table::<3, 3>().with(Style::blank()));
+    assert_fast_render(create_table::<3, 3>().with(Style::empty()));
+    assert_fast_render(
+        create_table::<3, 3>()
+            .with(Style::blank())
+            .with(Columns::single(1).modify().with(Alignment::right()))
+            .with(Rows::first().modify().with(Padding::new(2, 0, 1, 1))),
+    );
+    assert_fast_render(
+        create_table::<3, 3>()
+            .with(Style::ascii().off_horizontal().off_top().off_bottom())
+            .with(Modify::new(Cell(1, 1)).with(Format::new(|s| format!("{}\n\n", s)))),
+    );
+    assert_fast_render(multiline_table().with(Style::blank()));
+    assert_fast_render(
+        multiline_table()
+            .with(Style::empty())
+            .with(Alignment::center())
+            .with(Alignment::center_vertical())
+            .with(TabSize(2)),
+    );
+    assert_fast_render(
+        multiline_table().with(Style::blank()).with(
+            Segment::all()
+                .modify()
+                .with(Alignment::right())
+                .with(TrimStrategy::Both),
+        ),
+    );
+    assert_fast_render(
+        multiline_table().with(Style::blank()).with(
+            Segment::all()
+                .modify()
+                .with(Alignment::center())
+                .with(AlignmentStrategy::PerLine)
+                .with(TrimStrategy::Horizontal),
+        ),
+    );
+    assert_fast_render(create_table::<3, 3>().with(Style::psql()));
+    assert_fast_render(create_table::<3, 3>().with(Style::ascii()));
+    assert_fast_render(create_table::<3, 3>().with(Style::modern()));
+    assert_fast_render(multiline_table().with(Style::rounded()));
+    assert_fast_render(
+        create_table::<3, 3>()
+            .with(Style::ascii())
+            .with(Padding::new(2, 1, 1, 0)),
+    );
+    assert_fast_render(
+        create_table::<3, 3>()
+            .with(Style::blank())
+            .with(Rows::first().modify().with(Padding::new(2, 0, 1, 1))),
+    );
+}
+
+test_table!(
+    fast_render_psql,
+    create_table::<2, 2>().with(Style::psql()),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    fast_render_height_increase,
+    multiline_table().with(Style::blank()).with(Height::increase(12)),
+    "    N       column 0   column 1   column 2 "
+    "                                           "
+    "    0        Hello       0-1        0-2    "
+    "             World                         "
+    "                                           "
+    "    1         1-0                   1-2    "
+    "                                           "
+    "                          1                "
+    "                          22               "
+    "                                           "
+    "                                           "
+    "     tab     2-0        2-1        2-2    "
+);
+
+#[cfg(feature = "color")]
+#[test]
+fn fast_render_colored_borders_same_as_grid() {
+    use owo_colors::OwoColorize;
+    use tabled::style::{BorderColored, Symbol};
+
+    assert_fast_render(
+        create_table::<3, 3>().with(Style::psql()).with(
+            Rows::single(1).modify().with(
+                BorderColored::filled(Symbol::ansi('*'.blue().to_string()).unwrap())
+                    .top(Symbol::ansi('#'.green().to_string()).unwrap()),
+            ),
+        ),
+    );
+}
@@ -0,0 +1,67 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Filter, Sort, Style, Table};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    filter_rows,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Filter::rows(|record| record[0] != "1")),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |   0-1    |   0-2    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    filter_all_rows_keeps_header,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Filter::rows(|_| false)),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+);
+
+test_table!(
+    filter_rows_without_header,
+    Builder::from_iter([["a", "1"], ["b", "2"], ["c", "3"]])
+        .build()
+        .with(Style::psql())
+        .with(Filter::rows(|record| record[1] != "2")),
+    " a | 1 "
+    "---+---"
+    " c | 3 "
+);
+
+test_table!(
+    filter_and_sort,
+    Table::new([("Tom", 30), ("Ann", 4), ("Bob", 100), ("Kim", 1)])
+        .with(Style::psql())
+        .with(Filter::rows(|record| record[1].parse::<i32>().unwrap() > 1))
+        .with(Sort::by_column(1).numeric().descending()),
+    " &str | i32 "
+    "------+-----"
+    " Bob  | 100 "
+    " Tom  | 30  "
+    " Ann  | 4   "
+);
+
+test_table!(
+    filter_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(Filter::rows(|record| record[1] != "net" && record[0] != "pear"))
+        .with(Style::psql()),
+    " item  | price       "
+    "       +-----+-------"
+    "       | net | gross "
+    " apple | 10  | 12    "
+);
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use tabled::{
     format::Format,
     object::{Cell, Columns, Object, Rows, Segment},
@@ -211,6 +213,79 @@ test_table!(
     "+-------+--------------+--------------+--------------+"
 );
 
+test_table!(
+    formatting_content_test,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Format::content(|s| match s.ends_with("-1") {
+            true => Cow::Owned(format!("[{}]", s)),
+            false => Cow::Borrowed(s),
+        }))),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |  [0-1]   |   0-2    "
+    " 1 |   1-0    |  [1-1]   |   1-2    "
+    " 2 |   2-0    |  [2-1]   |   2-2    "
+);
+
+test_table!(
+    formatting_content_borrowed_part_test,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Modify::new(Columns::new(1..)).with(Format::content(|s| match s.split_once('-') {
+            Some((row, _)) => Cow::Borrowed(row),
+            None => Cow::Borrowed("static"),
+        }))),
+    " N | static | static | static "
+    "---+--------+--------+--------"
+    " 0 |   0    |   0    |   0    "
+    " 1 |   1    |   1    |   1    "
+    " 2 |   2    |   2    |   2    "
+);
+
+test_table!(
+    formatting_positioned_test,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Format::positioned(|s, (row, col)| match row == col {
+            true => Cow::Owned(format!("<{}>", s)),
+            false => Cow::Borrowed(s),
+        }))),
+    " <N> | column 0 | column 1 | column 2 "
+    "-----+----------+----------+----------"
+    "  0  |  <0-0>   |   0-1    |   0-2    "
+    "  1  |   1-0    |  <1-1>   |   1-2    "
+    "  2  |   2-0    |   2-1    |  <2-2>   "
+);
+
+test_table!(
+    formatting_buffered_test,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Format::buffered(|s, buf| {
+            buf.push_str(s);
+            if s.starts_with('1') {
+                buf.push('!');
+            }
+        }))),
+    " N  | column 0 | column 1 | column 2 "
+    "----+----------+----------+----------"
+    " 0  |   0-0    |   0-1    |   0-2    "
+    " 1! |   1-0!   |   1-1!   |   1-2!   "
+    " 2  |   2-0    |   2-1    |   2-2    "
+);
+
+#[test]
+fn formatting_buffered_keeps_widths_of_unchanged_cells() {
+    let mut table = create_table::<3, 3>();
+    table.with(Style::psql());
+    let before = table.to_string();
+
+    table.with(Modify::new(Segment::all()).with(Format::buffered(|s, buf| buf.push_str(s))));
+
+    assert_eq!(table.to_string(), before);
+}
+
 #[cfg(feature = "color")]
 mod color {
     use super::*;
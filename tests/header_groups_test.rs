@@ -0,0 +1,146 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, object::Cell, HeaderGroups, Modify, Span, Style, Table, Width};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table<const N: usize>(columns: [&'static str; N], record: [&'static str; N]) -> Table {
+    Builder::default()
+        .set_columns(columns)
+        .add_record(record)
+        .clone()
+        .build()
+}
+
+test_table!(
+    header_groups,
+    table(
+        ["name", "net.rx", "net.tx", "disk.read", "disk.write"],
+        ["alpha", "10", "2", "300", "40"],
+    )
+    .with(Style::modern())
+    .with(HeaderGroups::new(".")),
+    "┌───────┬────┬────┬──────┬───────┐"
+    "│ name  │ net     │ disk         │"
+    "├       ┼────┼────┼──────┼───────┤"
+    "│       │ rx │ tx │ read │ write │"
+    "├───────┼────┼────┼──────┼───────┤"
+    "│ alpha │ 10 │ 2  │ 300  │ 40    │"
+    "└───────┴────┴────┴──────┴───────┘"
+);
+
+test_table!(
+    header_groups_psql,
+    table(["name", "net.rx", "net.tx"], ["alpha", "10", "2"])
+        .with(Style::psql())
+        .with(HeaderGroups::new(".")),
+    " name  | net     "
+    "       +----+----"
+    "       | rx | tx "
+    " alpha | 10 | 2  "
+);
+
+test_table!(
+    header_groups_not_adjacent,
+    table(["a.x", "b.y", "a.z"], ["1", "2", "3"])
+        .with(Style::modern())
+        .with(HeaderGroups::new(".")),
+    "┌───┬───┬───┐"
+    "│ a │ b │ a │"
+    "├───┼───┼───┤"
+    "│ x │ y │ z │"
+    "├───┼───┼───┤"
+    "│ 1 │ 2 │ 3 │"
+    "└───┴───┴───┘"
+);
+
+test_table!(
+    header_groups_split_at_first_separator,
+    table(["a.b.c", "a.d"], ["1", "2"])
+        .with(Style::modern())
+        .with(HeaderGroups::new(".")),
+    "┌─────┬───┐"
+    "│ a       │"
+    "├─────┼───┤"
+    "│ b.c │ d │"
+    "├─────┼───┤"
+    "│ 1   │ 2 │"
+    "└─────┴───┘"
+);
+
+test_table!(
+    header_groups_custom_separator,
+    table(["net::rx", "net::tx", "net.total"], ["1", "2", "3"])
+        .with(Style::modern())
+        .with(HeaderGroups::new("::")),
+    "┌────┬────┬───────────┐"
+    "│ net     │ net.total │"
+    "├────┼────┼           ┤"
+    "│ rx │ tx │           │"
+    "├────┼────┼───────────┤"
+    "│ 1  │ 2  │ 3         │"
+    "└────┴────┴───────────┘"
+);
+
+test_table!(
+    header_groups_empty_prefix_is_not_a_group,
+    table([".a", "b"], ["1", "2"])
+        .with(Style::psql())
+        .with(HeaderGroups::new(".")),
+    " .a | b "
+    "----+---"
+    " 1  | 2 "
+);
+
+test_table!(
+    header_groups_without_separators,
+    table(["a", "b"], ["1", "2"])
+        .with(Style::psql())
+        .with(HeaderGroups::new(".")),
+    " a | b "
+    "---+---"
+    " 1 | 2 "
+);
+
+test_table!(
+    header_groups_without_header,
+    Builder::from_iter([["a.x", "a.y"], ["1", "2"]])
+        .build()
+        .with(Style::psql())
+        .with(HeaderGroups::new(".")),
+    " a.x | a.y "
+    "-----+-----"
+    " 1   | 2   "
+);
+
+test_table!(
+    header_groups_moves_spans,
+    table(["a.x", "a.y", "b"], ["1", "2", "3"])
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Style::modern())
+        .with(HeaderGroups::new(".")),
+    "┌───┬───┬───┐"
+    "│ a     │ b │"
+    "├───┼───┼   ┤"
+    "│ x │ y │   │"
+    "├───┼───┼───┤"
+    "│ 1     │ 3 │"
+    "└───┴───┴───┘"
+);
+
+test_table!(
+    header_groups_width,
+    table(["network.rx", "network.tx"], ["1", "2"])
+        .with(Style::modern())
+        .with(HeaderGroups::new("."))
+        .with(Width::truncate(9)),
+    "┌───┬───┐"
+    "│ netwo │"
+    "├───┼───┤"
+    "│ r │ t │"
+    "├───┼───┤"
+    "│ 1 │ 2 │"
+    "└───┴───┘"
+);
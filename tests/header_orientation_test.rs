@@ -0,0 +1,129 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder, locator::ByColumnName, Disable, HeaderOrientation, Style, Table, Width,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    header_orientation_vertical,
+    Table::new([(1, true)])
+        .with(Style::psql())
+        .with(HeaderOrientation::Vertical),
+    " i | b    "
+    " 3 | o    "
+    " 2 | o    "
+    "   | l    "
+    "---+------"
+    " 1 | true "
+);
+
+test_table!(
+    header_orientation_vertical_spaces,
+    Builder::default()
+        .set_columns(["a b", "c\nd"])
+        .add_record(["1", "2"])
+        .clone()
+        .build()
+        .with(Style::psql())
+        .with(HeaderOrientation::Vertical),
+    " a | c "
+    "   |   "
+    " b | d "
+    "---+---"
+    " 1 | 2 "
+);
+
+test_table!(
+    header_orientation_horizontal,
+    Table::new([(1, true)])
+        .with(Style::psql())
+        .with(HeaderOrientation::Horizontal),
+    " i32 | bool "
+    "-----+------"
+    " 1   | true "
+);
+
+test_table!(
+    header_orientation_without_header,
+    Builder::from_iter([["abc", "d"]])
+        .build()
+        .with(Style::psql())
+        .with(HeaderOrientation::Vertical),
+    " abc | d "
+);
+
+test_table!(
+    header_orientation_width_is_taken_into_account,
+    Table::new([("a", "b")])
+        .with(Style::psql())
+        .with(HeaderOrientation::Vertical)
+        .with(Width::increase(9)),
+    " &  | &  "
+    " s  | s  "
+    " t  | t  "
+    " r  | r  "
+    "----+----"
+    " a  | b  "
+);
+
+test_table!(
+    header_orientation_column_name_before,
+    Table::new([(1, true)])
+        .with(Style::psql())
+        .with(Disable::column(ByColumnName::new("bool")))
+        .with(HeaderOrientation::Vertical),
+    " i "
+    " 3 "
+    " 2 "
+    "---"
+    " 1 "
+);
+
+test_table!(
+    header_orientation_column_name_after_is_not_found,
+    Table::new([(1, true)])
+        .with(Style::psql())
+        .with(HeaderOrientation::Vertical)
+        .with(Disable::column(ByColumnName::new("bool"))),
+    " i | b    "
+    " 3 | o    "
+    " 2 | o    "
+    "   | l    "
+    "---+------"
+    " 1 | true "
+);
+
+#[test]
+fn header_orientation_total_size() {
+    let mut table = Table::new([(1, 2, 3)]);
+    table.with(HeaderOrientation::Vertical);
+
+    assert_eq!(table.total_width(), 13);
+    assert_eq!(table.total_height(), 7);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn header_orientation_color() {
+    let table = Builder::from_iter([["1"]])
+        .set_columns(["\u{1b}[31mab\u{1b}[39m"])
+        .clone()
+        .build()
+        .with(Style::psql())
+        .with(HeaderOrientation::Vertical)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " \u{1b}[31ma\u{1b}[39m \n",
+            " \u{1b}[31mb\u{1b}[39m \n",
+            "---\n",
+            " 1 ",
+        )
+    );
+}
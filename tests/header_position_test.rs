@@ -0,0 +1,164 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    object::{Cell, Rows},
+    style::HorizontalLine,
+    Alignment, HeaderPosition, Highlight, Modify, Style, Table,
+};
+
+use crate::util::{create_vector, test_table};
+
+mod util;
+
+test_table!(
+    header_position_top,
+    Table::new(create_vector::<2, 2>()).with(HeaderPosition::Top),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    header_position_bottom,
+    Table::new(create_vector::<2, 2>()).with(HeaderPosition::Bottom),
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    header_position_both,
+    Table::new(create_vector::<2, 2>()).with(HeaderPosition::Both),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    header_position_bottom_modern,
+    Table::new(create_vector::<2, 2>())
+        .with(
+            Style::modern()
+                .off_horizontal()
+                .horizontals([HorizontalLine::new(1, Style::modern().get_horizontal())]),
+        )
+        .with(HeaderPosition::Bottom),
+    "┌───┬──────────┬──────────┐"
+    "│ 0 │ 0-0      │ 0-1      │"
+    "│ 1 │ 1-0      │ 1-1      │"
+    "├───┼──────────┼──────────┤"
+    "│ N │ column 0 │ column 1 │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    header_position_both_psql,
+    Table::new(create_vector::<2, 2>())
+        .with(Style::psql())
+        .with(HeaderPosition::Both),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 | 0-0      | 0-1      "
+    " 1 | 1-0      | 1-1      "
+    "---+----------+----------"
+    " N | column 0 | column 1 "
+);
+
+test_table!(
+    header_position_bottom_keeps_cell_settings,
+    Table::new([("abcdef", "x")])
+        .with(Style::psql())
+        .with(Modify::new(Rows::first()).with(Alignment::right()))
+        .with(HeaderPosition::Bottom),
+    " abcdef | x    "
+    "--------+------"
+    "   &str | &str "
+);
+
+test_table!(
+    header_position_bottom_keeps_cell_borders_in_place,
+    Table::new(create_vector::<1, 1>())
+        .with(Style::blank())
+        .with(Highlight::new(Cell(0, 1), Style::ascii().get_frame()))
+        .with(HeaderPosition::Bottom),
+    "   +----------+"
+    " 0 | 0-0      |"
+    "   +----------+"
+    " N   column 0  "
+);
+
+test_table!(
+    header_position_without_header,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Style::psql())
+        .with(HeaderPosition::Bottom),
+    " a | b "
+    "---+---"
+    " c | d "
+);
+
+test_table!(
+    header_position_only_header,
+    Table::new(Vec::<(i32, i32)>::new()).with(HeaderPosition::Both),
+    "+-----+-----+"
+    "| i32 | i32 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    header_position_bottom_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(Style::psql())
+        .with(HeaderPosition::Bottom),
+    " pear  | 8   | 9     "
+    " apple | 10  | 12    "
+    " item  | price       "
+    "       +-----+-------"
+    "       | net | gross "
+);
+
+test_table!(
+    header_position_both_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(Style::modern())
+        .with(HeaderPosition::Both),
+    "┌───────┬─────┬───────┐"
+    "│ item  │ price       │"
+    "├       ┼─────┼───────┤"
+    "│       │ net │ gross │"
+    "├───────┼─────┼───────┤"
+    "│ pear  │ 8   │ 9     │"
+    "├───────┼─────┼───────┤"
+    "│ apple │ 10  │ 12    │"
+    "├───────┼─────┼───────┤"
+    "│ item  │ price       │"
+    "├       ┼─────┼───────┤"
+    "│       │ net │ gross │"
+    "└───────┴─────┴───────┘"
+);
@@ -1,9 +1,13 @@
+use std::iter::FromIterator;
+
 mod util;
 
 use tabled::{
+    builder::Builder,
     format::Format,
     object::{Columns, Segment},
-    Alignment, Height, Modify, Style,
+    peaker::{PriorityMax, PriorityMin},
+    Alignment, Height, Modify, Style, Table,
 };
 
 #[cfg(feature = "color")]
@@ -11,6 +15,10 @@ use owo_colors::OwoColorize;
 
 use util::{create_table, test_table};
 
+fn multiline_table() -> Table {
+    Builder::from_iter([["a\nb\nc\nd", "x"], ["1", "2\n3"], ["q", "w"]]).build()
+}
+
 test_table!(
     cell_height_increase,
     create_table::<3, 3>()
@@ -234,3 +242,63 @@ test_table!(
     "| +------+ |"
     "+----------+"
 );
+
+test_table!(
+    table_height_limit_priority_max,
+    multiline_table().with(Height::limit(8).priority::<PriorityMax>()),
+    "+---+---+"
+    "| a | x |"
+    "| b |   |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| q | w |"
+    "+---+---+"
+);
+
+test_table!(
+    table_height_limit_priority_min,
+    multiline_table().with(Height::limit(8).priority::<PriorityMin>()),
+    "+---+---+"
+    "| a | x |"
+    "| b |   |"
+    "| c |   |"
+    "| d |   |"
+    "+---+---+"
+    "+---+---+"
+    "+---+---+"
+);
+
+test_table!(
+    table_height_increase_priority_max,
+    multiline_table().with(Height::increase(12).priority::<PriorityMax>()),
+    "+---+---+"
+    "| a | x |"
+    "| b |   |"
+    "| c |   |"
+    "| d |   |"
+    "|   |   |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "|   | 3 |"
+    "+---+---+"
+    "| q | w |"
+    "+---+---+"
+);
+
+test_table!(
+    table_height_increase_priority_min,
+    multiline_table().with(Height::increase(12).priority::<PriorityMin>()),
+    "+---+---+"
+    "| a | x |"
+    "| b |   |"
+    "| c |   |"
+    "| d |   |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "|   | 3 |"
+    "+---+---+"
+    "| q | w |"
+    "|   |   |"
+    "+---+---+"
+);
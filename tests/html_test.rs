@@ -0,0 +1,205 @@
+#![cfg(feature = "html")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    html::HtmlTable,
+    object::{Cell, Columns},
+    Alignment, ModifyObject, Span, Table,
+};
+
+use crate::util::{create_table, init_table, test_table};
+
+mod util;
+
+test_table!(
+    html_table,
+    create_table::<2, 2>().to_html(),
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th style=\"text-align: center;\">N</th>"
+    "            <th style=\"text-align: center;\">column 0</th>"
+    "            <th style=\"text-align: center;\">column 1</th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td style=\"text-align: center;\">0</td>"
+    "            <td style=\"text-align: center;\">0-0</td>"
+    "            <td style=\"text-align: center;\">0-1</td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td style=\"text-align: center;\">1</td>"
+    "            <td style=\"text-align: center;\">1-0</td>"
+    "            <td style=\"text-align: center;\">1-1</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_without_header,
+    HtmlTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()),
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>a</td>"
+    "            <td>b</td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td>c</td>"
+    "            <td>d</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_empty,
+    Builder::default().build().to_html(),
+    "<table></table>"
+);
+
+test_table!(
+    html_table_id,
+    HtmlTable::new(&Builder::from_iter([["a"]]).build()).id("a\"b"),
+    "<table id=\"a&quot;b\">"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>a</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_alignment,
+    Table::new([(1, 2, 3)])
+        .with(Columns::single(1).modify().with(Alignment::right()).with(Alignment::bottom()))
+        .with(Columns::single(2).modify().with(Alignment::center_vertical()))
+        .to_html(),
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th>i32</th>"
+    "            <th style=\"text-align: right; vertical-align: bottom;\">i32</th>"
+    "            <th style=\"vertical-align: middle;\">i32</th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>1</td>"
+    "            <td style=\"text-align: right; vertical-align: bottom;\">2</td>"
+    "            <td style=\"vertical-align: middle;\">3</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_spans,
+    create_table::<3, 2>()
+        .with(Cell(1, 1).modify().with(Span::column(2)))
+        .with(Cell(2, 0).modify().with(Span::row(2)))
+        .with(Columns::new(..).modify().with(Alignment::left()))
+        .to_html(),
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th>N</th>"
+    "            <th>column 0</th>"
+    "            <th>column 1</th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>0</td>"
+    "            <td colspan=\"2\">0-0</td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td rowspan=\"2\">1</td>"
+    "            <td>1-0</td>"
+    "            <td>1-1</td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td>2-0</td>"
+    "            <td>2-1</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_header_span_is_not_crossing_body,
+    create_table::<1, 1>()
+        .with(Cell(0, 0).modify().with(Span::row(2)))
+        .with(Columns::new(..).modify().with(Alignment::left()))
+        .to_html(),
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th>N</th>"
+    "            <th>column 0</th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td></td>"
+    "            <td>0-0</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_escaping,
+    init_table::<1, 1, _, _>([((0, 1), "<b>&'\"\nline\r\n2")])
+        .with(Columns::new(..).modify().with(Alignment::left()))
+        .to_html(),
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th>N</th>"
+    "            <th>column 0</th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>0</td>"
+    "            <td>&lt;b&gt;&amp;&#39;&quot;<br>line<br>2</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_ansi_is_stripped_by_default,
+    HtmlTable::new(&Builder::from_iter([["\u{1b}[1;31mHello\u{1b}[0m \u{1b}]8;;https://a.b\u{1b}\\World\u{1b}]8;;\u{1b}\\"]]).build()),
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>Hello World</td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_table_ansi_to_css,
+    HtmlTable::new(
+        &Builder::from_iter([[
+            "\u{1b}[1;31mA\u{1b}[22mB\u{1b}[39;4;48;5;196mC\u{1b}[0mD\u{1b}[38;2;1;2;3;3;9mE\u{1b}[23;29m"
+        ]])
+        .build()
+    )
+    .ansi_to_css(true),
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td><span style=\"color: #cd0000; font-weight: bold;\">A</span><span style=\"color: #cd0000;\">B</span><span style=\"background-color: #ff0000; text-decoration: underline;\">C</span>D<span style=\"color: #010203; font-style: italic; text-decoration: line-through;\">E</span></td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
@@ -0,0 +1,104 @@
+use tabled::{
+    object::{Cell, Segment},
+    InnerTable, Modify, Sort, Table, Width,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn inner() -> Table {
+    create_table::<2, 2>()
+}
+
+test_table!(
+    inner_table_as_content,
+    Table::new([""]).with(Modify::new(Cell(1, 0)).with(InnerTable(inner()))),
+    "+-----------------------------+"
+    "| &str                        |"
+    "+-----------------------------+"
+    "| +---+----------+----------+ |"
+    "| | N | column 0 | column 1 | |"
+    "| +---+----------+----------+ |"
+    "| | 0 |   0-0    |   0-1    | |"
+    "| +---+----------+----------+ |"
+    "| | 1 |   1-0    |   1-1    | |"
+    "| +---+----------+----------+ |"
+    "+-----------------------------+"
+);
+
+test_table!(
+    inner_table_wrap,
+    Table::new([""])
+        .with(Modify::new(Cell(1, 0)).with(InnerTable(inner())))
+        .with(Modify::new(Segment::all()).with(Width::wrap(20))),
+    "+----------------------+"
+    "| &str                 |"
+    "+----------------------+"
+    "| +--+-------+-------+ |"
+    "| |  | colum | colum | |"
+    "| |  | n 0   | n 1   | |"
+    "| +--+-------+-------+ |"
+    "| |  |  0-0  |  0-1  | |"
+    "| +--+-------+-------+ |"
+    "| |  |  1-0  |  1-1  | |"
+    "| +--+-------+-------+ |"
+    "+----------------------+"
+);
+
+test_table!(
+    inner_table_truncate,
+    Table::new([""])
+        .with(Modify::new(Cell(1, 0)).with(InnerTable(inner())))
+        .with(Width::truncate(20)),
+    "+------------------+"
+    "| &str             |"
+    "+------------------+"
+    "| +--+-----+-----+ |"
+    "| |  | col | col | |"
+    "| +--+-----+-----+ |"
+    "| |  | 0-0 | 0-1 | |"
+    "| +--+-----+-----+ |"
+    "| |  | 1-0 | 1-1 | |"
+    "| +--+-----+-----+ |"
+    "+------------------+"
+);
+
+test_table!(
+    inner_table_too_narrow_is_cut_as_text,
+    Table::new([""])
+        .with(Modify::new(Cell(1, 0)).with(InnerTable(inner())))
+        .with(Modify::new(Segment::all()).with(Width::truncate(3))),
+    "+-----+"
+    "| &st |"
+    "+-----+"
+    "| +-- |"
+    "| | N |"
+    "| +-- |"
+    "| | 0 |"
+    "| +-- |"
+    "| | 1 |"
+    "| +-- |"
+    "+-----+"
+);
+
+test_table!(
+    inner_table_is_forgotten_after_move,
+    Table::new(["b", ""])
+        .with(Modify::new(Cell(2, 0)).with(InnerTable(inner())))
+        .with(Sort::by_column(0))
+        .with(Modify::new(Segment::all()).with(Width::truncate(20))),
+    "+----------------------+"
+    "| &str                 |"
+    "+----------------------+"
+    "| +---+----------+---- |"
+    "| | N | column 0 | col |"
+    "| +---+----------+---- |"
+    "| | 0 |   0-0    |   0 |"
+    "| +---+----------+---- |"
+    "| | 1 |   1-0    |   1 |"
+    "| +---+----------+---- |"
+    "+----------------------+"
+    "| b                    |"
+    "+----------------------+"
+);
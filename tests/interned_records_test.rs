@@ -0,0 +1,102 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    format::Format,
+    object::{Columns, Rows},
+    records::InternedRecords,
+    Disable, ModifyObject, Panel, Style,
+};
+
+use util::test_table;
+
+mod util;
+
+test_table!(
+    interned_build,
+    {
+        let mut b = Builder::default();
+        b.set_columns(["name", "status"]);
+        b.add_record(["a", "ok"]);
+        b.add_record(["b", "ok"]);
+        b.add_record(["c"]);
+        b.build_interned()
+    },
+    "+------+--------+"
+    "| name | status |"
+    "+------+--------+"
+    "| a    | ok     |"
+    "+------+--------+"
+    "| b    | ok     |"
+    "+------+--------+"
+    "| c    |        |"
+    "+------+--------+"
+);
+
+test_table!(
+    interned_set,
+    Builder::from_iter([["a", "b"], ["a", "b"]])
+        .build_interned()
+        .with(Style::markdown())
+        .with(Columns::first().modify().with(Format::new(|s| format!("{}!", s)))),
+    "| a! | b |"
+    "|----|---|"
+    "| a! | b |"
+);
+
+test_table!(
+    interned_resize,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build_interned()
+        .with(Style::markdown())
+        .with(Panel::header("panel"))
+        .with(Disable::column(Columns::last())),
+    "| panel |"
+    "|-------|"
+    "| a     |"
+    "| c     |"
+);
+
+#[test]
+fn interned_deduplicates() {
+    let data = (0..100).map(|i| [if i % 2 == 0 { "even" } else { "odd" }, "x"]);
+    let records = InternedRecords::from_iter(data);
+    assert_eq!(records.size(), (100, 2));
+    assert_eq!(records.count_unique(), 3);
+}
+
+#[test]
+fn interned_releases_unused() {
+    let mut table = Builder::from_iter([["a", "b"], ["a", "c"]]).build_interned();
+    assert_eq!(table.get_records().count_unique(), 3);
+
+    table.with(Disable::row(Rows::last()));
+    assert_eq!(table.get_records().count_unique(), 2);
+
+    table.with(
+        Columns::single(1)
+            .modify()
+            .with(Format::new(|_| String::from("a"))),
+    );
+    assert_eq!(table.get_records().count_unique(), 1);
+    assert_eq!(table.to_string(), "+---+---+\n| a | a |\n+---+---+");
+}
+
+#[test]
+fn interned_is_same_as_default() {
+    let data = [["1", "\tone\n1"], ["2", "two"], ["1", "\tone\n1"]];
+
+    let expected = Builder::from_iter(data).build().to_string();
+    let got = Builder::from_iter(data).build_interned().to_string();
+    assert_eq!(got, expected);
+
+    let expected = Builder::from_iter(data)
+        .build()
+        .with(tabled::formatting::TabSize(1))
+        .to_string();
+    let got = Builder::from_iter(data)
+        .build_interned()
+        .with(tabled::formatting::TabSize(1))
+        .to_string();
+    assert_eq!(got, expected);
+}
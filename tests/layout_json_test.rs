@@ -0,0 +1,197 @@
+use std::iter::FromIterator;
+
+use serde_json::{json, Value};
+use tabled::{
+    builder::Builder,
+    object::{Cell, Rows, Segment},
+    Alignment, Margin, Modify, ModifyObject, Padding, Span, Style, Table, Width,
+};
+
+use crate::util::create_table;
+
+mod util;
+
+fn layout(table: &Table) -> Value {
+    serde_json::from_str(&table.layout_json()).expect("a layout must be a valid json")
+}
+
+#[test]
+fn layout_json_default() {
+    let table = Table::new([("a", 1)]);
+
+    assert_eq!(
+        table.layout_json(),
+        concat!(
+            "{\"version\":1,\"rows\":2,\"columns\":2,\"header\":true,",
+            "\"widths\":[6,5],\"heights\":[1,1],",
+            "\"horizontal_lines\":[0,1,2],\"vertical_lines\":[0,1,2],",
+            "\"margin\":{\"top\":0,\"bottom\":0,\"left\":0,\"right\":0},",
+            "\"cells\":[",
+            "{\"row\":0,\"column\":0,\"row_span\":1,\"column_span\":1,\"text\":\"&str\",",
+            "\"alignment\":{\"horizontal\":\"left\",\"vertical\":\"top\"},",
+            "\"padding\":{\"top\":0,\"bottom\":0,\"left\":1,\"right\":1},",
+            "\"border\":{\"top\":\"-\",\"bottom\":\"-\",\"left\":\"|\",\"right\":\"|\",",
+            "\"left_top_corner\":\"+\",\"left_bottom_corner\":\"+\",",
+            "\"right_top_corner\":\"+\",\"right_bottom_corner\":\"+\"}},",
+            "{\"row\":0,\"column\":1,\"row_span\":1,\"column_span\":1,\"text\":\"i32\",",
+            "\"alignment\":{\"horizontal\":\"left\",\"vertical\":\"top\"},",
+            "\"padding\":{\"top\":0,\"bottom\":0,\"left\":1,\"right\":1},",
+            "\"border\":{\"top\":\"-\",\"bottom\":\"-\",\"left\":\"|\",\"right\":\"|\",",
+            "\"left_top_corner\":\"+\",\"left_bottom_corner\":\"+\",",
+            "\"right_top_corner\":\"+\",\"right_bottom_corner\":\"+\"}},",
+            "{\"row\":1,\"column\":0,\"row_span\":1,\"column_span\":1,\"text\":\"a\",",
+            "\"alignment\":{\"horizontal\":\"left\",\"vertical\":\"top\"},",
+            "\"padding\":{\"top\":0,\"bottom\":0,\"left\":1,\"right\":1},",
+            "\"border\":{\"top\":\"-\",\"bottom\":\"-\",\"left\":\"|\",\"right\":\"|\",",
+            "\"left_top_corner\":\"+\",\"left_bottom_corner\":\"+\",",
+            "\"right_top_corner\":\"+\",\"right_bottom_corner\":\"+\"}},",
+            "{\"row\":1,\"column\":1,\"row_span\":1,\"column_span\":1,\"text\":\"1\",",
+            "\"alignment\":{\"horizontal\":\"left\",\"vertical\":\"top\"},",
+            "\"padding\":{\"top\":0,\"bottom\":0,\"left\":1,\"right\":1},",
+            "\"border\":{\"top\":\"-\",\"bottom\":\"-\",\"left\":\"|\",\"right\":\"|\",",
+            "\"left_top_corner\":\"+\",\"left_bottom_corner\":\"+\",",
+            "\"right_top_corner\":\"+\",\"right_bottom_corner\":\"+\"}}",
+            "]}",
+        )
+    );
+}
+
+#[test]
+fn layout_json_sizes_match_rendering() {
+    let table = create_table::<3, 3>();
+    let json = layout(&table);
+
+    let widths = json["widths"].as_array().unwrap();
+    let total_width = widths.iter().map(|w| w.as_u64().unwrap()).sum::<u64>()
+        + json["vertical_lines"].as_array().unwrap().len() as u64;
+
+    let heights = json["heights"].as_array().unwrap();
+    let total_height = heights.iter().map(|h| h.as_u64().unwrap()).sum::<u64>()
+        + json["horizontal_lines"].as_array().unwrap().len() as u64;
+
+    assert_eq!(total_width as usize, table.total_width());
+    assert_eq!(total_height as usize, table.total_height());
+    assert_eq!(json["rows"], 4);
+    assert_eq!(json["columns"], 4);
+    assert_eq!(json["cells"].as_array().unwrap().len(), 16);
+}
+
+#[test]
+fn layout_json_multiline_height() {
+    let table = Builder::from_iter([["a\nb\nc", "d"]]).build();
+    let json = layout(&table);
+
+    assert_eq!(json["header"], false);
+    assert_eq!(json["heights"], json!([3]));
+    assert_eq!(json["cells"][0]["text"], "a\nb\nc");
+}
+
+#[test]
+fn layout_json_spans() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Cell(0, 0).modify().with(Span::column(2)))
+        .with(Cell(1, 0).modify().with(Span::row(2)));
+
+    let json = layout(&table);
+    let cells = json["cells"].as_array().unwrap();
+
+    assert_eq!(cells.len(), 14);
+    assert_eq!(cells[0]["column_span"], 2);
+    assert_eq!(cells[1]["column"], 2);
+    assert_eq!(cells[3]["row"], 1);
+    assert_eq!(cells[3]["column"], 0);
+    assert_eq!(cells[3]["row_span"], 2);
+    assert_eq!(cells[7]["row"], 2);
+    assert_eq!(cells[7]["column"], 1);
+}
+
+#[test]
+fn layout_json_resolved_widths() {
+    let mut table = Table::new([("Hello World", 1)]);
+    table.with(Width::truncate(10));
+
+    let json = layout(&table);
+
+    assert_eq!(json["widths"], json!([5, 2]));
+    assert_eq!(table.total_width(), 10);
+}
+
+#[test]
+fn layout_json_styles() {
+    let mut table = create_table::<1, 2>();
+    table
+        .with(Style::modern())
+        .with(Margin::new(1, 2, 3, 4))
+        .with(Modify::new(Segment::all()).with(Padding::new(0, 2, 1, 0)))
+        .with(Modify::new(Rows::first()).with(Alignment::right()))
+        .with(Modify::new(Rows::first()).with(Alignment::bottom()));
+
+    let json = layout(&table);
+
+    assert_eq!(
+        json["margin"],
+        json!({"top": 3, "bottom": 4, "left": 1, "right": 2})
+    );
+    assert_eq!(
+        json["cells"][0]["padding"],
+        json!({"top": 1, "bottom": 0, "left": 0, "right": 2})
+    );
+    assert_eq!(
+        json["cells"][0]["alignment"],
+        json!({"horizontal": "right", "vertical": "bottom"})
+    );
+    assert_eq!(
+        json["cells"][3]["alignment"],
+        json!({"horizontal": "center", "vertical": "top"})
+    );
+    assert_eq!(
+        json["cells"][3]["border"],
+        json!({
+            "top": "─",
+            "bottom": "─",
+            "left": "│",
+            "right": "│",
+            "left_top_corner": "├",
+            "left_bottom_corner": "└",
+            "right_top_corner": "┼",
+            "right_bottom_corner": "┴",
+        })
+    );
+}
+
+#[test]
+fn layout_json_without_borders() {
+    let mut table = create_table::<1, 1>();
+    table.with(Style::empty());
+
+    let json = layout(&table);
+
+    assert_eq!(json["horizontal_lines"], json!([]));
+    assert_eq!(json["vertical_lines"], json!([]));
+    assert_eq!(json["cells"][0]["border"]["top"], Value::Null);
+}
+
+#[test]
+fn layout_json_escapes_text() {
+    let table = Builder::from_iter([["\"quoted\" \\ \t\u{1b}[31m"]]).build();
+    let json = layout(&table);
+
+    assert_eq!(json["cells"][0]["text"], "\"quoted\" \\ \t\u{1b}[31m");
+}
+
+#[test]
+fn layout_json_empty() {
+    let table = Builder::default().build();
+
+    assert_eq!(
+        table.layout_json(),
+        concat!(
+            "{\"version\":1,\"rows\":0,\"columns\":0,\"header\":false,",
+            "\"widths\":[],\"heights\":[],",
+            "\"horizontal_lines\":[],\"vertical_lines\":[],",
+            "\"margin\":{\"top\":0,\"bottom\":0,\"left\":0,\"right\":0},",
+            "\"cells\":[]}",
+        )
+    );
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use tabled::{display::Live, Style, Table};
+
+#[test]
+fn live_render_first_frame() {
+    let mut live = Live::new(|| Table::new([1]));
+
+    let mut out = Vec::new();
+    live.render(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "+-----+\n",
+            "| i32 |\n",
+            "+-----+\n",
+            "| 1   |\n",
+            "+-----+\n",
+        )
+    );
+}
+
+#[test]
+fn live_render_clears_previous_frame() {
+    let mut count = 0;
+    let mut live = Live::new(|| {
+        count += 1;
+        let mut table = Table::new(1..=count);
+        table.with(Style::blank());
+        table
+    });
+
+    let mut out = Vec::new();
+    live.render(&mut out).unwrap();
+    live.render(&mut out).unwrap();
+    live.render(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            " i32 \n",
+            " 1   \n",
+            "\u{1b}[2A\u{1b}[J",
+            " i32 \n",
+            " 1   \n",
+            " 2   \n",
+            "\u{1b}[3A\u{1b}[J",
+            " i32 \n",
+            " 1   \n",
+            " 2   \n",
+            " 3   \n",
+        )
+    );
+}
+
+#[test]
+fn live_render_empty_table() {
+    let mut live = Live::new(|| {
+        Table::new(Vec::<usize>::new())
+            .with(Style::empty())
+            .to_string()
+    });
+
+    let mut out = Vec::new();
+    live.render(&mut out).unwrap();
+    live.render(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        " usize \n\u{1b}[1A\u{1b}[J usize \n"
+    );
+}
+
+#[test]
+fn live_run_with_limit() {
+    let mut calls = 0;
+    let mut out = Vec::new();
+    Live::new(|| {
+        calls += 1;
+        calls
+    })
+    .interval(Duration::from_millis(0))
+    .limit(3)
+    .run_to(&mut out)
+    .unwrap();
+
+    assert_eq!(calls, 3);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "1\n\u{1b}[1A\u{1b}[J2\n\u{1b}[1A\u{1b}[J3\n"
+    );
+}
+
+#[cfg(feature = "strict")]
+#[test]
+#[should_panic(expected = "Live::run depends on an environment")]
+fn live_run_strict() {
+    let _ = Live::new(|| "Hello").limit(1).run();
+}
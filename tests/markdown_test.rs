@@ -0,0 +1,91 @@
+use tabled::{
+    object::{Columns, Segment},
+    Alignment, ModifyObject, Span, Table,
+};
+
+use crate::util::{create_table, init_table, static_table, test_table};
+
+mod util;
+
+test_table!(
+    markdown_default,
+    create_table::<3, 3>().to_markdown(),
+    "|  N  | column 0 | column 1 | column 2 |"
+    "|:---:|:--------:|:--------:|:--------:|"
+    "|  0  |   0-0    |   0-1    |   0-2    |"
+    "|  1  |   1-0    |   1-1    |   1-2    |"
+    "|  2  |   2-0    |   2-1    |   2-2    |"
+);
+
+test_table!(
+    markdown_alignment,
+    create_table::<3, 3>()
+        .with(Segment::all().modify().with(Alignment::left()))
+        .with(Columns::single(1).modify().with(Alignment::right()))
+        .with(Columns::single(2).modify().with(Alignment::center()))
+        .to_markdown(),
+    "| N   | column 0 | column 1 | column 2 |"
+    "|-----|---------:|:--------:|----------|"
+    "| 0   |      0-0 |   0-1    | 0-2      |"
+    "| 1   |      1-0 |   1-1    | 1-2      |"
+    "| 2   |      2-0 |   2-1    | 2-2      |"
+);
+
+test_table!(
+    markdown_escapes_pipes,
+    init_table::<2, 2, _, _>([((0, 1), "a|b"), ((1, 2), "||")])
+        .with(Segment::all().modify().with(Alignment::left()))
+        .to_markdown(),
+    "| N   | column 0 | column 1 |"
+    "|-----|----------|----------|"
+    "| 0   | a\\|b     | 0-1      |"
+    "| 1   | 1-0      | \\|\\|     |"
+);
+
+test_table!(
+    markdown_multiline,
+    init_table::<2, 2, _, _>([((0, 1), "Hello\nWorld"), ((1, 2), "1\n\n2\r\n3")])
+        .with(Segment::all().modify().with(Alignment::left()))
+        .to_markdown(),
+    "| N   | column 0       | column 1        |"
+    "|-----|----------------|-----------------|"
+    "| 0   | Hello<br>World | 0-1             |"
+    "| 1   | 1-0            | 1<br><br>2<br>3 |"
+);
+
+test_table!(
+    markdown_span_is_ignored,
+    create_table::<2, 2>()
+        .with(Segment::all().modify().with(Alignment::left()))
+        .with(tabled::object::Cell(1, 0).modify().with(Span::column(2)))
+        .to_markdown(),
+    "| N   | column 0 | column 1 |"
+    "|-----|----------|----------|"
+    "| 0   |          | 0-1      |"
+    "| 1   | 1-0      | 1-1      |"
+);
+
+#[test]
+fn markdown_empty() {
+    assert_eq!(Table::new([[0usize; 0]]).to_markdown(), "");
+    assert_eq!(
+        Table::builder(Vec::<String>::new()).build().to_markdown(),
+        "| String |\n|--------|"
+    );
+}
+
+#[test]
+fn markdown_style_is_not_affected() {
+    let table = create_table::<1, 1>();
+    let before = table.to_string();
+    let _ = table.to_markdown();
+    assert_eq!(table.to_string(), before);
+    assert_eq!(
+        table.to_markdown(),
+        static_table!(
+            "|  N  | column 0 |"
+            "|:---:|:--------:|"
+            "|  0  |   0-0    |"
+        )
+    );
+}
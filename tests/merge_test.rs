@@ -1,4 +1,8 @@
-use tabled::{merge::Merge, Table};
+use tabled::{
+    merge::Merge,
+    object::{Columns, Rows},
+    ModifyObject, Table,
+};
 
 use crate::util::test_table;
 
@@ -194,3 +198,48 @@ test_table!(
     "| 2 | 0     |"
     "+---+---+---+"
 );
+
+test_table!(
+    merge_vertical_column,
+    Table::new([[0, 3, 5], [0, 3, 3], [0, 2, 3]])
+        .with(Columns::single(1).modify().with(Merge::vertical())),
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+    "| 0 | 3 | 5 |"
+    "+---+   +---+"
+    "| 0 |   | 3 |"
+    "+---+---+---+"
+    "| 0 | 2 | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    merge_vertical_columns,
+    Table::new([[0, 3, 5], [0, 3, 3], [0, 2, 3]])
+        .with(Columns::new(1..).modify().with(Merge::vertical())),
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+    "| 0 | 3 | 5 |"
+    "+---+   +---+"
+    "| 0 |   | 3 |"
+    "+---+---+   +"
+    "| 0 | 2 |   |"
+    "+---+---+---+"
+);
+
+test_table!(
+    merge_horizontal_row,
+    Table::new([[0, 1, 1], [1, 1, 2], [1, 1, 1]])
+        .with(Rows::last().modify().with(Merge::horizontal())),
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+    "| 0 | 1 | 1 |"
+    "+---+---+---+"
+    "| 1 | 1 | 2 |"
+    "+---+---+---+"
+    "| 1         |"
+    "+---+---+---+"
+);
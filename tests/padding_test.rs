@@ -136,3 +136,46 @@ test_table!(
     "   |          |          |          "
     "   |          |          |          "
 );
+
+#[cfg(feature = "color")]
+test_table!(
+    padding_colored_as_cell,
+    tabled::Table::new(["\u{1b}[44ma\u{1b}[49m", "b", "\u{1b}[31;48;5;100mc\u{1b}[0m"])
+        .with(Style::psql())
+        .with(Padding::new(1, 1, 1, 0).colored_as_cell()),
+    "      "
+    " &str "
+    "------"
+    "\u{1b}[44m      \u{1b}[49m"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44ma\u{1b}[49m   \u{1b}[44m \u{1b}[49m"
+    "      "
+    " b    "
+    "\u{1b}[48;5;100m      \u{1b}[49m"
+    "\u{1b}[48;5;100m \u{1b}[49m\u{1b}[31;48;5;100mc\u{1b}[0m   \u{1b}[48;5;100m \u{1b}[49m"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    padding_colored_as_cell_ignores_foreground,
+    tabled::Table::new(["\u{1b}[38;5;41ma\u{1b}[39m"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Padding::new(1, 1, 0, 0).colored_as_cell())),
+    " &str "
+    "------"
+    " \u{1b}[38;5;41ma\u{1b}[39m    "
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    padding_colored_as_cell_with_colorization,
+    create_table::<1, 2>()
+        .with(Style::psql())
+        .with(tabled::Colorization::by(|(row, _), _| match row {
+            0 => Some(tabled::color::Color::BG_BLUE),
+            _ => None,
+        }))
+        .with(Modify::new(Rows::first()).with(Padding::new(1, 1, 0, 0).colored_as_cell())),
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44mN\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 0\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 1\u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+);
@@ -0,0 +1,149 @@
+use tabled::{object::Segment, Alignment, Modify, Padding, PoolTable, Style, Width};
+
+use crate::util::{static_table, test_table};
+
+mod util;
+
+fn pool() -> PoolTable {
+    PoolTable::new([
+        vec!["CPU", "12%"],
+        vec!["Memory", "1.2G", "of", "16G"],
+        vec!["Uptime: 3 days"],
+    ])
+}
+
+test_table!(
+    pool_table_default,
+    pool(),
+    "+-------------+------------+"
+    "| CPU         | 12%        |"
+    "+--------+----+-+----+-----+"
+    "| Memory | 1.2G | of | 16G |"
+    "+--------+------+----+-----+"
+    "| Uptime: 3 days           |"
+    "+--------------------------+"
+);
+
+test_table!(
+    pool_table_modern,
+    pool().with(Style::modern()),
+    "┌─────────────┬────────────┐"
+    "│ CPU         │ 12%        │"
+    "├────────┬────┴─┬────┬─────┤"
+    "│ Memory │ 1.2G │ of │ 16G │"
+    "├────────┴──────┴────┴─────┤"
+    "│ Uptime: 3 days           │"
+    "└──────────────────────────┘"
+);
+
+test_table!(
+    pool_table_psql,
+    pool().with(Style::psql()),
+    " CPU         | 12%        "
+    " Memory | 1.2G | of | 16G "
+    " Uptime: 3 days           "
+);
+
+test_table!(
+    pool_table_blank,
+    pool().with(Style::blank()),
+    " CPU           12%        "
+    " Memory   1.2G   of   16G "
+    " Uptime: 3 days           "
+);
+
+test_table!(
+    pool_table_rounded_without_horizontal,
+    pool().with(Style::rounded()),
+    "╭─────────────┬────────────╮"
+    "│ CPU         │ 12%        │"
+    "│ Memory │ 1.2G │ of │ 16G │"
+    "│ Uptime: 3 days           │"
+    "╰──────────────────────────╯"
+);
+
+test_table!(
+    pool_table_padding_and_alignment,
+    pool()
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Padding::new(2, 2, 0, 0)))
+        .with(Modify::new(Segment::all()).with(Alignment::center())),
+    "┌─────────────────┬────────────────┐"
+    "│       CPU       │      12%       │"
+    "├──────────┬──────┴─┬──────┬───────┤"
+    "│  Memory  │  1.2G  │  of  │  16G  │"
+    "├──────────┴────────┴──────┴───────┤"
+    "│          Uptime: 3 days          │"
+    "└──────────────────────────────────┘"
+);
+
+test_table!(
+    pool_table_wrap,
+    pool()
+        .with(Style::modern())
+        .with(Width::wrap(24)),
+    "┌───────────┬──────────┐"
+    "│ CPU       │ 12%      │"
+    "├───────┬───┴─┬───┬────┤"
+    "│ Memor │ 1.2 │ o │ 16 │"
+    "│ y     │ G   │ f │ G  │"
+    "├───────┴─────┴───┴────┤"
+    "│ Uptime: 3 days       │"
+    "└──────────────────────┘"
+);
+
+test_table!(
+    pool_table_multiline,
+    PoolTable::new([vec!["a\nb\nc", "d"], vec!["e", "f", "g"]]).with(Style::modern()),
+    "┌─────┬─────┐"
+    "│ a   │ d   │"
+    "│ b   │     │"
+    "│ c   │     │"
+    "├───┬─┴─┬───┤"
+    "│ e │ f │ g │"
+    "└───┴───┴───┘"
+);
+
+test_table!(
+    pool_table_skips_empty_rows,
+    PoolTable::new([vec!["a", "b"], vec![], vec!["c"]]).with(Style::modern()),
+    "┌───┬───┐"
+    "│ a │ b │"
+    "├───┴───┤"
+    "│ c     │"
+    "└───────┘"
+);
+
+test_table!(
+    pool_table_empty,
+    PoolTable::new(Vec::<Vec<String>>::new()).with(Style::modern()),
+);
+
+test_table!(
+    pool_table_single_row,
+    PoolTable::new([["a", "b"]]).with(Style::modern()),
+    "┌───┬───┐"
+    "│ a │ b │"
+    "└───┴───┘"
+);
+
+#[test]
+fn pool_table_get_row_mut() {
+    let mut table = pool();
+    table.with(Style::modern());
+    table.get_row_mut(2).with(Alignment::right());
+
+    assert_eq!(table.count_rows(), 3);
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "┌─────────────┬────────────┐"
+            "│ CPU         │ 12%        │"
+            "├────────┬────┴─┬────┬─────┤"
+            "│ Memory │ 1.2G │ of │ 16G │"
+            "├────────┴──────┴────┴─────┤"
+            "│           Uptime: 3 days │"
+            "└──────────────────────────┘"
+        )
+    );
+}
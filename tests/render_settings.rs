@@ -1,7 +1,7 @@
 use tabled::{
-    formatting::{AlignmentStrategy, TabSize, TrimStrategy},
+    formatting::{AlignmentStrategy, Charset, Substitution, TabSize, TrimStrategy},
     object::{Cell, Segment},
-    Alignment, Modify, Span, Style,
+    Alignment, Modify, Span, Style, Width,
 };
 
 use crate::util::{create_vector, new_table, test_table};
@@ -250,6 +250,54 @@ test_table!(
     "           |          | \u{1b}[44m/en\u{1b}[49m      |          "
 );
 
+test_table!(
+    charset_ascii_test,
+    new_table(unicode_data())
+        .with(Style::psql())
+        .with(Charset::ascii()),
+    "      N      | column 0 | column 1 | column 2 "
+    "-------------+----------+----------+----------"
+    "      0      |   0-0    |   0-1    |   0-2    "
+    " 'Hi' - \"Jo\" |   1-0    |   1-1    |   1-2    "
+    "      2      |   2-0    |   Ao-o   |   2-2    "
+);
+
+test_table!(
+    charset_ascii_unknown_test,
+    new_table(unicode_data())
+        .with(Style::psql())
+        .with(Modify::new(Cell(3, 2)).with(Charset::ascii().unknown('?'))),
+    "      N      | column 0 | column 1 | column 2 "
+    "-------------+----------+----------+----------"
+    "      0      |   0-0    |   0-1    |   0-2    "
+    " ‘Hi’ – “Jo” |   1-0    |   1-1    |   1-2    "
+    "      2      |   2-0    |  Ao?-o   |   2-2    "
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    charset_ascii_colored_test,
+    new_table(colored_data())
+        .with(Modify::new(Cell(2, 0)).with(tabled::format::Format::new(|s| s.replace("asd", "ąśd"))))
+        .with(Style::psql())
+        .with(Charset::ascii()),
+    "     N     | column 0 | column 1 | column 2 "
+    "-----------+----------+----------+----------"
+    "     0     |   0-0    |   0-1    |   0-2    "
+    " \u{1b}[31masd\u{1b}[39m       |   1-0    |   1-1    |   1-2    "
+    " \u{1b}[31m21213123\u{1b}[39m  |          |          |          "
+    "           |          |          |          "
+    " \u{1b}[31m   asdasd\u{1b}[39m |          |          |          "
+    "           |          |          |          "
+    " \u{1b}[31m\u{1b}[39m          |          |          |          "
+    "     2     |   2-0    | \u{1b}[44mhttps://\u{1b}[49m |   2-2    "
+    "           |          | \u{1b}[44mwww\u{1b}[49m      |          "
+    "           |          | \u{1b}[44m.\u{1b}[49m        |          "
+    "           |          | \u{1b}[44mredhat\u{1b}[49m   |          "
+    "           |          | \u{1b}[44m.com\u{1b}[49m     |          "
+    "           |          | \u{1b}[44m/en\u{1b}[49m      |          "
+);
+
 fn multiline_data1() -> Vec<util::Obj<3_usize>> {
     let mut data = create_vector::<3, 3>();
     data[1][0] = String::from("asd\n21213123\n\n   asdasd\n\n");
@@ -279,6 +327,62 @@ fn tab_data2() -> Vec<util::Obj<3_usize>> {
     data
 }
 
+test_table!(
+    substitution_emoji_test,
+    new_table(emoji_data())
+        .with(Style::psql())
+        .with(Substitution::emoji()),
+    "   N    | column 0 | column 1 | column 2 "
+    "--------+----------+----------+----------"
+    " 👨👩👧 |   0-0    |   0-1    |   0-2    "
+    "   1    |   ! <3   |   1-1    |   1-2    "
+    "   2    |   2-0    |    1     |   2-2    "
+);
+
+test_table!(
+    substitution_box_drawing_test,
+    new_table(unicode_data())
+        .with(Style::psql())
+        .with(Substitution::box_drawing()),
+    "      N      | column 0 | column 1 | column 2 "
+    "-------------+----------+----------+----------"
+    "      0      |   0-0    |   0-1    |   0-2    "
+    " ‘Hi’ – “Jo” |   1-0    |   1-1    |   1-2    "
+    "      2      |   2-0    |  Åø✓-ö   |   2-2    "
+);
+
+test_table!(
+    substitution_custom_test,
+    new_table(emoji_data())
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Substitution::new().replace('\u{1F468}', "man").remove('\u{200D}'))),
+    "    N    | column 0 | column 1 | column 2 "
+    "---------+----------+----------+----------"
+    " man👩👧 |   0-0    |   0-1    |   0-2    "
+    "    1    |  ⚠\u{fe0f} ❤\u{fe0f}   |   1-1    |   1-2    "
+    "    2    |   2-0    |    1\u{fe0f}\u{20e3}    |   2-2    "
+);
+
+test_table!(
+    substitution_before_width_test,
+    new_table(emoji_data())
+        .with(Style::psql())
+        .with(Substitution::emoji().replace('\u{1F468}', "M").replace('\u{1F469}', "W").replace('\u{1F467}', "G"))
+        .with(Width::truncate(35)),
+    " N  | column  | column  | column 2 "
+    "----+---------+---------+----------"
+    " MW |   0-0   |   0-1   |   0-2    "
+    " 1  |  ! <3   |   1-1   |   1-2    "
+    " 2  |   2-0   |    1    |   2-2    "
+);
+
+fn unicode_data() -> Vec<util::Obj<3_usize>> {
+    let mut data = create_vector::<3, 3>();
+    data[1][0] = String::from("\u{2018}Hi\u{2019} \u{2013} \u{201C}Jo\u{201D}");
+    data[2][2] = String::from("\u{C5}\u{F8}\u{2713}\u{2500}\u{F6}");
+    data
+}
+
 #[cfg(feature = "color")]
 fn colored_data() -> Vec<util::Obj<3_usize>> {
     use owo_colors::OwoColorize;
@@ -287,3 +391,11 @@ fn colored_data() -> Vec<util::Obj<3_usize>> {
     data[2][2] = "https://\nwww\n.\nredhat\n.com\n/en".on_blue().to_string();
     data
 }
+
+fn emoji_data() -> Vec<util::Obj<3_usize>> {
+    let mut data = create_vector::<3, 3>();
+    data[0][0] = String::from("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}");
+    data[1][1] = String::from("\u{26A0}\u{FE0F} \u{2764}\u{FE0F}");
+    data[2][2] = String::from("1\u{FE0F}\u{20E3}");
+    data
+}
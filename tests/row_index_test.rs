@@ -0,0 +1,93 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, object::Columns, Extract, Modify, RowIndex, Style, Table, Width};
+
+use crate::util::{create_vector, test_table};
+
+mod util;
+
+test_table!(
+    row_index,
+    Table::new(create_vector::<3, 2>()).with(RowIndex::new()),
+    "+---+---+----------+----------+"
+    "|   | N | column 0 | column 1 |"
+    "+---+---+----------+----------+"
+    "| 0 | 0 | 0-0      | 0-1      |"
+    "+---+---+----------+----------+"
+    "| 1 | 1 | 1-0      | 1-1      |"
+    "+---+---+----------+----------+"
+    "| 2 | 2 | 2-0      | 2-1      |"
+    "+---+---+----------+----------+"
+);
+
+test_table!(
+    row_index_start_at_with_name,
+    Table::new(create_vector::<2, 2>())
+        .with(Style::psql())
+        .with(RowIndex::new().start_at(1).name("#")),
+    " # | N | column 0 | column 1 "
+    "---+---+----------+----------"
+    " 1 | 0 | 0-0      | 0-1      "
+    " 2 | 1 | 1-0      | 1-1      "
+);
+
+test_table!(
+    row_index_without_header,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Style::psql())
+        .with(RowIndex::new().name("#")),
+    " 0 | a | b "
+    "---+---+---"
+    " 1 | c | d "
+);
+
+test_table!(
+    row_index_extract,
+    Table::new(create_vector::<3, 2>())
+        .with(Style::psql())
+        .with(RowIndex::new().name("#"))
+        .with(Extract::segment(1.., ..2)),
+    " 0 | 0 "
+    "---+---"
+    " 1 | 1 "
+    " 2 | 2 "
+);
+
+test_table!(
+    row_index_wrap,
+    Table::new(["a", "b"])
+        .with(Style::psql())
+        .with(RowIndex::new().name("index"))
+        .with(Modify::new(Columns::first()).with(Width::wrap(3))),
+    " ind | &str "
+    " ex  |      "
+    "-----+------"
+    " 0   | a    "
+    " 1   | b    "
+);
+
+test_table!(
+    row_index_empty,
+    Table::new(Vec::<&str>::new()).with(RowIndex::new()),
+    "+--+------+"
+    "|  | &str |"
+    "+--+------+"
+);
+
+test_table!(
+    row_index_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(RowIndex::new().name("N"))
+        .with(Style::psql()),
+    " N | item  | price       "
+    "   +       +-----+-------"
+    "   |       | net | gross "
+    " 0 | pear  | 8   | 9     "
+    " 1 | apple | 10  | 12    "
+);
@@ -0,0 +1,127 @@
+#![cfg(feature = "derive")]
+
+use tabled::{
+    selection::{Selection, SelectionError},
+    Disable, Style, Table, Tabled,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+#[derive(Tabled)]
+struct Process {
+    pid: usize,
+    #[tabled(rename = "Command Name")]
+    name: &'static str,
+    cpu: f32,
+}
+
+fn data() -> Vec<Process> {
+    vec![
+        Process {
+            pid: 1,
+            name: "init",
+            cpu: 0.1,
+        },
+        Process {
+            pid: 22,
+            name: "sshd",
+            cpu: 1.5,
+        },
+    ]
+}
+
+test_table!(
+    selection_mask,
+    Table::new_masked(
+        data(),
+        &Selection::<Process>::from_names(["cpu", "pid"]).unwrap().mask()
+    )
+    .with(Style::psql()),
+    " pid | cpu "
+    "-----+-----"
+    " 1   | 0.1 "
+    " 22  | 1.5 "
+);
+
+test_table!(
+    selection_unselected,
+    Table::new(data())
+        .with(Disable::column(Selection::<Process>::from_names(["Command Name"]).unwrap().unselected()))
+        .with(Style::psql()),
+    " Command Name "
+    "--------------"
+    " init         "
+    " sshd         "
+);
+
+test_table!(
+    selection_all,
+    Table::new(data())
+        .with(Disable::column(Selection::<Process>::all().unselected()))
+        .with(Style::psql()),
+    " pid | Command Name | cpu "
+    "-----+--------------+-----"
+    " 1   | init         | 0.1 "
+    " 22  | sshd         | 1.5 "
+);
+
+#[test]
+fn selection_columns_keep_order() {
+    let selection =
+        Selection::<Process>::from_names(vec![String::from("cpu"), String::from("pid")]).unwrap();
+
+    assert_eq!(selection.columns(), [2, 0]);
+    assert_eq!(selection.mask(), [false, true, false]);
+}
+
+#[test]
+fn selection_ignores_case() {
+    let selection = Selection::<Process>::from_names(["PID", "command name"]).unwrap();
+
+    assert_eq!(selection.columns(), [0, 1]);
+}
+
+#[test]
+fn selection_unknown_column() {
+    let error = Selection::<Process>::from_names(["pid", "comand name"]).unwrap_err();
+
+    assert_eq!(
+        error,
+        SelectionError::UnknownColumn {
+            name: String::from("comand name"),
+            suggestion: Some(String::from("Command Name")),
+            available: vec![
+                String::from("pid"),
+                String::from("Command Name"),
+                String::from("cpu"),
+            ],
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "unknown column \"comand name\", did you mean \"Command Name\"? available columns are: pid, Command Name, cpu"
+    );
+}
+
+#[test]
+fn selection_unknown_column_without_suggestion() {
+    let error = Selection::<Process>::from_names(["memory"]).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "unknown column \"memory\", available columns are: pid, Command Name, cpu"
+    );
+}
+
+#[test]
+fn selection_duplicate_column() {
+    let error = Selection::<Process>::from_names(["pid", "cpu", "PID"]).unwrap_err();
+
+    assert_eq!(error, SelectionError::DuplicateColumn(String::from("PID")));
+    assert_eq!(
+        error.to_string(),
+        "column \"PID\" is selected more than once"
+    );
+}
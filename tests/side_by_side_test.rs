@@ -0,0 +1,82 @@
+use tabled::{display::SideBySide, Style, Table};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    side_by_side,
+    SideBySide::new(&create_table::<1, 1>(), &create_table::<1, 2>()),
+    "+---+----------+ +---+----------+----------+"
+    "| N | column 0 | | N | column 0 | column 1 |"
+    "+---+----------+ +---+----------+----------+"
+    "| 0 |   0-0    | | 0 |   0-0    |   0-1    |"
+    "+---+----------+ +---+----------+----------+"
+);
+
+test_table!(
+    side_by_side_gap,
+    SideBySide::new(&Table::new(["a"]), &Table::new(["b"])).gap(0),
+    "+------++------+"
+    "| &str || &str |"
+    "+------++------+"
+    "| a    || b    |"
+    "+------++------+"
+);
+
+test_table!(
+    side_by_side_balances_rows,
+    SideBySide::new(
+        &Table::new(["1\n2\n3", "4"]),
+        &Table::new(["1", "2\n3"]),
+    )
+    .gap(2),
+    "+------+  +------+"
+    "| &str |  | &str |"
+    "+------+  +------+"
+    "| 1    |  | 1    |"
+    "| 2    |  |      |"
+    "| 3    |  |      |"
+    "+------+  +------+"
+    "| 4    |  | 2    |"
+    "|      |  | 3    |"
+    "+------+  +------+"
+);
+
+test_table!(
+    side_by_side_left_is_shorter,
+    SideBySide::new(
+        &Table::new(["a"]).with(Style::psql()).clone(),
+        &Table::new(["b", "c", "d"]).with(Style::psql()).clone(),
+    ),
+    " &str   &str "
+    "------ ------"
+    " a      b    "
+    "        c    "
+    "        d    "
+);
+
+test_table!(
+    side_by_side_right_is_shorter,
+    SideBySide::new(
+        &Table::new(["a", "b", "c"]).with(Style::psql()).clone(),
+        &Table::new(["d"]).with(Style::psql()).clone(),
+    ),
+    " &str   &str "
+    "------ ------"
+    " a      d    "
+    " b    "
+    " c    "
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    side_by_side_color,
+    SideBySide::new(
+        &Table::new(["\u{1b}[31mred\u{1b}[39m"]).with(Style::psql()).clone(),
+        &Table::new(["b"]).with(Style::psql()).clone(),
+    ),
+    " &str   &str "
+    "------ ------"
+    " \u{1b}[31mred\u{1b}[39m    b    "
+);
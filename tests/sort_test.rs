@@ -0,0 +1,124 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Rotate, Sort, Style, Table};
+
+use crate::util::{init_table, test_table};
+
+mod util;
+
+test_table!(
+    sort_by_column,
+    init_table::<3, 3, _, _>([((0, 1), "c"), ((1, 1), "a"), ((2, 1), "b")])
+        .with(Style::psql())
+        .with(Sort::by_column(1)),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 1 |    a     |   1-1    |   1-2    "
+    " 2 |    b     |   2-1    |   2-2    "
+    " 0 |    c     |   0-1    |   0-2    "
+);
+
+test_table!(
+    sort_by_column_descending,
+    init_table::<3, 3, _, _>([((0, 1), "c"), ((1, 1), "a"), ((2, 1), "b")])
+        .with(Style::psql())
+        .with(Sort::by_column(1).descending()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |    c     |   0-1    |   0-2    "
+    " 2 |    b     |   2-1    |   2-2    "
+    " 1 |    a     |   1-1    |   1-2    "
+);
+
+test_table!(
+    sort_numeric,
+    Table::new(["10", "9", "x", "-1.5", "100"])
+        .with(Style::psql())
+        .with(Sort::by_column(0).numeric()),
+    " &str "
+    "------"
+    " -1.5 "
+    " 9    "
+    " 10   "
+    " 100  "
+    " x    "
+);
+
+test_table!(
+    sort_lexicographic,
+    Table::new(["10", "9", "x", "-1.5", "100"])
+        .with(Style::psql())
+        .with(Sort::by_column(0)),
+    " &str "
+    "------"
+    " -1.5 "
+    " 10   "
+    " 100  "
+    " 9    "
+    " x    "
+);
+
+test_table!(
+    sort_custom_comparator_is_stable,
+    Table::new(["bb", "a", "cc", "d", "eee"])
+        .with(Style::psql())
+        .with(Sort::by_column(0).by(|a, b| a.len().cmp(&b.len()))),
+    " &str "
+    "------"
+    " a    "
+    " d    "
+    " bb   "
+    " cc   "
+    " eee  "
+);
+
+test_table!(
+    sort_without_header,
+    Builder::from_iter([["c", "1"], ["a", "2"], ["b", "3"]])
+        .build()
+        .with(Style::psql())
+        .with(Sort::by_column(0)),
+    " a | 2 "
+    "---+---"
+    " b | 3 "
+    " c | 1 "
+);
+
+test_table!(
+    sort_column_out_of_bounds,
+    Table::new(["b", "a"]).with(Style::psql()).with(Sort::by_column(10)),
+    " &str "
+    "------"
+    " b    "
+    " a    "
+);
+
+test_table!(
+    sort_after_rotate,
+    Builder::from_iter([["c", "b", "a"], ["1", "2", "3"]])
+        .build()
+        .with(Rotate::Left)
+        .with(Style::psql())
+        .with(Sort::by_column(0)),
+    " a | 3 "
+    "---+---"
+    " b | 2 "
+    " c | 1 "
+);
+
+test_table!(
+    sort_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(Sort::by_column(0))
+        .with(Style::psql()),
+    " item  | price       "
+    "       +-----+-------"
+    "       | net | gross "
+    " apple | 10  | 12    "
+    " pear  | 8   | 9     "
+);
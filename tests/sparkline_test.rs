@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use tabled::{
+    display::Live,
+    object::{Cell, Columns},
+    Sparkline, Style, Table,
+};
+
+use crate::util::static_table;
+
+mod util;
+
+fn render<O>(sparkline: &mut Sparkline<O>, data: &[(&str, f64)]) -> String
+where
+    O: tabled::object::Object,
+{
+    let mut table = Table::new(data);
+    table.with(Style::modern()).with(sparkline);
+    table.to_string()
+}
+
+#[test]
+fn sparkline_first_render() {
+    let mut sparkline = Sparkline::new(Columns::single(1));
+    let table = render(&mut sparkline, &[("cpu", 10.0), ("mem", 2.5)]);
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──────┬───────┐"
+            "│ &str │ f64   │"
+            "├──────┼───────┤"
+            "│ cpu  │ 10 ▁  │"
+            "├──────┼───────┤"
+            "│ mem  │ 2.5 ▁ │"
+            "└──────┴───────┘"
+        )
+    );
+}
+
+#[test]
+fn sparkline_trend() {
+    let mut sparkline = Sparkline::new(Columns::single(1));
+    for i in 0..8 {
+        render(
+            &mut sparkline,
+            &[("cpu", i as f64), ("mem", (8 - i) as f64)],
+        );
+    }
+
+    let table = render(&mut sparkline, &[("cpu", 8.0), ("mem", 0.0)]);
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──────┬────────────┐"
+            "│ &str │ f64        │"
+            "├──────┼────────────┤"
+            "│ cpu  │ 8 ▁▂▃▄▅▆▇█ │"
+            "├──────┼────────────┤"
+            "│ mem  │ 0 █▇▆▅▄▃▂▁ │"
+            "└──────┴────────────┘"
+        )
+    );
+}
+
+#[test]
+fn sparkline_length() {
+    let mut sparkline = Sparkline::new(Columns::single(1)).length(3);
+    for value in [1.0, 100.0, 2.0, 3.0] {
+        render(&mut sparkline, &[("cpu", value)]);
+    }
+
+    let table = render(&mut sparkline, &[("cpu", 4.0)]);
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──────┬───────┐"
+            "│ &str │ f64   │"
+            "├──────┼───────┤"
+            "│ cpu  │ 4 ▁▅█ │"
+            "└──────┴───────┘"
+        )
+    );
+}
+
+#[test]
+fn sparkline_skips_not_numbers() {
+    let mut sparkline = Sparkline::new(Columns::first());
+    let table = render(&mut sparkline, &[("cpu", 1.0)]);
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──────┬─────┐"
+            "│ &str │ f64 │"
+            "├──────┼─────┤"
+            "│ cpu  │ 1   │"
+            "└──────┴─────┘"
+        )
+    );
+}
+
+#[test]
+fn sparkline_single_cell() {
+    let mut sparkline = Sparkline::new(Cell(2, 1));
+    render(&mut sparkline, &[("cpu", 1.0), ("mem", 1.0)]);
+    let table = render(&mut sparkline, &[("cpu", 2.0), ("mem", 3.0)]);
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──────┬──────┐"
+            "│ &str │ f64  │"
+            "├──────┼──────┤"
+            "│ cpu  │ 2    │"
+            "├──────┼──────┤"
+            "│ mem  │ 3 ▁█ │"
+            "└──────┴──────┘"
+        )
+    );
+}
+
+#[test]
+fn sparkline_clear() {
+    let mut sparkline = Sparkline::new(Columns::single(1));
+    render(&mut sparkline, &[("cpu", 1.0)]);
+    sparkline.clear();
+
+    let table = render(&mut sparkline, &[("cpu", 2.0)]);
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──────┬─────┐"
+            "│ &str │ f64 │"
+            "├──────┼─────┤"
+            "│ cpu  │ 2 ▁ │"
+            "└──────┴─────┘"
+        )
+    );
+}
+
+#[test]
+fn sparkline_live() {
+    let mut values = vec![3, 1, 2].into_iter();
+    let mut sparkline = Sparkline::new(Columns::single(1));
+    let mut out = Vec::new();
+
+    Live::new(|| {
+        let mut table = Table::new([("cpu", values.next().unwrap())]);
+        table.with(Style::blank()).with(&mut sparkline);
+        table
+    })
+    .interval(Duration::from_millis(0))
+    .limit(3)
+    .run_to(&mut out)
+    .unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            " &str   i32 \n",
+            " cpu    3 ▁ \n",
+            "\u{1b}[2A\u{1b}[J",
+            " &str   i32  \n",
+            " cpu    1 █▁ \n",
+            "\u{1b}[2A\u{1b}[J",
+            " &str   i32   \n",
+            " cpu    2 █▁▅ \n",
+        )
+    );
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "color")]
+
+use tabled::{
+    builder::Builder,
+    color::Color,
+    object::{Cell, Segment},
+    Alignment, Modify, Padding, Span, Stripe, Style, Table, Width,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    stripe_rows,
+    create_table::<3, 1>()
+        .with(Style::psql())
+        .with(Stripe::new(Color::BG_BLUE, Color::BG_RED)),
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44mN\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 0\u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "---+----------"
+    "\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  0-0   \u{1b}[49m\u{1b}[41m \u{1b}[49m"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m1\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m  1-0   \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "\u{1b}[41m \u{1b}[49m\u{1b}[41m2\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  2-0   \u{1b}[49m\u{1b}[41m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_skip_header,
+    create_table::<3, 1>()
+        .with(Style::psql())
+        .with(Stripe::new(Color::BG_BLUE, Color::default()).skip_header()),
+    " N | column 0 "
+    "---+----------"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m0\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m  0-0   \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    " 1 |   1-0    "
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m2\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m  2-0   \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_alignment,
+    create_table::<2, 1>()
+        .with(Style::psql())
+        .with(Stripe::new(Color::BG_BLUE, Color::BG_RED))
+        .with(Modify::new(Segment::all()).with(Alignment::right())),
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44mN\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 0\u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "---+----------"
+    "\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m     0-0\u{1b}[49m\u{1b}[41m \u{1b}[49m"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m1\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m     1-0\u{1b}[49m\u{1b}[44m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_multiline_rows,
+    Table::new(["Hello World", "a"])
+        .with(Style::psql())
+        .with(Stripe::new(Color::BG_BLUE, Color::BG_RED).skip_header())
+        .with(Width::wrap(8)),
+    " &str   "
+    "--------"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44mHello \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44mWorld \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "\u{1b}[41m \u{1b}[49m\u{1b}[41ma     \u{1b}[49m\u{1b}[41m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_vertical_padding,
+    create_table::<1, 1>()
+        .with(Style::psql())
+        .with(Padding::new(1, 1, 1, 0))
+        .with(Stripe::new(Color::BG_BLUE, Color::BG_RED).skip_header()),
+    "   |          "
+    " N | column 0 "
+    "---+----------"
+    "\u{1b}[44m   \u{1b}[49m|\u{1b}[44m          \u{1b}[49m"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m0\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m  0-0   \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_colored_content,
+    Table::new(["\u{1b}[31mred\u{1b}[39m text", "\u{1b}[43myellow\u{1b}[49m text"])
+        .with(Style::psql())
+        .with(Stripe::new(Color::BG_BLUE, Color::BG_BLUE).skip_header()),
+    " &str        "
+    "-------------"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m\u{1b}[31mred\u{1b}[39m text   \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m\u{1b}[43myellow\u{1b}[49m\u{1b}[44m text\u{1b}[49m\u{1b}[44m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_span,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Stripe::new(Color::BG_BLUE, Color::BG_RED).skip_header()),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m     0      \u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m  0-1   \u{1b}[49m\u{1b}[44m \u{1b}[49m"
+    "\u{1b}[41m \u{1b}[49m\u{1b}[41m1\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  1-0   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  1-1   \u{1b}[49m\u{1b}[41m \u{1b}[49m"
+);
+
+test_table!(
+    stripe_empty_table,
+    Table::new(Vec::<String>::new()).with(Stripe::new(Color::BG_BLUE, Color::BG_RED).skip_header()),
+    "+--------+"
+    "| String |"
+    "+--------+"
+);
+
+test_table!(
+    stripe_skip_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "g"], vec!["a", "b"]])
+        .add_record(["1", "2"])
+        .clone()
+        .build()
+        .with(Style::psql())
+        .with(Stripe::new(Color::BG_BLUE, Color::default()).skip_header()),
+    " a | g "
+    "   +---"
+    "   | b "
+    "\u{1b}[44m \u{1b}[49m\u{1b}[44m1\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44m2\u{1b}[49m\u{1b}[44m \u{1b}[49m"
+);
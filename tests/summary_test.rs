@@ -0,0 +1,197 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    object::{Cell, Columns, Object, Rows},
+    summary::Summary,
+    Alignment, Modify, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn sales() -> Table {
+    Table::new([
+        ("apple", 3, "1,200.5"),
+        ("melon", 10, "800.25"),
+        ("lemon", 7, "n/a"),
+    ])
+}
+
+test_table!(
+    summary_sum,
+    sales()
+        .with(Summary::new().sum(Columns::new(1..)).label("Total"))
+        .with(Style::psql()),
+    " &str  | i32 | &str     "
+    "-------+-----+----------"
+    " apple | 3   | 1,200.5  "
+    " melon | 10  | 800.25   "
+    " lemon | 7   | n/a      "
+    " Total | 20  | 2,000.75 "
+);
+
+test_table!(
+    summary_count,
+    sales()
+        .with(Summary::new().count(Columns::new(..)))
+        .with(Style::psql()),
+    " &str  | i32 | &str    "
+    "-------+-----+---------"
+    " apple | 3   | 1,200.5 "
+    " melon | 10  | 800.25  "
+    " lemon | 7   | n/a     "
+    " 3     | 3   | 3       "
+);
+
+test_table!(
+    summary_avg,
+    sales()
+        .with(Summary::new().avg(Columns::new(1..)).label("Avg"))
+        .with(Style::psql()),
+    " &str  | i32  | &str     "
+    "-------+------+----------"
+    " apple | 3    | 1,200.5  "
+    " melon | 10   | 800.25   "
+    " lemon | 7    | n/a      "
+    " Avg   | 6.67 | 1,000.38 "
+);
+
+test_table!(
+    summary_min_max,
+    sales()
+        .with(Summary::new().min(Columns::single(1)).max(Columns::single(2)))
+        .with(Style::psql()),
+    " &str  | i32 | &str     "
+    "-------+-----+----------"
+    " apple | 3   | 1,200.5  "
+    " melon | 10  | 800.25   "
+    " lemon | 7   | n/a      "
+    "       | 3   | 1,200.50 "
+);
+
+test_table!(
+    summary_last_aggregation_is_used,
+    sales()
+        .with(Summary::new().sum(Columns::new(1..)).count(Columns::single(2)).label("Total"))
+        .with(Style::psql()),
+    " &str  | i32 | &str    "
+    "-------+-----+---------"
+    " apple | 3   | 1,200.5 "
+    " melon | 10  | 800.25  "
+    " lemon | 7   | n/a     "
+    " Total | 20  | 3       "
+);
+
+test_table!(
+    summary_label_goes_to_first_free_cell,
+    sales()
+        .with(Summary::new().count(Columns::first()).sum(Columns::single(1)).label("Total"))
+        .with(Style::psql()),
+    " &str  | i32 | &str    "
+    "-------+-----+---------"
+    " apple | 3   | 1,200.5 "
+    " melon | 10  | 800.25  "
+    " lemon | 7   | n/a     "
+    " 3     | 20  | Total   "
+);
+
+test_table!(
+    summary_negative_numbers,
+    Table::new([("a", "-1.5"), ("b", "2"), ("c", "-10")])
+        .with(Summary::new().sum(Columns::single(1)).min(Columns::single(0)))
+        .with(Style::psql()),
+    " &str | &str "
+    "------+------"
+    " a    | -1.5 "
+    " b    | 2    "
+    " c    | -10  "
+    "      | -9.5 "
+);
+
+test_table!(
+    summary_of_cells,
+    create_table::<3, 2>()
+        .with(Summary::new().count(Cell(1, 1).and(Cell(2, 1))))
+        .with(Style::psql()),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+    " 2 |   2-0    |   2-1    "
+    "   |    2     |          "
+);
+
+test_table!(
+    summary_without_header,
+    Builder::from_iter([["1", "2"], ["3", "4"]])
+        .build()
+        .with(Summary::new().sum(Columns::new(..)))
+        .with(Style::psql()),
+    " 1 | 2 "
+    "---+---"
+    " 3 | 4 "
+    " 4 | 6 "
+);
+
+test_table!(
+    summary_of_empty_table,
+    Table::new(Vec::<(&str, usize)>::new())
+        .with(Summary::new().sum(Columns::single(1)).avg(Columns::single(0)))
+        .with(Style::psql()),
+    " &str | usize "
+    "------+-------"
+    "      | 0     "
+);
+
+test_table!(
+    summary_is_styled_as_a_row,
+    sales()
+        .with(Summary::new().sum(Columns::single(1)).label("Total"))
+        .with(Modify::new(Rows::last()).with(Alignment::right())),
+    "+-------+-----+---------+"
+    "| &str  | i32 | &str    |"
+    "+-------+-----+---------+"
+    "| apple | 3   | 1,200.5 |"
+    "+-------+-----+---------+"
+    "| melon | 10  | 800.25  |"
+    "+-------+-----+---------+"
+    "| lemon | 7   | n/a     |"
+    "+-------+-----+---------+"
+    "| Total |  20 |         |"
+    "+-------+-----+---------+"
+);
+
+test_table!(
+    summary_sum_overflowing_integers,
+    Table::new([
+        ("a", "100000000000000000000000000000000000000"),
+        ("b", "100000000000000000000000000000000000000"),
+    ])
+    .with(Summary::new().sum(Columns::single(1)))
+    .with(Style::psql()),
+    " &str | &str                                    "
+    "------+-----------------------------------------"
+    " a    | 100000000000000000000000000000000000000 "
+    " b    | 100000000000000000000000000000000000000 "
+    "      | 200000000000000000000000000000000000000 "
+);
+
+test_table!(
+    summary_header_rows,
+    Builder::default()
+        .set_header_rows(vec![vec!["", "price", "price"], vec!["item", "net", "gross"]])
+        .add_record(["pear", "8", "9"])
+        .add_record(["apple", "10", "12"])
+        .clone()
+        .build()
+        .with(Summary::new().count(Columns::new(1..)).label("Count"))
+        .with(Style::psql()),
+    " item  | price       "
+    "       +-----+-------"
+    "       | net | gross "
+    " pear  | 8   | 9     "
+    " apple | 10  | 12    "
+    " Count | 2   | 2     "
+);
@@ -0,0 +1,232 @@
+use tabled::{
+    alignment::AlignmentHorizontal, Alignment, ColumnConfig, Padding, Style, TableConfig, Theme,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    table_config_default_is_default_theme,
+    create_table::<2, 2>().with(TableConfig::new()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_config_column_alignment_skips_header,
+    create_table::<2, 2>().with(
+        TableConfig::new()
+            .theme(Theme::new().style(Style::psql()))
+            .column(ColumnConfig::new(1).alignment(Alignment::right()))
+    ),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |      0-0 | 0-1      "
+    " 1 |      1-0 | 1-1      "
+);
+
+test_table!(
+    table_config_column_padding,
+    create_table::<2, 2>().with(
+        TableConfig::new()
+            .theme(Theme::new().style(Style::psql()))
+            .column(ColumnConfig::new(0).padding(Padding::new(3, 0, 0, 0)))
+    ),
+    "   N| column 0 | column 1 "
+    "----+----------+----------"
+    "   0| 0-0      | 0-1      "
+    "   1| 1-0      | 1-1      "
+);
+
+test_table!(
+    table_config_column_overrides_theme,
+    create_table::<2, 2>().with(
+        TableConfig::new()
+            .theme(
+                Theme::new()
+                    .style(Style::psql())
+                    .alignment(AlignmentHorizontal::Center)
+            )
+            .column(ColumnConfig::new(2).alignment(Alignment::left()))
+    ),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    | 0-1      "
+    " 1 |   1-0    | 1-1      "
+);
+
+test_table!(
+    table_config_column_out_of_table_is_ignored,
+    create_table::<2, 2>().with(
+        TableConfig::new()
+            .theme(Theme::new().style(Style::psql()))
+            .column(ColumnConfig::new(10).alignment(Alignment::right()).padding(Padding::zero()))
+    ),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 | 0-0      | 0-1      "
+    " 1 | 1-0      | 1-1      "
+);
+
+#[cfg(feature = "color")]
+#[test]
+fn table_config_column_color_skips_header() {
+    use tabled::color::Color;
+
+    let table = create_table::<1, 1>()
+        .with(
+            TableConfig::new()
+                .theme(Theme::new().style(Style::psql()))
+                .column(ColumnConfig::new(1).color(Color::FG_RED)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            " N | column 0 "
+            "---+----------"
+            " 0 | \u{1b}[31m0-0\u{1b}[39m      "
+        )
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn table_config_serde_round_trip() {
+    let config = TableConfig::new()
+        .theme(Theme::new().style(Style::psql()))
+        .column(
+            ColumnConfig::new(1)
+                .alignment(Alignment::right())
+                .padding(Padding::new(2, 0, 0, 0).set_fill('>', ' ', ' ', ' ')),
+        );
+
+    let json = serde_json::to_string(&config).unwrap();
+    let restored = serde_json::from_str::<TableConfig>(&json).unwrap();
+
+    assert_eq!(
+        create_table::<2, 2>().with(restored).to_string(),
+        create_table::<2, 2>().with(config).to_string(),
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn table_config_serde_missing_fields_are_default() {
+    let config = serde_json::from_str::<TableConfig>(
+        r#"{ "columns": [ { "index": 1, "alignment": { "Horizontal": "Right" } } ] }"#,
+    )
+    .unwrap();
+
+    let table = create_table::<2, 2>().with(config).to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 |      0-0 | 0-1      |"
+            "+---+----------+----------+"
+            "| 1 |      1-0 | 1-1      |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn table_config_serde_theme() {
+    let config = serde_json::from_str::<TableConfig>(
+        r#"{
+            "theme": {
+                "padding": { "left": 0, "right": 0, "top": 0, "bottom": 0 },
+                "alignment": "Right"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let table = create_table::<1, 1>().with(config).to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            "+-+--------+"
+            "|N|column 0|"
+            "+-+--------+"
+            "|0|     0-0|"
+            "+-+--------+"
+        )
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn alignment_serde() {
+    let json = serde_json::to_string(&Alignment::center_vertical()).unwrap();
+    assert_eq!(json, r#"{"Vertical":"Center"}"#);
+
+    let alignment = serde_json::from_str::<Alignment>(r#"{"Horizontal":"Right"}"#).unwrap();
+    assert_eq!(
+        create_table::<1, 1>().with(alignment).to_string(),
+        create_table::<1, 1>().with(Alignment::right()).to_string(),
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn padding_serde() {
+    let padding = serde_json::from_str::<Padding>(
+        r#"{
+            "indent": {
+                "top": { "fill": "^", "size": 1 },
+                "bottom": { "fill": " ", "size": 0 },
+                "left": { "fill": ">", "size": 2 },
+                "right": { "fill": " ", "size": 0 }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        create_table::<1, 1>().with(padding.clone()).to_string(),
+        create_table::<1, 1>()
+            .with(Padding::new(2, 0, 1, 0).set_fill('>', ' ', '^', ' '))
+            .to_string(),
+    );
+
+    let json = serde_json::to_string(&padding).unwrap();
+    let restored = serde_json::from_str::<Padding>(&json).unwrap();
+    assert_eq!(
+        create_table::<1, 1>().with(restored).to_string(),
+        create_table::<1, 1>().with(padding).to_string(),
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn border_serde() {
+    use tabled::Border;
+
+    let border = Border::default().top('-').top_left_corner('+');
+    let json = serde_json::to_string(&border).unwrap();
+    assert_eq!(
+        json,
+        r#"{"top":"-","bottom":null,"left":null,"right":null,"left_top_corner":"+","left_bottom_corner":null,"right_top_corner":null,"right_bottom_corner":null}"#
+    );
+    assert_eq!(serde_json::from_str::<Border>(&json).unwrap(), border);
+
+    let border = serde_json::from_str::<Border>(r#"{ "bottom": "=" }"#).unwrap();
+    assert_eq!(border, Border::default().bottom('='));
+
+    let border = serde_json::from_str::<Border>("null").unwrap();
+    assert_eq!(border, Border::empty());
+}
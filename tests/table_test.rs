@@ -341,6 +341,60 @@ mod derived {
         "+----+----+"
     );
 
+    test_table!(
+        table_with_iter_owned,
+        Table::with_iter(vec![TestType { f1: 0, f2: "0" }, TestType { f1: 1, f2: "1" }]),
+        "+----+----+"
+        "| f1 | f2 |"
+        "+----+----+"
+        "| 0  | 0  |"
+        "+----+----+"
+        "| 1  | 1  |"
+        "+----+----+"
+    );
+
+    test_table!(
+        table_with_iter_borrowed,
+        {
+            let data = [TestType { f1: 0, f2: "0" }, TestType { f1: 1, f2: "1" }];
+            Table::with_iter(data.iter().filter(|t| t.f1 > 0))
+        },
+        "+----+----+"
+        "| f1 | f2 |"
+        "+----+----+"
+        "| 1  | 1  |"
+        "+----+----+"
+    );
+
+    test_table!(
+        table_from_iter_borrowed,
+        {
+            let data = [TestType { f1: 0, f2: "0" }, TestType { f1: 1, f2: "1" }];
+            data.iter().filter(|t| t.f1 > 0).collect::<Table<_>>().to_string()
+        },
+        "+----+----+"
+        "| f1 | f2 |"
+        "+----+----+"
+        "| 1  | 1  |"
+        "+----+----+"
+    );
+
+    test_table!(
+        table_new_references_to_references,
+        {
+            let data = [TestType { f1: 0, f2: "0" }, TestType { f1: 1, f2: "1" }];
+            let refs = data.iter().collect::<Vec<_>>();
+            Table::new(&refs)
+        },
+        "+----+----+"
+        "| f1 | f2 |"
+        "+----+----+"
+        "| 0  | 0  |"
+        "+----+----+"
+        "| 1  | 1  |"
+        "+----+----+"
+    );
+
     test_table!(
         table_option,
         Table::new(Some(TestType { f1: 0, f2: "0" })),
@@ -359,6 +413,44 @@ mod derived {
         "+----+----+"
     );
 
+    test_table!(
+        table_kv,
+        Table::kv([TestType { f1: 0, f2: "0str" }]),
+        "+----+------+"
+        "| f1 | 0    |"
+        "+----+------+"
+        "| f2 | 0str |"
+        "+----+------+"
+    );
+
+    test_table!(
+        table_kv_multiple_records,
+        Table::kv([TestType { f1: 0, f2: "0" }, TestType { f1: 1, f2: "1" }]),
+        "+----+---+---+"
+        "| f1 | 0 | 1 |"
+        "+----+---+---+"
+        "| f2 | 0 | 1 |"
+        "+----+---+---+"
+    );
+
+    test_table!(
+        table_kv_empty,
+        Table::kv(Vec::<TestType>::new()),
+        "+----+"
+        "| f1 |"
+        "+----+"
+        "| f2 |"
+        "+----+"
+    );
+
+    test_table!(
+        table_kv_tuple,
+        Table::kv([(0, TestType { f1: 1, f2: "2" })]).with(Style::blank()),
+        " i32   0 "
+        " f1    1 "
+        " f2    2 "
+    );
+
     test_table!(
         table_tuple_with_structure_vec,
         Table::new([(0, TestType { f1: 0, f2: "0str" }), (1, TestType { f1: 1, f2: "1str" })]),
@@ -820,3 +912,107 @@ test_table!(
     "├┼┤"
     "└┴┘"
 );
+
+#[test]
+fn table_len_and_rows() {
+    let table = create_table::<3, 2>();
+
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.shape(), (4, 3));
+    assert!(!table.is_empty());
+    assert_eq!(table.header(), Some(vec!["N", "column 0", "column 1"]));
+
+    let rows = table.rows();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(
+        rows.collect::<Vec<_>>(),
+        [
+            ["0", "0-0", "0-1"],
+            ["1", "1-0", "1-1"],
+            ["2", "2-0", "2-1"]
+        ]
+    );
+}
+
+#[test]
+fn table_rows_without_header() {
+    let mut builder = Builder::default();
+    builder.add_record(["a", "b"]);
+    builder.add_record(["c", "d"]);
+    let table = builder.build();
+
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.header(), None);
+    assert_eq!(table.rows().collect::<Vec<_>>(), [["a", "b"], ["c", "d"]]);
+}
+
+#[test]
+fn table_rows_only_header() {
+    let table = Table::new(Vec::<String>::new());
+
+    assert_eq!(table.len(), 0);
+    assert!(!table.is_empty());
+    assert_eq!(table.header(), Some(vec!["String"]));
+    assert_eq!(table.rows().count(), 0);
+}
+
+#[test]
+fn table_rows_empty() {
+    let table = Builder::default().build();
+
+    assert_eq!(table.len(), 0);
+    assert!(table.is_empty());
+    assert_eq!(table.header(), None);
+    assert_eq!(table.rows().count(), 0);
+}
+
+#[test]
+fn table_rows_reflect_changes() {
+    let mut table = Table::new(["Hello", "World"]);
+    table.with(Width::truncate(6));
+
+    assert_eq!(table.rows().collect::<Vec<_>>(), [["He"], ["Wo"]]);
+}
+
+#[test]
+fn table_new_masked() {
+    let data = [("Hello", 1, '!'), ("World", 2, '?')];
+
+    let table = Table::new_masked(data, &[false, true]).to_string();
+    assert_eq!(
+        table,
+        util::static_table!(
+            "+-------+------+"
+            "| &str  | char |"
+            "+-------+------+"
+            "| Hello | !    |"
+            "+-------+------+"
+            "| World | ?    |"
+            "+-------+------+"
+        )
+    );
+
+    let table = Table::new_masked(data, &[true, true, true, true]).to_string();
+    assert_eq!(table, "");
+}
+
+#[test]
+fn table_rows_header_rows() {
+    let mut builder = Builder::default();
+    builder
+        .set_header_rows(vec![
+            vec!["", "price", "price"],
+            vec!["item", "net", "gross"],
+        ])
+        .add_record(["apple", "10", "12"])
+        .add_record(["pear", "8", "9"]);
+    let table = builder.build();
+
+    assert_eq!(table.count_header_rows(), 2);
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.header(), Some(vec!["item", "net", "gross"]));
+    assert_eq!(
+        table.rows().collect::<Vec<_>>(),
+        [["apple", "10", "12"], ["pear", "8", "9"]]
+    );
+}
@@ -0,0 +1,185 @@
+use tabled::{
+    alignment::AlignmentHorizontal,
+    style::{HorizontalLine, Style},
+    Table, Theme,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    theme_new_is_default_look,
+    create_table::<2, 2>().with(Style::psql()).with(Theme::new()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    theme_custom,
+    create_table::<2, 2>().with(
+        Theme::new()
+            .style(Style::rounded())
+            .padding(2, 0, 1, 0)
+            .alignment(AlignmentHorizontal::Right)
+            .header_alignment(AlignmentHorizontal::Center)
+    ),
+    "╭───┬──────────┬──────────╮"
+    "│   │          │          │"
+    "│  N│  column 0│  column 1│"
+    "├───┼──────────┼──────────┤"
+    "│   │          │          │"
+    "│  0│       0-0│       0-1│"
+    "│   │          │          │"
+    "│  1│       1-0│       1-1│"
+    "╰───┴──────────┴──────────╯"
+);
+
+test_table!(
+    theme_style_with_lines,
+    create_table::<3, 2>().with(
+        Theme::new().style(
+            Style::blank().horizontals([HorizontalLine::new(1, Style::extended().get_horizontal())])
+        )
+    ),
+    "  N   column 0   column 1  "
+    "╠═══╬══════════╬══════════╣"
+    "  0   0-0        0-1       "
+    "  1   1-0        1-1       "
+    "  2   2-0        2-1       "
+);
+
+test_table!(
+    theme_without_header,
+    {
+        let mut builder = tabled::builder::Builder::default();
+        builder.add_record(["a", "bbb"]);
+        builder.add_record(["ccc", "d"]);
+        builder.build().with(
+            Theme::new()
+                .alignment(AlignmentHorizontal::Right)
+                .header_alignment(AlignmentHorizontal::Left),
+        )
+    },
+    "+-----+-----+"
+    "|   a | bbb |"
+    "+-----+-----+"
+    "| ccc |   d |"
+    "+-----+-----+"
+);
+
+test_table!(
+    theme_empty_table,
+    Table::new(Vec::<String>::new()).with(Theme::new().style(Style::modern())),
+    "┌────────┐"
+    "│ String │"
+    "└────────┘"
+);
+
+#[cfg(not(feature = "color"))]
+test_table!(
+    theme_modern_dark,
+    create_table::<3, 2>().with(Theme::modern_dark()),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │ 0-0      │ 0-1      │"
+    "│ 1 │ 1-0      │ 1-1      │"
+    "│ 2 │ 2-0      │ 2-1      │"
+    "└───┴──────────┴──────────┘"
+);
+
+#[cfg(feature = "color")]
+mod color {
+    use tabled::{color::Color, Theme};
+
+    use crate::util::{create_table, test_table};
+
+    test_table!(
+        theme_modern_dark,
+        create_table::<3, 2>().with(Theme::modern_dark()),
+        "\u{1b}[90m┌───┬──────────┬──────────┐\u{1b}[39m"
+        "\u{1b}[90m│\u{1b}[39m \u{1b}[1mN\u{1b}[22m \u{1b}[90m│\u{1b}[39m \u{1b}[1mcolumn 0\u{1b}[22m \u{1b}[90m│\u{1b}[39m \u{1b}[1mcolumn 1\u{1b}[22m \u{1b}[90m│\u{1b}[39m"
+        "\u{1b}[90m├───┼──────────┼──────────┤\u{1b}[39m"
+        "\u{1b}[90m│\u{1b}[39m \u{1b}[37m0\u{1b}[39m \u{1b}[90m│\u{1b}[39m \u{1b}[37m0-0\u{1b}[39m      \u{1b}[90m│\u{1b}[39m \u{1b}[37m0-1\u{1b}[39m      \u{1b}[90m│\u{1b}[39m"
+        "\u{1b}[90m│\u{1b}[39m \u{1b}[97m1\u{1b}[39m \u{1b}[90m│\u{1b}[39m \u{1b}[97m1-0\u{1b}[39m      \u{1b}[90m│\u{1b}[39m \u{1b}[97m1-1\u{1b}[39m      \u{1b}[90m│\u{1b}[39m"
+        "\u{1b}[90m│\u{1b}[39m \u{1b}[37m2\u{1b}[39m \u{1b}[90m│\u{1b}[39m \u{1b}[37m2-0\u{1b}[39m      \u{1b}[90m│\u{1b}[39m \u{1b}[37m2-1\u{1b}[39m      \u{1b}[90m│\u{1b}[39m"
+        "\u{1b}[90m└───┴──────────┴──────────┘\u{1b}[39m"
+    );
+
+    test_table!(
+        theme_row_colors,
+        create_table::<3, 1>().with(
+            Theme::new()
+                .header_color(Color::FG_RED)
+                .row_colors([Color::FG_BLUE, Color::FG_GREEN])
+        ),
+        "+---+----------+"
+        "| \u{1b}[31mN\u{1b}[39m | \u{1b}[31mcolumn 0\u{1b}[39m |"
+        "+---+----------+"
+        "| \u{1b}[34m0\u{1b}[39m | \u{1b}[34m0-0\u{1b}[39m      |"
+        "+---+----------+"
+        "| \u{1b}[32m1\u{1b}[39m | \u{1b}[32m1-0\u{1b}[39m      |"
+        "+---+----------+"
+        "| \u{1b}[34m2\u{1b}[39m | \u{1b}[34m2-0\u{1b}[39m      |"
+        "+---+----------+"
+    );
+
+    test_table!(
+        theme_border_color,
+        create_table::<1, 1>().with(Theme::new().border_color(Color::FG_RED)),
+        "\u{1b}[31m+---+----------+\u{1b}[39m"
+        "\u{1b}[31m|\u{1b}[39m N \u{1b}[31m|\u{1b}[39m column 0 \u{1b}[31m|\u{1b}[39m"
+        "\u{1b}[31m+---+----------+\u{1b}[39m"
+        "\u{1b}[31m|\u{1b}[39m 0 \u{1b}[31m|\u{1b}[39m 0-0      \u{1b}[31m|\u{1b}[39m"
+        "\u{1b}[31m+---+----------+\u{1b}[39m"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn theme_serde_round_trip() {
+    let theme = Theme::new()
+        .style(Style::modern().off_horizontal())
+        .padding(0, 2, 0, 0)
+        .alignment(AlignmentHorizontal::Right);
+
+    let json = serde_json::to_string(&theme).unwrap();
+    let theme = serde_json::from_str::<Theme>(&json).unwrap();
+
+    let table = create_table::<2, 2>().with(theme).to_string();
+    let expected = create_table::<2, 2>()
+        .with(Style::modern().off_horizontal())
+        .with(tabled::Padding::new(0, 2, 0, 0))
+        .with(tabled::Alignment::right())
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn theme_serde_missing_fields_are_default() {
+    let theme = serde_json::from_str::<Theme>(
+        r#"{ "padding": { "left": 0, "right": 0, "top": 0, "bottom": 0 } }"#,
+    )
+    .unwrap();
+
+    let table = create_table::<1, 1>().with(theme).to_string();
+
+    assert_eq!(
+        table,
+        util::static_table!(
+            "+-+--------+"
+            "|N|column 0|"
+            "+-+--------+"
+            "|0|0-0     |"
+            "+-+--------+"
+        )
+    );
+}
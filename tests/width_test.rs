@@ -1,14 +1,21 @@
-#[cfg(feature = "color")]
 use std::iter::FromIterator;
 #[cfg(feature = "color")]
 use tabled::ModifyObject;
 
 use tabled::{
-    formatting::TrimStrategy,
+    builder::Builder,
+    format::Format,
+    formatting::{AlignmentStrategy, TrimStrategy},
+    measurement::Percent,
     object::{Cell, Columns, Object, Rows, Segment},
-    papergrid::util::string_width_multiline,
+    papergrid::{
+        records::{cell_info::CellInfo, Records, RecordsMut},
+        util::string_width_multiline,
+        width::{CfgWidthFunction, WidthFunc},
+        Entity, Indent, Position, Sides,
+    },
     peaker::{PriorityMax, PriorityMin},
-    width::{Justify, MinWidth, SuffixLimit, Width},
+    width::{CutSide, HeaderWrap, Justify, LayoutFallback, Measure, MinWidth, SuffixLimit, Width},
     Alignment, Margin, Modify, Padding, Panel, Span, Style, Table,
 };
 
@@ -506,6 +513,7 @@ fn dont_change_content_if_width_is_less_then_max_width() {
     );
 }
 
+#[cfg(not(feature = "grapheme"))]
 #[test]
 fn max_width_with_emoji() {
     let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
@@ -527,6 +535,68 @@ fn max_width_with_emoji() {
     );
 }
 
+#[cfg(feature = "grapheme")]
+#[test]
+fn max_width_with_emoji_grapheme() {
+    let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
+
+    let table = new_table(data)
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::truncate(6).suffix("...")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  &str  |"
+            "|--------|"
+            "|   🤠   |"
+            "| 😳�... |"
+            "| 🚴🏻‍♀️�... |"
+        )
+    );
+}
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn wrap_grapheme_clusters() {
+    let data = &["🚵🏻🚵🏻🚵🏻", "👨‍👩‍👧 family"];
+
+    let table = new_table(data)
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(4)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| 🚵🏻🚵🏻 |"
+            "| 🚵🏻   |"
+            "| 👨‍👩‍👧 f |"
+            "| amil |"
+            "| y    |"
+        )
+    );
+
+    let table = new_table(data)
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(6).keep_words()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  &str  |"
+            "|--------|"
+            "| 🚵🏻🚵🏻🚵🏻 |"
+            "| 👨‍👩‍👧     |"
+            "| family |"
+        )
+    );
+}
+
 #[cfg(feature = "color")]
 #[test]
 fn color_chars_are_stripped() {
@@ -823,6 +893,130 @@ fn min_with_max_width_truncate_suffix_try_color() {
     );
 }
 
+#[test]
+fn max_width_with_suffix_multiline() {
+    let table = Table::new(["Hi\nWorld!!\nHello"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).suffix("..")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str  |"
+            "|-------|"
+            "| Hi    |"
+            "| Wor.. |"
+            "| Hello |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn max_width_with_suffix_multiline_color() {
+    use owo_colors::OwoColorize;
+
+    let table = Table::new(["Hi\nWorld!!".red().to_string()])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Segment::all())
+                .with(Width::truncate(5).suffix("..").suffix_try_color(true)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| Str.. |"
+            "|-------|"
+            "| \u{1b}[31mHi\u{1b}[39m    |"
+            "| \u{1b}[31mWor\u{1b}[39m\u{1b}[31m..\u{1b}[39m |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn max_width_with_suffix_color() {
+    use owo_colors::OwoColorize;
+    use tabled::color::Color;
+
+    let data = &[
+        "asd".red().to_string(),
+        "asdasd".on_black().green().to_string(),
+    ];
+
+    let table = new_table(data)
+        .with(Style::markdown())
+        .with(
+            Modify::new(Segment::all()).with(
+                Width::truncate(5)
+                    .suffix("..")
+                    .suffix_try_color(true)
+                    .suffix_color(Color::FG_BLUE),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| Str\u{1b}[34m..\u{1b}[39m |"
+            "|-------|"
+            "|  \u{1b}[31masd\u{1b}[39m  |"
+            "| \u{1b}[32;40masd\u{1b}[39m\u{1b}[49m\u{1b}[34m..\u{1b}[39m |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn max_width_with_suffix_try_color_zero_width() {
+    use owo_colors::OwoColorize;
+
+    let table = new_table(&["asdasd".red().to_string()])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..))
+                .with(Width::truncate(2).suffix("..").suffix_try_color(true)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| String |"
+            "|--------|"
+            "|   \u{1b}[31m..\u{1b}[39m   |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn max_width_with_suffix_closes_color() {
+    use tabled::color::Color;
+
+    // a text which doesn't close its color
+    let table = new_table(["\u{1b}[31mHello World"])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..))
+                .with(Width::truncate(5).suffix("..").suffix_color(Color::FG_BLUE)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str  |"
+            "|-------|"
+            "| \u{1b}[31mHel\u{1b}[39m\u{1b}[34m..\u{1b}[39m |"
+        )
+    );
+}
+
 #[cfg(feature = "color")]
 #[test]
 fn min_width_color() {
@@ -1592,6 +1786,8 @@ fn justify_width_constant_different_sizes_test() {
             "| 0   | Hel | 0-1 | 0-2 |"
             "| 1   | 1-0 | 1-1 | 1-2 |"
             "| 2   | 2-0 | mul | 2-2 |"
+            "|     |     | lin |     |"
+            "|     |     |     |     |"
         )
     );
 }
@@ -1715,11 +1911,11 @@ fn max_width_truncate_with_big_span() {
     assert_eq!(
         table,
         static_table!(
-            "|  | column 0  | column 1  | column 2  |"
-            "|--|-----------|-----------|-----------|"
-            "|  |    0-0    |    0-1    |    0-2    |"
-            "|  | Hello World With Big Line; Here w |"
-            "|  |    2-0    |    2-1    |    2-2    |"
+            "| N | column 0  | column 1 | column 2  |"
+            "|---|-----------|----------|-----------|"
+            "| 0 |    0-0    |   0-1    |    0-2    |"
+            "| 1 | Hello World With Big Line; Here  |"
+            "| 2 |    2-0    |   2-1    |    2-2    |"
         )
     );
 
@@ -1756,11 +1952,11 @@ fn max_width_truncate_with_big_span() {
     assert_eq!(
         table,
         static_table!(
-            "|  | colum |  column 1   |  column 2   |"
-            "|--|-------|-------------|-------------|"
-            "|  |  0-0  |     0-1     |     0-2     |"
-            "|  | Hello World With Big Line; Here w |"
-            "|  |  2-0  | Hello World With Big Line |"
+            "| N | column 0 | column 1  | column 2  |"
+            "|---|----------|-----------|-----------|"
+            "| 0 |   0-0    |    0-1    |    0-2    |"
+            "| 1 | Hello World With Big Line; Here  |"
+            "| 2 |   2-0    | Hello World With Big  |"
         )
     );
     assert_eq!(string_width_multiline(&table), 40);
@@ -1779,11 +1975,11 @@ fn max_width_truncate_with_big_span() {
     assert_eq!(
         table,
         static_table!(
-            "|  |   column 0    |   column 1    | c |"
-            "|--|---------------|---------------|---|"
-            "|  |      0-0      |      0-1      | 0 |"
-            "|  | Hello World With Big Line; He | 1 |"
-            "|  |      2-0      | Hello World With  |"
+            "| N | column 0  | column 1  | column 2 |"
+            "|---|-----------|-----------|----------|"
+            "| 0 |    0-0    |    0-1    |   0-2    |"
+            "| 1 | Hello World With Big  |   1-2    |"
+            "| 2 |    2-0    | Hello World With Big |"
         )
     );
 
@@ -2062,11 +2258,11 @@ fn max_width_truncate_priority_min_with_span() {
     assert_eq!(
         table,
         static_table!(
-            "|  |  | co |  |"
-            "|--|--|----|--|"
-            "|  |  | 0- |  |"
-            "|  | Hello |  |"
-            "|  |  | 2- |  |"
+            "|  |  |  | co |"
+            "|--|--|--|----|"
+            "|  |  |  | 0- |"
+            "|  | Hel | 1- |"
+            "|  |  |  | 2- |"
         )
     );
 
@@ -2080,11 +2276,11 @@ fn max_width_truncate_priority_min_with_span() {
     assert_eq!(
         table,
         static_table!(
-            "|  |  | colu |  |"
-            "|--|--|------|--|"
-            "|  |  | 0-1  |  |"
-            "|  | Hello W |  |"
-            "|  |  | 2-1  |  |"
+            "|  |  |  | colu |"
+            "|--|--|--|------|"
+            "|  |  |  | 0-2  |"
+            "|  | Hel | 1-2  |"
+            "|  |  |  | 2-2  |"
         )
     );
 }
@@ -2160,20 +2356,133 @@ fn max_width_wrap_priority_min_with_span() {
     assert_eq!(
         table,
         static_table!(
-            "|  |  | co |  |"
-            "|  |  | lu |  |"
-            "|  |  | mn |  |"
-            "|  |  |  1 |  |"
-            "|--|--|----|--|"
-            "|  |  | 0- |  |"
-            "|  |  | 1  |  |"
-            "|  | Hello |  |"
-            "|  |  Worl |  |"
-            "|  | d Wit |  |"
-            "|  | h Big |  |"
-            "|  |  Line |  |"
-            "|  |  | 2- |  |"
-            "|  |  | 1  |  |"
+            "|  |  |  | co |"
+            "|  |  |  | lu |"
+            "|  |  |  | mn |"
+            "|  |  |  |  2 |"
+            "|--|--|--|----|"
+            "|  |  |  | 0- |"
+            "|  |  |  | 2  |"
+            "|  | Hel | 1- |"
+            "|  | lo  | 2  |"
+            "|  | Wor |    |"
+            "|  | ld  |    |"
+            "|  | Wit |    |"
+            "|  | h B |    |"
+            "|  | ig  |    |"
+            "|  | Lin |    |"
+            "|  | e   |    |"
+            "|  |  |  | 2- |"
+            "|  |  |  | 2  |"
+        )
+    );
+}
+
+#[test]
+fn max_width_wrap_with_span_keeps_other_columns() {
+    let table = Builder::from_iter([
+        vec!["a very long spanned text here which is even longer", "", ""],
+        vec!["aa", "a much longer text x", "cc"],
+    ])
+    .build()
+    .with(Style::modern())
+    .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+    .with(Width::wrap(34))
+    .to_string();
+
+    assert!(is_lines_equal(&table, 34));
+    assert_eq!(
+        table,
+        static_table!(
+            "┌────┬──────────────────────┬────┐"
+            "│ a very long spanned text here  │"
+            "│ which is even longer           │"
+            "├────┼──────────────────────┼────┤"
+            "│ aa │ a much longer text x │ cc │"
+            "└────┴──────────────────────┴────┘"
+        )
+    );
+}
+
+#[test]
+fn max_width_truncate_with_span_keeps_other_columns() {
+    let table = Builder::from_iter([
+        vec!["a very long spanned text here which is even longer", "", ""],
+        vec!["aa", "a much longer text x", "cc"],
+    ])
+    .build()
+    .with(Style::modern())
+    .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+    .with(Width::truncate(34).priority::<PriorityMax>())
+    .to_string();
+
+    assert!(is_lines_equal(&table, 34));
+    assert_eq!(
+        table,
+        static_table!(
+            "┌────┬──────────────────────┬────┐"
+            "│ a very long spanned text here  │"
+            "├────┼──────────────────────┼────┤"
+            "│ aa │ a much longer text x │ cc │"
+            "└────┴──────────────────────┴────┘"
+        )
+    );
+}
+
+#[test]
+fn max_width_wrap_with_span_in_last_columns() {
+    let table = Builder::from_iter([
+        vec!["aa", "a much longer text x", "cc"],
+        vec![
+            "x",
+            "a very long spanned text here which is even longer",
+            "",
+        ],
+    ])
+    .build()
+    .with(Style::modern())
+    .with(Modify::new(Cell(1, 1)).with(Span::column(2)))
+    .with(Width::wrap(34))
+    .to_string();
+
+    assert!(is_lines_equal(&table, 34));
+    assert_eq!(
+        table,
+        static_table!(
+            "┌────┬──────────────────────┬────┐"
+            "│ aa │ a much longer text x │ cc │"
+            "├────┼──────────────────────┼────┤"
+            "│ x  │ a very long spanned text  │"
+            "│    │ here which is even longer │"
+            "└────┴──────────────────────┴────┘"
+        )
+    );
+}
+
+#[test]
+fn max_width_wrap_with_span_below_natural_widths() {
+    let table = Builder::from_iter([
+        vec!["a very long spanned text here which is even longer", "", ""],
+        vec!["aa", "a much longer text x", "cc"],
+    ])
+    .build()
+    .with(Style::modern())
+    .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+    .with(Width::wrap(28))
+    .to_string();
+
+    assert!(is_lines_equal(&table, 28));
+    assert_eq!(
+        table,
+        static_table!(
+            "┌──┬────────────────────┬──┐"
+            "│ a very long spanned text │"
+            "│  here which is even long │"
+            "│ er                       │"
+            "├──┼────────────────────┼──┤"
+            "│  │ a much longer text │  │"
+            "│  │  x                 │  │"
+            "└──┴────────────────────┴──┘"
         )
     );
 }
@@ -2631,3 +2940,1334 @@ mod derived {
         );
     }
 }
+
+#[cfg(all(feature = "terminal_size", feature = "strict"))]
+#[test]
+#[should_panic(expected = "Width::fit_terminal depends on an environment")]
+fn width_fit_terminal_strict() {
+    create_table::<3, 3>().with(Width::fit_terminal());
+}
+
+#[cfg(all(feature = "terminal_size", not(feature = "strict")))]
+#[test]
+fn width_fit_terminal() {
+    let terminal_width = terminal_size::terminal_size().map(|(width, _)| width.0 as usize);
+
+    // a terminal narrower than the table can be is not checked, as the table overflows it.
+    let min_width = create_table::<3, 3>()
+        .with(Width::truncate(0))
+        .total_width();
+    if matches!(terminal_width, Some(width) if width < min_width) {
+        return;
+    }
+
+    for table in [
+        create_table::<3, 3>().with(Width::fit_terminal()),
+        create_table::<3, 3>().with(Width::fit_terminal().truncate().priority::<PriorityMax>()),
+        create_table::<3, 3>().with(Width::fit_terminal().keep_words()),
+    ] {
+        match terminal_width {
+            Some(width) => assert_eq!(table.total_width(), width),
+            None => assert_eq!(table.to_string(), create_table::<3, 3>().to_string()),
+        }
+    }
+
+    let mut table = create_table::<3, 3>();
+    table.with(Width::fit_terminal().no_increase());
+    match terminal_width {
+        Some(width) => assert!(table.total_width() <= width),
+        None => assert_eq!(table.to_string(), create_table::<3, 3>().to_string()),
+    }
+}
+
+#[test]
+fn wrap_keeping_words_wide_chars_pads_lines() {
+    let wrap = |text: &str, width| {
+        Table::new([text])
+            .with(
+                Modify::new(Segment::all())
+                    .with(Width::wrap(width).keep_words())
+                    .with(Alignment::right())
+                    .with(AlignmentStrategy::PerLine),
+            )
+            .to_string()
+    };
+
+    assert_eq!(
+        wrap("你好 world", 6),
+        static_table!(
+            "+--------+"
+            "|   &str |"
+            "+--------+"
+            "| 你好   |"
+            "| world  |"
+            "+--------+"
+        )
+    );
+    assert_eq!(
+        wrap("你 好 你 好", 4),
+        static_table!(
+            "+------+"
+            "| &str |"
+            "+------+"
+            "| 你   |"
+            "| 好   |"
+            "| 你   |"
+            "| 好   |"
+            "+------+"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+fn wrap_colored_and_plain(text: &str, width: usize, keep_words: bool) -> (String, String) {
+    use owo_colors::OwoColorize;
+
+    let wrap = |text: String| {
+        let wrap = Width::wrap(width);
+        let wrap = if keep_words { wrap.keep_words() } else { wrap };
+
+        Table::new([text])
+            .with(Style::empty())
+            .with(Padding::zero())
+            .with(Alignment::right())
+            .with(Modify::new(Segment::all()).with(wrap))
+            .to_string()
+    };
+
+    (wrap(text.red().to_string()), wrap(text.to_string()))
+}
+
+#[cfg(feature = "color")]
+fn is_wrap_color_consistent(text: &str, width: usize, keep_words: bool) -> bool {
+    let (colored, plain) = wrap_colored_and_plain(text, width, keep_words);
+
+    let lines_have_width = colored
+        .lines()
+        .chain(plain.lines())
+        .all(|line| string_width_multiline(line) == width);
+    let is_same_layout = ansi_str::AnsiStr::ansi_strip(&colored) == plain;
+
+    lines_have_width && is_same_layout
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn wrap_colored_wide_chars_keeps_width() {
+    let texts = [
+        "你好 world",
+        "a你好你b 好",
+        "ab 你好 cd 😀😀 x",
+        "你 好 你 好",
+        "😳😳 😳 😳😳😳",
+    ];
+
+    for text in texts {
+        for width in 4..10 {
+            assert!(
+                is_wrap_color_consistent(text, width, true),
+                "{:?} {}",
+                text,
+                width
+            );
+            assert!(
+                is_wrap_color_consistent(text, width, false),
+                "{:?} {}",
+                text,
+                width
+            );
+        }
+    }
+}
+
+#[cfg(feature = "color")]
+#[quickcheck_macros::quickcheck]
+#[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
+fn qc_wrap_colored_lines_have_exact_width(text: String, width: u8, keep_words: bool) -> bool {
+    let text = text.chars().filter(|c| !c.is_control()).collect::<String>();
+    let width = 4 + width as usize % 20;
+    if string_width_multiline(&text) < width {
+        return true;
+    }
+
+    is_wrap_color_consistent(&text, width, keep_words)
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn min_width_colored_fill() {
+    let table = Table::new(["\u{1b}[44mab\u{1b}[49m\n\u{1b}[41mc\u{1b}[49m", "d"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Width::increase(4).colored_fill()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str "
+            "------"
+            " \u{1b}[44mab\u{1b}[49m\u{1b}[44m  \u{1b}[49m "
+            " \u{1b}[41mc\u{1b}[49m\u{1b}[41m   \u{1b}[49m "
+            " d    "
+        )
+    );
+}
+
+#[test]
+fn width_list_percent() {
+    let table = create_table::<2, 3>()
+        .with(Style::psql())
+        .with(Width::list([
+            Percent(20),
+            Percent(30),
+            Percent(30),
+            Percent(20),
+        ]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "   N   | column 0 | column 1 | colu "
+            "       |          |          | mn 2 "
+            "-------+----------+----------+------"
+            "   0   |   0-0    |   0-1    | 0-2  "
+            "   1   |   1-0    |   1-1    | 1-2  "
+        )
+    );
+}
+
+#[test]
+fn width_list_percent_total() {
+    let table = create_table::<2, 3>()
+        .with(Style::psql())
+        .with(Width::list([Percent(25), Percent(25), Percent(25), Percent(25)]).total(30))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "   N   | colum | colum | colu "
+            "       | n 0   | n 1   | mn 2 "
+            "-------+-------+-------+------"
+            "   0   |  0-0  |  0-1  | 0-2  "
+            "   1   |  1-0  |  1-1  | 1-2  "
+        )
+    );
+    assert_eq!(string_width_multiline(&table), 30);
+}
+
+#[test]
+fn width_list_percent_truncate() {
+    let table = create_table::<2, 3>()
+        .with(Style::psql())
+        .with(
+            Width::list([Percent(10), Percent(30), Percent(30), Percent(30)])
+                .total(30)
+                .truncate(),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column | column | column "
+            "---+--------+--------+--------"
+            " 0 |  0-0   |  0-1   |  0-2   "
+            " 1 |  1-0   |  1-1   |  1-2   "
+        )
+    );
+}
+
+#[test]
+fn width_list_percent_less_than_100() {
+    let table = create_table::<2, 3>()
+        .with(Style::psql())
+        .with(Width::list([Percent(10), Percent(10), Percent(10), Percent(10)]).total(60))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "  N  | col | col | col "
+            "     | umn | umn | umn "
+            "     |  0  |  1  |  2  "
+            "-----+-----+-----+-----"
+            "  0  | 0-0 | 0-1 | 0-2 "
+            "  1  | 1-0 | 1-1 | 1-2 "
+        )
+    );
+}
+
+#[test]
+fn width_list_percent_not_enough_columns() {
+    let table = create_table::<2, 3>()
+        .with(Style::psql())
+        .with(Width::list([Percent(50), Percent(50)]).total(60))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            " 1 |   1-0    |   1-1    |   1-2    "
+        )
+    );
+}
+
+#[test]
+fn width_list_percent_with_margin() {
+    let table = create_table::<2, 2>()
+        .with(Margin::new(2, 2, 0, 0))
+        .with(Width::list([Percent(20), Percent(40), Percent(40)]).total(40))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "  +------+-------------+-------------+  "
+            "  |  N   |  column 0   |  column 1   |  "
+            "  +------+-------------+-------------+  "
+            "  |  0   |     0-0     |     0-1     |  "
+            "  +------+-------------+-------------+  "
+            "  |  1   |     1-0     |     1-1     |  "
+            "  +------+-------------+-------------+  "
+        )
+    );
+    assert_eq!(string_width_multiline(&table), 40);
+}
+
+#[test]
+fn width_list_percent_zero() {
+    let table = create_table::<2, 2>()
+        .with(Padding::zero())
+        .with(Width::list([Percent(0), Percent(50), Percent(50)]).total(30))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "++-------------+-------------+"
+            "||  column 0   |  column 1   |"
+            "++-------------+-------------+"
+            "||     0-0     |     0-1     |"
+            "++-------------+-------------+"
+            "||     1-0     |     1-1     |"
+            "++-------------+-------------+"
+        )
+    );
+}
+
+#[test]
+fn truncate_by_bytes() {
+    let table = Table::new(["Привет мир", "Hello", "你好世界"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).by(Measure::Bytes)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str  "
+            "-------"
+            " Пр    "
+            " Hello "
+            " 你    "
+        )
+    );
+}
+
+#[test]
+fn truncate_by_chars() {
+    let table = Table::new(["Привет мир", "Hello", "你好世界"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).by(Measure::Chars)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str     "
+            "----------"
+            " Приве    "
+            " Hello    "
+            " 你好世界 "
+        )
+    );
+}
+
+#[test]
+fn truncate_by_bytes_suffix() {
+    let table = Table::new(["Привет мир", "Hello", "你好世界"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(7).by(Measure::Bytes).suffix("...")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str  "
+            "-------"
+            " Пр... "
+            " Hello "
+            " 你... "
+        )
+    );
+}
+
+#[test]
+fn truncate_by_bytes_suffix_replace() {
+    let table = Table::new(["Привет мир", "Hello", "你好世界"])
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all()).with(
+                Width::truncate(3)
+                    .by(Measure::Bytes)
+                    .suffix("…")
+                    .suffix_limit(SuffixLimit::Replace('…')),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " … "
+            "---"
+            " … "
+            " … "
+            " … "
+        )
+    );
+}
+
+#[test]
+fn truncate_by_bytes_multiline() {
+    let table = Table::new(["Привет\nмир"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(6).by(Measure::Bytes)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str "
+            "------"
+            " При  "
+            " мир  "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn truncate_by_bytes_colored() {
+    let table = Table::new(["\u{1b}[31mПривет\u{1b}[39m"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).by(Measure::Bytes)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str "
+            "------"
+            " \u{1b}[31mПр\u{1b}[39m   "
+        )
+    );
+}
+
+#[test]
+fn header_wrap() {
+    let mut builder = Builder::default();
+    builder.set_columns(["Identifier", "Amount"]);
+    builder.add_record(["1", "100"]);
+    builder.add_record(["2", "1000000"]);
+    let table = builder.build().with(HeaderWrap::new(5)).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+-------+---------+"
+            "| Ident | Amoun   |"
+            "| ifier | t       |"
+            "+-------+---------+"
+            "| 1     | 100     |"
+            "+-------+---------+"
+            "| 2     | 1000000 |"
+            "+-------+---------+"
+        )
+    );
+}
+
+#[test]
+fn header_wrap_keep_words() {
+    let mut builder = Builder::default();
+    builder.set_columns(["Product code", "Unit price"]);
+    builder.add_record(["A", "B"]);
+    let table = builder
+        .build()
+        .with(HeaderWrap::new(8).keep_words())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+----------+----------+"
+            "| Product  | Unit     |"
+            "| code     | price    |"
+            "+----------+----------+"
+            "| A        | B        |"
+            "+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn header_wrap_data() {
+    let mut builder = Builder::default();
+    builder.set_columns(["Product code", "Description"]);
+    builder.add_record(["X1", "A long description of it"]);
+    let table = builder
+        .build()
+        .with(HeaderWrap::data(12).keep_words())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+--------------+--------------+"
+            "| Product code | Description  |"
+            "+--------------+--------------+"
+            "| X1           | A long       |"
+            "|              | description  |"
+            "|              | of it        |"
+            "+--------------+--------------+"
+        )
+    );
+}
+
+#[test]
+fn header_wrap_without_header() {
+    let mut builder = Builder::default();
+    builder.add_record(["Product code", "Description"]);
+    builder.add_record(["X1", "A very long description"]);
+    let table = builder.build().with(HeaderWrap::new(4)).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+--------------+-------------------------+"
+            "| Product code | Description             |"
+            "+--------------+-------------------------+"
+            "| X1           | A very long description |"
+            "+--------------+-------------------------+"
+        )
+    );
+
+    let mut builder = Builder::default();
+    builder.add_record(["Product code", "Description"]);
+    builder.add_record(["X1", "A very long description"]);
+    let table = builder.build().with(HeaderWrap::data(4)).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+------+------+"
+            "| Prod | Desc |"
+            "| uct  | ript |"
+            "| code | ion  |"
+            "+------+------+"
+            "| X1   | A ve |"
+            "|      | ry l |"
+            "|      | ong  |"
+            "|      | desc |"
+            "|      | ript |"
+            "|      | ion  |"
+            "+------+------+"
+        )
+    );
+}
+
+#[test]
+fn wrap_min_per_column() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(30).min_per_column([Some(3), None, Some(8)]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | colu | column 1 | colu |"
+            "|   | mn 0 |          | mn 2 |"
+            "|---|------|----------|------|"
+            "| 0 | 0-0  |   0-1    | 0-2  |"
+            "| 1 | 1-0  |   1-1    | 1-2  |"
+            "| 2 | 2-0  |   2-1    | 2-2  |"
+        )
+    );
+    assert_eq!(string_width_multiline(&table), 30);
+}
+
+#[test]
+fn wrap_min_per_column_exceeds_width() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(20).min_per_column([None, Some(8), Some(8)]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  | column 0 | column 1 |  |"
+            "|--|----------|----------|--|"
+            "|  |   0-0    |   0-1    |  |"
+            "|  |   1-0    |   1-1    |  |"
+            "|  |   2-0    |   2-1    |  |"
+        )
+    );
+}
+
+#[test]
+fn wrap_min_per_column_bigger_than_column() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(25).min_per_column([Some(100)]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | col | colu | colu |"
+            "|   | umn | mn 1 | mn 2 |"
+            "|   |  0  |      |      |"
+            "|---|-----|------|------|"
+            "| 0 | 0-0 | 0-1  | 0-2  |"
+            "| 1 | 1-0 | 1-1  | 1-2  |"
+            "| 2 | 2-0 | 2-1  | 2-2  |"
+        )
+    );
+}
+
+#[test]
+fn wrap_min_per_column_priority() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(
+            Width::wrap(30)
+                .priority::<PriorityMax>()
+                .min_per_column([None, Some(8)]),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column 0 | colu | colu |"
+            "|   |          | mn 1 | mn 2 |"
+            "|---|----------|------|------|"
+            "| 0 |   0-0    | 0-1  | 0-2  |"
+            "| 1 |   1-0    | 1-1  | 1-2  |"
+            "| 2 |   2-0    | 2-1  | 2-2  |"
+        )
+    );
+}
+
+#[test]
+fn wrap_min_per_column_on_cells() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(3).min_per_column([Some(8)])))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | col | col | col |"
+            "|   | umn | umn | umn |"
+            "|   |  0  |  1  |  2  |"
+            "|---|-----|-----|-----|"
+            "| 0 | 0-0 | 0-1 | 0-2 |"
+            "| 1 | 1-0 | 1-1 | 1-2 |"
+            "| 2 | 2-0 | 2-1 | 2-2 |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate() {
+    let table = Table::new(["An extraordinarily long word"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(8).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str     |"
+            "|----------|"
+            "| An extr- |"
+            "| aordina- |"
+            "| rily     |"
+            "| long     |"
+            "| word     |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate_punctuation() {
+    let table = Table::new(["path/to/some_file-name"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(9).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str      |"
+            "|-----------|"
+            "| path/to/  |"
+            "| some_     |"
+            "| file-name |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate_fills_line() {
+    let table = Table::new(["a b hyphenation"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(8).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str     |"
+            "|----------|"
+            "| a b hyp- |"
+            "| henation |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate_wide_chars() {
+    let table = Table::new(["😳😳😳😳"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(5).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str  |"
+            "|-------|"
+            "| 😳😳- |"
+            "| 😳😳  |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate_multiline() {
+    let table = Table::new(["hyphenation\nis fun"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(6).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str   |"
+            "|--------|"
+            "| hyphe- |"
+            "| nation |"
+            "| is fun |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate_total_width() {
+    let table = Table::new([(1, "configuration_file_name")])
+        .with(Style::markdown())
+        .with(Width::wrap(20).hyphenate().priority::<PriorityMax>())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| i32 | &str       |"
+            "|-----|------------|"
+            "| 1   | configura- |"
+            "|     | tion_file_ |"
+            "|     | name       |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn wrap_hyphenate_color() {
+    let table = Table::new(["\u{1b}[31mhyphenation\u{1b}[39m"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(8).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str     |"
+            "|----------|"
+            "| \u{1b}[31mhyphena\u{1b}[39m\u{1b}[31m-\u{1b}[39m |"
+            "| \u{1b}[31mtion\u{1b}[39m     |"
+        )
+    );
+}
+
+#[test]
+fn truncate_cut_side_left() {
+    let table = Table::new(["123456789", "abc"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).cut_side(CutSide::Left)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str  "
+            "-------"
+            " 56789 "
+            " abc   "
+        )
+    );
+}
+
+#[test]
+fn truncate_cut_side_left_suffix() {
+    let table = Table::new(["123456789"])
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all())
+                .with(Width::truncate(8).suffix("…").cut_side(CutSide::Left)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str     "
+            "----------"
+            " …3456789 "
+        )
+    );
+}
+
+#[test]
+fn truncate_cut_side_auto() {
+    let table = Table::new(["123456789", "abcdefghi"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..2)).with(Alignment::right()))
+        .with(
+            Modify::new(Segment::all())
+                .with(Width::truncate(6).suffix("…").cut_side(CutSide::Auto)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str   "
+            "--------"
+            " …56789 "
+            " abcde… "
+        )
+    );
+}
+
+#[test]
+fn truncate_cut_side_left_wide_chars() {
+    let table = Table::new(["你好世界"])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).cut_side(CutSide::Left)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str "
+            "------"
+            " 世界 "
+        )
+    );
+}
+
+#[test]
+fn truncate_cut_side_total_width() {
+    let table = Table::new(["123456789"])
+        .with(Style::psql())
+        .with(Width::truncate(7).suffix("…").cut_side(CutSide::Left))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str  "
+            "-------"
+            " …6789 "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn truncate_cut_side_left_color() {
+    let table = Table::new(["\u{1b}[31m123\u{1b}[39m456789"])
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all()).with(
+                Width::truncate(8)
+                    .suffix("…")
+                    .suffix_try_color(true)
+                    .cut_side(CutSide::Left),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " &str     "
+            "----------"
+            " \u{1b}[31m…\u{1b}[39m\u{1b}[31m3\u{1b}[39m456789 "
+        )
+    );
+}
+
+#[test]
+fn width_is_measured_again_after_changing_column() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Width::truncate(100))
+        .with(Modify::new(Columns::single(2)).with(Format::new(|s| format!("{} text", s))))
+        .with(Width::truncate(30))
+        .with(Modify::new(Columns::single(0)).with(Padding::new(2, 2, 0, 0)));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+----+------+-----------+------+"
+            "|    | colu | column 1  | colu |"
+            "+----+------+-----------+------+"
+            "|    | 0-0  | 0-1 text  | 0-2  |"
+            "+----+------+-----------+------+"
+            "|    | 1-0  | 1-1 text  | 1-2  |"
+            "+----+------+-----------+------+"
+            "|    | 2-0  | 2-1 text  | 2-2  |"
+            "+----+------+-----------+------+"
+        )
+    );
+}
+
+#[test]
+fn width_is_measured_again_after_changing_records() {
+    let mut table = create_table::<2, 2>();
+    table.with(Width::truncate(100));
+    table.get_records_mut().set(
+        (1, 1),
+        String::from("a long text"),
+        CfgWidthFunction::new(4),
+    );
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+---+-------------+----------+"
+            "| N |  column 0   | column 1 |"
+            "+---+-------------+----------+"
+            "| 0 | a long text |   0-1    |"
+            "+---+-------------+----------+"
+            "| 1 |     1-0     |   1-1    |"
+            "+---+-------------+----------+"
+        )
+    );
+}
+
+#[test]
+fn width_is_measured_again_after_changing_config() {
+    let mut table = create_table::<2, 2>();
+    table.with(Width::truncate(100));
+    table.get_config_mut().set_padding(
+        Entity::Column(1),
+        Sides::new(
+            Indent::spaced(3),
+            Indent::spaced(3),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+---+--------------+----------+"
+            "| N |   column 0   | column 1 |"
+            "+---+--------------+----------+"
+            "| 0 |     0-0      |   0-1    |"
+            "+---+--------------+----------+"
+            "| 1 |     1-0      |   1-1    |"
+            "+---+--------------+----------+"
+        )
+    );
+}
+
+#[test]
+fn width_is_measured_again_after_span() {
+    let mut table = create_table::<2, 2>();
+    table
+        .with(Width::truncate(100))
+        .with(
+            Modify::new(Cell(1, 0))
+                .with(Span::column(2))
+                .with(Format::new(|_| String::from("a long long text"))),
+        )
+        .with(Width::increase(40));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+--------+---------------+-------------+"
+            "|   N    |   column 0    |  column 1   |"
+            "+--------+---------------+-------------+"
+            "|    a long long text    |     0-1     |"
+            "+--------+---------------+-------------+"
+            "|   1    |      1-0      |     1-1     |"
+            "+--------+---------------+-------------+"
+        )
+    );
+}
+
+#[test]
+fn min_width_weights() {
+    let table = create_table::<2, 3>()
+        .with(Style::markdown())
+        .with(Width::increase(50).weights([0, 1, 3]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N |  column 0   |     column 1      | column 2 |"
+            "|---|-------------|-------------------|----------|"
+            "| 0 |     0-0     |        0-1        |   0-2    |"
+            "| 1 |     1-0     |        1-1        |   1-2    |"
+        )
+    );
+    assert_eq!(string_width_multiline(&table), 50);
+}
+
+#[test]
+fn min_width_weights_more_than_columns() {
+    let table = create_table::<1, 1>()
+        .with(Style::markdown())
+        .with(Width::increase(20).weights([1, 1, 1, 1]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  N  |  column 0  |"
+            "|-----|------------|"
+            "|  0  |    0-0     |"
+        )
+    );
+}
+
+#[test]
+fn min_width_zero_weights_use_priority() {
+    let table = create_table::<1, 2>()
+        .with(Style::markdown())
+        .with(
+            Width::increase(30)
+                .weights([0, 0])
+                .priority::<PriorityMax>(),
+        )
+        .to_string();
+
+    let expected = create_table::<1, 2>()
+        .with(Style::markdown())
+        .with(Width::increase(30).priority::<PriorityMax>())
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn wrap_fallback_overflow_is_default() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(20).min_per_column([None, Some(8), Some(8)]))
+        .to_string();
+
+    let expected = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(
+            Width::wrap(20)
+                .min_per_column([None, Some(8), Some(8)])
+                .fallback(LayoutFallback::Overflow),
+        )
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn wrap_fallback_hide_columns() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(
+            Width::wrap(20)
+                .min_per_column([None, Some(8), Some(8)])
+                .fallback(LayoutFallback::HideColumns),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column 0 |"
+            "|---|----------|"
+            "| 0 |   0-0    |"
+            "| 1 |   1-0    |"
+            "| 2 |   2-0    |"
+        )
+    );
+}
+
+#[test]
+fn wrap_fallback_hide_columns_keeps_first_column() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(1).fallback(LayoutFallback::HideColumns))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  |"
+            "|--|"
+            "|  |"
+            "|  |"
+            "|  |"
+        )
+    );
+}
+
+#[test]
+fn wrap_fallback_hide_columns_not_used_when_fits() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(20).fallback(LayoutFallback::HideColumns))
+        .to_string();
+
+    let expected = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::wrap(20))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn wrap_fallback_expanded() {
+    let table = create_table::<2, 2>()
+        .with(Style::markdown())
+        .with(
+            Width::wrap(20)
+                .min_per_column([None, Some(8), Some(8)])
+                .fallback(LayoutFallback::Expanded),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| -[ RECORD 0 ]- |"
+            "| N        | 0   |"
+            "| column 0 | 0-0 |"
+            "| column 1 | 0-1 |"
+            "| -[ RECORD 1 ]- |"
+            "| N        | 1   |"
+            "| column 0 | 1-0 |"
+            "| column 1 | 1-1 |"
+        )
+    );
+}
+
+#[test]
+fn wrap_fallback_expanded_without_header() {
+    let table = Builder::from_iter([["Hello", "World", "!"]])
+        .build()
+        .with(Style::psql())
+        .with(
+            Width::wrap(12)
+                .min_per_column([Some(5), Some(5)])
+                .fallback(LayoutFallback::Expanded),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " -[ RECORD  "
+            " 0 ]-       "
+            " 0 | He     "
+            " llo        "
+            " 1 | Wor    "
+            " ld         "
+            " 2 | !      "
+        )
+    );
+}
+
+#[test]
+fn wrap_fallback_expanded_without_records() {
+    let mut builder = Builder::default();
+    builder.set_columns(["Hello", "World"]);
+
+    let table = builder
+        .build()
+        .with(Style::psql())
+        .with(Width::wrap(4).fallback(LayoutFallback::Expanded))
+        .to_string();
+
+    assert_eq!(table, static_table!("  |  "));
+}
+
+#[test]
+fn truncate_fallback_hide_columns() {
+    let table = create_table::<3, 3>()
+        .with(Style::markdown())
+        .with(Width::truncate(12).fallback(LayoutFallback::HideColumns))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  | c | c |"
+            "|--|---|---|"
+            "|  | 0 | 0 |"
+            "|  | 1 | 1 |"
+            "|  | 2 | 2 |"
+        )
+    );
+}
+
+#[test]
+fn truncate_fallback_expanded() {
+    let table = create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Width::truncate(7).fallback(LayoutFallback::Expanded))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " -[ RE "
+            " N     "
+            " colum "
+            " colum "
+            " -[ RE "
+            " N     "
+            " colum "
+            " colum "
+        )
+    );
+}
+
+/// Records which can be changed but not resized.
+struct FixedRecords(tabled::papergrid::records::vec_records::VecRecords<CellInfo<'static>>);
+
+impl Records for FixedRecords {
+    fn count_rows(&self) -> usize {
+        self.0.count_rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.0.count_columns()
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        self.0.get_text(pos)
+    }
+
+    fn get_line(&self, pos: Position, i: usize) -> &str {
+        self.0.get_line(pos, i)
+    }
+
+    fn count_lines(&self, pos: Position) -> usize {
+        self.0.count_lines(pos)
+    }
+
+    fn get_width<W: WidthFunc>(&self, pos: Position, width_ctrl: W) -> usize {
+        self.0.get_width(pos, width_ctrl)
+    }
+
+    fn get_line_width<W: WidthFunc>(&self, pos: Position, i: usize, width_ctrl: W) -> usize {
+        self.0.get_line_width(pos, i, width_ctrl)
+    }
+
+    fn fmt_text_prefix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result {
+        self.0.fmt_text_prefix(f, pos)
+    }
+
+    fn fmt_text_suffix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result {
+        self.0.fmt_text_suffix(f, pos)
+    }
+}
+
+impl RecordsMut<String> for FixedRecords {
+    fn set<W: WidthFunc>(&mut self, pos: Position, text: String, width_ctrl: W) {
+        self.0.set(pos, text, width_ctrl)
+    }
+
+    fn update<W: WidthFunc>(&mut self, pos: Position, width_ctrl: W) {
+        RecordsMut::<String>::update(&mut self.0, pos, width_ctrl)
+    }
+}
+
+#[test]
+fn wrap_and_truncate_records_without_resize() {
+    let records = || {
+        let table = Builder::from_iter([["Hello", "World"]]).build();
+        let mut table = Table::from(FixedRecords(table.get_records().clone()));
+        table.with(Style::ascii());
+        table
+    };
+
+    let wrapped = records().with(Width::wrap(9)).to_string();
+    let truncated = records().with(Width::truncate(9)).to_string();
+
+    assert_eq!(
+        wrapped,
+        static_table!(
+            "+---+---+"
+            "|Hel|Wor|"
+            "|lo |ld |"
+            "+---+---+"
+        )
+    );
+    assert_eq!(
+        truncated,
+        static_table!(
+            "+---+---+"
+            "|Hel|Wor|"
+            "+---+---+"
+        )
+    );
+}